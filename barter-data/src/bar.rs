@@ -0,0 +1,333 @@
+use crate::{
+    error::DataError,
+    event::MarketEvent,
+    subscription::{candle::Candle, trade::PublicTrade},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Threshold at which a [`BarBuilder`] closes the current bar.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub enum BarMode {
+    /// Close a bar every `trades` [`PublicTrade`]s, which must be greater than zero.
+    TickBars { trades: u64 },
+    /// Close a bar every `volume` of cumulative [`PublicTrade`] amount, which must be finite and
+    /// greater than zero.
+    ///
+    /// A trade crossing the threshold is split, with the leftover amount carried into the next
+    /// bar.
+    VolumeBars { volume: f64 },
+}
+
+/// Aggregates a stream of [`PublicTrade`]s into OHLCV [`Candle`] bars based on trade count or
+/// traded volume, rather than time.
+///
+/// The `close_time` of each [`Candle`] is the time of the trade that closed it, and the
+/// `trade_count` includes any trade split across bars in both bars. Trades with a non-finite
+/// price or amount are skipped.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BarBuilder {
+    pub mode: BarMode,
+    current: Option<Candle>,
+}
+
+impl BarBuilder {
+    /// Construct a new [`BarBuilder`] using the provided [`BarMode`].
+    ///
+    /// Returns a [`DataError::InvalidBarMode`] if the [`BarMode`] threshold is zero, negative or
+    /// non-finite, since bars would otherwise never (or always) close.
+    pub fn new(mode: BarMode) -> Result<Self, DataError> {
+        let valid = match mode {
+            BarMode::TickBars { trades } => trades > 0,
+            BarMode::VolumeBars { volume } => volume.is_finite() && volume > 0.0,
+        };
+
+        if !valid {
+            return Err(DataError::InvalidBarMode(format!("{mode:?}")));
+        }
+
+        Ok(Self {
+            mode,
+            current: None,
+        })
+    }
+
+    /// Update the [`BarBuilder`] with the [`PublicTrade`] contained in the [`MarketEvent`],
+    /// using the `time_exchange` as the trade time.
+    ///
+    /// Returns every bar closed by the trade.
+    pub fn process<InstrumentKey>(
+        &mut self,
+        event: &MarketEvent<InstrumentKey, PublicTrade>,
+    ) -> Vec<Candle> {
+        self.update(event.time_exchange, &event.kind)
+    }
+
+    /// Update the [`BarBuilder`] with a [`PublicTrade`] that occurred at the provided `time`.
+    ///
+    /// Returns every bar closed by the trade, which may be several [`BarMode::VolumeBars`] if
+    /// the trade amount exceeds the volume threshold.
+    pub fn update(&mut self, time: DateTime<Utc>, trade: &PublicTrade) -> Vec<Candle> {
+        let mut closed = Vec::new();
+
+        if !trade.price.is_finite() || !trade.amount.is_finite() {
+            return closed;
+        }
+
+        match self.mode {
+            BarMode::TickBars { trades } => {
+                self.extend(time, trade.price, trade.amount);
+                if self.current.is_some_and(|bar| bar.trade_count >= trades) {
+                    closed.extend(self.current.take());
+                }
+            }
+            BarMode::VolumeBars { volume } => {
+                let mut remaining = trade.amount;
+
+                loop {
+                    let bar_volume = self.current.map_or(0.0, |bar| bar.volume);
+                    let bar_remaining = volume - bar_volume;
+
+                    if remaining < bar_remaining {
+                        self.extend(time, trade.price, remaining);
+                        break;
+                    }
+
+                    // Fill the current bar up to the threshold, carrying any leftover forward
+                    self.extend(time, trade.price, bar_remaining.max(0.0));
+                    closed.extend(self.current.take());
+                    remaining -= bar_remaining.max(0.0);
+
+                    if remaining <= 0.0 || bar_remaining <= 0.0 {
+                        break;
+                    }
+                }
+            }
+        }
+
+        closed
+    }
+
+    /// Returns the current partially built bar, or `None` if the last trade closed a bar.
+    pub fn current(&self) -> Option<&Candle> {
+        self.current.as_ref()
+    }
+
+    /// Add a trade `amount` at the provided `price` to the current bar, opening a new bar if
+    /// required.
+    fn extend(&mut self, time: DateTime<Utc>, price: f64, amount: f64) {
+        match &mut self.current {
+            Some(bar) => {
+                bar.close_time = time;
+                bar.high = bar.high.max(price);
+                bar.low = bar.low.min(price);
+                bar.close = price;
+                bar.volume += amount;
+                bar.trade_count += 1;
+            }
+            None => {
+                self.current = Some(Candle {
+                    close_time: time,
+                    open: price,
+                    high: price,
+                    low: price,
+                    close: price,
+                    volume: amount,
+                    trade_count: 1,
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_instrument::Side;
+
+    fn trade(price: f64, amount: f64) -> PublicTrade {
+        PublicTrade {
+            id: "id".to_string(),
+            price,
+            amount,
+            side: Side::Buy,
+        }
+    }
+
+    fn time(secs: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(secs, 0).unwrap()
+    }
+
+    fn candle(
+        close_secs: i64,
+        [open, high, low, close]: [f64; 4],
+        volume: f64,
+        trade_count: u64,
+    ) -> Candle {
+        Candle {
+            close_time: time(close_secs),
+            open,
+            high,
+            low,
+            close,
+            volume,
+            trade_count,
+        }
+    }
+
+    #[test]
+    fn test_bar_builder() {
+        struct TestCase {
+            mode: BarMode,
+            trades: Vec<(i64, PublicTrade)>,
+            expected_closed: Vec<Candle>,
+            expected_current: Option<Candle>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: tick bar of size 3 closes on every third trade
+                mode: BarMode::TickBars { trades: 3 },
+                trades: vec![
+                    (1, trade(100.0, 1.0)),
+                    (2, trade(105.0, 2.0)),
+                    (3, trade(95.0, 1.0)),
+                    (4, trade(101.0, 0.5)),
+                    (5, trade(102.0, 0.5)),
+                    (6, trade(99.0, 1.5)),
+                    (7, trade(98.0, 1.0)),
+                ],
+                expected_closed: vec![
+                    candle(3, [100.0, 105.0, 95.0, 95.0], 4.0, 3),
+                    candle(6, [101.0, 102.0, 99.0, 99.0], 2.5, 3),
+                ],
+                expected_current: Some(candle(7, [98.0, 98.0, 98.0, 98.0], 1.0, 1)),
+            },
+            TestCase {
+                // TC1: volume bar closes mid-trade, carrying the leftover into the next bar
+                mode: BarMode::VolumeBars { volume: 5.0 },
+                trades: vec![
+                    (1, trade(100.0, 2.0)),
+                    (2, trade(102.0, 2.0)),
+                    (3, trade(101.0, 3.0)),
+                ],
+                expected_closed: vec![candle(3, [100.0, 102.0, 100.0, 101.0], 5.0, 3)],
+                expected_current: Some(candle(3, [101.0, 101.0, 101.0, 101.0], 2.0, 1)),
+            },
+            TestCase {
+                // TC2: volume bar closes exactly on the threshold without opening a new bar
+                mode: BarMode::VolumeBars { volume: 5.0 },
+                trades: vec![(1, trade(100.0, 2.0)), (2, trade(99.0, 3.0))],
+                expected_closed: vec![candle(2, [100.0, 100.0, 99.0, 99.0], 5.0, 2)],
+                expected_current: None,
+            },
+            TestCase {
+                // TC3: large trade closes several volume bars
+                mode: BarMode::VolumeBars { volume: 5.0 },
+                trades: vec![(1, trade(100.0, 4.0)), (2, trade(110.0, 12.0))],
+                expected_closed: vec![
+                    candle(2, [100.0, 110.0, 100.0, 110.0], 5.0, 2),
+                    candle(2, [110.0, 110.0, 110.0, 110.0], 5.0, 1),
+                    candle(2, [110.0, 110.0, 110.0, 110.0], 5.0, 1),
+                ],
+                expected_current: Some(candle(2, [110.0, 110.0, 110.0, 110.0], 1.0, 1)),
+            },
+            TestCase {
+                // TC4: volume bar skips trades with a non-finite price or amount
+                mode: BarMode::VolumeBars { volume: 5.0 },
+                trades: vec![
+                    (1, trade(100.0, 2.0)),
+                    (2, trade(101.0, f64::NAN)),
+                    (3, trade(102.0, f64::INFINITY)),
+                    (4, trade(f64::NAN, 1.0)),
+                    (5, trade(99.0, 3.0)),
+                ],
+                expected_closed: vec![candle(5, [100.0, 100.0, 99.0, 99.0], 5.0, 2)],
+                expected_current: None,
+            },
+            TestCase {
+                // TC5: tick bar skips trades with a non-finite amount
+                mode: BarMode::TickBars { trades: 2 },
+                trades: vec![(1, trade(100.0, 1.0)), (2, trade(101.0, f64::NAN))],
+                expected_closed: vec![],
+                expected_current: Some(candle(1, [100.0, 100.0, 100.0, 100.0], 1.0, 1)),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut builder = BarBuilder::new(test.mode).unwrap();
+
+            let closed = test
+                .trades
+                .iter()
+                .flat_map(|(secs, trade)| builder.update(time(*secs), trade))
+                .collect::<Vec<_>>();
+
+            assert_eq!(closed, test.expected_closed, "TC{index} failed");
+            assert_eq!(
+                builder.current(),
+                test.expected_current.as_ref(),
+                "TC{index} failed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_bar_builder_new() {
+        struct TestCase {
+            mode: BarMode,
+            expected_ok: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: valid tick bars
+                mode: BarMode::TickBars { trades: 1 },
+                expected_ok: true,
+            },
+            TestCase {
+                // TC1: zero trade tick bars are rejected
+                mode: BarMode::TickBars { trades: 0 },
+                expected_ok: false,
+            },
+            TestCase {
+                // TC2: valid volume bars
+                mode: BarMode::VolumeBars { volume: 0.5 },
+                expected_ok: true,
+            },
+            TestCase {
+                // TC3: zero volume bars are rejected
+                mode: BarMode::VolumeBars { volume: 0.0 },
+                expected_ok: false,
+            },
+            TestCase {
+                // TC4: negative volume bars are rejected
+                mode: BarMode::VolumeBars { volume: -1.0 },
+                expected_ok: false,
+            },
+            TestCase {
+                // TC5: NaN volume bars are rejected
+                mode: BarMode::VolumeBars { volume: f64::NAN },
+                expected_ok: false,
+            },
+            TestCase {
+                // TC6: infinite volume bars are rejected
+                mode: BarMode::VolumeBars {
+                    volume: f64::INFINITY,
+                },
+                expected_ok: false,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = BarBuilder::new(test.mode);
+            assert_eq!(actual.is_ok(), test.expected_ok, "TC{index} failed");
+            if let Err(error) = actual {
+                assert!(
+                    matches!(error, DataError::InvalidBarMode(_)),
+                    "TC{index} failed"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,603 @@
+use crate::books::{
+    Level, OrderBook,
+    map::{OrderBookMap, OrderBookMapMulti},
+};
+use barter_instrument::{Side, exchange::ExchangeId};
+use chrono::{DateTime, TimeDelta, Utc};
+use fnv::FnvHashMap;
+use parking_lot::RwLock;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::{hash::Hash, sync::Arc};
+
+/// Aggregates the shared-state [`OrderBookMap`] of each exchange, enabling the
+/// [`OrderBook`](super::OrderBook)s for the same instrument to be compared across exchanges.
+#[derive(Debug, Clone)]
+pub struct OrderBookAggregator<Map>
+where
+    Map: OrderBookMap,
+{
+    pub exchanges: FnvHashMap<ExchangeId, Map>,
+
+    /// Optional time-to-live after which an instrument [`OrderBook`] without updates is evicted
+    /// by [`OrderBookAggregator::evict_expired`].
+    pub ttl: Option<TimeDelta>,
+
+    /// [`SpreadEma`] of each instrument, maintained by [`OrderBookAggregator::spread_ema`].
+    pub spread_emas: FnvHashMap<Map::Key, SpreadEma>,
+}
+
+impl<Map> Default for OrderBookAggregator<Map>
+where
+    Map: OrderBookMap,
+{
+    fn default() -> Self {
+        Self::new(FnvHashMap::default())
+    }
+}
+
+impl<Map> OrderBookAggregator<Map>
+where
+    Map: OrderBookMap,
+{
+    /// Construct a new [`OrderBookAggregator`] from the [`OrderBookMap`] of each exchange,
+    /// without an eviction time-to-live.
+    pub fn new(exchanges: FnvHashMap<ExchangeId, Map>) -> Self {
+        Self {
+            exchanges,
+            ttl: None,
+            spread_emas: FnvHashMap::default(),
+        }
+    }
+
+    /// Set the time-to-live after which an instrument [`OrderBook`] without updates is evicted
+    /// by [`OrderBookAggregator::evict_expired`].
+    pub fn with_ttl(self, ttl: TimeDelta) -> Self {
+        Self {
+            ttl: Some(ttl),
+            ..self
+        }
+    }
+}
+
+impl<Map> OrderBookAggregator<Map>
+where
+    Map: OrderBookMap,
+{
+    /// Insert the [`OrderBookMap`] for the provided exchange into the [`OrderBookAggregator`].
+    pub fn insert(&mut self, exchange: ExchangeId, books: Map) {
+        self.exchanges.insert(exchange, books);
+    }
+
+    /// Determine the exchange offering the best average execution price for immediately
+    /// filling the provided `quantity` of the instrument.
+    ///
+    /// For a [`Side::Buy`] this is the lowest average price, and for a [`Side::Sell`] the
+    /// highest. Exchanges without enough liquidity to fill the entire `quantity` are skipped.
+    ///
+    /// Returns the chosen [`ExchangeId`] and its average execution price.
+    pub fn best_venue(
+        &self,
+        instrument: &Map::Key,
+        side: Side,
+        quantity: Decimal,
+    ) -> Option<(ExchangeId, Decimal)> {
+        if quantity <= Decimal::ZERO {
+            return None;
+        }
+
+        self.exchanges
+            .iter()
+            .filter_map(|(exchange, books)| {
+                let book = books.find(instrument)?;
+                let cost = book.read().cost_to_fill(side, quantity)?;
+                Some((*exchange, cost / quantity))
+            })
+            .min_by(|(exchange_a, price_a), (exchange_b, price_b)| {
+                let by_price = match side {
+                    Side::Buy => price_a.cmp(price_b),
+                    Side::Sell => price_b.cmp(price_a),
+                };
+
+                // Break ties deterministically, since map iteration order is arbitrary
+                by_price.then_with(|| exchange_a.cmp(exchange_b))
+            })
+    }
+
+    /// Determine the highest bid [`Level`] for the instrument across every exchange.
+    pub fn best_bid(&self, instrument: &Map::Key) -> Option<(ExchangeId, Level)> {
+        self.best_level(instrument, |book| book.bids().levels().first().copied())
+            .max_by(|(exchange_a, level_a), (exchange_b, level_b)| {
+                level_a
+                    .price
+                    .cmp(&level_b.price)
+                    .then_with(|| exchange_b.cmp(exchange_a))
+            })
+    }
+
+    /// Determine the lowest ask [`Level`] for the instrument across every exchange.
+    pub fn best_ask(&self, instrument: &Map::Key) -> Option<(ExchangeId, Level)> {
+        self.best_level(instrument, |book| book.asks().levels().first().copied())
+            .min_by(|(exchange_a, level_a), (exchange_b, level_b)| {
+                level_a
+                    .price
+                    .cmp(&level_b.price)
+                    .then_with(|| exchange_a.cmp(exchange_b))
+            })
+    }
+
+    /// Update and return the exponential moving average of the instrument's aggregated spread
+    /// (lowest ask minus highest bid across every exchange), smoothed by `alpha` in (0, 1].
+    ///
+    /// The [`SpreadEma`] is only updated if an instrument [`OrderBook`] has changed since the
+    /// previous call, and is seeded by the first observed spread.
+    ///
+    /// Returns `None` if no exchange has both a bid and an ask for the instrument, and no spread
+    /// has been observed yet.
+    pub fn spread_ema(&mut self, instrument: &Map::Key, alpha: Decimal) -> Option<Decimal>
+    where
+        Map::Key: Clone + Eq + Hash,
+    {
+        let mut sequences = self
+            .exchanges
+            .iter()
+            .filter_map(|(exchange, books)| {
+                let book = books.find(instrument)?;
+                let sequence = book.read().sequence;
+                Some((*exchange, sequence))
+            })
+            .collect::<Vec<_>>();
+        sequences.sort_unstable();
+
+        let spread = self
+            .best_ask(instrument)
+            .zip(self.best_bid(instrument))
+            .map(|((_, ask), (_, bid))| ask.price - bid.price);
+
+        let Some(spread) = spread else {
+            return self.spread_emas.get(instrument)?.value;
+        };
+
+        let ema = self.spread_emas.entry(instrument.clone()).or_default();
+        if ema.value.is_none() || ema.sequences != sequences {
+            ema.update(spread, alpha);
+            ema.sequences = sequences;
+        }
+
+        ema.value
+    }
+
+    fn best_level<'a, FnLevel>(
+        &'a self,
+        instrument: &'a Map::Key,
+        fn_level: FnLevel,
+    ) -> impl Iterator<Item = (ExchangeId, Level)> + 'a
+    where
+        FnLevel: Fn(&OrderBook) -> Option<Level> + 'a,
+    {
+        self.exchanges.iter().filter_map(move |(exchange, books)| {
+            let book = books.find(instrument)?;
+            let level = fn_level(&book.read())?;
+            Some((*exchange, level))
+        })
+    }
+
+    /// Export a serialisable [`AggregatorState`] checkpoint containing a copy of every
+    /// [`OrderBook`], keyed by exchange and instrument.
+    ///
+    /// Restore the checkpoint using [`OrderBookAggregator::import_state`].
+    pub fn export_state(&self) -> AggregatorState<Map::Key>
+    where
+        Map::Key: Clone,
+    {
+        let books = self
+            .exchanges
+            .iter()
+            .flat_map(|(exchange, books)| {
+                books.keys().filter_map(|instrument| {
+                    let book = books.find(instrument)?.read().clone();
+                    Some(AggregatorBookState {
+                        exchange: *exchange,
+                        instrument: instrument.clone(),
+                        book,
+                    })
+                })
+            })
+            .collect();
+
+        AggregatorState { books }
+    }
+}
+
+impl<Key> OrderBookAggregator<OrderBookMapMulti<Key>>
+where
+    Key: Clone + Eq + Hash,
+{
+    /// Remove the [`OrderBook`] of the provided exchange instrument, returning it if present.
+    ///
+    /// An exchange is removed entirely once it has no remaining instruments.
+    ///
+    /// Note this only removes the [`OrderBook`] from this [`OrderBookAggregator`], clones of the
+    /// [`OrderBookMap`] (eg/ held by an order book manager) are unaffected.
+    pub fn remove_instrument(
+        &mut self,
+        exchange: ExchangeId,
+        instrument: &Key,
+    ) -> Option<Arc<RwLock<OrderBook>>> {
+        let books = self.exchanges.get_mut(&exchange)?;
+        let removed = books.remove(instrument);
+
+        if books.books.is_empty() {
+            self.exchanges.remove(&exchange);
+        }
+
+        removed
+    }
+
+    /// Evict every instrument [`OrderBook`] that has not been updated within the configured
+    /// time-to-live of the provided `now`, returning the evicted exchange instruments.
+    ///
+    /// Does nothing if no time-to-live is configured. [`OrderBook`]s without a `time_engine`
+    /// have never been updated with a timestamp, so are never evicted.
+    pub fn evict_expired(&mut self, now: DateTime<Utc>) -> Vec<(ExchangeId, Key)> {
+        let Some(ttl) = self.ttl else {
+            return Vec::new();
+        };
+
+        let expired = self
+            .exchanges
+            .iter()
+            .flat_map(|(exchange, books)| {
+                books.books.iter().filter_map(move |(instrument, book)| {
+                    let time_engine = book.read().time_engine?;
+                    (now - time_engine > ttl).then(|| (*exchange, instrument.clone()))
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for (exchange, instrument) in &expired {
+            self.remove_instrument(*exchange, instrument);
+        }
+
+        expired
+    }
+
+    /// Restore every [`OrderBook`] contained in an [`AggregatorState`] checkpoint.
+    ///
+    /// Existing shared-state [`OrderBook`]s are overwritten in place, so any clones of the
+    /// [`OrderBookMap`] observe the restored state.
+    pub fn import_state(&mut self, state: AggregatorState<Key>) {
+        for AggregatorBookState {
+            exchange,
+            instrument,
+            book,
+        } in state.books
+        {
+            let books = self
+                .exchanges
+                .entry(exchange)
+                .or_insert_with(|| OrderBookMapMulti::new(FnvHashMap::default()));
+
+            match books.find(&instrument) {
+                Some(existing) => *existing.write() = book,
+                None => books.insert(instrument, Arc::new(RwLock::new(book))),
+            }
+        }
+    }
+}
+
+/// Exponential moving average of an instrument's aggregated spread, along with the
+/// [`OrderBook`] sequence of each exchange it was last updated from.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub struct SpreadEma {
+    pub value: Option<Decimal>,
+    pub sequences: Vec<(ExchangeId, u64)>,
+}
+
+impl SpreadEma {
+    /// Update the [`SpreadEma`] with the latest spread observation, returning the new smoothed
+    /// spread.
+    ///
+    /// The first observation seeds the average.
+    pub fn update(&mut self, spread: Decimal, alpha: Decimal) -> Decimal {
+        let value = match self.value {
+            Some(previous) => alpha * spread + (Decimal::ONE - alpha) * previous,
+            None => spread,
+        };
+        self.value = Some(value);
+        value
+    }
+}
+
+/// Serialisable checkpoint of every [`OrderBook`] held by an [`OrderBookAggregator`].
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize, Serialize)]
+pub struct AggregatorState<InstrumentKey> {
+    pub books: Vec<AggregatorBookState<InstrumentKey>>,
+}
+
+/// [`OrderBook`] of a single exchange instrument contained in an [`AggregatorState`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct AggregatorBookState<InstrumentKey> {
+    pub exchange: ExchangeId,
+    pub instrument: InstrumentKey,
+    pub book: OrderBook,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::books::map::OrderBookMapSingle;
+    use rust_decimal_macros::dec;
+
+    fn books(bids: Vec<Level>, asks: Vec<Level>) -> OrderBookMapSingle<&'static str> {
+        OrderBookMapSingle::new(
+            "btc_usdt",
+            Arc::new(RwLock::new(OrderBook::new(0, None, bids, asks))),
+        )
+    }
+
+    #[test]
+    fn test_best_venue() {
+        struct TestCase {
+            side: Side,
+            quantity: Decimal,
+            expected: Option<(ExchangeId, Decimal)>,
+        }
+
+        // BinanceSpot: best price but shallow, Okx: worse top of book but deeper liquidity
+        let aggregator = OrderBookAggregator::new(FnvHashMap::from_iter([
+            (
+                ExchangeId::BinanceSpot,
+                books(
+                    vec![
+                        Level::new(dec!(100), dec!(1)),
+                        Level::new(dec!(90), dec!(10)),
+                    ],
+                    vec![
+                        Level::new(dec!(101), dec!(1)),
+                        Level::new(dec!(112), dec!(10)),
+                    ],
+                ),
+            ),
+            (
+                ExchangeId::Okx,
+                books(
+                    vec![Level::new(dec!(99), dec!(10))],
+                    vec![Level::new(dec!(102), dec!(10))],
+                ),
+            ),
+        ]));
+
+        let tests = vec![
+            TestCase {
+                // TC0: small buy is cheapest on the venue with the best ask
+                side: Side::Buy,
+                quantity: dec!(1),
+                expected: Some((ExchangeId::BinanceSpot, dec!(101))),
+            },
+            TestCase {
+                // TC1: large buy is cheapest on the venue with deeper liquidity
+                side: Side::Buy,
+                quantity: dec!(2),
+                expected: Some((ExchangeId::Okx, dec!(102))),
+            },
+            TestCase {
+                // TC2: small sell is best on the venue with the best bid
+                side: Side::Sell,
+                quantity: dec!(1),
+                expected: Some((ExchangeId::BinanceSpot, dec!(100))),
+            },
+            TestCase {
+                // TC3: large sell is best on the venue with deeper liquidity
+                side: Side::Sell,
+                quantity: dec!(5),
+                expected: Some((ExchangeId::Okx, dec!(99))),
+            },
+            TestCase {
+                // TC4: only one venue has enough liquidity
+                side: Side::Buy,
+                quantity: dec!(11),
+                expected: Some((ExchangeId::BinanceSpot, dec!(111))),
+            },
+            TestCase {
+                // TC5: no venue has enough liquidity
+                side: Side::Sell,
+                quantity: dec!(20),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = aggregator.best_venue(&"btc_usdt", test.side, test.quantity);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_remove_instrument_and_evict_expired() {
+        let time_base = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+        let book = |secs: Option<i64>| {
+            let time_engine = secs.map(|secs| time_base + TimeDelta::seconds(secs));
+            Arc::new(RwLock::new(OrderBook::new(
+                0,
+                time_engine,
+                vec![Level::new(dec!(100), dec!(1))],
+                vec![Level::new(dec!(101), dec!(1))],
+            )))
+        };
+
+        let mut aggregator = OrderBookAggregator::new(FnvHashMap::from_iter([
+            (
+                ExchangeId::BinanceSpot,
+                OrderBookMapMulti::new(FnvHashMap::from_iter([
+                    ("btc_usdt", book(Some(0))),
+                    ("eth_usdt", book(Some(0))),
+                    ("sol_usdt", book(None)),
+                ])),
+            ),
+            (
+                ExchangeId::Okx,
+                OrderBookMapMulti::new(FnvHashMap::from_iter([("btc_usdt", book(Some(0)))])),
+            ),
+        ]))
+        .with_ttl(TimeDelta::seconds(60));
+
+        // Explicitly remove an instrument
+        assert!(
+            aggregator
+                .remove_instrument(ExchangeId::BinanceSpot, &"eth_usdt")
+                .is_some()
+        );
+        assert!(
+            aggregator
+                .remove_instrument(ExchangeId::BinanceSpot, &"eth_usdt")
+                .is_none()
+        );
+        assert!(aggregator.best_bid(&"eth_usdt").is_none());
+
+        // Only the Okx btc_usdt OrderBook receives a recent update
+        aggregator.exchanges[&ExchangeId::Okx]
+            .find(&"btc_usdt")
+            .unwrap()
+            .write()
+            .time_engine = Some(time_base + TimeDelta::seconds(100));
+
+        // Nothing is stale yet
+        assert!(
+            aggregator
+                .evict_expired(time_base + TimeDelta::seconds(60))
+                .is_empty()
+        );
+
+        // Stale BinanceSpot btc_usdt is evicted, active Okx btc_usdt is kept
+        assert_eq!(
+            aggregator.evict_expired(time_base + TimeDelta::seconds(120)),
+            vec![(ExchangeId::BinanceSpot, "btc_usdt")]
+        );
+        assert_eq!(
+            aggregator.best_bid(&"btc_usdt"),
+            Some((ExchangeId::Okx, Level::new(dec!(100), dec!(1))))
+        );
+
+        // OrderBook without a time_engine is never evicted
+        assert!(
+            aggregator.exchanges[&ExchangeId::BinanceSpot]
+                .find(&"sol_usdt")
+                .is_some()
+        );
+
+        // Exchange is removed once it has no remaining instruments
+        aggregator.remove_instrument(ExchangeId::BinanceSpot, &"sol_usdt");
+        assert!(!aggregator.exchanges.contains_key(&ExchangeId::BinanceSpot));
+    }
+
+    #[test]
+    fn test_export_import_state() {
+        fn books(
+            instrument: &'static str,
+            bids: Vec<Level>,
+            asks: Vec<Level>,
+        ) -> (&'static str, Arc<RwLock<OrderBook>>) {
+            (
+                instrument,
+                Arc::new(RwLock::new(OrderBook::new(1, None, bids, asks))),
+            )
+        }
+
+        let aggregator = OrderBookAggregator::new(FnvHashMap::from_iter([
+            (
+                ExchangeId::BinanceSpot,
+                OrderBookMapMulti::new(FnvHashMap::from_iter([
+                    books(
+                        "btc_usdt",
+                        vec![Level::new(dec!(100), dec!(1))],
+                        vec![Level::new(dec!(103), dec!(1))],
+                    ),
+                    books(
+                        "eth_usdt",
+                        vec![Level::new(dec!(10), dec!(1))],
+                        vec![Level::new(dec!(11), dec!(1))],
+                    ),
+                ])),
+            ),
+            (
+                ExchangeId::Okx,
+                OrderBookMapMulti::new(FnvHashMap::from_iter([books(
+                    "btc_usdt",
+                    vec![Level::new(dec!(99), dec!(2))],
+                    vec![Level::new(dec!(102), dec!(2))],
+                )])),
+            ),
+        ]));
+
+        // Checkpoint via serialisation, as if persisted to disk before a crash
+        let checkpoint = serde_json::to_string(&aggregator.export_state()).unwrap();
+        let state = serde_json::from_str::<AggregatorState<String>>(&checkpoint).unwrap();
+
+        let mut restored = OrderBookAggregator::<OrderBookMapMulti<String>>::default();
+        restored.import_state(state);
+
+        for instrument in ["btc_usdt", "eth_usdt"] {
+            assert_eq!(
+                restored.best_bid(&instrument.to_string()),
+                aggregator.best_bid(&instrument),
+                "{instrument} best_bid mismatch"
+            );
+            assert_eq!(
+                restored.best_ask(&instrument.to_string()),
+                aggregator.best_ask(&instrument),
+                "{instrument} best_ask mismatch"
+            );
+        }
+
+        assert_eq!(
+            restored.best_bid(&"btc_usdt".to_string()),
+            Some((ExchangeId::BinanceSpot, Level::new(dec!(100), dec!(1))))
+        );
+        assert_eq!(
+            restored.best_ask(&"btc_usdt".to_string()),
+            Some((ExchangeId::Okx, Level::new(dec!(102), dec!(2))))
+        );
+    }
+
+    #[test]
+    fn test_spread_ema() {
+        let book = Arc::new(RwLock::new(OrderBook::new(
+            0,
+            None,
+            vec![Level::new(dec!(100), dec!(1))],
+            vec![Level::new(dec!(110), dec!(1))],
+        )));
+        let mut aggregator = OrderBookAggregator::new(FnvHashMap::from_iter([(
+            ExchangeId::BinanceSpot,
+            OrderBookMapSingle::new("btc_usdt", Arc::clone(&book)),
+        )]));
+        let alpha = dec!(0.5);
+
+        // First observation seeds the EMA
+        assert_eq!(aggregator.spread_ema(&"btc_usdt", alpha), Some(dec!(10)));
+
+        // Unchanged OrderBook does not update the EMA
+        assert_eq!(aggregator.spread_ema(&"btc_usdt", alpha), Some(dec!(10)));
+
+        // Each OrderBook change moves the EMA toward the new steady spread of 2
+        let mut previous_error = dec!(8);
+        for sequence in 1..=20 {
+            *book.write() = OrderBook::new(
+                sequence,
+                None,
+                vec![Level::new(dec!(100), dec!(1))],
+                vec![Level::new(dec!(102), dec!(1))],
+            );
+
+            let ema = aggregator.spread_ema(&"btc_usdt", alpha).unwrap();
+            let error = ema - dec!(2);
+            assert!(error > Decimal::ZERO && error < previous_error);
+            previous_error = error;
+        }
+        assert!(previous_error < dec!(0.0001));
+
+        // Unknown instrument has no spread
+        assert_eq!(aggregator.spread_ema(&"eth_usdt", alpha), None);
+    }
+}
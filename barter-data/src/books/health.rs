@@ -0,0 +1,213 @@
+use crate::{event::MarketEvent, subscription::book::OrderBookEvent};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::metric::{Field, Metric, Tag};
+use chrono::{DateTime, TimeDelta, Utc};
+use fnv::FnvHashMap;
+use std::{fmt::Display, hash::Hash};
+
+/// [`Metric`] name used for emitted [`OrderBookHealthMonitor`] metrics.
+pub const METRIC_ORDER_BOOK_HEALTH: &str = "order_book_health";
+
+/// Monitors the health of L2 [`OrderBook`](super::OrderBook) feeds by consuming
+/// [`OrderBookEvent`]s, periodically emitting a [`Metric`] for each exchange instrument.
+///
+/// Each emitted [`Metric`] is tagged by `exchange` and `instrument`, and contains the fields:
+/// - `latency_ms`: Latest update latency (`time_received - time_exchange`) in milliseconds.
+/// - `updates_per_sec`: Number of updates per second observed since the last emitted metric.
+/// - `sequence`: Latest [`OrderBook`](super::OrderBook) sequence.
+///
+/// Useful for detecting lagging or stale feeds in production.
+#[derive(Debug, Clone)]
+pub struct OrderBookHealthMonitor<InstrumentKey> {
+    /// Minimum interval between emitted [`Metric`]s for each exchange instrument.
+    pub interval: TimeDelta,
+    books: FnvHashMap<(ExchangeId, InstrumentKey), OrderBookHealth>,
+}
+
+/// Health statistics of a single exchange instrument [`OrderBook`](super::OrderBook) feed.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct OrderBookHealth {
+    /// Start of the current measurement window.
+    pub time_window_start: DateTime<Utc>,
+
+    /// Latest update `time_received` observed.
+    pub time_last_update: DateTime<Utc>,
+
+    /// Number of updates observed in the current measurement window.
+    pub updates: u64,
+
+    /// Latest update latency (`time_received - time_exchange`).
+    pub latency: TimeDelta,
+
+    /// Latest [`OrderBook`](super::OrderBook) sequence.
+    pub sequence: u64,
+}
+
+impl<InstrumentKey> OrderBookHealthMonitor<InstrumentKey>
+where
+    InstrumentKey: Clone + Eq + Hash + Display,
+{
+    /// Construct a new [`OrderBookHealthMonitor`] that emits [`Metric`]s at the provided
+    /// `interval`.
+    pub fn new(interval: TimeDelta) -> Self {
+        Self {
+            interval,
+            books: FnvHashMap::default(),
+        }
+    }
+
+    /// Returns the current [`OrderBookHealth`] of the provided exchange instrument, if any
+    /// updates have been observed.
+    pub fn health(
+        &self,
+        exchange: ExchangeId,
+        instrument: &InstrumentKey,
+    ) -> Option<&OrderBookHealth> {
+        self.books.get(&(exchange, instrument.clone()))
+    }
+
+    /// Update the [`OrderBookHealth`] associated with the [`MarketEvent`], returning a
+    /// [`Metric`] if the `interval` has elapsed since the last one was emitted.
+    pub fn process(
+        &mut self,
+        event: &MarketEvent<InstrumentKey, OrderBookEvent>,
+    ) -> Option<Metric> {
+        let sequence = match &event.kind {
+            OrderBookEvent::Snapshot(book) | OrderBookEvent::Update(book) => book.sequence,
+        };
+        let latency = event
+            .time_received
+            .signed_duration_since(event.time_exchange);
+
+        let health = self
+            .books
+            .entry((event.exchange, event.instrument.clone()))
+            .or_insert_with(|| OrderBookHealth {
+                time_window_start: event.time_received,
+                time_last_update: event.time_received,
+                updates: 0,
+                latency,
+                sequence,
+            });
+
+        health.time_last_update = event.time_received;
+        health.updates += 1;
+        health.latency = latency;
+        health.sequence = sequence;
+
+        let window = event
+            .time_received
+            .signed_duration_since(health.time_window_start);
+
+        if window < self.interval || window <= TimeDelta::zero() {
+            return None;
+        }
+
+        let updates_per_sec = health.updates as f64 / (window.num_milliseconds() as f64 / 1000.0);
+
+        let metric = Metric {
+            name: METRIC_ORDER_BOOK_HEALTH,
+            time: event.time_received.timestamp_millis() as u64,
+            tags: vec![
+                Tag::new("exchange", event.exchange.as_str()),
+                Tag::new("instrument", event.instrument.to_string()),
+            ],
+            fields: vec![
+                Field::new("latency_ms", latency.num_milliseconds()),
+                Field::new("updates_per_sec", updates_per_sec),
+                Field::new("sequence", sequence),
+            ],
+        };
+
+        health.time_window_start = event.time_received;
+        health.updates = 0;
+
+        Some(metric)
+    }
+
+    /// Returns the exchange instruments that have not received an update within the provided
+    /// `threshold` of the provided `time_now`.
+    pub fn stale(
+        &self,
+        time_now: DateTime<Utc>,
+        threshold: TimeDelta,
+    ) -> impl Iterator<Item = &(ExchangeId, InstrumentKey)> {
+        self.books.iter().filter_map(move |(key, health)| {
+            (time_now.signed_duration_since(health.time_last_update) > threshold).then_some(key)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::books::{Level, OrderBook};
+
+    fn event(
+        time_exchange: DateTime<Utc>,
+        latency_ms: i64,
+        sequence: u64,
+    ) -> MarketEvent<&'static str, OrderBookEvent> {
+        MarketEvent {
+            time_exchange,
+            time_received: time_exchange + TimeDelta::milliseconds(latency_ms),
+            exchange: ExchangeId::BinanceSpot,
+            instrument: "btc_usdt",
+            kind: OrderBookEvent::Update(OrderBook::new::<_, _, Level>(
+                sequence,
+                None,
+                vec![],
+                vec![],
+            )),
+        }
+    }
+
+    #[test]
+    fn test_order_book_health_monitor_emits_metrics() {
+        let mut monitor = OrderBookHealthMonitor::new(TimeDelta::seconds(1));
+        let base = DateTime::from_timestamp_millis(1_700_000_000_000).unwrap();
+
+        // Updates within the interval do not emit a Metric
+        for (offset_ms, sequence) in [(0, 1), (250, 2), (500, 3), (750, 4)] {
+            let metric = monitor.process(&event(
+                base + TimeDelta::milliseconds(offset_ms),
+                10,
+                sequence,
+            ));
+            assert!(metric.is_none());
+        }
+
+        // Update after the interval emits a Metric for the window
+        let metric = monitor
+            .process(&event(base + TimeDelta::milliseconds(985), 25, 5))
+            .unwrap();
+
+        assert_eq!(metric.name, METRIC_ORDER_BOOK_HEALTH);
+        assert_eq!(
+            metric.time,
+            (base + TimeDelta::milliseconds(1010)).timestamp_millis() as u64
+        );
+        assert_eq!(
+            metric.tags,
+            vec![
+                Tag::new("exchange", "binance_spot"),
+                Tag::new("instrument", "btc_usdt"),
+            ]
+        );
+        assert_eq!(metric.fields[0], Field::new("latency_ms", 25i64));
+        assert_eq!(metric.fields[1], Field::new("updates_per_sec", 5.0));
+        assert_eq!(metric.fields[2], Field::new("sequence", 5u64));
+
+        // Window is reset after a Metric is emitted
+        let health = monitor
+            .health(ExchangeId::BinanceSpot, &"btc_usdt")
+            .unwrap();
+        assert_eq!(health.updates, 0);
+        assert_eq!(health.sequence, 5);
+
+        // Feed is stale if no updates are received within the threshold
+        let time_now = base + TimeDelta::seconds(10);
+        assert_eq!(monitor.stale(time_now, TimeDelta::seconds(5)).count(), 1);
+        assert_eq!(monitor.stale(time_now, TimeDelta::seconds(60)).count(), 0);
+    }
+}
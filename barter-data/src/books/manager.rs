@@ -22,7 +22,7 @@ use std::{
     hash::Hash,
     sync::Arc,
 };
-use tracing::warn;
+use tracing::{error, warn};
 
 /// Maintains a set of local L2 [`OrderBook`]s by applying streamed [`OrderBookEvent`]s to the
 /// associated [`OrderBook`] in the [`OrderBookMap`].
@@ -30,6 +30,20 @@ use tracing::warn;
 pub struct OrderBookL2Manager<St, BookMap> {
     pub stream: St,
     pub books: BookMap,
+    /// Opt-in [`OrderBook::validate`] after each update, logging any invariant violations.
+    ///
+    /// Useful for debugging sequencing bugs, but adds overhead to every update.
+    pub validate: bool,
+    /// Optional maximum number of [`OrderBook`] levels retained per side, applied with
+    /// [`OrderBook::truncate`] after each update.
+    ///
+    /// Bounds memory for markets with very deep books, at the cost of deep-level queries
+    /// becoming approximate.
+    ///
+    /// The cap only applies to [`OrderBook`]s maintained by this manager. The L2 exchange
+    /// transformers emit [`OrderBookEvent`] deltas and hold no [`OrderBook`] state, so consumers
+    /// applying those events to their own [`OrderBook`]s should call [`OrderBook::truncate`].
+    pub max_depth: Option<usize>,
 }
 
 impl<St, BookMap> OrderBookL2Manager<St, BookMap>
@@ -61,8 +75,35 @@ where
 
             let mut book_lock = book.write();
             book_lock.update(event.kind);
+
+            if let Some(max_depth) = self.max_depth {
+                book_lock.truncate(max_depth);
+            }
+
+            if self.validate
+                && let Err(error) = book_lock.validate()
+            {
+                error!(
+                    exchange = %event.exchange,
+                    instrument = ?event.instrument,
+                    %error,
+                    "OrderBook invariant violated after update"
+                );
+            }
         }
     }
+
+    /// Enable or disable [`OrderBook::validate`] after each update.
+    pub fn with_validation(self, validate: bool) -> Self {
+        Self { validate, ..self }
+    }
+
+    /// Set the maximum number of [`OrderBook`] levels retained per side after each update.
+    ///
+    /// See [`OrderBook::truncate`] for why this makes deep-level queries approximate.
+    pub fn with_max_depth(self, max_depth: Option<usize>) -> Self {
+        Self { max_depth, ..self }
+    }
 }
 
 /// Initialise a [`OrderBookL2Manager`] using the provided batches of [`OrderBooksL2`]
@@ -122,5 +163,67 @@ where
     Ok(OrderBookL2Manager {
         stream,
         books: OrderBookMapMulti::new(books),
+        validate: false,
+        max_depth: None,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{books::Level, event::MarketEvent};
+    use barter_instrument::exchange::ExchangeId;
+    use chrono::{DateTime, Utc};
+
+    fn event(kind: OrderBookEvent) -> MarketStreamEvent<&'static str, OrderBookEvent> {
+        MarketStreamEvent::Item(MarketEvent {
+            time_exchange: DateTime::<Utc>::MIN_UTC,
+            time_received: DateTime::<Utc>::MIN_UTC,
+            exchange: ExchangeId::BinanceSpot,
+            instrument: "btc_usdt",
+            kind,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_manager_truncates_books_to_max_depth() {
+        let book = Arc::new(RwLock::new(OrderBook::default()));
+
+        let events = vec![
+            event(OrderBookEvent::Snapshot(OrderBook::new(
+                1,
+                None,
+                (1..=10).map(|price| Level::new(price, 1)),
+                (11..=20).map(|price| Level::new(price, 1)),
+            ))),
+            event(OrderBookEvent::Update(OrderBook::new(
+                2,
+                None,
+                vec![Level::new(10, 0), Level::new(9, 5)],
+                vec![Level::new(21, 1), Level::new(11, 0)],
+            ))),
+        ];
+
+        OrderBookL2Manager {
+            stream: futures::stream::iter(events),
+            books: OrderBookMapMulti::new(FnvHashMap::from_iter([("btc_usdt", book.clone())])),
+            validate: false,
+            max_depth: None,
+        }
+        .with_max_depth(Some(2))
+        .run()
+        .await;
+
+        // Bid Level 8 was truncated after the snapshot, so is not restored once the best bid is
+        // removed, and the ask Level 21 beyond the truncated Level 13 is retained
+        assert_eq!(
+            *book.read(),
+            OrderBook::new(
+                2,
+                None,
+                vec![Level::new(9, 5)],
+                vec![Level::new(12, 1), Level::new(21, 1)],
+            )
+        );
+    }
+}
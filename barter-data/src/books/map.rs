@@ -77,4 +77,10 @@ where
     pub fn insert(&mut self, instrument: Key, book: Arc<RwLock<OrderBook>>) {
         self.books.insert(instrument, book);
     }
+
+    /// Remove the [`OrderBook`] of the provided instrument from the [`OrderBookMapMulti`],
+    /// returning it if present.
+    pub fn remove(&mut self, instrument: &Key) -> Option<Arc<RwLock<OrderBook>>> {
+        self.books.remove(instrument)
+    }
 }
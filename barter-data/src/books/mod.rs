@@ -1,11 +1,27 @@
-use crate::subscription::book::OrderBookEvent;
+use crate::{error::DataError, subscription::book::OrderBookEvent};
+use barter_instrument::Side;
 use chrono::{DateTime, Utc};
 use derive_more::Display;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize, Serializer};
-use std::cmp::Ordering;
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+};
+use thiserror::Error;
 use tracing::debug;
 
+/// Number of basis points in one unit (ie/ 100%).
+const BASIS_POINTS: Decimal = Decimal::from_parts(10_000, 0, 0, false, 0);
+
+/// Provides an [`OrderBookHealthMonitor`](health::OrderBookHealthMonitor) for emitting L2
+/// [`OrderBook`] feed health metrics.
+pub mod health;
+
+/// Provides an [`OrderBookAggregator`](aggregator::OrderBookAggregator) for comparing
+/// [`OrderBook`]s of the same instrument across exchanges.
+pub mod aggregator;
+
 /// Provides a [`OrderBookL2Manager`](manager::OrderBookL2Manager) for maintaining a set of local
 /// L2 [`OrderBook`]s.
 pub mod manager;
@@ -13,6 +29,23 @@ pub mod manager;
 /// Provides an abstract collection of cheaply cloneable shared-state [`OrderBook`].
 pub mod map;
 
+/// Provides a read-only HTTP server for inspecting the [`OrderBook`]s held by an
+/// [`OrderBookAggregator`](aggregator::OrderBookAggregator).
+pub mod server;
+
+/// How an [`OrderBook`] is applied to a local [`OrderBook`], see [`OrderBook::apply`].
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Display, Deserialize, Serialize,
+)]
+pub enum ApplyMode {
+    /// Replace both sides of the local [`OrderBook`], eg/ a full snapshot.
+    Replace,
+    /// Merge level-by-level into the local [`OrderBook`], eg/ a delta update.
+    ///
+    /// Levels with a zero amount are removed, and all other levels are upserted.
+    Merge,
+}
+
 /// Normalised Jackbot [`OrderBook`] snapshot.
 #[derive(Clone, PartialEq, Eq, Debug, Default, Deserialize, Serialize)]
 pub struct OrderBook {
@@ -55,21 +88,44 @@ impl OrderBook {
         }
     }
 
-    /// Update the local [`OrderBook`] from a new [`OrderBookEvent`].
+    /// Update the local [`OrderBook`] from a new [`OrderBookEvent`], using the event
+    /// [`ApplyMode`].
     pub fn update(&mut self, event: OrderBookEvent) {
-        match event {
-            OrderBookEvent::Snapshot(snapshot) => {
-                *self = snapshot;
+        let (mode, book) = event.into_parts();
+        self.apply(mode, book)
+    }
+
+    /// Apply an [`OrderBook`] to the local [`OrderBook`] using the provided [`ApplyMode`].
+    ///
+    /// In both modes the `sequence` and `time_engine` are taken from the applied [`OrderBook`].
+    pub fn apply(&mut self, mode: ApplyMode, book: OrderBook) {
+        match mode {
+            ApplyMode::Replace => {
+                *self = book;
             }
-            OrderBookEvent::Update(update) => {
-                self.sequence = update.sequence;
-                self.time_engine = update.time_engine;
-                self.upsert_bids(update.bids);
-                self.upsert_asks(update.asks);
+            ApplyMode::Merge => {
+                self.sequence = book.sequence;
+                self.time_engine = book.time_engine;
+                self.upsert_bids(book.bids);
+                self.upsert_asks(book.asks);
             }
         }
     }
 
+    /// Truncate the local [`OrderBook`] to the best `depth` levels per side.
+    ///
+    /// Levels removed beyond `depth` are forgotten, so if better levels are later removed the
+    /// [`OrderBook`] will be missing any levels that have since become top-of-book. Queries on
+    /// levels deeper than the best few should therefore be considered approximate.
+    ///
+    /// [`OrderBookL2Manager::max_depth`](manager::OrderBookL2Manager::max_depth) applies this
+    /// after each update, but L2 [`OrderBookEvent`] streams consumed without the manager are
+    /// never truncated.
+    pub fn truncate(&mut self, depth: usize) {
+        self.bids.truncate(depth);
+        self.asks.truncate(depth);
+    }
+
     /// Update the local [`OrderBook`] by upserting the levels in an [`OrderBookSide`].
     pub fn upsert_bids(&mut self, update: OrderBookSide<Bids>) {
         self.bids.upsert(update.levels)
@@ -90,6 +146,26 @@ impl OrderBook {
         &self.asks
     }
 
+    /// Return the number of bid [`Level`]s in this [`OrderBook`].
+    pub fn bid_levels(&self) -> usize {
+        self.bids.len()
+    }
+
+    /// Return the number of ask [`Level`]s in this [`OrderBook`].
+    pub fn ask_levels(&self) -> usize {
+        self.asks.len()
+    }
+
+    /// Return the sum of the amount of every bid [`Level`] in this [`OrderBook`].
+    pub fn total_bid_volume(&self) -> Decimal {
+        self.bids.total_volume()
+    }
+
+    /// Return the sum of the amount of every ask [`Level`] in this [`OrderBook`].
+    pub fn total_ask_volume(&self) -> Decimal {
+        self.asks.total_volume()
+    }
+
     /// Calculate the mid-price by taking the average of the best bid and ask prices.
     ///
     /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
@@ -116,22 +192,257 @@ impl OrderBook {
             (None, None) => None,
         }
     }
+
+    /// Calculate the total cost of immediately filling the provided `quantity` by walking the
+    /// levels of the [`OrderBook`] from the best price.
+    ///
+    /// A [`Side::Buy`] consumes the asks, and a [`Side::Sell`] consumes the bids. Returns `None`
+    /// if the [`OrderBook`] does not have enough liquidity to fill the entire `quantity`.
+    pub fn cost_to_fill(&self, side: Side, quantity: Decimal) -> Option<Decimal> {
+        let levels = match side {
+            Side::Buy => self.asks.levels(),
+            Side::Sell => self.bids.levels(),
+        };
+
+        let mut remaining = quantity;
+        let mut cost = Decimal::ZERO;
+
+        for level in levels {
+            if remaining <= Decimal::ZERO {
+                break;
+            }
+
+            let fill = remaining.min(level.amount);
+            cost += fill * level.price;
+            remaining -= fill;
+        }
+
+        (remaining <= Decimal::ZERO).then_some(cost)
+    }
+
+    /// Calculate the order book imbalance over the best `depth` [`Level`]s of each side.
+    ///
+    /// Imbalance is `(bid_volume - ask_volume) / (bid_volume + ask_volume)`, ranging from `-1`
+    /// (all ask volume) to `1` (all bid volume). Returns `None` if the levels contain no volume.
+    pub fn imbalance(&self, depth: usize) -> Option<Decimal> {
+        let volume = |levels: &[Level]| -> Decimal {
+            levels.iter().take(depth).map(|level| level.amount).sum()
+        };
+
+        let bid_volume = volume(self.bids.levels());
+        let ask_volume = volume(self.asks.levels());
+        let total_volume = bid_volume + ask_volume;
+
+        (total_volume > Decimal::ZERO).then(|| (bid_volume - ask_volume) / total_volume)
+    }
+
+    /// Calculate the bid-ask spread relative to the mid-price, in basis points.
+    ///
+    /// Returns `None` if either side of the [`OrderBook`] is empty, or the mid-price is zero.
+    pub fn spread_bps(&self) -> Option<Decimal> {
+        let best_bid = self.bids.levels.first()?;
+        let best_ask = self.asks.levels.first()?;
+        let mid = mid_price(best_bid.price, best_ask.price);
+
+        (!mid.is_zero()).then(|| (best_ask.price - best_bid.price) / mid * BASIS_POINTS)
+    }
+
+    /// Sum the amount available within `bps` basis points of the best price.
+    ///
+    /// As with [`Self::cost_to_fill`], a [`Side::Buy`] sums the asks priced at or below
+    /// `best_ask * (1 + bps / 10_000)`, and a [`Side::Sell`] sums the bids priced at or above
+    /// `best_bid * (1 - bps / 10_000)`. Returns zero if the side is empty.
+    pub fn volume_within_bps(&self, side: Side, bps: Decimal) -> Decimal {
+        let levels = match side {
+            Side::Buy => self.asks.levels(),
+            Side::Sell => self.bids.levels(),
+        };
+
+        let Some(best) = levels.first() else {
+            return Decimal::ZERO;
+        };
+
+        let band = best.price * bps / BASIS_POINTS;
+        levels
+            .iter()
+            .take_while(|level| (level.price - best.price).abs() <= band)
+            .map(|level| level.amount)
+            .sum()
+    }
+
+    /// Aggregate the [`OrderBook`] [`Level`]s into price buckets of the provided `tick` size,
+    /// summing the amount of each bucket.
+    ///
+    /// Bid prices are floored down, and ask prices ceiled up, to the nearest multiple of the
+    /// `tick`. A non-positive `tick` returns an ungrouped copy of the [`OrderBook`].
+    pub fn group_by(&self, tick: Decimal) -> OrderBook {
+        if tick <= Decimal::ZERO {
+            return self.clone();
+        }
+
+        Self {
+            sequence: self.sequence,
+            time_engine: self.time_engine,
+            bids: OrderBookSide::bids(group_levels(self.bids.levels(), |price| {
+                (price / tick).floor() * tick
+            })),
+            asks: OrderBookSide::asks(group_levels(self.asks.levels(), |price| {
+                (price / tick).ceil() * tick
+            })),
+        }
+    }
+
+    /// Validate the [`OrderBook`] invariants, useful for detecting subtle sequencing bugs.
+    ///
+    /// Checks that:
+    /// - Bids are strictly descending, and asks strictly ascending, by price.
+    /// - Every [`Level`] has a positive amount.
+    /// - The book is not crossed (ie/ best bid < best ask).
+    pub fn validate(&self) -> Result<(), BookError> {
+        validate_levels(Side::Buy, self.bids.levels(), |prev, next| prev > next)?;
+        validate_levels(Side::Sell, self.asks.levels(), |prev, next| prev < next)?;
+
+        match (self.bids.levels.first(), self.asks.levels.first()) {
+            (Some(best_bid), Some(best_ask)) if best_bid.price >= best_ask.price => {
+                Err(BookError::Crossed {
+                    best_bid: best_bid.price,
+                    best_ask: best_ask.price,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Generate the minimal [`OrderBookEvent::Update`] that transforms this [`OrderBook`] into
+    /// the `other` [`OrderBook`] when applied via [`OrderBook::update`].
+    ///
+    /// Contains every added or resized [`Level`] with its new amount, and every removed
+    /// [`Level`] with a zero amount.
+    pub fn diff(&self, other: &OrderBook) -> OrderBookEvent {
+        OrderBookEvent::Update(Self {
+            sequence: other.sequence,
+            time_engine: other.time_engine,
+            bids: OrderBookSide::bids(diff_levels(self.bids.levels(), other.bids.levels())),
+            asks: OrderBookSide::asks(diff_levels(self.asks.levels(), other.asks.levels())),
+        })
+    }
+}
+
+/// Aggregate sorted [`Level`]s into the price bucket determined by `fn_bucket`, summing the
+/// amount of each bucket.
+fn group_levels<FnBucket>(levels: &[Level], fn_bucket: FnBucket) -> Vec<Level>
+where
+    FnBucket: Fn(Decimal) -> Decimal,
+{
+    // Sorted levels map to monotonic buckets, so only adjacent levels can share a bucket
+    levels.iter().fold(Vec::new(), |mut grouped, level| {
+        let bucket = fn_bucket(level.price);
+        match grouped.last_mut() {
+            Some(last) if last.price == bucket => last.amount += level.amount,
+            _ => grouped.push(Level::new(bucket, level.amount)),
+        }
+        grouped
+    })
+}
+
+/// Validate the [`Level`]s of one side of an [`OrderBook`] have positive amounts, and prices
+/// that are strictly sorted according to the provided `fn_sorted`.
+fn validate_levels<FnSorted>(
+    side: Side,
+    levels: &[Level],
+    fn_sorted: FnSorted,
+) -> Result<(), BookError>
+where
+    FnSorted: Fn(Decimal, Decimal) -> bool,
+{
+    if let Some(level) = levels.iter().find(|level| level.amount <= Decimal::ZERO) {
+        return Err(BookError::NonPositiveAmount {
+            side,
+            price: level.price,
+            amount: level.amount,
+        });
+    }
+
+    match levels
+        .windows(2)
+        .find(|pair| !fn_sorted(pair[0].price, pair[1].price))
+    {
+        Some(pair) => Err(BookError::Unsorted {
+            side,
+            prev_price: pair[0].price,
+            price: pair[1].price,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// [`OrderBook`] invariant violation detected by [`OrderBook::validate`].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum BookError {
+    #[error("{side} levels not strictly sorted: price {price} follows {prev_price}")]
+    Unsorted {
+        side: Side,
+        prev_price: Decimal,
+        price: Decimal,
+    },
+
+    #[error("{side} level at price {price} has non-positive amount: {amount}")]
+    NonPositiveAmount {
+        side: Side,
+        price: Decimal,
+        amount: Decimal,
+    },
+
+    #[error("crossed book: best bid {best_bid} >= best ask {best_ask}")]
+    Crossed {
+        best_bid: Decimal,
+        best_ask: Decimal,
+    },
+}
+
+/// Determine the [`Level`] changes required to transform the `current` levels into the `target`
+/// levels, using a zero amount to represent a removed [`Level`].
+fn diff_levels(current: &[Level], target: &[Level]) -> Vec<Level> {
+    let current_amounts = current
+        .iter()
+        .map(|level| (level.price, level.amount))
+        .collect::<HashMap<_, _>>();
+    let target_prices = target
+        .iter()
+        .map(|level| level.price)
+        .collect::<HashSet<_>>();
+
+    let changed = target
+        .iter()
+        .filter(|level| current_amounts.get(&level.price) != Some(&level.amount))
+        .copied();
+
+    let removed = current
+        .iter()
+        .filter(|level| !target_prices.contains(&level.price))
+        .map(|level| Level::new(level.price, Decimal::ZERO));
+
+    changed.chain(removed).collect()
 }
 
 /// Normalised Jackbot [`Level`]s for one `Side` ( of the [`OrderBook`].
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 pub struct OrderBookSide<Side> {
-    #[serde(skip_serializing)]
+    #[serde(skip)]
     pub side: Side,
     levels: Vec<Level>,
 }
 
 /// Unit type to tag an [`OrderBookSide`] as the bid Side (ie/ buyers) of an [`OrderBook`].
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Display)]
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Deserialize, Display,
+)]
 pub struct Bids;
 
 /// Unit type to tag an [`OrderBookSide`] as the ask Side (ie/ sellers) of an [`OrderBook`].
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Display)]
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Deserialize, Display,
+)]
 pub struct Asks;
 
 impl Serialize for Asks {
@@ -179,7 +490,7 @@ impl OrderBookSide<Asks> {
         L: Into<Level>,
     {
         let mut levels = levels.into_iter().map(L::into).collect::<Vec<_>>();
-        levels.sort_unstable_by(|a, b| a.price.cmp(&b.price));
+        levels.sort_unstable_by_key(|level| level.price);
 
         Self { side: Asks, levels }
     }
@@ -206,6 +517,26 @@ where
         &self.levels
     }
 
+    /// Return the number of [`Level`]s in this [`OrderBookSide`].
+    pub fn len(&self) -> usize {
+        self.levels.len()
+    }
+
+    /// Return `true` if this [`OrderBookSide`] contains no [`Level`]s.
+    pub fn is_empty(&self) -> bool {
+        self.levels.is_empty()
+    }
+
+    /// Return the sum of the amount of every [`Level`] in this [`OrderBookSide`].
+    pub fn total_volume(&self) -> Decimal {
+        self.levels.iter().map(|level| level.amount).sum()
+    }
+
+    /// Retain only the best `depth` [`Level`]s of this [`OrderBookSide`].
+    pub fn truncate(&mut self, depth: usize) {
+        self.levels.truncate(depth)
+    }
+
     /// Upsert a single [`Level`] into this [`OrderBookSide`].
     ///
     /// ### Upsert Scenarios
@@ -311,6 +642,31 @@ pub fn volume_weighted_mid_price(best_bid: Level, best_ask: Level) -> Decimal {
         / (best_bid.amount + best_ask.amount)
 }
 
+/// Verify a persisted [`OrderBook`] snapshot and subsequent [`OrderBookEvent`] deltas replay
+/// consistently, returning the final [`OrderBook`] after every delta has been applied.
+///
+/// Each [`OrderBookEvent::Update`] must follow on exactly from the sequence of the previous
+/// [`OrderBook`] (ie/ `prev.sequence + 1`), otherwise a [`DataError::InvalidSequence`] is
+/// returned. An [`OrderBookEvent::Snapshot`] replaces the [`OrderBook`] and resets the sequence.
+pub fn verify_replay(
+    snapshot: &OrderBook,
+    deltas: &[OrderBookEvent],
+) -> Result<OrderBook, DataError> {
+    deltas.iter().try_fold(snapshot.clone(), |mut book, event| {
+        if let OrderBookEvent::Update(update) = event
+            && update.sequence != book.sequence + 1
+        {
+            return Err(DataError::InvalidSequence {
+                prev_last_update_id: book.sequence,
+                first_update_id: update.sequence,
+            });
+        }
+
+        book.update(event.clone());
+        Ok(book)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -602,6 +958,644 @@ mod tests {
                 )
             }
         }
+
+        #[test]
+        fn test_cost_to_fill() {
+            struct TestCase {
+                side: Side,
+                quantity: Decimal,
+                expected: Option<Decimal>,
+            }
+
+            let book = OrderBook::new(
+                0,
+                None,
+                vec![
+                    Level::new(dec!(99.0), dec!(1.0)),
+                    Level::new(dec!(98.0), dec!(2.0)),
+                ],
+                vec![
+                    Level::new(dec!(101.0), dec!(1.0)),
+                    Level::new(dec!(102.0), dec!(2.0)),
+                ],
+            );
+
+            let tests = vec![
+                TestCase {
+                    // TC0: buy filled entirely by best ask
+                    side: Side::Buy,
+                    quantity: dec!(0.5),
+                    expected: Some(dec!(50.5)),
+                },
+                TestCase {
+                    // TC1: buy walks multiple ask levels
+                    side: Side::Buy,
+                    quantity: dec!(2.0),
+                    expected: Some(dec!(203.0)),
+                },
+                TestCase {
+                    // TC2: sell walks multiple bid levels
+                    side: Side::Sell,
+                    quantity: dec!(3.0),
+                    expected: Some(dec!(295.0)),
+                },
+                TestCase {
+                    // TC3: insufficient liquidity
+                    side: Side::Sell,
+                    quantity: dec!(3.5),
+                    expected: None,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = book.cost_to_fill(test.side, test.quantity);
+                assert_eq!(actual, test.expected, "TC{index} failed");
+            }
+        }
+
+        #[test]
+        fn test_group_by() {
+            struct TestCase {
+                tick: Decimal,
+                expected: OrderBook,
+            }
+
+            let book = OrderBook::new(
+                1,
+                None,
+                vec![
+                    Level::new(dec!(100.0), dec!(1.0)),
+                    Level::new(dec!(99.5), dec!(2.0)),
+                    Level::new(dec!(95.0), dec!(3.0)),
+                    Level::new(dec!(89.9), dec!(4.0)),
+                ],
+                vec![
+                    Level::new(dec!(100.5), dec!(1.0)),
+                    Level::new(dec!(101.0), dec!(2.0)),
+                    Level::new(dec!(110.0), dec!(3.0)),
+                    Level::new(dec!(110.1), dec!(4.0)),
+                ],
+            );
+
+            let tests = vec![
+                TestCase {
+                    // TC0: coarse buckets floor bids down & ceil asks up, summing amounts
+                    tick: dec!(10),
+                    expected: OrderBook::new(
+                        1,
+                        None,
+                        vec![
+                            Level::new(dec!(100), dec!(1.0)),
+                            Level::new(dec!(90), dec!(5.0)),
+                            Level::new(dec!(80), dec!(4.0)),
+                        ],
+                        vec![
+                            Level::new(dec!(110), dec!(6.0)),
+                            Level::new(dec!(120), dec!(4.0)),
+                        ],
+                    ),
+                },
+                TestCase {
+                    // TC1: tick that does not evenly divide prices
+                    tick: dec!(3),
+                    expected: OrderBook::new(
+                        1,
+                        None,
+                        vec![
+                            Level::new(dec!(99), dec!(3.0)),
+                            Level::new(dec!(93), dec!(3.0)),
+                            Level::new(dec!(87), dec!(4.0)),
+                        ],
+                        vec![
+                            Level::new(dec!(102), dec!(3.0)),
+                            Level::new(dec!(111), dec!(7.0)),
+                        ],
+                    ),
+                },
+                TestCase {
+                    // TC2: non-positive tick returns ungrouped book
+                    tick: dec!(0),
+                    expected: book.clone(),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                assert_eq!(book.group_by(test.tick), test.expected, "TC{index} failed");
+            }
+        }
+
+        #[test]
+        fn test_validate() {
+            struct TestCase {
+                input: OrderBook,
+                expected: Result<(), BookError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid book
+                    input: OrderBook::new(
+                        0,
+                        None,
+                        vec![
+                            Level::new(dec!(99.0), dec!(1.0)),
+                            Level::new(dec!(98.0), dec!(2.0)),
+                        ],
+                        vec![
+                            Level::new(dec!(101.0), dec!(1.0)),
+                            Level::new(dec!(102.0), dec!(2.0)),
+                        ],
+                    ),
+                    expected: Ok(()),
+                },
+                TestCase {
+                    // TC1: empty book is valid
+                    input: OrderBook::default(),
+                    expected: Ok(()),
+                },
+                TestCase {
+                    // TC2: crossed book
+                    input: OrderBook::new(
+                        0,
+                        None,
+                        vec![Level::new(dec!(101.0), dec!(1.0))],
+                        vec![Level::new(dec!(100.0), dec!(1.0))],
+                    ),
+                    expected: Err(BookError::Crossed {
+                        best_bid: dec!(101.0),
+                        best_ask: dec!(100.0),
+                    }),
+                },
+                TestCase {
+                    // TC3: locked book (best bid == best ask) is crossed
+                    input: OrderBook::new(
+                        0,
+                        None,
+                        vec![Level::new(dec!(100.0), dec!(1.0))],
+                        vec![Level::new(dec!(100.0), dec!(1.0))],
+                    ),
+                    expected: Err(BookError::Crossed {
+                        best_bid: dec!(100.0),
+                        best_ask: dec!(100.0),
+                    }),
+                },
+                TestCase {
+                    // TC4: duplicate bid price is not strictly descending
+                    input: OrderBook::new(
+                        0,
+                        None,
+                        vec![
+                            Level::new(dec!(99.0), dec!(1.0)),
+                            Level::new(dec!(99.0), dec!(2.0)),
+                        ],
+                        vec![Level::new(dec!(101.0), dec!(1.0))],
+                    ),
+                    expected: Err(BookError::Unsorted {
+                        side: Side::Buy,
+                        prev_price: dec!(99.0),
+                        price: dec!(99.0),
+                    }),
+                },
+                TestCase {
+                    // TC5: duplicate ask price is not strictly ascending
+                    input: OrderBook::new(
+                        0,
+                        None,
+                        vec![Level::new(dec!(99.0), dec!(1.0))],
+                        vec![
+                            Level::new(dec!(101.0), dec!(1.0)),
+                            Level::new(dec!(101.0), dec!(2.0)),
+                        ],
+                    ),
+                    expected: Err(BookError::Unsorted {
+                        side: Side::Sell,
+                        prev_price: dec!(101.0),
+                        price: dec!(101.0),
+                    }),
+                },
+                TestCase {
+                    // TC6: zero amount level
+                    input: OrderBook::new(
+                        0,
+                        None,
+                        vec![Level::new(dec!(99.0), dec!(1.0))],
+                        vec![Level::new(dec!(101.0), dec!(0))],
+                    ),
+                    expected: Err(BookError::NonPositiveAmount {
+                        side: Side::Sell,
+                        price: dec!(101.0),
+                        amount: dec!(0),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                assert_eq!(test.input.validate(), test.expected, "TC{index} failed");
+            }
+        }
+
+        #[test]
+        fn test_diff() {
+            struct TestCase {
+                current: OrderBook,
+                other: OrderBook,
+                expected: OrderBook,
+            }
+
+            let current = OrderBook::new(
+                1,
+                None,
+                vec![
+                    Level::new(dec!(99.0), dec!(1.0)),
+                    Level::new(dec!(98.0), dec!(2.0)),
+                ],
+                vec![
+                    Level::new(dec!(101.0), dec!(1.0)),
+                    Level::new(dec!(102.0), dec!(2.0)),
+                ],
+            );
+
+            let tests = vec![
+                TestCase {
+                    // TC0: identical books produce an empty update
+                    current: current.clone(),
+                    other: current.clone(),
+                    expected: OrderBook::new(1, None, Vec::<Level>::new(), vec![]),
+                },
+                TestCase {
+                    // TC1: added levels
+                    current: current.clone(),
+                    other: OrderBook::new(
+                        2,
+                        None,
+                        vec![
+                            Level::new(dec!(99.0), dec!(1.0)),
+                            Level::new(dec!(98.0), dec!(2.0)),
+                            Level::new(dec!(97.0), dec!(3.0)),
+                        ],
+                        vec![
+                            Level::new(dec!(100.5), dec!(4.0)),
+                            Level::new(dec!(101.0), dec!(1.0)),
+                            Level::new(dec!(102.0), dec!(2.0)),
+                        ],
+                    ),
+                    expected: OrderBook::new(
+                        2,
+                        None,
+                        vec![Level::new(dec!(97.0), dec!(3.0))],
+                        vec![Level::new(dec!(100.5), dec!(4.0))],
+                    ),
+                },
+                TestCase {
+                    // TC2: removed levels have a zero amount
+                    current: current.clone(),
+                    other: OrderBook::new(
+                        2,
+                        None,
+                        vec![Level::new(dec!(98.0), dec!(2.0))],
+                        vec![Level::new(dec!(101.0), dec!(1.0))],
+                    ),
+                    expected: OrderBook::new(
+                        2,
+                        None,
+                        vec![Level::new(dec!(99.0), dec!(0))],
+                        vec![Level::new(dec!(102.0), dec!(0))],
+                    ),
+                },
+                TestCase {
+                    // TC3: added, removed, and resized levels
+                    current: current.clone(),
+                    other: OrderBook::new(
+                        3,
+                        None,
+                        vec![
+                            Level::new(dec!(99.0), dec!(5.0)),
+                            Level::new(dec!(97.0), dec!(1.0)),
+                        ],
+                        vec![
+                            Level::new(dec!(101.0), dec!(1.0)),
+                            Level::new(dec!(102.0), dec!(0.5)),
+                        ],
+                    ),
+                    expected: OrderBook::new(
+                        3,
+                        None,
+                        vec![
+                            Level::new(dec!(99.0), dec!(5.0)),
+                            Level::new(dec!(98.0), dec!(0)),
+                            Level::new(dec!(97.0), dec!(1.0)),
+                        ],
+                        vec![Level::new(dec!(102.0), dec!(0.5))],
+                    ),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let diff = test.current.diff(&test.other);
+                assert_eq!(
+                    diff,
+                    OrderBookEvent::Update(test.expected),
+                    "TC{index} failed"
+                );
+
+                // Applying the diff reconstructs the other OrderBook
+                let mut reconstructed = test.current;
+                reconstructed.update(diff);
+                assert_eq!(reconstructed, test.other, "TC{index} failed to reconstruct");
+            }
+        }
+
+        #[test]
+        fn test_imbalance() {
+            struct TestCase {
+                input: OrderBook,
+                depth: usize,
+                expected: Option<Decimal>,
+            }
+
+            let book = OrderBook::new(
+                0,
+                None,
+                vec![
+                    Level::new(dec!(99.0), dec!(3.0)),
+                    Level::new(dec!(98.0), dec!(5.0)),
+                ],
+                vec![
+                    Level::new(dec!(101.0), dec!(1.0)),
+                    Level::new(dec!(102.0), dec!(1.0)),
+                ],
+            );
+
+            let tests = vec![
+                TestCase {
+                    // TC0: empty OrderBook
+                    input: OrderBook::new(0, None, Vec::<Level>::new(), vec![]),
+                    depth: 5,
+                    expected: None,
+                },
+                TestCase {
+                    // TC1: best level only
+                    input: book.clone(),
+                    depth: 1,
+                    expected: Some(dec!(0.5)),
+                },
+                TestCase {
+                    // TC2: depth beyond the available levels uses every level
+                    input: book,
+                    depth: 10,
+                    expected: Some(dec!(0.6)),
+                },
+                TestCase {
+                    // TC3: only asks
+                    input: OrderBook::new(
+                        0,
+                        None,
+                        vec![],
+                        vec![Level::new(dec!(101.0), dec!(1.0))],
+                    ),
+                    depth: 5,
+                    expected: Some(dec!(-1)),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                assert_eq!(
+                    test.input.imbalance(test.depth),
+                    test.expected,
+                    "TC{index} failed"
+                );
+            }
+        }
+
+        #[test]
+        fn test_spread_bps_and_volume_within_bps() {
+            let book = OrderBook::new(
+                0,
+                None,
+                vec![
+                    Level::new(dec!(99.0), dec!(3.0)),
+                    Level::new(dec!(98.9), dec!(2.0)),
+                    Level::new(dec!(98.0), dec!(5.0)),
+                ],
+                vec![
+                    Level::new(dec!(101.0), dec!(1.0)),
+                    Level::new(dec!(101.05), dec!(2.0)),
+                    Level::new(dec!(102.0), dec!(1.0)),
+                ],
+            );
+            let empty = OrderBook::new(0, None, Vec::<Level>::new(), vec![]);
+
+            // Spread of 2 around a mid-price of 100
+            assert_eq!(book.spread_bps(), Some(dec!(200)));
+            assert_eq!(empty.spread_bps(), None);
+
+            struct TestCase {
+                input: OrderBook,
+                side: Side,
+                bps: Decimal,
+                expected: Decimal,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: buy within 10 bps of best ask 101 includes asks up to 101.101
+                    input: book.clone(),
+                    side: Side::Buy,
+                    bps: dec!(10),
+                    expected: dec!(3.0),
+                },
+                TestCase {
+                    // TC1: sell within 20 bps of best bid 99 includes bids down to 98.802
+                    input: book.clone(),
+                    side: Side::Sell,
+                    bps: dec!(20),
+                    expected: dec!(5.0),
+                },
+                TestCase {
+                    // TC2: zero bps includes only the best level
+                    input: book,
+                    side: Side::Sell,
+                    bps: dec!(0),
+                    expected: dec!(3.0),
+                },
+                TestCase {
+                    // TC3: empty side
+                    input: empty,
+                    side: Side::Buy,
+                    bps: dec!(100),
+                    expected: dec!(0),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                assert_eq!(
+                    test.input.volume_within_bps(test.side, test.bps),
+                    test.expected,
+                    "TC{index} failed"
+                );
+            }
+        }
+
+        #[test]
+        fn test_level_counts_and_total_volume() {
+            struct TestCase {
+                input: OrderBook,
+                expected_levels: (usize, usize),
+                expected_volume: (Decimal, Decimal),
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: empty OrderBook
+                    input: OrderBook::new(0, None, Vec::<Level>::new(), vec![]),
+                    expected_levels: (0, 0),
+                    expected_volume: (dec!(0), dec!(0)),
+                },
+                TestCase {
+                    // TC1: populated OrderBook
+                    input: OrderBook::new(
+                        0,
+                        None,
+                        vec![
+                            Level::new(dec!(99.0), dec!(3.0)),
+                            Level::new(dec!(98.0), dec!(5.5)),
+                            Level::new(dec!(97.0), dec!(0.5)),
+                        ],
+                        vec![
+                            Level::new(dec!(101.0), dec!(1.0)),
+                            Level::new(dec!(102.0), dec!(2.25)),
+                        ],
+                    ),
+                    expected_levels: (3, 2),
+                    expected_volume: (dec!(9.0), dec!(3.25)),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                assert_eq!(
+                    (test.input.bid_levels(), test.input.ask_levels()),
+                    test.expected_levels,
+                    "TC{index} failed"
+                );
+                assert_eq!(
+                    (test.input.total_bid_volume(), test.input.total_ask_volume()),
+                    test.expected_volume,
+                    "TC{index} failed"
+                );
+            }
+        }
+
+        #[test]
+        fn test_apply() {
+            struct TestCase {
+                mode: ApplyMode,
+                expected: OrderBook,
+            }
+
+            let local = OrderBook::new(1, None, vec![(100, 1), (99, 1)], vec![(101, 1), (102, 1)]);
+            let applied = OrderBook::new(2, None, vec![(100, 0), (98, 2)], vec![(101, 3)]);
+
+            let tests = vec![
+                TestCase {
+                    // TC0: Replace discards all existing levels
+                    mode: ApplyMode::Replace,
+                    expected: OrderBook::new(2, None, vec![(100, 0), (98, 2)], vec![(101, 3)]),
+                },
+                TestCase {
+                    // TC1: Merge upserts levels & removes zero amount levels
+                    mode: ApplyMode::Merge,
+                    expected: OrderBook::new(
+                        2,
+                        None,
+                        vec![(99, 1), (98, 2)],
+                        vec![(101, 3), (102, 1)],
+                    ),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let mut actual = local.clone();
+                actual.apply(test.mode, applied.clone());
+                assert_eq!(actual, test.expected, "TC{index} failed");
+
+                let mut actual = local.clone();
+                actual.update(OrderBookEvent::new(test.mode, applied.clone()));
+                assert_eq!(actual, test.expected, "TC{index} failed");
+            }
+        }
+
+        #[test]
+        fn test_truncate_after_update() {
+            const MAX_DEPTH: usize = 3;
+
+            let mut book = OrderBook::new(
+                0,
+                None,
+                (1..=10).map(|price| Level::new(price * 10, 1)),
+                (11..=20).map(|price| Level::new(price * 10, 1)),
+            );
+            book.truncate(MAX_DEPTH);
+
+            let updates = vec![
+                // TC0: insert levels beyond the cap on both sides
+                (vec![(10, 5), (20, 5)], vec![(300, 5), (400, 5)]),
+                // TC1: insert new best levels, pushing existing levels beyond the cap
+                (vec![(105, 2), (106, 2)], vec![(109, 2), (108, 2)]),
+                // TC2: remove the best level on both sides
+                (vec![(106, 0)], vec![(108, 0)]),
+                // TC3: amend a retained level
+                (vec![(100, 7)], vec![(110, 7)]),
+            ];
+
+            let expected = vec![
+                // TC0
+                (
+                    vec![(100, 1), (90, 1), (80, 1)],
+                    vec![(110, 1), (120, 1), (130, 1)],
+                ),
+                // TC1
+                (
+                    vec![(106, 2), (105, 2), (100, 1)],
+                    vec![(108, 2), (109, 2), (110, 1)],
+                ),
+                // TC2
+                (vec![(105, 2), (100, 1)], vec![(109, 2), (110, 1)]),
+                // TC3
+                (vec![(105, 2), (100, 7)], vec![(109, 2), (110, 7)]),
+            ];
+
+            for (index, ((bids, asks), (expected_bids, expected_asks))) in
+                updates.into_iter().zip(expected).enumerate()
+            {
+                let levels = |levels: Vec<(u64, u64)>| {
+                    levels
+                        .into_iter()
+                        .map(|(price, amount)| Level::new(price, amount))
+                        .collect::<Vec<_>>()
+                };
+
+                book.update(OrderBookEvent::Update(OrderBook::new(
+                    0,
+                    None,
+                    levels(bids),
+                    levels(asks),
+                )));
+                book.truncate(MAX_DEPTH);
+
+                assert!(book.bids().levels().len() <= MAX_DEPTH, "TC{index} failed");
+                assert!(book.asks().levels().len() <= MAX_DEPTH, "TC{index} failed");
+                assert_eq!(
+                    book.bids().levels(),
+                    levels(expected_bids),
+                    "TC{index} failed"
+                );
+                assert_eq!(
+                    book.asks().levels(),
+                    levels(expected_asks),
+                    "TC{index} failed"
+                );
+            }
+        }
     }
 
     mod order_book_side {
@@ -683,4 +1677,65 @@ mod tests {
             }
         }
     }
+
+    mod verify_replay {
+        use super::*;
+
+        fn book(sequence: u64, bids: Vec<(u64, u64)>, asks: Vec<(u64, u64)>) -> OrderBook {
+            OrderBook::new(sequence, None, bids, asks)
+        }
+
+        #[test]
+        fn test_verify_replay() {
+            struct TestCase {
+                snapshot: OrderBook,
+                deltas: Vec<OrderBookEvent>,
+                expected: Result<OrderBook, DataError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: no deltas returns the snapshot
+                    snapshot: book(10, vec![(100, 1)], vec![(110, 1)]),
+                    deltas: vec![],
+                    expected: Ok(book(10, vec![(100, 1)], vec![(110, 1)])),
+                },
+                TestCase {
+                    // TC1: clean sequence of deltas folds onto the snapshot
+                    snapshot: book(10, vec![(100, 1)], vec![(110, 1)]),
+                    deltas: vec![
+                        OrderBookEvent::Update(book(11, vec![(101, 2)], vec![])),
+                        OrderBookEvent::Update(book(12, vec![(100, 0)], vec![(109, 3)])),
+                    ],
+                    expected: Ok(book(12, vec![(101, 2)], vec![(109, 3), (110, 1)])),
+                },
+                TestCase {
+                    // TC2: gapped sequence of deltas
+                    snapshot: book(10, vec![(100, 1)], vec![(110, 1)]),
+                    deltas: vec![
+                        OrderBookEvent::Update(book(11, vec![(101, 2)], vec![])),
+                        OrderBookEvent::Update(book(13, vec![(100, 0)], vec![(109, 3)])),
+                    ],
+                    expected: Err(DataError::InvalidSequence {
+                        prev_last_update_id: 11,
+                        first_update_id: 13,
+                    }),
+                },
+                TestCase {
+                    // TC3: snapshot delta resets the sequence
+                    snapshot: book(10, vec![(100, 1)], vec![(110, 1)]),
+                    deltas: vec![
+                        OrderBookEvent::Snapshot(book(20, vec![(105, 1)], vec![(106, 1)])),
+                        OrderBookEvent::Update(book(21, vec![], vec![(106, 0)])),
+                    ],
+                    expected: Ok(book(21, vec![(105, 1)], vec![])),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = verify_replay(&test.snapshot, &test.deltas);
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
 }
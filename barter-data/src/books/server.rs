@@ -0,0 +1,242 @@
+use crate::books::{
+    Level, OrderBook,
+    aggregator::OrderBookAggregator,
+    map::{OrderBookMap, OrderBookMapMulti},
+};
+use barter_instrument::exchange::ExchangeId;
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::{hash::Hash, io, sync::Arc};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+use tracing::{debug, warn};
+
+/// Maximum size of an HTTP request head accepted by [`serve_order_books`].
+const MAX_REQUEST_HEAD_BYTES: usize = 8 * 1024;
+
+/// JSON response served by [`serve_order_books`] for a single exchange instrument
+/// [`OrderBook`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct OrderBookSnapshotResponse {
+    pub exchange: ExchangeId,
+    pub instrument: String,
+    pub best_bid: Option<Level>,
+    pub best_ask: Option<Level>,
+    pub book: OrderBook,
+}
+
+/// Serve read-only JSON snapshots of the [`OrderBook`]s held by the shared
+/// [`OrderBookAggregator`] over HTTP, until the [`TcpListener`] fails.
+///
+/// Supports a single route, `GET /book/{exchange}/{instrument}`, which responds with an
+/// [`OrderBookSnapshotResponse`], eg/ `GET /book/binance_spot/btc_usdt`. Every other request
+/// receives an error status code.
+///
+/// Intended for operators to inspect the reconstructed [`OrderBook`]s, so each connection is
+/// closed after a single response.
+pub async fn serve_order_books<Key>(
+    listener: TcpListener,
+    aggregator: Arc<RwLock<OrderBookAggregator<OrderBookMapMulti<Key>>>>,
+) -> io::Result<()>
+where
+    Key: for<'a> From<&'a str> + Clone + Eq + Hash + Send + Sync + 'static,
+{
+    loop {
+        let (stream, peer) = listener.accept().await?;
+        let aggregator = Arc::clone(&aggregator);
+
+        tokio::spawn(async move {
+            if let Err(error) = handle_connection(stream, &aggregator).await {
+                warn!(%peer, ?error, "OrderBook snapshot server failed to handle connection");
+            }
+        });
+    }
+}
+
+async fn handle_connection<Key>(
+    mut stream: TcpStream,
+    aggregator: &RwLock<OrderBookAggregator<OrderBookMapMulti<Key>>>,
+) -> io::Result<()>
+where
+    Key: for<'a> From<&'a str> + Clone + Eq + Hash,
+{
+    let Some(request_line) = read_request_line(&mut stream).await? else {
+        return write_response(&mut stream, "400 Bad Request", "text/plain", "bad request").await;
+    };
+    debug!(%request_line, "OrderBook snapshot server received request");
+
+    let (status, content_type, body) = match route(&request_line, aggregator) {
+        Ok(response) => match serde_json::to_string(&response) {
+            Ok(body) => ("200 OK", "application/json", body),
+            Err(error) => ("500 Internal Server Error", "text/plain", error.to_string()),
+        },
+        Err((status, message)) => (status, "text/plain", message.to_string()),
+    };
+
+    write_response(&mut stream, status, content_type, &body).await
+}
+
+/// Read the HTTP request head, returning the request line (eg/ "GET /book/okx/btc_usdt HTTP/1.1")
+/// if it is valid UTF-8 and within [`MAX_REQUEST_HEAD_BYTES`].
+async fn read_request_line(stream: &mut TcpStream) -> io::Result<Option<String>> {
+    let mut head = Vec::new();
+    let mut buffer = [0u8; 1024];
+
+    while !head.windows(4).any(|window| window == b"\r\n\r\n") {
+        if head.len() > MAX_REQUEST_HEAD_BYTES {
+            return Ok(None);
+        }
+
+        let read = stream.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        head.extend_from_slice(&buffer[..read]);
+    }
+
+    Ok(String::from_utf8(head)
+        .ok()
+        .and_then(|head| head.lines().next().map(str::to_owned)))
+}
+
+fn route<Key>(
+    request_line: &str,
+    aggregator: &RwLock<OrderBookAggregator<OrderBookMapMulti<Key>>>,
+) -> Result<OrderBookSnapshotResponse, (&'static str, &'static str)>
+where
+    Key: for<'a> From<&'a str> + Clone + Eq + Hash,
+{
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(path)) = (parts.next(), parts.next()) else {
+        return Err(("400 Bad Request", "bad request"));
+    };
+
+    if method != "GET" {
+        return Err(("405 Method Not Allowed", "method not allowed"));
+    }
+
+    let path = path.split('?').next().unwrap_or_default();
+    let segments = path.trim_start_matches('/').split('/').collect::<Vec<_>>();
+    let ["book", exchange, instrument] = segments.as_slice() else {
+        return Err(("404 Not Found", "not found"));
+    };
+
+    let exchange = serde_json::from_value::<ExchangeId>(serde_json::Value::from(*exchange))
+        .map_err(|_| ("404 Not Found", "unknown exchange"))?;
+
+    let book = aggregator
+        .read()
+        .exchanges
+        .get(&exchange)
+        .and_then(|books| books.find(&Key::from(*instrument)))
+        .map(|book| book.read().clone())
+        .ok_or(("404 Not Found", "order book not found"))?;
+
+    Ok(OrderBookSnapshotResponse {
+        exchange,
+        instrument: instrument.to_string(),
+        best_bid: book.bids().levels().first().copied(),
+        best_ask: book.asks().levels().first().copied(),
+        book,
+    })
+}
+
+async fn write_response(
+    stream: &mut TcpStream,
+    status: &str,
+    content_type: &str,
+    body: &str,
+) -> io::Result<()> {
+    let response = format!(
+        "HTTP/1.1 {status}\r\n\
+         Content-Type: {content_type}\r\n\
+         Content-Length: {}\r\n\
+         Connection: close\r\n\r\n\
+         {body}",
+        body.len()
+    );
+
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fnv::FnvHashMap;
+    use rust_decimal_macros::dec;
+
+    #[tokio::test]
+    async fn test_serve_order_books() {
+        let book = OrderBook::new(
+            7,
+            None,
+            vec![
+                Level::new(dec!(100), dec!(1)),
+                Level::new(dec!(99), dec!(2)),
+            ],
+            vec![
+                Level::new(dec!(101), dec!(3)),
+                Level::new(dec!(102), dec!(4)),
+            ],
+        );
+
+        let aggregator = Arc::new(RwLock::new(OrderBookAggregator::new(
+            FnvHashMap::from_iter([(
+                ExchangeId::BinanceSpot,
+                OrderBookMapMulti::new(FnvHashMap::from_iter([(
+                    String::from("btc_usdt"),
+                    Arc::new(RwLock::new(book.clone())),
+                )])),
+            )]),
+        )));
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(serve_order_books(listener, Arc::clone(&aggregator)));
+
+        let client = reqwest::Client::new();
+        let get = |path: &str| client.get(format!("http://{address}{path}")).send();
+
+        let response = get("/book/binance_spot/btc_usdt").await.unwrap();
+        assert_eq!(response.status(), reqwest::StatusCode::OK);
+
+        let body = response.json::<serde_json::Value>().await.unwrap();
+        assert_eq!(
+            body["best_bid"],
+            serde_json::json!({"price": "100", "amount": "1"})
+        );
+        assert_eq!(
+            body["best_ask"],
+            serde_json::json!({"price": "101", "amount": "3"})
+        );
+
+        let snapshot = serde_json::from_value::<OrderBookSnapshotResponse>(body).unwrap();
+        assert_eq!(
+            snapshot,
+            OrderBookSnapshotResponse {
+                exchange: ExchangeId::BinanceSpot,
+                instrument: String::from("btc_usdt"),
+                best_bid: Some(Level::new(dec!(100), dec!(1))),
+                best_ask: Some(Level::new(dec!(101), dec!(3))),
+                book,
+            }
+        );
+
+        for path in [
+            "/book/binance_spot/eth_usdt",
+            "/book/okx/btc_usdt",
+            "/book/unknown/btc_usdt",
+            "/books",
+        ] {
+            let response = get(path).await.unwrap();
+            assert_eq!(
+                response.status(),
+                reqwest::StatusCode::NOT_FOUND,
+                "{path} failed"
+            );
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use crate::{event::MarketEvent, subscription::trade::PublicTrade};
+use barter_instrument::Side;
+use chrono::{DateTime, TimeDelta, Utc};
+
+/// Maintains the cumulative volume delta (CVD) of a stream of [`PublicTrade`]s.
+///
+/// CVD is calculated as `buy_volume - sell_volume`, where each [`PublicTrade`] is signed by its
+/// aggressor [`Side`].
+///
+/// Two readings are maintained:
+/// - Session: CVD of every trade since construction or the last [`CvdTracker::reset`].
+/// - Window: CVD of the trades in the current fixed `window`, aligned to multiples of the window
+///   duration since the Unix epoch. The window rolls over on the first trade of a new window,
+///   retaining the CVD of the window that just ended.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CvdTracker {
+    pub window: TimeDelta,
+    window_start: Option<DateTime<Utc>>,
+    window_cvd: f64,
+    previous_window_cvd: Option<f64>,
+    session_cvd: f64,
+}
+
+impl CvdTracker {
+    /// Construct a new empty [`CvdTracker`] using the provided window duration.
+    pub fn new(window: TimeDelta) -> Self {
+        Self {
+            window,
+            window_start: None,
+            window_cvd: 0.0,
+            previous_window_cvd: None,
+            session_cvd: 0.0,
+        }
+    }
+
+    /// Update the [`CvdTracker`] with the [`PublicTrade`] contained in the [`MarketEvent`],
+    /// using the `time_exchange` as the trade time.
+    pub fn process<InstrumentKey>(&mut self, event: &MarketEvent<InstrumentKey, PublicTrade>) {
+        self.update(event.time_exchange, &event.kind)
+    }
+
+    /// Update the [`CvdTracker`] with a [`PublicTrade`] that occurred at the provided `time`,
+    /// rolling over the window if the trade falls outside the current window.
+    pub fn update(&mut self, time: DateTime<Utc>, trade: &PublicTrade) {
+        let window_start = self.window_start(time);
+
+        match self.window_start {
+            Some(current) if current == window_start => {}
+            Some(_) => {
+                self.previous_window_cvd = Some(self.window_cvd);
+                self.window_cvd = 0.0;
+                self.window_start = Some(window_start);
+            }
+            None => self.window_start = Some(window_start),
+        }
+
+        let signed_volume = match trade.side {
+            Side::Buy => trade.amount,
+            Side::Sell => -trade.amount,
+        };
+
+        self.window_cvd += signed_volume;
+        self.session_cvd += signed_volume;
+    }
+
+    /// Returns the start time of the window containing the provided `time`.
+    fn window_start(&self, time: DateTime<Utc>) -> DateTime<Utc> {
+        let window_ms = self.window.num_milliseconds().max(1);
+        let start_ms = time.timestamp_millis().div_euclid(window_ms) * window_ms;
+        DateTime::from_timestamp_millis(start_ms).unwrap_or(time)
+    }
+
+    /// Returns the CVD of the current window.
+    pub fn window_cvd(&self) -> f64 {
+        self.window_cvd
+    }
+
+    /// Returns the start time of the current window, or `None` if no trades have been observed.
+    pub fn current_window_start(&self) -> Option<DateTime<Utc>> {
+        self.window_start
+    }
+
+    /// Returns the CVD of the most recently completed window, or `None` if no window has rolled
+    /// over yet.
+    pub fn previous_window_cvd(&self) -> Option<f64> {
+        self.previous_window_cvd
+    }
+
+    /// Returns the CVD of every trade in the session.
+    pub fn session_cvd(&self) -> f64 {
+        self.session_cvd
+    }
+
+    /// Remove every observed trade, starting a new session.
+    pub fn reset(&mut self) {
+        self.window_start = None;
+        self.window_cvd = 0.0;
+        self.previous_window_cvd = None;
+        self.session_cvd = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trade(side: Side, amount: f64) -> PublicTrade {
+        PublicTrade {
+            id: "id".to_string(),
+            price: 100.0,
+            amount,
+            side,
+        }
+    }
+
+    fn time(secs: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(secs, 0).unwrap()
+    }
+
+    #[test]
+    fn test_cvd_tracker() {
+        struct TestCase {
+            trades: Vec<(i64, PublicTrade)>,
+            expected_window: f64,
+            expected_previous_window: Option<f64>,
+            expected_session: f64,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: no trades
+                trades: vec![],
+                expected_window: 0.0,
+                expected_previous_window: None,
+                expected_session: 0.0,
+            },
+            TestCase {
+                // TC1: buys & sells within a single window
+                trades: vec![
+                    (0, trade(Side::Buy, 3.0)),
+                    (10, trade(Side::Sell, 1.0)),
+                    (59, trade(Side::Buy, 0.5)),
+                ],
+                expected_window: 2.5,
+                expected_previous_window: None,
+                expected_session: 2.5,
+            },
+            TestCase {
+                // TC2: window rolls over on the first trade of the next window
+                trades: vec![
+                    (0, trade(Side::Buy, 3.0)),
+                    (30, trade(Side::Sell, 1.0)),
+                    (60, trade(Side::Sell, 4.0)),
+                ],
+                expected_window: -4.0,
+                expected_previous_window: Some(2.0),
+                expected_session: -2.0,
+            },
+            TestCase {
+                // TC3: window rolls over across several empty windows
+                trades: vec![
+                    (5, trade(Side::Sell, 2.0)),
+                    (65, trade(Side::Buy, 1.0)),
+                    (500, trade(Side::Buy, 1.5)),
+                ],
+                expected_window: 1.5,
+                expected_previous_window: Some(1.0),
+                expected_session: 0.5,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut tracker = CvdTracker::new(TimeDelta::seconds(60));
+            for (secs, trade) in &test.trades {
+                tracker.update(time(*secs), trade);
+            }
+            assert_eq!(
+                tracker.window_cvd(),
+                test.expected_window,
+                "TC{index} failed"
+            );
+            assert_eq!(
+                tracker.previous_window_cvd(),
+                test.expected_previous_window,
+                "TC{index} failed"
+            );
+            assert_eq!(
+                tracker.session_cvd(),
+                test.expected_session,
+                "TC{index} failed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_cvd_tracker_window_start() {
+        let mut tracker = CvdTracker::new(TimeDelta::seconds(60));
+        assert_eq!(tracker.current_window_start(), None);
+
+        tracker.update(time(90), &trade(Side::Buy, 1.0));
+        assert_eq!(tracker.current_window_start(), Some(time(60)));
+
+        tracker.update(time(125), &trade(Side::Buy, 1.0));
+        assert_eq!(tracker.current_window_start(), Some(time(120)));
+    }
+
+    #[test]
+    fn test_cvd_tracker_reset() {
+        let mut tracker = CvdTracker::new(TimeDelta::seconds(60));
+        tracker.update(time(0), &trade(Side::Buy, 1.0));
+        tracker.update(time(60), &trade(Side::Sell, 3.0));
+        assert_eq!(tracker.session_cvd(), -2.0);
+
+        tracker.reset();
+        assert_eq!(tracker.window_cvd(), 0.0);
+        assert_eq!(tracker.previous_window_cvd(), None);
+        assert_eq!(tracker.session_cvd(), 0.0);
+        assert_eq!(tracker.current_window_start(), None);
+
+        tracker.update(time(61), &trade(Side::Sell, 2.0));
+        assert_eq!(tracker.window_cvd(), -2.0);
+        assert_eq!(tracker.previous_window_cvd(), None);
+        assert_eq!(tracker.session_cvd(), -2.0);
+    }
+}
@@ -13,6 +13,9 @@ pub enum DataError {
     #[error("failed to initialise reconnecting MarketStream due to empty subscriptions")]
     SubscriptionsEmpty,
 
+    #[error("exhausted {0} MarketStream reconnection attempts")]
+    ReconnectAttemptsExhausted(usize),
+
     #[error("unsupported DynamicStreams Subscription SubKind: {0}")]
     UnsupportedSubKind(SubKind),
 
@@ -25,6 +28,9 @@ pub enum DataError {
     #[error("SocketError: {0}")]
     Socket(String),
 
+    #[error("invalid BarMode: {0}")]
+    InvalidBarMode(String),
+
     #[error("unsupported dynamic Subscription for exchange: {exchange}, kind: {sub_kind}")]
     Unsupported {
         exchange: ExchangeId,
@@ -41,6 +47,15 @@ pub enum DataError {
         prev_last_update_id: u64,
         first_update_id: u64,
     },
+
+    #[error(
+        "ChecksumMismatch for subscription {subscription}: expected {expected}, actual {actual}"
+    )]
+    ChecksumMismatch {
+        expected: u32,
+        actual: u32,
+        subscription: SubscriptionId,
+    },
 }
 
 impl DataError {
@@ -49,6 +64,7 @@ impl DataError {
     pub fn is_terminal(&self) -> bool {
         match self {
             DataError::InvalidSequence { .. } => true,
+            DataError::ChecksumMismatch { .. } => true,
             _ => false,
         }
     }
@@ -81,7 +97,16 @@ mod tests {
                 expected: true,
             },
             TestCase {
-                // TC1: is not terminal w/ DataError::Socket
+                // TC1: is terminal w/ DataError::ChecksumMismatch
+                input: DataError::ChecksumMismatch {
+                    expected: 0,
+                    actual: 1,
+                    subscription: SubscriptionId::from("books|BTC-USDT"),
+                },
+                expected: true,
+            },
+            TestCase {
+                // TC2: is not terminal w/ DataError::Socket
                 input: DataError::from(SocketError::Sink),
                 expected: false,
             },
@@ -92,4 +117,19 @@ mod tests {
             assert_eq!(actual, test.expected, "TC{} failed", index);
         }
     }
+
+    #[test]
+    fn test_data_error_checksum_mismatch_display() {
+        let error = DataError::ChecksumMismatch {
+            expected: 3366601478,
+            actual: 1234,
+            subscription: SubscriptionId::from("books|BTC-USDT"),
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "ChecksumMismatch for subscription books|BTC-USDT: expected 3366601478, actual 1234"
+        );
+        assert!(error.is_terminal());
+    }
 }
@@ -5,6 +5,7 @@ use crate::{
         book::{OrderBookEvent, OrderBookL1},
         candle::Candle,
         liquidation::Liquidation,
+        mark_price::MarkPrice,
         trade::PublicTrade,
     },
 };
@@ -98,6 +99,13 @@ impl<InstrumentKey> MarketEvent<InstrumentKey, DataKind> {
         }
     }
 
+    pub fn as_mark_price(&self) -> Option<MarketEvent<&InstrumentKey, &MarkPrice>> {
+        match &self.kind {
+            DataKind::MarkPrice(mark_price) => Some(self.as_event(mark_price)),
+            _ => None,
+        }
+    }
+
     fn as_event<'a, K>(&'a self, kind: &'a K) -> MarketEvent<&'a InstrumentKey, &'a K> {
         MarketEvent {
             time_exchange: self.time_exchange,
@@ -127,6 +135,7 @@ pub enum DataKind {
     OrderBook(OrderBookEvent),
     Candle(Candle),
     Liquidation(Liquidation),
+    MarkPrice(MarkPrice),
 }
 
 impl DataKind {
@@ -137,6 +146,7 @@ impl DataKind {
             DataKind::OrderBook(_) => "l2",
             DataKind::Candle(_) => "candle",
             DataKind::Liquidation(_) => "liquidation",
+            DataKind::MarkPrice(_) => "mark_price",
         }
     }
 }
@@ -220,3 +230,19 @@ impl<InstrumentKey> From<MarketEvent<InstrumentKey, Liquidation>>
         value.map_kind(Liquidation::into)
     }
 }
+
+impl<InstrumentKey> From<MarketStreamResult<InstrumentKey, MarkPrice>>
+    for MarketStreamResult<InstrumentKey, DataKind>
+{
+    fn from(value: MarketStreamResult<InstrumentKey, MarkPrice>) -> Self {
+        value.map_ok(MarketEvent::from)
+    }
+}
+
+impl<InstrumentKey> From<MarketEvent<InstrumentKey, MarkPrice>>
+    for MarketEvent<InstrumentKey, DataKind>
+{
+    fn from(value: MarketEvent<InstrumentKey, MarkPrice>) -> Self {
+        value.map_kind(MarkPrice::into)
+    }
+}
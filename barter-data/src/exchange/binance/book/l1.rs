@@ -111,6 +111,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rust_decimal_macros::dec;
 
     mod de {
         use super::*;
@@ -179,4 +180,76 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_binance_order_book_l1_into_market_event() {
+        struct TestCase {
+            input: &'static str,
+            expected: OrderBookL1,
+        }
+
+        let time = DateTime::from_timestamp_millis(1671621244670).unwrap();
+
+        let tests = vec![
+            TestCase {
+                // TC0: best bid & ask mapped into Levels
+                input: r#"
+                {
+                    "e":"bookTicker",
+                    "u":2286618712950,
+                    "s":"BTCUSDT",
+                    "b":"16858.90",
+                    "B":"13.692",
+                    "a":"16859.00",
+                    "A":"30.219",
+                    "T":1671621244670,
+                    "E":1671621244673
+                }"#,
+                expected: OrderBookL1 {
+                    last_update_time: time,
+                    best_bid: Some(Level::new(dec!(16858.90), dec!(13.692))),
+                    best_ask: Some(Level::new(dec!(16859.00), dec!(30.219))),
+                },
+            },
+            TestCase {
+                // TC1: zero best ask price mapped to None
+                input: r#"
+                {
+                    "e":"bookTicker",
+                    "u":2286618712950,
+                    "s":"BTCUSDT",
+                    "b":"16858.90",
+                    "B":"13.692",
+                    "a":"0.00",
+                    "A":"0.000",
+                    "T":1671621244670,
+                    "E":1671621244673
+                }"#,
+                expected: OrderBookL1 {
+                    last_update_time: time,
+                    best_bid: Some(Level::new(dec!(16858.90), dec!(13.692))),
+                    best_ask: None,
+                },
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let book = serde_json::from_str::<BinanceOrderBookL1>(test.input).unwrap();
+
+            let MarketIter(events) =
+                MarketIter::from((ExchangeId::BinanceFuturesUsd, "btc_usdt", book));
+            let [Ok(event)] = events.as_slice() else {
+                panic!("TC{index} failed: expected one MarketEvent, got: {events:?}");
+            };
+
+            assert_eq!(event.time_exchange, time, "TC{index} failed");
+            assert_eq!(
+                event.exchange,
+                ExchangeId::BinanceFuturesUsd,
+                "TC{index} failed"
+            );
+            assert_eq!(event.instrument, "btc_usdt", "TC{index} failed");
+            assert_eq!(event.kind, test.expected, "TC{index} failed");
+        }
+    }
 }
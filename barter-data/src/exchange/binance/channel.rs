@@ -5,6 +5,7 @@ use crate::{
         Subscription,
         book::{OrderBooksL1, OrderBooksL2},
         liquidation::Liquidations,
+        mark_price::MarkPrices,
         trade::PublicTrades,
     },
 };
@@ -46,6 +47,11 @@ impl BinanceChannel {
     ///
     /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#liquidation-order-streams>
     pub const LIQUIDATIONS: Self = Self("@forceOrder");
+
+    /// [`BinanceFuturesUsd`] mark price and funding rate channel name (1s updates).
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#mark-price-stream>
+    pub const MARK_PRICE: Self = Self("@markPrice@1s");
 }
 
 impl<Server, Instrument> Identifier<BinanceChannel>
@@ -80,6 +86,14 @@ impl<Instrument> Identifier<BinanceChannel>
     }
 }
 
+impl<Instrument> Identifier<BinanceChannel>
+    for Subscription<BinanceFuturesUsd, Instrument, MarkPrices>
+{
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::MARK_PRICE
+    }
+}
+
 impl AsRef<str> for BinanceChannel {
     fn as_ref(&self) -> &str {
         self.0
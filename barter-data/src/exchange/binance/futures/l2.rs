@@ -43,6 +43,7 @@ impl SnapshotFetcher<BinanceFuturesUsd, OrderBooksL2>
     for BinanceFuturesUsdOrderBooksL2SnapshotFetcher
 {
     fn fetch_snapshots<Instrument>(
+        client: &reqwest::Client,
         subscriptions: &[Subscription<BinanceFuturesUsd, Instrument, OrderBooksL2>],
     ) -> impl Future<Output = Result<Vec<MarketEvent<Instrument::Key, OrderBookEvent>>, SocketError>>
     + Send
@@ -61,7 +62,9 @@ impl SnapshotFetcher<BinanceFuturesUsd, OrderBooksL2>
 
             async move {
                 // Fetch initial OrderBook snapshot via HTTP
-                let snapshot = reqwest::get(snapshot_url)
+                let snapshot = client
+                    .get(snapshot_url)
+                    .send()
                     .await
                     .map_err(SocketError::Http)?
                     .json::<BinanceOrderBookL2Snapshot>()
@@ -0,0 +1,132 @@
+use super::super::BinanceChannel;
+use crate::{
+    Identifier,
+    event::{MarketEvent, MarketIter},
+    subscription::mark_price::MarkPrice,
+};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::subscription::SubscriptionId;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// [`BinanceFuturesUsd`](super::BinanceFuturesUsd) mark price and funding rate message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#mark-price-stream>
+/// ```json
+/// {
+///     "e": "markPriceUpdate",
+///     "E": 1562305380000,
+///     "s": "BTCUSDT",
+///     "p": "11794.15000000",
+///     "i": "11784.62659091",
+///     "P": "11784.25641265",
+///     "r": "0.00038167",
+///     "T": 1562306400000
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct BinanceMarkPrice {
+    #[serde(alias = "s", deserialize_with = "de_mark_price_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(
+        alias = "E",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "p", with = "rust_decimal::serde::str")]
+    pub mark_price: Decimal,
+    #[serde(alias = "i", with = "rust_decimal::serde::str")]
+    pub index_price: Decimal,
+    #[serde(alias = "r", with = "rust_decimal::serde::str")]
+    pub funding_rate: Decimal,
+    #[serde(
+        alias = "T",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub next_funding_time: DateTime<Utc>,
+}
+
+impl Identifier<Option<SubscriptionId>> for BinanceMarkPrice {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl<InstrumentKey> From<(ExchangeId, InstrumentKey, BinanceMarkPrice)>
+    for MarketIter<InstrumentKey, MarkPrice>
+{
+    fn from(
+        (exchange_id, instrument, mark_price): (ExchangeId, InstrumentKey, BinanceMarkPrice),
+    ) -> Self {
+        Self(vec![Ok(MarketEvent {
+            time_exchange: mark_price.time,
+            time_received: Utc::now(),
+            exchange: exchange_id,
+            instrument,
+            kind: MarkPrice {
+                mark_price: mark_price.mark_price,
+                index_price: mark_price.index_price,
+                funding_rate: mark_price.funding_rate,
+                next_funding_time: mark_price.next_funding_time,
+                time: mark_price.time,
+            },
+        })])
+    }
+}
+
+/// Deserialize a [`BinanceMarkPrice`] "s" (eg/ "BTCUSDT") as the associated
+/// [`SubscriptionId`].
+///
+/// eg/ "@markPrice@1s|BTCUSDT"
+pub fn de_mark_price_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    Deserialize::deserialize(deserializer).map(|market: String| {
+        SubscriptionId::from(format!("{}|{}", BinanceChannel::MARK_PRICE.0, market))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use rust_decimal_macros::dec;
+        use std::time::Duration;
+
+        #[test]
+        fn test_binance_mark_price() {
+            let input = r#"
+            {
+                "e": "markPriceUpdate",
+                "E": 1562305380000,
+                "s": "BTCUSDT",
+                "p": "11794.15000000",
+                "i": "11784.62659091",
+                "P": "11784.25641265",
+                "r": "0.00038167",
+                "T": 1562306400000
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<BinanceMarkPrice>(input).unwrap(),
+                BinanceMarkPrice {
+                    subscription_id: SubscriptionId::from("@markPrice@1s|BTCUSDT"),
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1562305380000)),
+                    mark_price: dec!(11794.15000000),
+                    index_price: dec!(11784.62659091),
+                    funding_rate: dec!(0.00038167),
+                    next_funding_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                        1562306400000
+                    )),
+                }
+            );
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use self::liquidation::BinanceLiquidation;
+use self::{liquidation::BinanceLiquidation, mark_price::BinanceMarkPrice};
 use super::{Binance, ExchangeServer};
 use crate::{
     ExchangeWsStream, NoInitialSnapshots,
@@ -9,7 +9,7 @@ use crate::{
         },
     },
     instrument::InstrumentData,
-    subscription::{book::OrderBooksL2, liquidation::Liquidations},
+    subscription::{book::OrderBooksL2, liquidation::Liquidations, mark_price::MarkPrices},
     transformer::stateless::StatelessTransformer,
 };
 use barter_instrument::exchange::ExchangeId;
@@ -21,6 +21,9 @@ pub mod l2;
 /// Liquidation types.
 pub mod liquidation;
 
+/// Mark price types.
+pub mod mark_price;
+
 /// [`BinanceFuturesUsd`] WebSocket server base url.
 ///
 /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#websocket-market-streams>
@@ -59,6 +62,15 @@ where
     >;
 }
 
+impl<Instrument> StreamSelector<Instrument, MarkPrices> for BinanceFuturesUsd
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, Instrument::Key, MarkPrices, BinanceMarkPrice>>;
+}
+
 impl Display for BinanceFuturesUsd {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "BinanceFuturesUsd")
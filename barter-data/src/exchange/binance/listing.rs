@@ -0,0 +1,136 @@
+use super::Binance;
+use crate::{
+    exchange::{ExchangeServer, listing::MarketListing},
+    instrument::InstrumentData,
+    subscription::Subscription,
+};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::error::SocketError;
+use fnv::FnvHashSet;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+
+/// [`BinanceSpot`](super::spot::BinanceSpot) HTTP exchange information url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#exchange-information>
+pub const HTTP_EXCHANGE_INFO_URL_BINANCE_SPOT: &str = "https://api.binance.com/api/v3/exchangeInfo";
+
+/// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) HTTP exchange information url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#exchange-information>
+pub const HTTP_EXCHANGE_INFO_URL_BINANCE_FUTURES_USD: &str =
+    "https://fapi.binance.com/fapi/v1/exchangeInfo";
+
+/// Binance HTTP exchange information, containing every listed symbol.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#exchange-information>
+/// ```json
+/// {
+///     "timezone": "UTC",
+///     "serverTime": 1565246363776,
+///     "symbols": [
+///         {
+///             "symbol": "ETHBTC",
+///             "status": "TRADING",
+///             "baseAsset": "ETH",
+///             "quoteAsset": "BTC"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BinanceExchangeInfo {
+    pub symbols: Vec<BinanceSymbolInfo>,
+}
+
+/// Listed symbol contained in [`BinanceExchangeInfo`].
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BinanceSymbolInfo {
+    pub symbol: String,
+}
+
+impl<Server> MarketListing for Binance<Server>
+where
+    Server: ExchangeServer,
+{
+    fn fetch_listed_markets<Instrument, Kind>(
+        client: &reqwest::Client,
+        _: &[Subscription<Self, Instrument, Kind>],
+    ) -> impl Future<Output = Result<FnvHashSet<String>, SocketError>> + Send
+    where
+        Instrument: InstrumentData,
+    {
+        let url = match Server::ID {
+            ExchangeId::BinanceFuturesUsd => HTTP_EXCHANGE_INFO_URL_BINANCE_FUTURES_USD,
+            _ => HTTP_EXCHANGE_INFO_URL_BINANCE_SPOT,
+        };
+
+        fetch_listed_markets(client, url)
+    }
+}
+
+/// Fetch the symbols listed on Binance from the HTTP exchange information `url`.
+pub async fn fetch_listed_markets(
+    client: &reqwest::Client,
+    url: &str,
+) -> Result<FnvHashSet<String>, SocketError> {
+    let info = client
+        .get(url)
+        .send()
+        .await
+        .map_err(SocketError::Http)?
+        .json::<BinanceExchangeInfo>()
+        .await
+        .map_err(SocketError::Http)?;
+
+    Ok(info
+        .symbols
+        .into_iter()
+        .map(|symbol| symbol.symbol)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::{binance::spot::BinanceSpot, listing::unlisted_markets},
+        streams::test_utils::bind_mock_http,
+        subscription::trade::PublicTrades,
+    };
+    use barter_instrument::instrument::market_data::{
+        MarketDataInstrument, kind::MarketDataInstrumentKind,
+    };
+
+    #[tokio::test]
+    async fn test_unknown_symbol_is_named() {
+        let url = bind_mock_http(
+            r#"{
+                "timezone": "UTC",
+                "serverTime": 1565246363776,
+                "symbols": [
+                    {"symbol": "BTCUSDT", "status": "TRADING", "baseAsset": "BTC", "quoteAsset": "USDT"},
+                    {"symbol": "ETHBTC", "status": "TRADING", "baseAsset": "ETH", "quoteAsset": "BTC"}
+                ]
+            }"#
+            .to_string(),
+        )
+        .await;
+
+        let subscriptions = [("btc", "usdt"), ("foo", "usdt")].map(|(base, quote)| {
+            Subscription::<_, MarketDataInstrument, _>::new(
+                BinanceSpot::default(),
+                MarketDataInstrument::new(base, quote, MarketDataInstrumentKind::Spot),
+                PublicTrades,
+            )
+        });
+
+        let listed = fetch_listed_markets(&reqwest::Client::new(), &url)
+            .await
+            .unwrap();
+
+        let actual = unlisted_markets(&subscriptions, &listed);
+        assert_eq!(actual, vec!["FOOUSDT"]);
+    }
+}
@@ -1,6 +1,9 @@
 use self::{
-    book::l1::BinanceOrderBookL1, channel::BinanceChannel, market::BinanceMarket,
-    subscription::BinanceSubResponse, trade::BinanceTrade,
+    book::l1::BinanceOrderBookL1,
+    channel::BinanceChannel,
+    market::BinanceMarket,
+    subscription::BinanceSubResponse,
+    trade::{BinanceTrade, BinanceTradesBackfillFetcher},
 };
 use crate::{
     ExchangeWsStream, NoInitialSnapshots,
@@ -27,6 +30,10 @@ pub mod channel;
 /// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
 pub mod futures;
 
+/// [`MarketListing`](crate::exchange::listing::MarketListing) implementation common to both
+/// [`BinanceSpot`](spot::BinanceSpot) and [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
+pub mod listing;
+
 /// Defines the type that translates a Jackbot [`Subscription`](crate::subscription::Subscription)
 /// into an execution [`Connector`] specific market used for generating [`Connector::requests`].
 pub mod market;
@@ -104,7 +111,7 @@ where
     Instrument: InstrumentData,
     Server: ExchangeServer + Debug + Send + Sync,
 {
-    type SnapFetcher = NoInitialSnapshots;
+    type SnapFetcher = BinanceTradesBackfillFetcher;
     type Stream =
         ExchangeWsStream<StatelessTransformer<Self, Instrument::Key, PublicTrades, BinanceTrade>>;
 }
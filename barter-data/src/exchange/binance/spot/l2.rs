@@ -40,6 +40,7 @@ pub struct BinanceSpotOrderBooksL2SnapshotFetcher;
 
 impl SnapshotFetcher<BinanceSpot, OrderBooksL2> for BinanceSpotOrderBooksL2SnapshotFetcher {
     fn fetch_snapshots<Instrument>(
+        client: &reqwest::Client,
         subscriptions: &[Subscription<BinanceSpot, Instrument, OrderBooksL2>],
     ) -> impl Future<Output = Result<Vec<MarketEvent<Instrument::Key, OrderBookEvent>>, SocketError>>
     + Send
@@ -57,7 +58,9 @@ impl SnapshotFetcher<BinanceSpot, OrderBooksL2> for BinanceSpotOrderBooksL2Snaps
 
             async move {
                 // Fetch initial OrderBook snapshot via HTTP
-                let snapshot = reqwest::get(snapshot_url)
+                let snapshot = client
+                    .get(snapshot_url)
+                    .send()
                     .await
                     .map_err(SocketError::Http)?
                     .json::<BinanceOrderBookL2Snapshot>()
@@ -1,14 +1,34 @@
-use super::BinanceChannel;
+use super::{Binance, BinanceChannel, market::BinanceMarket};
 use crate::{
-    Identifier,
+    Identifier, SnapshotFetcher,
     event::{MarketEvent, MarketIter},
-    exchange::ExchangeSub,
-    subscription::trade::PublicTrade,
+    exchange::{Connector, ExchangeServer, ExchangeSub},
+    instrument::InstrumentData,
+    subscription::{
+        Subscription,
+        trade::{PublicTrade, PublicTrades},
+    },
 };
 use barter_instrument::{Side, exchange::ExchangeId};
-use barter_integration::subscription::SubscriptionId;
+use barter_integration::{error::SocketError, subscription::SubscriptionId};
 use chrono::{DateTime, Utc};
+use futures_util::{TryFutureExt, future::try_join_all};
 use serde::{Deserialize, Serialize};
+use std::future::Future;
+
+/// [`BinanceSpot`](super::spot::BinanceSpot) HTTP recent trades url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#recent-trades-list>
+pub const HTTP_RECENT_TRADES_URL_BINANCE_SPOT: &str = "https://api.binance.com/api/v3/trades";
+
+/// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) HTTP recent trades url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#recent-trades-list>
+pub const HTTP_RECENT_TRADES_URL_BINANCE_FUTURES_USD: &str =
+    "https://fapi.binance.com/fapi/v1/trades";
+
+/// Number of recent trades fetched per instrument by the [`BinanceTradesBackfillFetcher`].
+pub const HTTP_RECENT_TRADES_LIMIT_BINANCE: usize = 100;
 
 /// Binance real-time trade message.
 ///
@@ -95,6 +115,131 @@ impl<InstrumentKey> From<(ExchangeId, InstrumentKey, BinanceTrade)>
     }
 }
 
+/// Binance HTTP recent trade.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#recent-trades-list>
+/// ```json
+/// {
+///     "id": 28457,
+///     "price": "4.00000100",
+///     "qty": "12.00000000",
+///     "quoteQty": "48.000012",
+///     "time": 1499865549590,
+///     "isBuyerMaker": true,
+///     "isBestMatch": true
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceRecentTrade {
+    pub id: u64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(rename = "qty", deserialize_with = "barter_integration::de::de_str")]
+    pub amount: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+    #[serde(
+        rename = "isBuyerMaker",
+        deserialize_with = "de_side_from_buyer_is_maker"
+    )]
+    pub side: Side,
+}
+
+impl<InstrumentKey> From<(ExchangeId, InstrumentKey, BinanceRecentTrade)>
+    for MarketEvent<InstrumentKey, PublicTrade>
+{
+    fn from(
+        (exchange, instrument, trade): (ExchangeId, InstrumentKey, BinanceRecentTrade),
+    ) -> Self {
+        Self {
+            time_exchange: trade.time,
+            time_received: Utc::now(),
+            exchange,
+            instrument,
+            kind: PublicTrade {
+                id: trade.id.to_string(),
+                price: trade.price,
+                amount: trade.amount,
+                side: trade.side,
+            },
+        }
+    }
+}
+
+/// [`SnapshotFetcher`] that backfills the most recent [`PublicTrade`]s of each
+/// [`Subscription`] via HTTP, so they are yielded before the live [`Binance`] trade stream.
+///
+/// Note that recent trades are re-fetched each time the stream (re)connects.
+#[derive(Debug)]
+pub struct BinanceTradesBackfillFetcher;
+
+impl<Server> SnapshotFetcher<Binance<Server>, PublicTrades> for BinanceTradesBackfillFetcher
+where
+    Server: ExchangeServer,
+{
+    fn fetch_snapshots<Instrument>(
+        client: &reqwest::Client,
+        subscriptions: &[Subscription<Binance<Server>, Instrument, PublicTrades>],
+    ) -> impl Future<Output = Result<Vec<MarketEvent<Instrument::Key, PublicTrade>>, SocketError>> + Send
+    where
+        Instrument: InstrumentData,
+        Subscription<Binance<Server>, Instrument, PublicTrades>:
+            Identifier<<Binance<Server> as Connector>::Market>,
+    {
+        let url = match Server::ID {
+            ExchangeId::BinanceFuturesUsd => HTTP_RECENT_TRADES_URL_BINANCE_FUTURES_USD,
+            _ => HTTP_RECENT_TRADES_URL_BINANCE_SPOT,
+        };
+
+        let trades_futures = subscriptions.iter().map(|subscription| {
+            fetch_recent_trades(
+                client,
+                Server::ID,
+                url,
+                subscription.id(),
+                subscription.instrument.key().clone(),
+            )
+        });
+
+        try_join_all(trades_futures).map_ok(|trades| trades.into_iter().flatten().collect())
+    }
+}
+
+/// Fetch the most recent [`PublicTrade`]s for the provided [`BinanceMarket`] from the Binance
+/// HTTP recent trades `url`, ordered from oldest to newest.
+pub async fn fetch_recent_trades<InstrumentKey>(
+    client: &reqwest::Client,
+    exchange: ExchangeId,
+    url: &str,
+    market: BinanceMarket,
+    instrument: InstrumentKey,
+) -> Result<Vec<MarketEvent<InstrumentKey, PublicTrade>>, SocketError>
+where
+    InstrumentKey: Clone,
+{
+    let trades_url = format!(
+        "{}?symbol={}&limit={}",
+        url, market.0, HTTP_RECENT_TRADES_LIMIT_BINANCE
+    );
+
+    let mut trades = client
+        .get(trades_url)
+        .send()
+        .await
+        .map_err(SocketError::Http)?
+        .json::<Vec<BinanceRecentTrade>>()
+        .await
+        .map_err(SocketError::Http)?;
+
+    trades.sort_by_key(|trade| trade.id);
+
+    Ok(trades
+        .into_iter()
+        .map(|trade| MarketEvent::from((exchange, instrument.clone(), trade)))
+        .collect())
+}
+
 /// Deserialize a [`BinanceTrade`] "s" (eg/ "BTCUSDT") as the associated [`SubscriptionId`]
 /// (eg/ "@trade|BTCUSDT").
 pub fn de_trade_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
@@ -251,4 +396,64 @@ mod tests {
             }
         }
     }
+
+    mod backfill {
+        use super::*;
+        use crate::streams::test_utils::bind_mock_http;
+
+        #[tokio::test]
+        async fn test_fetch_recent_trades() {
+            let url = bind_mock_http(
+                r#"[
+                    {"id":3,"price":"102.0","qty":"0.3","quoteQty":"30.6","time":1649324825003,"isBuyerMaker":true,"isBestMatch":true},
+                    {"id":1,"price":"100.0","qty":"0.1","quoteQty":"10.0","time":1649324825001,"isBuyerMaker":false,"isBestMatch":true},
+                    {"id":2,"price":"101.0","qty":"0.2","quoteQty":"20.2","time":1649324825002,"isBuyerMaker":false,"isBestMatch":true}
+                ]"#
+                .to_string(),
+            )
+            .await;
+
+            let trades = fetch_recent_trades(
+                &reqwest::Client::new(),
+                ExchangeId::BinanceSpot,
+                &url,
+                BinanceMarket("BTCUSDT".into()),
+                "btc_usdt",
+            )
+            .await
+            .unwrap();
+
+            let actual = trades
+                .into_iter()
+                .map(|event| {
+                    assert_eq!(event.exchange, ExchangeId::BinanceSpot);
+                    assert_eq!(event.instrument, "btc_usdt");
+                    event.kind
+                })
+                .collect::<Vec<_>>();
+
+            let expected = vec![
+                PublicTrade {
+                    id: "1".to_string(),
+                    price: 100.0,
+                    amount: 0.1,
+                    side: Side::Buy,
+                },
+                PublicTrade {
+                    id: "2".to_string(),
+                    price: 101.0,
+                    amount: 0.2,
+                    side: Side::Buy,
+                },
+                PublicTrade {
+                    id: "3".to_string(),
+                    price: 102.0,
+                    amount: 0.3,
+                    side: Side::Sell,
+                },
+            ];
+
+            assert_eq!(actual, expected);
+        }
+    }
 }
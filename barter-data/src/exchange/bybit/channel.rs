@@ -22,8 +22,11 @@ impl BybitChannel {
     /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/trade>
     pub const TRADES: Self = Self("publicTrade");
 
-    /// [`Bybit`] OrderBook Level2 channel name.
-    pub const ORDER_BOOK_L2: Self = Self("orderbook");
+    /// [`Bybit`] OrderBook Level2 channel name, subscribing to the 50 level depth stream
+    /// supported by both spot & linear markets.
+    ///
+    /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+    pub const ORDER_BOOK_L2: Self = Self("orderbook.50");
 }
 
 impl<Server, Instrument> Identifier<BybitChannel>
@@ -0,0 +1,442 @@
+use crate::{
+    Identifier, SnapshotFetcher,
+    books::OrderBook,
+    error::DataError,
+    event::{MarketEvent, MarketIter},
+    exchange::{
+        Connector,
+        bybit::{
+            futures::BybitPerpetualsUsd,
+            market::BybitMarket,
+            spot::l2::{
+                BybitOrderBookL2Meta, BybitOrderBookL2Snapshot, BybitSpotOrderBookL2Sequencer,
+                BybitSpotOrderBookL2Update,
+            },
+        },
+    },
+    instrument::InstrumentData,
+    subscription::{
+        Map, Subscription,
+        book::{OrderBookEvent, OrderBooksL2},
+    },
+    transformer::ExchangeTransformer,
+};
+use async_trait::async_trait;
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::{Transformer, error::SocketError, protocol::websocket::WsMessage};
+use chrono::Utc;
+use futures_util::future::try_join_all;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use tokio::sync::mpsc::UnboundedSender;
+
+/// [`BybitPerpetualsUsd`] HTTP OrderBook L2 snapshot url.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/market/orderbook>
+pub const HTTP_BOOK_L2_SNAPSHOT_URL_BYBIT_PERPETUALS_USD: &str =
+    "https://api.bybit.com/v5/market/orderbook";
+
+/// [`BybitPerpetualsUsd`] OrderBook L2 WebSocket message.
+///
+/// The linear `orderbook.50.SYMBOL` payload shares the same format as the spot payload.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+/// ```json
+/// {
+///     "topic": "orderbook.50.BTCUSDT",
+///     "type": "delta",
+///     "ts": 1687940967466,
+///     "data": {
+///         "s": "BTCUSDT",
+///         "b": [["30247.20", "30.028"], ["30245.40", "0"]],
+///         "a": [["30248.70", "0"]],
+///         "u": 177400507,
+///         "seq": 66544703342
+///     },
+///     "cts": 1687940967464
+/// }
+/// ```
+pub type BybitPerpetualsUsdOrderBookL2Update = BybitSpotOrderBookL2Update;
+
+/// [`BybitPerpetualsUsd`] OrderBook L2 sequencer, validating the contiguous update id `u` in the
+/// same way as the spot sequencer.
+pub type BybitPerpetualsUsdOrderBookL2Sequencer = BybitSpotOrderBookL2Sequencer;
+
+/// [`BybitPerpetualsUsd`] HTTP OrderBook L2 snapshot response.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BybitPerpetualsUsdOrderBookL2SnapshotResponse {
+    pub result: BybitOrderBookL2Snapshot,
+}
+
+#[derive(Debug)]
+pub struct BybitPerpetualsUsdOrderBooksL2SnapshotFetcher;
+
+impl SnapshotFetcher<BybitPerpetualsUsd, OrderBooksL2>
+    for BybitPerpetualsUsdOrderBooksL2SnapshotFetcher
+{
+    fn fetch_snapshots<Instrument>(
+        client: &reqwest::Client,
+        subscriptions: &[Subscription<BybitPerpetualsUsd, Instrument, OrderBooksL2>],
+    ) -> impl Future<Output = Result<Vec<MarketEvent<Instrument::Key, OrderBookEvent>>, SocketError>>
+    + Send
+    where
+        Instrument: InstrumentData,
+        Subscription<BybitPerpetualsUsd, Instrument, OrderBooksL2>: Identifier<BybitMarket>,
+    {
+        let snapshot_futures = subscriptions.iter().map(|sub| {
+            let market = sub.id();
+            let snapshot_url = format!(
+                "{}?category=linear&symbol={}&limit=200",
+                HTTP_BOOK_L2_SNAPSHOT_URL_BYBIT_PERPETUALS_USD,
+                market.as_ref(),
+            );
+
+            async move {
+                let snapshot = client
+                    .get(snapshot_url)
+                    .send()
+                    .await
+                    .map_err(SocketError::Http)?
+                    .json::<BybitPerpetualsUsdOrderBookL2SnapshotResponse>()
+                    .await
+                    .map_err(SocketError::Http)?
+                    .result;
+
+                Ok(MarketEvent::from((
+                    ExchangeId::BybitPerpetualsUsd,
+                    sub.instrument.key().clone(),
+                    snapshot,
+                )))
+            }
+        });
+
+        try_join_all(snapshot_futures)
+    }
+}
+
+#[derive(Debug)]
+pub struct BybitPerpetualsUsdOrderBooksL2Transformer<InstrumentKey> {
+    instrument_map:
+        Map<BybitOrderBookL2Meta<InstrumentKey, BybitPerpetualsUsdOrderBookL2Sequencer>>,
+}
+
+#[async_trait]
+impl<InstrumentKey> ExchangeTransformer<BybitPerpetualsUsd, InstrumentKey, OrderBooksL2>
+    for BybitPerpetualsUsdOrderBooksL2Transformer<InstrumentKey>
+where
+    InstrumentKey: Clone + PartialEq + Send + Sync,
+{
+    async fn init(
+        instrument_map: Map<InstrumentKey>,
+        initial_snapshots: &[MarketEvent<InstrumentKey, OrderBookEvent>],
+        _: UnboundedSender<WsMessage>,
+    ) -> Result<Self, DataError> {
+        let instrument_map = instrument_map
+            .0
+            .into_iter()
+            .map(|(sub_id, instrument_key)| {
+                let snapshot = initial_snapshots
+                    .iter()
+                    .find(|snapshot| snapshot.instrument == instrument_key)
+                    .ok_or_else(|| DataError::InitialSnapshotMissing(sub_id.clone()))?;
+
+                let OrderBookEvent::Snapshot(snapshot) = &snapshot.kind else {
+                    return Err(DataError::InitialSnapshotInvalid(String::from(
+                        "expected OrderBookEvent::Snapshot but found OrderBookEvent::Update",
+                    )));
+                };
+
+                let sequencer = BybitPerpetualsUsdOrderBookL2Sequencer::new(snapshot.sequence);
+                Ok((sub_id, BybitOrderBookL2Meta::new(instrument_key, sequencer)))
+            })
+            .collect::<Result<Map<_>, _>>()?;
+
+        Ok(Self { instrument_map })
+    }
+}
+
+impl<InstrumentKey> Transformer for BybitPerpetualsUsdOrderBooksL2Transformer<InstrumentKey>
+where
+    InstrumentKey: Clone,
+{
+    type Error = DataError;
+    type Input = BybitPerpetualsUsdOrderBookL2Update;
+    type Output = MarketEvent<InstrumentKey, OrderBookEvent>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        let Some(subscription_id) = input.id() else {
+            return vec![];
+        };
+
+        let instrument = match self.instrument_map.find_mut(&subscription_id) {
+            Ok(instrument) => instrument,
+            Err(unidentifiable) => return vec![Err(DataError::from(unidentifiable))],
+        };
+
+        // Bybit sends a full snapshot on subscription & after a service restart, which
+        // replaces the local OrderBook and resets the sequence
+        if input.r#type == "snapshot" {
+            instrument.sequencer = BybitPerpetualsUsdOrderBookL2Sequencer::new(input.data.sequence);
+
+            return vec![Ok(MarketEvent {
+                time_exchange: input.time_exchange,
+                time_received: Utc::now(),
+                exchange: BybitPerpetualsUsd::ID,
+                instrument: instrument.key.clone(),
+                kind: OrderBookEvent::Snapshot(OrderBook::new(
+                    input.data.sequence,
+                    None,
+                    input.data.bids,
+                    input.data.asks,
+                )),
+            })];
+        }
+
+        let valid_update = match instrument.sequencer.validate_sequence(input) {
+            Ok(Some(valid_update)) => valid_update,
+            Ok(None) => return vec![],
+            Err(error) => return vec![Err(error)],
+        };
+
+        MarketIter::<InstrumentKey, OrderBookEvent>::from((
+            BybitPerpetualsUsd::ID,
+            instrument.key.clone(),
+            valid_update,
+        ))
+        .0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        books::Level,
+        exchange::bybit::spot::l2::{BybitLevel, BybitSpotOrderBookL2UpdatePayload},
+    };
+    use barter_integration::subscription::SubscriptionId;
+    use chrono::DateTime;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+    use tokio::sync::mpsc;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_bybit_perpetuals_usd_order_book_l2_update() {
+            let input = r#"
+                {
+                    "topic": "orderbook.50.BTCUSDT",
+                    "type": "delta",
+                    "ts": 1687940967466,
+                    "data": {
+                        "s": "BTCUSDT",
+                        "b": [["30247.20", "30.028"], ["30245.40", "0"]],
+                        "a": [["30248.70", "0"]],
+                        "u": 177400507,
+                        "seq": 66544703342
+                    },
+                    "cts": 1687940967464
+                }
+            "#;
+
+            let actual =
+                serde_json::from_str::<BybitPerpetualsUsdOrderBookL2Update>(input).unwrap();
+
+            assert_eq!(
+                actual.subscription_id,
+                SubscriptionId::from("orderbook.50|BTCUSDT")
+            );
+            assert_eq!(actual.r#type, "delta");
+            assert_eq!(
+                actual.time_exchange,
+                DateTime::from_timestamp_millis(1687940967466).unwrap()
+            );
+            assert_eq!(actual.data.sequence, 177400507);
+            assert_eq!(
+                actual.data.bids,
+                vec![
+                    BybitLevel {
+                        price: dec!(30247.20),
+                        amount: dec!(30.028)
+                    },
+                    BybitLevel {
+                        price: dec!(30245.40),
+                        amount: dec!(0)
+                    },
+                ]
+            );
+            assert_eq!(
+                actual.data.asks,
+                vec![BybitLevel {
+                    price: dec!(30248.70),
+                    amount: dec!(0)
+                }]
+            );
+        }
+
+        #[test]
+        fn test_bybit_perpetuals_usd_order_book_l2_snapshot_response() {
+            let input = r#"
+                {
+                    "retCode": 0,
+                    "retMsg": "OK",
+                    "result": {
+                        "s": "BTCUSDT",
+                        "a": [["65557.7", "16.606555"]],
+                        "b": [["65485.47", "47.081829"]],
+                        "ts": 1716863719031,
+                        "u": 230704,
+                        "seq": 1432604333,
+                        "cts": 1716863718905
+                    },
+                    "retExtInfo": {},
+                    "time": 1716863719382
+                }
+            "#;
+
+            let actual =
+                serde_json::from_str::<BybitPerpetualsUsdOrderBookL2SnapshotResponse>(input)
+                    .unwrap();
+
+            assert_eq!(actual.result.sequence, 230704);
+            assert_eq!(
+                actual.result.bids,
+                vec![BybitLevel {
+                    price: dec!(65485.47),
+                    amount: dec!(47.081829)
+                }]
+            );
+            assert_eq!(
+                actual.result.asks,
+                vec![BybitLevel {
+                    price: dec!(65557.7),
+                    amount: dec!(16.606555)
+                }]
+            );
+        }
+    }
+
+    fn update(
+        r#type: &str,
+        sequence: u64,
+        bids: Vec<BybitLevel>,
+        asks: Vec<BybitLevel>,
+    ) -> BybitPerpetualsUsdOrderBookL2Update {
+        BybitPerpetualsUsdOrderBookL2Update {
+            subscription_id: SubscriptionId::from("orderbook.50|BTCUSDT"),
+            r#type: r#type.to_string(),
+            time_exchange: DateTime::from_timestamp_millis(1687940967466).unwrap(),
+            data: BybitSpotOrderBookL2UpdatePayload {
+                sequence,
+                bids,
+                asks,
+            },
+        }
+    }
+
+    fn level(price: Decimal, amount: Decimal) -> BybitLevel {
+        BybitLevel { price, amount }
+    }
+
+    #[tokio::test]
+    async fn test_bybit_perpetuals_usd_order_books_l2_transformer_sequencing() {
+        struct TestCase {
+            input: BybitPerpetualsUsdOrderBookL2Update,
+            expected: Vec<Result<OrderBookEvent, ()>>,
+        }
+
+        let initial_snapshot = MarketEvent {
+            time_exchange: Default::default(),
+            time_received: Default::default(),
+            exchange: ExchangeId::BybitPerpetualsUsd,
+            instrument: "btc_usdt_perp",
+            kind: OrderBookEvent::Snapshot(OrderBook::new(
+                10,
+                None,
+                vec![Level::new(dec!(100), dec!(1))],
+                vec![Level::new(dec!(101), dec!(1))],
+            )),
+        };
+
+        let mut transformer = BybitPerpetualsUsdOrderBooksL2Transformer::init(
+            Map::from_iter([(
+                SubscriptionId::from("orderbook.50|BTCUSDT"),
+                "btc_usdt_perp",
+            )]),
+            &[initial_snapshot],
+            mpsc::unbounded_channel().0,
+        )
+        .await
+        .unwrap();
+
+        let tests = vec![
+            TestCase {
+                // TC0: update preceding the initial snapshot is dropped
+                input: update("delta", 10, vec![level(dec!(99), dec!(1))], vec![]),
+                expected: vec![],
+            },
+            TestCase {
+                // TC1: next contiguous update is applied
+                input: update("delta", 11, vec![level(dec!(100), dec!(2))], vec![]),
+                expected: vec![Ok(OrderBookEvent::Update(OrderBook::new(
+                    11,
+                    None,
+                    vec![Level::new(dec!(100), dec!(2))],
+                    vec![],
+                )))],
+            },
+            TestCase {
+                // TC2: update with a sequence gap is an error
+                input: update("delta", 13, vec![], vec![level(dec!(101), dec!(0))]),
+                expected: vec![Err(())],
+            },
+            TestCase {
+                // TC3: WebSocket snapshot replaces the OrderBook and resets the sequence
+                input: update(
+                    "snapshot",
+                    1,
+                    vec![level(dec!(200), dec!(1))],
+                    vec![level(dec!(201), dec!(1))],
+                ),
+                expected: vec![Ok(OrderBookEvent::Snapshot(OrderBook::new(
+                    1,
+                    None,
+                    vec![Level::new(dec!(200), dec!(1))],
+                    vec![Level::new(dec!(201), dec!(1))],
+                )))],
+            },
+            TestCase {
+                // TC4: update following the WebSocket snapshot is applied
+                input: update("delta", 2, vec![], vec![level(dec!(202), dec!(3))]),
+                expected: vec![Ok(OrderBookEvent::Update(OrderBook::new(
+                    2,
+                    None,
+                    vec![],
+                    vec![Level::new(dec!(202), dec!(3))],
+                )))],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = transformer
+                .transform(test.input)
+                .into_iter()
+                .map(|result| {
+                    result
+                        .map(|event| {
+                            assert_eq!(event.exchange, ExchangeId::BybitPerpetualsUsd);
+                            assert_eq!(event.instrument, "btc_usdt_perp");
+                            event.kind
+                        })
+                        .map_err(|_| ())
+                })
+                .collect::<Vec<_>>();
+
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
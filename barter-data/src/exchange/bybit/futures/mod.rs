@@ -1,7 +1,22 @@
 use super::{Bybit, ExchangeServer};
+use crate::{
+    ExchangeWsStream,
+    exchange::{
+        StreamSelector,
+        bybit::futures::l2::{
+            BybitPerpetualsUsdOrderBooksL2SnapshotFetcher,
+            BybitPerpetualsUsdOrderBooksL2Transformer,
+        },
+    },
+    instrument::InstrumentData,
+    subscription::book::OrderBooksL2,
+};
 use barter_instrument::exchange::ExchangeId;
 use std::fmt::Display;
 
+/// Level 2 OrderBook types.
+pub mod l2;
+
 /// [`BybitPerpetualsUsd`] WebSocket server base url.
 ///
 /// See docs: <https://bybit-exchange.github.io/docs/v5/ws/connect>
@@ -22,6 +37,14 @@ impl ExchangeServer for BybitServerPerpetualsUsd {
     }
 }
 
+impl<Instrument> StreamSelector<Instrument, OrderBooksL2> for BybitPerpetualsUsd
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = BybitPerpetualsUsdOrderBooksL2SnapshotFetcher;
+    type Stream = ExchangeWsStream<BybitPerpetualsUsdOrderBooksL2Transformer<Instrument::Key>>;
+}
+
 impl Display for BybitPerpetualsUsd {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "BybitPerpetualsUsd")
@@ -1,4 +1,4 @@
-use super::super::super::market::BybitMarket;
+use super::super::market::BybitMarket;
 use super::super::channel::BybitChannel;
 use crate::{
     Identifier, SnapshotFetcher,
@@ -136,14 +136,22 @@ where
 }
 
 #[derive(Debug)]
-pub struct BybitSpotOrderBookL2Sequencer { pub last_sequence: u64 }
+pub struct BybitSpotOrderBookL2Sequencer {
+    pub last_sequence: u64,
+    /// Number of skipped sequence numbers tolerated between updates, where zero requires
+    /// strictly consecutive sequences.
+    pub max_gap: u64,
+}
 
 impl BybitSpotOrderBookL2Sequencer {
-    pub fn new(sequence: u64) -> Self { Self { last_sequence: sequence } }
+    pub fn new(sequence: u64) -> Self { Self { last_sequence: sequence, max_gap: 0 } }
+
+    /// Tolerate up to `max_gap` skipped sequence numbers between updates, rather than erroring.
+    pub fn with_max_gap(self, max_gap: u64) -> Self { Self { max_gap, ..self } }
 
     pub fn validate_sequence(&mut self, update: BybitSpotOrderBookL2Update) -> Result<Option<BybitSpotOrderBookL2Update>, DataError> {
         if update.data.sequence <= self.last_sequence { return Ok(None); }
-        if update.data.sequence != self.last_sequence + 1 {
+        if update.data.sequence > self.last_sequence.saturating_add(self.max_gap + 1) {
             return Err(DataError::InvalidSequence { prev_last_update_id: self.last_sequence, first_update_id: update.data.sequence });
         }
         self.last_sequence = update.data.sequence;
@@ -156,6 +164,7 @@ pub struct BybitSpotOrderBooksL2SnapshotFetcher;
 
 impl SnapshotFetcher<BybitSpot, OrderBooksL2> for BybitSpotOrderBooksL2SnapshotFetcher {
     fn fetch_snapshots<Instrument>(
+        client: &reqwest::Client,
         subscriptions: &[Subscription<BybitSpot, Instrument, OrderBooksL2>],
     ) -> impl Future<Output = Result<Vec<MarketEvent<Instrument::Key, OrderBookEvent>>, SocketError>> + Send
     where
@@ -166,10 +175,10 @@ impl SnapshotFetcher<BybitSpot, OrderBooksL2> for BybitSpotOrderBooksL2SnapshotF
             let market = sub.id();
             let url = format!("{}?category=spot&symbol={}&limit=200", HTTP_BOOK_L2_SNAPSHOT_URL_BYBIT_SPOT, market.as_ref());
             async move {
-                let resp = reqwest::get(url).await.map_err(SocketError::Http)?;
+                let resp = client.get(url).send().await.map_err(SocketError::Http)?;
                 let value = resp.json::<serde_json::Value>().await.map_err(SocketError::Http)?;
                 let data = value.get("result").cloned().unwrap_or(value);
-                let snapshot: BybitOrderBookL2Snapshot = serde_json::from_value(data).map_err(SocketError::Serde)?;
+                let snapshot: BybitOrderBookL2Snapshot = serde_json::from_value(data).map_err(SocketError::Serialise)?;
                 Ok(MarketEvent::from((ExchangeId::BybitSpot, sub.instrument.key().clone(), snapshot)))
             }
         });
@@ -249,9 +258,9 @@ mod tests {
 
     #[test]
     fn test_de_bybit_spot_order_book_l2_update() {
-        let input = r#"{\"topic\":\"orderbook.50.BTCUSDT\",\"type\":\"delta\",\"ts\":1000,\"data\":{\"u\":2,\"b\":[[\"100\",\"1\"]],\"a\":[]}}"#;
+        let input = r#"{"topic":"orderbook.50.BTCUSDT","type":"delta","ts":1000,"data":{"u":2,"b":[["100","1"]],"a":[]}}"#;
         let parsed: BybitSpotOrderBookL2Update = serde_json::from_str(input).unwrap();
-        assert_eq!(parsed.subscription_id, SubscriptionId::from("orderbook|BTCUSDT"));
+        assert_eq!(parsed.subscription_id, SubscriptionId::from("orderbook.50|BTCUSDT"));
         assert_eq!(parsed.data.sequence, 2);
         assert_eq!(parsed.data.bids, vec![BybitLevel { price: dec!(100), amount: dec!(1) }]);
     }
@@ -260,7 +269,7 @@ mod tests {
     fn test_sequencer_validate_sequence() {
         let mut seq = BybitSpotOrderBookL2Sequencer::new(1);
         let update = BybitSpotOrderBookL2Update {
-            subscription_id: SubscriptionId::from("orderbook|BTCUSDT"),
+            subscription_id: SubscriptionId::from("orderbook.50|BTCUSDT"),
             r#type: "delta".into(),
             time_exchange: DateTime::from_timestamp_millis(0).unwrap(),
             data: BybitSpotOrderBookL2UpdatePayload { sequence: 2, bids: vec![], asks: vec![] },
@@ -268,12 +277,27 @@ mod tests {
         assert!(seq.validate_sequence(update).is_ok());
     }
 
+    #[test]
+    fn test_sequencer_validate_sequence_with_max_gap() {
+        let update = |sequence| BybitSpotOrderBookL2Update {
+            subscription_id: SubscriptionId::from("orderbook.50|BTCUSDT"),
+            r#type: "delta".into(),
+            time_exchange: DateTime::from_timestamp_millis(0).unwrap(),
+            data: BybitSpotOrderBookL2UpdatePayload { sequence, bids: vec![], asks: vec![] },
+        };
+        let mut seq = BybitSpotOrderBookL2Sequencer::new(1).with_max_gap(2);
+        assert!(seq.validate_sequence(update(4)).unwrap().is_some());
+        assert_eq!(seq.last_sequence, 4);
+        assert!(seq.validate_sequence(update(8)).is_err());
+        assert_eq!(seq.last_sequence, 4);
+    }
+
     #[test]
     fn test_update_jackbot_order_book_with_sequenced_updates() {
         let mut seq = BybitSpotOrderBookL2Sequencer::new(1);
         let mut book = OrderBook::new(1, None, vec![Level::new(50,1)], vec![Level::new(100,1)]);
         let update = BybitSpotOrderBookL2Update {
-            subscription_id: SubscriptionId::from("orderbook|BTCUSDT"),
+            subscription_id: SubscriptionId::from("orderbook.50|BTCUSDT"),
             r#type: "delta".into(),
             time_exchange: DateTime::from_timestamp_millis(0).unwrap(),
             data: BybitSpotOrderBookL2UpdatePayload {
@@ -285,7 +309,7 @@ mod tests {
         if let Some(valid) = seq.validate_sequence(update).unwrap() {
             book.update(OrderBookEvent::Update(OrderBook::new(valid.data.sequence, None, valid.data.bids, valid.data.asks)));
         }
-        assert_eq!(book, OrderBook::new(2, None, vec![Level::new(100,1)], vec![Level::new(110,2)]));
+        assert_eq!(book, OrderBook::new(2, None, vec![Level::new(50,1)], vec![Level::new(100,1), Level::new(110,2)]));
     }
 }
 
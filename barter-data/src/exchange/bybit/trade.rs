@@ -356,11 +356,11 @@ mod tests {
 
         let trades: BybitTrade = serde_json::from_str(trade_json).unwrap();
         let events: MarketIter<&str, PublicTrade> =
-            (ExchangeId::Bybit, "BTCUSDT", trades).into();
+            (ExchangeId::BybitSpot, "BTCUSDT", trades).into();
 
         assert_eq!(events.0.len(), 1);
         let event = events.0.into_iter().next().unwrap().unwrap();
-        assert_eq!(event.exchange, ExchangeId::Bybit);
+        assert_eq!(event.exchange, ExchangeId::BybitSpot);
         assert_eq!(event.instrument, "BTCUSDT");
         assert_eq!(event.kind, PublicTrade {
             id: "id1".to_string(),
@@ -370,4 +370,71 @@ mod tests {
         });
         assert!(event.time_received >= event.time_exchange);
     }
+
+    #[test]
+    fn test_market_iter_conversion_perpetuals_usd() {
+        // Linear trades contain additional fields (eg/ "seq") & may be batched
+        let trade_json = r#"{
+            "topic": "publicTrade.BTCUSDT",
+            "type": "snapshot",
+            "ts": 1672304486868,
+            "data": [
+                {
+                    "T": 1672304486865,
+                    "s": "BTCUSDT",
+                    "S": "Buy",
+                    "v": "0.001",
+                    "p": "16578.50",
+                    "L": "PlusTick",
+                    "i": "id1",
+                    "BT": false,
+                    "seq": 1783284617
+                },
+                {
+                    "T": 1672304486866,
+                    "s": "BTCUSDT",
+                    "S": "Sell",
+                    "v": "0.5",
+                    "p": "16578.00",
+                    "L": "MinusTick",
+                    "i": "id2",
+                    "BT": false,
+                    "seq": 1783284618
+                }
+            ]
+        }"#;
+
+        let trades: BybitTrade = serde_json::from_str(trade_json).unwrap();
+        let events: MarketIter<&str, PublicTrade> =
+            (ExchangeId::BybitPerpetualsUsd, "btc_usdt_perp", trades).into();
+
+        let trades = events
+            .0
+            .into_iter()
+            .map(|event| {
+                let event = event.unwrap();
+                assert_eq!(event.exchange, ExchangeId::BybitPerpetualsUsd);
+                assert_eq!(event.instrument, "btc_usdt_perp");
+                event.kind
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            trades,
+            vec![
+                PublicTrade {
+                    id: "id1".to_string(),
+                    price: 16578.50,
+                    amount: 0.001,
+                    side: Side::Buy,
+                },
+                PublicTrade {
+                    id: "id2".to_string(),
+                    price: 16578.00,
+                    amount: 0.5,
+                    side: Side::Sell,
+                },
+            ]
+        );
+    }
 }
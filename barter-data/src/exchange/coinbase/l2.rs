@@ -1,4 +1,4 @@
-use super::{channel::CoinbaseChannel, Coinbase};
+use super::{channel::CoinbaseChannel, market::CoinbaseMarket, Coinbase};
 use crate::{
     Identifier, SnapshotFetcher,
     books::OrderBook,
@@ -101,7 +101,7 @@ impl From<CoinbaseLevel> for crate::books::Level {
     }
 }
 
-#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize)]
 pub struct CoinbaseOrderBookL2Update {
     #[serde(alias = "product_id", deserialize_with = "de_ob_l2_subscription_id")]
     pub subscription_id: SubscriptionId,
@@ -182,11 +182,19 @@ where
 #[derive(Debug)]
 pub struct CoinbaseOrderBookL2Sequencer {
     pub sequence: u64,
+    /// Number of skipped sequence numbers tolerated between updates, where zero requires
+    /// strictly consecutive sequences.
+    pub max_gap: u64,
 }
 
 impl CoinbaseOrderBookL2Sequencer {
     pub fn new(sequence: u64) -> Self {
-        Self { sequence }
+        Self { sequence, max_gap: 0 }
+    }
+
+    /// Tolerate up to `max_gap` skipped sequence numbers between updates, rather than erroring.
+    pub fn with_max_gap(self, max_gap: u64) -> Self {
+        Self { max_gap, ..self }
     }
 
     pub fn validate_sequence(
@@ -196,7 +204,7 @@ impl CoinbaseOrderBookL2Sequencer {
         if update.sequence <= self.sequence {
             return Ok(None);
         }
-        if update.sequence != self.sequence + 1 {
+        if update.sequence > self.sequence.saturating_add(self.max_gap + 1) {
             return Err(DataError::InvalidSequence {
                 prev_last_update_id: self.sequence,
                 first_update_id: update.sequence,
@@ -212,6 +220,7 @@ pub struct CoinbaseOrderBooksL2SnapshotFetcher;
 
 impl SnapshotFetcher<Coinbase, OrderBooksL2> for CoinbaseOrderBooksL2SnapshotFetcher {
     fn fetch_snapshots<Instrument>(
+        client: &reqwest::Client,
         subscriptions: &[Subscription<Coinbase, Instrument, OrderBooksL2>],
     ) -> impl Future<Output = Result<Vec<MarketEvent<Instrument::Key, OrderBookEvent>>, SocketError>> + Send
     where
@@ -226,7 +235,9 @@ impl SnapshotFetcher<Coinbase, OrderBooksL2> for CoinbaseOrderBooksL2SnapshotFet
                 market.as_ref()
             );
             async move {
-                let snapshot = reqwest::get(snapshot_url)
+                let snapshot = client
+                    .get(snapshot_url)
+                    .send()
                     .await
                     .map_err(SocketError::Http)?
                     .json::<CoinbaseOrderBookL2Snapshot>()
@@ -308,7 +319,7 @@ mod tests {
 
     #[test]
     fn test_de_coinbase_order_book_l2_snapshot() {
-        let input = r#"{\"sequence\":100,\"bids\":[[\"10101.10\",\"0.50\",\"1\"]],\"asks\":[[\"10102.55\",\"1.0\",\"1\"]]}"#;
+        let input = r#"{"sequence":100,"bids":[["10101.10","0.50","1"]],"asks":[["10102.55","1.0","1"]]}"#;
         assert_eq!(
             serde_json::from_str::<CoinbaseOrderBookL2Snapshot>(input).unwrap(),
             CoinbaseOrderBookL2Snapshot {
@@ -321,13 +332,13 @@ mod tests {
 
     #[test]
     fn test_de_coinbase_order_book_l2_update() {
-        let input = r#"{\"type\":\"l2update\",\"product_id\":\"ETH-USD\",\"time\":\"2014-11-07T08:19:27.028459Z\",\"sequence\":10,\"changes\":[[\"buy\",\"10101.80\",\"0.1\"],[\"sell\",\"10102.02\",\"0\"]]}"#;
+        let input = r#"{"type":"l2update","product_id":"ETH-USD","time":"2014-11-07T08:19:27.028459Z","sequence":10,"changes":[["buy","10101.80","0.1"],["sell","10102.02","0"]]}"#;
         assert_eq!(
             serde_json::from_str::<CoinbaseOrderBookL2Update>(input).unwrap(),
             CoinbaseOrderBookL2Update {
                 subscription_id: SubscriptionId::from("level2|ETH-USD"),
                 sequence: 10,
-                time: DateTime::from_timestamp_millis(1415357967028).unwrap(),
+                time: DateTime::from_timestamp_micros(1415348367028459).unwrap(),
                 changes: vec![
                     CoinbaseChange { side: Side::Buy, level: CoinbaseLevel { price: dec!(10101.80), size: dec!(0.1) } },
                     CoinbaseChange { side: Side::Sell, level: CoinbaseLevel { price: dec!(10102.02), size: dec!(0) } },
@@ -355,4 +366,23 @@ mod tests {
         };
         assert!(seq.validate_sequence(invalid).is_err());
     }
+
+    #[test]
+    fn test_sequencer_validate_sequence_with_max_gap() {
+        let update = |sequence| CoinbaseOrderBookL2Update {
+            subscription_id: SubscriptionId::from("level2|ETH-USD"),
+            sequence,
+            time: Utc::now(),
+            changes: vec![],
+        };
+        let mut seq = CoinbaseOrderBookL2Sequencer::new(1).with_max_gap(2);
+
+        // Gap within tolerance is accepted, advancing the sequence
+        assert!(seq.validate_sequence(update(4)).unwrap().is_some());
+        assert_eq!(seq.sequence, 4);
+
+        // Gap beyond tolerance errors
+        assert!(seq.validate_sequence(update(8)).is_err());
+        assert_eq!(seq.sequence, 4);
+    }
 }
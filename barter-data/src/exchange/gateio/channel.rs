@@ -60,7 +60,6 @@ where
 {
     fn id(&self) -> GateioChannel {
         GateioChannel::FUTURE_ORDER_BOOK_L2
-        GateioChannel::SPOT_ORDER_BOOK_L2
     }
 }
 
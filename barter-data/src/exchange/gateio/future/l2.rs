@@ -38,9 +38,15 @@ pub const HTTP_BOOK_L2_SNAPSHOT_URL_GATEIO_FUTURES_BTC: &str =
 
 #[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct GateioLevel {
-    #[serde(with = "rust_decimal::serde::str")]
+    #[serde(
+        serialize_with = "rust_decimal::serde::str::serialize",
+        deserialize_with = "barter_integration::de::de_decimal_flexible"
+    )]
     pub price: rust_decimal::Decimal,
-    #[serde(with = "rust_decimal::serde::str")]
+    #[serde(
+        serialize_with = "rust_decimal::serde::str::serialize",
+        deserialize_with = "barter_integration::de::de_decimal_flexible"
+    )]
     pub amount: rust_decimal::Decimal,
 }
 
@@ -248,6 +254,7 @@ pub struct GateioFuturesUsdOrderBooksL2SnapshotFetcher;
 
 impl SnapshotFetcher<GateioFuturesUsd, OrderBooksL2> for GateioFuturesUsdOrderBooksL2SnapshotFetcher {
     fn fetch_snapshots<Instrument>(
+        client: &reqwest::Client,
         subscriptions: &[Subscription<GateioFuturesUsd, Instrument, OrderBooksL2>],
     ) -> impl Future<Output = Result<Vec<MarketEvent<Instrument::Key, OrderBookEvent>>, SocketError>> + Send
     where
@@ -262,7 +269,9 @@ impl SnapshotFetcher<GateioFuturesUsd, OrderBooksL2> for GateioFuturesUsdOrderBo
                 market.as_ref()
             );
             async move {
-                let snapshot = reqwest::get(url)
+                let snapshot = client
+                    .get(url)
+                    .send()
                     .await
                     .map_err(SocketError::Http)?
                     .json::<GateioOrderBookL2Snapshot>()
@@ -284,6 +293,7 @@ pub struct GateioFuturesBtcOrderBooksL2SnapshotFetcher;
 
 impl SnapshotFetcher<GateioFuturesBtc, OrderBooksL2> for GateioFuturesBtcOrderBooksL2SnapshotFetcher {
     fn fetch_snapshots<Instrument>(
+        client: &reqwest::Client,
         subscriptions: &[Subscription<GateioFuturesBtc, Instrument, OrderBooksL2>],
     ) -> impl Future<Output = Result<Vec<MarketEvent<Instrument::Key, OrderBookEvent>>, SocketError>> + Send
     where
@@ -298,7 +308,9 @@ impl SnapshotFetcher<GateioFuturesBtc, OrderBooksL2> for GateioFuturesBtcOrderBo
                 market.as_ref()
             );
             async move {
-                let snapshot = reqwest::get(url)
+                let snapshot = client
+                    .get(url)
+                    .send()
                     .await
                     .map_err(SocketError::Http)?
                     .json::<GateioOrderBookL2Snapshot>()
@@ -323,11 +335,11 @@ mod tests {
     #[test]
     fn test_de_gateio_futures_order_book_l2_update() {
         let input = r#"{
-            \"s\":\"BTC_USDT\",
-            \"t\":1600000000000,
-            \"u\":100,
-            \"bids\":[[\"100\",\"1\"]],
-            \"asks\":[[\"101\",\"2\"]]
+            "s":"BTC_USDT",
+            "t":1600000000000,
+            "u":100,
+            "bids":[["100","1"]],
+            "asks":[["101","2"]]
         }"#;
         assert_eq!(
             serde_json::from_str::<GateioFuturesOrderBookL2Update>(input).unwrap(),
@@ -355,4 +367,17 @@ mod tests {
         let outdated = GateioFuturesOrderBookL2Update { last_update_id: 11, ..base };
         assert!(seq.validate_sequence(outdated).unwrap().is_none());
     }
+
+    #[test]
+    fn test_de_gateio_level_flexible_numbers() {
+        let input = r#"[["100.5","1"],[100.5,1],["1e-8",1e-8]]"#;
+        assert_eq!(
+            serde_json::from_str::<Vec<GateioLevel>>(input).unwrap(),
+            vec![
+                GateioLevel { price: dec!(100.5), amount: dec!(1) },
+                GateioLevel { price: dec!(100.5), amount: dec!(1) },
+                GateioLevel { price: dec!(0.00000001), amount: dec!(0.00000001) },
+            ]
+        );
+    }
 }
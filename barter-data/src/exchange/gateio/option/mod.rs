@@ -1,9 +1,8 @@
+use self::trade::GateioOptionsTrades;
+use super::Gateio;
 use crate::{
     ExchangeWsStream, NoInitialSnapshots,
-    exchange::{
-        ExchangeServer, StreamSelector,
-        gateio::{Gateio, perpetual::trade::GateioFuturesTrades},
-    },
+    exchange::{ExchangeServer, StreamSelector},
     instrument::InstrumentData,
     subscription::trade::PublicTrades,
     transformer::stateless::StatelessTransformer,
@@ -11,9 +10,12 @@ use crate::{
 use barter_instrument::exchange::ExchangeId;
 use std::fmt::Display;
 
+/// Public trades types.
+pub mod trade;
+
 /// [`GateioOptions`] WebSocket server base url.
 ///
-/// See docs: <https://www.gate.io/docs/developers/futures/ws/en/>
+/// See docs: <https://www.gate.io/docs/developers/options/ws/en/>
 pub const WEBSOCKET_BASE_URL_GATEIO_OPTIONS_USD: &str = "wss://op-ws.gateio.live/v4/ws";
 
 /// [`Gateio`] options execution.
@@ -37,7 +39,7 @@ where
 {
     type SnapFetcher = NoInitialSnapshots;
     type Stream = ExchangeWsStream<
-        StatelessTransformer<Self, Instrument::Key, PublicTrades, GateioFuturesTrades>,
+        StatelessTransformer<Self, Instrument::Key, PublicTrades, GateioOptionsTrades>,
     >;
 }
 
@@ -0,0 +1,151 @@
+use super::super::message::GateioMessage;
+use crate::{
+    Identifier,
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeSub,
+    subscription::trade::PublicTrade,
+};
+use barter_instrument::{Side, exchange::ExchangeId};
+use barter_integration::subscription::SubscriptionId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a `GateioOptions` real-time trades WebSocket message.
+pub type GateioOptionsTrades = GateioMessage<Vec<GateioOptionsTradeInner>>;
+
+/// `GateioOptions` real-time trade WebSocket message.
+///
+/// Unlike futures trades, the options `price` is a number rather than a string.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.gate.io/docs/developers/options/ws/en/#public-contract-trades-channel>
+/// #### Option Sell Trade
+/// ```json
+/// {
+///   "contract": "BTC_USDT-20211231-59800-C",
+///   "create_time": 1639144526,
+///   "id": 12279,
+///   "price": 997.8,
+///   "size": -100,
+///   "create_time_ms": 1639144526597,
+///   "underlying": "BTC_USDT"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioOptionsTradeInner {
+    #[serde(rename = "contract")]
+    pub market: String,
+    pub underlying: String,
+    #[serde(
+        rename = "create_time_ms",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    pub id: u64,
+    pub price: f64,
+    #[serde(rename = "size")]
+    pub amount: f64,
+}
+
+impl Identifier<Option<SubscriptionId>> for GateioOptionsTrades {
+    fn id(&self) -> Option<SubscriptionId> {
+        self.data
+            .first()
+            .map(|trade| ExchangeSub::from((&self.channel, &trade.market)).id())
+    }
+}
+
+impl<InstrumentKey: Clone> From<(ExchangeId, InstrumentKey, GateioOptionsTrades)>
+    for MarketIter<InstrumentKey, PublicTrade>
+{
+    fn from(
+        (exchange, instrument, trades): (ExchangeId, InstrumentKey, GateioOptionsTrades),
+    ) -> Self {
+        trades
+            .data
+            .into_iter()
+            .map(|trade| {
+                Ok(MarketEvent {
+                    time_exchange: trade.time,
+                    time_received: Utc::now(),
+                    exchange,
+                    instrument: instrument.clone(),
+                    kind: PublicTrade {
+                        id: trade.id.to_string(),
+                        price: trade.price,
+                        amount: trade.amount.abs(),
+                        side: if trade.amount.is_sign_positive() {
+                            Side::Buy
+                        } else {
+                            Side::Sell
+                        },
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_gateio_message_options_trade() {
+            let input = r#"
+            {
+              "time": 1630576356,
+              "channel": "options.trades",
+              "event": "update",
+              "result": [
+                {
+                  "contract": "BTC_USDT-20211231-59800-C",
+                  "create_time": 1639144526,
+                  "id": 12279,
+                  "price": 997.8,
+                  "size": -100,
+                  "create_time_ms": 1639144526597,
+                  "underlying": "BTC_USDT"
+                }
+              ]
+            }"#;
+
+            let trades = serde_json::from_str::<GateioOptionsTrades>(input).unwrap();
+            assert_eq!(
+                trades.id(),
+                Some(SubscriptionId::from(
+                    "options.trades|BTC_USDT-20211231-59800-C"
+                ))
+            );
+
+            let events = MarketIter::<&str, PublicTrade>::from((
+                ExchangeId::GateioOptions,
+                "instrument",
+                trades,
+            ))
+            .0
+            .into_iter()
+            .map(Result::unwrap)
+            .collect::<Vec<_>>();
+
+            assert_eq!(
+                events,
+                vec![MarketEvent {
+                    time_exchange: DateTime::from_timestamp_millis(1639144526597).unwrap(),
+                    time_received: events[0].time_received,
+                    exchange: ExchangeId::GateioOptions,
+                    instrument: "instrument",
+                    kind: PublicTrade {
+                        id: "12279".to_string(),
+                        price: 997.8,
+                        amount: 100.0,
+                        side: Side::Sell,
+                    },
+                }]
+            );
+        }
+    }
+}
@@ -4,7 +4,7 @@ use crate::{
     books::OrderBook,
     error::DataError,
     event::{MarketEvent, MarketIter},
-    exchange::{Gateio, gateio::market::GateioMarket, Connector},
+    exchange::gateio::{Gateio, market::GateioMarket},
     instrument::InstrumentData,
     subscription::{
         Map, Subscription,
@@ -28,7 +28,7 @@ pub const HTTP_BOOK_L2_SNAPSHOT_URL_GATEIO_SPOT: &str = "https://api.gateio.ws/a
 pub struct GateioSpotOrderBooksL2SnapshotFetcher;
 
 impl SnapshotFetcher<Gateio<super::GateioServerSpot>, OrderBooksL2> for GateioSpotOrderBooksL2SnapshotFetcher {
-    fn fetch_snapshots<Instrument>(subs: &[Subscription<Gateio<super::GateioServerSpot>, Instrument, OrderBooksL2>]) -> impl Future<Output = Result<Vec<MarketEvent<Instrument::Key, OrderBookEvent>>, SocketError>> + Send
+    fn fetch_snapshots<Instrument>(client: &reqwest::Client, subs: &[Subscription<Gateio<super::GateioServerSpot>, Instrument, OrderBooksL2>]) -> impl Future<Output = Result<Vec<MarketEvent<Instrument::Key, OrderBookEvent>>, SocketError>> + Send
     where
         Instrument: InstrumentData,
         Subscription<Gateio<super::GateioServerSpot>, Instrument, OrderBooksL2>: Identifier<GateioMarket>,
@@ -37,7 +37,9 @@ impl SnapshotFetcher<Gateio<super::GateioServerSpot>, OrderBooksL2> for GateioSp
             let market = sub.id();
             let url = format!("{}?currency_pair={}&limit=200", HTTP_BOOK_L2_SNAPSHOT_URL_GATEIO_SPOT, market.as_ref());
             async move {
-                let snapshot = reqwest::get(url)
+                let snapshot = client
+                    .get(url)
+                    .send()
                     .await
                     .map_err(SocketError::Http)?
                     .json::<GateioOrderBookL2Snapshot>()
@@ -134,11 +136,17 @@ impl<InstrumentKey> From<(ExchangeId, InstrumentKey, GateioOrderBookL2Snapshot)>
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Debug, Deserialize, Serialize)]
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct GateioLevel {
-    #[serde(with = "rust_decimal::serde::str")]
+    #[serde(
+        serialize_with = "rust_decimal::serde::str::serialize",
+        deserialize_with = "barter_integration::de::de_decimal_flexible"
+    )]
     pub price: Decimal,
-    #[serde(with = "rust_decimal::serde::str")]
+    #[serde(
+        serialize_with = "rust_decimal::serde::str::serialize",
+        deserialize_with = "barter_integration::de::de_decimal_flexible"
+    )]
     pub amount: Decimal,
 }
 
@@ -260,12 +268,12 @@ mod tests {
     #[test]
     fn test_de_gateio_spot_order_book_l2_update() {
         let input = r#"{
-            \"s\": \"ETH_USDT\",
-            \"t\": 1671656397761,
-            \"U\": 22611425143,
-            \"u\": 22611425151,
-            \"b\": [[\"1209.67000000\",\"85.48210000\"],[\"1209.66000000\",\"20.68790000\"]],
-            \"a\": []
+            "s": "ETH_USDT",
+            "t": 1671656397761,
+            "U": 22611425143,
+            "u": 22611425151,
+            "b": [["1209.67000000","85.48210000"],["1209.66000000","20.68790000"]],
+            "a": []
         }"#;
         assert_eq!(serde_json::from_str::<GateioSpotOrderBookL2Update>(input).unwrap(), GateioSpotOrderBookL2Update {
             subscription_id: SubscriptionId::from("spot.order_book_update|ETH_USDT"),
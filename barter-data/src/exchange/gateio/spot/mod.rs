@@ -4,7 +4,7 @@ use crate::{
     ExchangeWsStream, NoInitialSnapshots,
     exchange::{ExchangeServer, StreamSelector},
     instrument::InstrumentData,
-    subscription::trade::PublicTrades,
+    subscription::{book::OrderBooksL2, trade::PublicTrades},
     transformer::stateless::StatelessTransformer,
 };
 use barter_instrument::exchange::ExchangeId;
@@ -0,0 +1,110 @@
+use std::time::Duration;
+
+/// Default timeout of each request made by a snapshot HTTP [`reqwest::Client`].
+pub const DEFAULT_SNAPSHOT_HTTP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Duration an idle pooled connection is kept alive for re-use.
+const SNAPSHOT_HTTP_POOL_IDLE_TIMEOUT: Duration = Duration::from_secs(90);
+
+/// Build the default keep-alive snapshot HTTP [`reqwest::Client`], applying the
+/// [`DEFAULT_SNAPSHOT_HTTP_TIMEOUT`] to each request.
+pub fn default_snapshot_http_client() -> Result<reqwest::Client, reqwest::Error> {
+    build_snapshot_http_client(DEFAULT_SNAPSHOT_HTTP_TIMEOUT)
+}
+
+/// Build a keep-alive HTTP [`reqwest::Client`] that applies the provided `timeout` to each
+/// request.
+///
+/// [`reqwest::Client`] clones share the same connection pool, so connections are re-used across
+/// every snapshot fetch made with a clone.
+pub fn build_snapshot_http_client(timeout: Duration) -> Result<reqwest::Client, reqwest::Error> {
+    reqwest::Client::builder()
+        .timeout(timeout)
+        .pool_idle_timeout(SNAPSHOT_HTTP_POOL_IDLE_TIMEOUT)
+        .tcp_keepalive(SNAPSHOT_HTTP_POOL_IDLE_TIMEOUT)
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    /// Bind a mock keep-alive HTTP server, returning its url and the number of accepted
+    /// connections.
+    async fn bind_mock_http_keep_alive(body: &'static str) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let connections = Arc::new(AtomicUsize::new(0));
+
+        let accepted = Arc::clone(&connections);
+        tokio::spawn(async move {
+            loop {
+                let (mut tcp, _) = listener.accept().await.unwrap();
+                accepted.fetch_add(1, Ordering::SeqCst);
+
+                tokio::spawn(async move {
+                    let mut request = [0u8; 4096];
+                    while tcp.read(&mut request).await.is_ok_and(|read| read > 0) {
+                        let response = format!(
+                            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{body}",
+                            body.len()
+                        );
+                        tcp.write_all(response.as_bytes()).await.unwrap();
+                    }
+                });
+            }
+        });
+
+        (url, connections)
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_http_client_reuses_connections() {
+        let (url, connections) = bind_mock_http_keep_alive(r#"{"lastUpdateId":1}"#).await;
+
+        let client = default_snapshot_http_client().unwrap();
+
+        for _ in 0..3 {
+            let body = client
+                .clone()
+                .get(&url)
+                .send()
+                .await
+                .unwrap()
+                .text()
+                .await
+                .unwrap();
+            assert_eq!(body, r#"{"lastUpdateId":1}"#);
+        }
+
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_http_client_timeout() {
+        // Mock server accepts connections but never responds
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (_tcp, _) = listener.accept().await.unwrap();
+            std::future::pending::<()>().await;
+        });
+
+        let client = build_snapshot_http_client(Duration::from_millis(50)).unwrap();
+
+        let error = tokio::time::timeout(Duration::from_secs(5), client.get(&url).send())
+            .await
+            .expect("configured timeout was not honored")
+            .unwrap_err();
+
+        assert!(error.is_timeout());
+    }
+}
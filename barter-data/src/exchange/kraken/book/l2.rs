@@ -4,7 +4,8 @@ use crate::{
     event::{MarketEvent, MarketIter},
     exchange::{kraken::channel::KrakenChannel, subscription::ExchangeSub},
     exchange::kraken::market::KrakenMarket,
-    subscription::{book::OrderBookEvent, Map, Subscription},
+    subscription::{book::{OrderBookEvent, OrderBooksL2}, Map, Subscription},
+    exchange::Connector,
     transformer::ExchangeTransformer,
     SnapshotFetcher, Identifier, instrument::InstrumentData,
 };
@@ -32,6 +33,7 @@ pub struct KrakenOrderBooksL2SnapshotFetcher;
 
 impl SnapshotFetcher<super::super::Kraken, OrderBooksL2> for KrakenOrderBooksL2SnapshotFetcher {
     fn fetch_snapshots<Instrument>(
+        _: &reqwest::Client,
         _: &[Subscription<super::super::Kraken, Instrument, OrderBooksL2>],
     ) -> impl Future<Output = Result<Vec<MarketEvent<Instrument::Key, OrderBookEvent>>, SocketError>> + Send
     where
@@ -67,7 +69,7 @@ where
                     KrakenOrderBookL2Meta::new(instrument_key, KrakenOrderBookL2Sequencer::default()),
                 ))
             })
-            .collect::<Result<Map<_>, _>>()?;
+            .collect::<Result<Map<_>, crate::error::DataError>>()?;
 
         Ok(Self { instrument_map })
     }
@@ -249,18 +251,27 @@ impl<InstrumentKey> From<(ExchangeId, InstrumentKey, KrakenOrderBookL2Inner)>
                 })]
             }
         }
-        .into()
+        .into_iter()
+        .collect()
     }
 }
 
 #[derive(Debug, Default)]
 pub struct KrakenOrderBookL2Sequencer {
     pub last_sequence: u64,
+    /// Number of skipped sequence numbers tolerated between updates, where zero requires
+    /// strictly consecutive sequences.
+    pub max_gap: u64,
 }
 
 impl KrakenOrderBookL2Sequencer {
     pub fn new(sequence: u64) -> Self {
-        Self { last_sequence: sequence }
+        Self { last_sequence: sequence, max_gap: 0 }
+    }
+
+    /// Tolerate up to `max_gap` skipped sequence numbers between updates, rather than erroring.
+    pub fn with_max_gap(self, max_gap: u64) -> Self {
+        Self { max_gap, ..self }
     }
 
     pub fn validate_sequence(
@@ -276,7 +287,7 @@ impl KrakenOrderBookL2Sequencer {
             return Ok(None);
         }
 
-        if self.last_sequence != 0 && sequence != self.last_sequence + 1 {
+        if self.last_sequence != 0 && sequence > self.last_sequence.saturating_add(self.max_gap + 1) {
             return Err(crate::error::DataError::InvalidSequence {
                 prev_last_update_id: self.last_sequence,
                 first_update_id: sequence,
@@ -337,5 +348,24 @@ mod tests {
         };
         assert!(seq.validate_sequence(invalid).is_err());
     }
+
+    #[test]
+    fn test_sequencer_validate_sequence_with_max_gap() {
+        let update = |sequence| KrakenOrderBookL2Inner::Update {
+            subscription_id: SubscriptionId::from("book|XBT/USD"),
+            sequence,
+            bids: vec![],
+            asks: vec![],
+        };
+        let mut seq = KrakenOrderBookL2Sequencer::new(1).with_max_gap(2);
+
+        // Gap within tolerance is accepted, advancing the last sequence
+        assert!(seq.validate_sequence(update(4)).unwrap().is_some());
+        assert_eq!(seq.last_sequence, 4);
+
+        // Gap beyond tolerance errors
+        assert!(seq.validate_sequence(update(8)).is_err());
+        assert_eq!(seq.last_sequence, 4);
+    }
 }
 
@@ -0,0 +1,174 @@
+use crate::{
+    Identifier,
+    event::{MarketEvent, MarketIter},
+    subscription::liquidation::Liquidation,
+};
+use barter_instrument::{Side, exchange::ExchangeId};
+use barter_integration::subscription::SubscriptionId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Kraken Futures public trade message, which includes liquidations.
+///
+/// Only messages with a [`KrakenFuturesTradeKind::Liquidation`] `type` are liquidations, all
+/// other trades are ignored when converting into [`Liquidation`]s.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.futures.kraken.com/#websocket-api-public-feeds-trade>
+/// ```json
+/// {
+///     "feed": "trade",
+///     "product_id": "PI_XBTUSD",
+///     "uid": "05af78ac-a774-478c-a50c-8b9c234e071e",
+///     "side": "sell",
+///     "type": "liquidation",
+///     "seq": 653355,
+///     "time": 1612266317519,
+///     "qty": 15000,
+///     "price": 34969.5
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KrakenFuturesLiquidation {
+    #[serde(
+        alias = "product_id",
+        deserialize_with = "de_liquidation_subscription_id"
+    )]
+    pub subscription_id: SubscriptionId,
+    pub side: Side,
+    #[serde(rename = "type")]
+    pub kind: KrakenFuturesTradeKind,
+    pub price: f64,
+    #[serde(alias = "qty")]
+    pub quantity: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+}
+
+/// Kraken Futures public trade `type`.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KrakenFuturesTradeKind {
+    Fill,
+    Liquidation,
+    Termination,
+    Block,
+}
+
+impl Identifier<Option<SubscriptionId>> for KrakenFuturesLiquidation {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl<InstrumentKey> From<(ExchangeId, InstrumentKey, KrakenFuturesLiquidation)>
+    for MarketIter<InstrumentKey, Liquidation>
+{
+    fn from(
+        (exchange_id, instrument, liquidation): (
+            ExchangeId,
+            InstrumentKey,
+            KrakenFuturesLiquidation,
+        ),
+    ) -> Self {
+        if liquidation.kind != KrakenFuturesTradeKind::Liquidation {
+            return Self(vec![]);
+        }
+
+        Self(vec![Ok(MarketEvent {
+            time_exchange: liquidation.time,
+            time_received: Utc::now(),
+            exchange: exchange_id,
+            instrument,
+            kind: Liquidation {
+                side: liquidation.side,
+                price: liquidation.price,
+                quantity: liquidation.quantity,
+                time: liquidation.time,
+            },
+        })])
+    }
+}
+
+/// Deserialize a [`KrakenFuturesLiquidation`] "product_id" (eg/ "PI_XBTUSD") as the associated
+/// [`SubscriptionId`].
+///
+/// eg/ "trade|PI_XBTUSD"
+pub fn de_liquidation_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    Deserialize::deserialize(deserializer)
+        .map(|product_id: String| SubscriptionId::from(format!("trade|{product_id}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_kraken_futures_liquidation() {
+            let input = r#"
+            {
+                "feed": "trade",
+                "product_id": "PI_XBTUSD",
+                "uid": "05af78ac-a774-478c-a50c-8b9c234e071e",
+                "side": "sell",
+                "type": "liquidation",
+                "seq": 653355,
+                "time": 1612266317519,
+                "qty": 15000,
+                "price": 34969.5
+            }
+            "#;
+
+            let liquidation = serde_json::from_str::<KrakenFuturesLiquidation>(input).unwrap();
+            let time = datetime_utc_from_epoch_duration(Duration::from_millis(1612266317519));
+
+            assert_eq!(
+                liquidation,
+                KrakenFuturesLiquidation {
+                    subscription_id: SubscriptionId::from("trade|PI_XBTUSD"),
+                    side: Side::Sell,
+                    kind: KrakenFuturesTradeKind::Liquidation,
+                    price: 34969.5,
+                    quantity: 15000.0,
+                    time,
+                }
+            );
+
+            let events = MarketIter::<&str, Liquidation>::from((
+                ExchangeId::Kraken,
+                "xbt_usd",
+                liquidation.clone(),
+            ))
+            .0;
+            assert_eq!(events.len(), 1);
+            assert_eq!(
+                events[0].as_ref().unwrap().kind,
+                Liquidation {
+                    side: Side::Sell,
+                    price: 34969.5,
+                    quantity: 15000.0,
+                    time,
+                }
+            );
+
+            // Non-liquidation trades are ignored
+            let fill = KrakenFuturesLiquidation {
+                kind: KrakenFuturesTradeKind::Fill,
+                ..liquidation
+            };
+            assert!(
+                MarketIter::<&str, Liquidation>::from((ExchangeId::Kraken, "xbt_usd", fill))
+                    .0
+                    .is_empty()
+            );
+        }
+    }
+}
@@ -0,0 +1,2 @@
+/// Liquidation types.
+pub mod liquidation;
@@ -1,10 +1,7 @@
 use self::{
     book::{
         l1::KrakenOrderBookL1,
-        l2::{
-            KrakenOrderBookL2, KrakenOrderBooksL2SnapshotFetcher,
-            KrakenOrderBooksL2Transformer,
-        },
+        l2::{KrakenOrderBooksL2SnapshotFetcher, KrakenOrderBooksL2Transformer},
     },
     channel::KrakenChannel,
     market::KrakenMarket,
@@ -34,6 +31,10 @@ pub mod book;
 /// into an execution [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
 
+/// Kraken Futures types, which do not yet have an associated
+/// [`Connector`](crate::exchange::Connector).
+pub mod futures;
+
 /// Defines the type that translates a Jackbot [`Subscription`](crate::subscription::Subscription)
 /// into an execution [`Connector`]  specific market used for generating [`Connector::requests`].
 pub mod market;
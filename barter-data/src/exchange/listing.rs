@@ -0,0 +1,125 @@
+use crate::{
+    Identifier, exchange::Connector, instrument::InstrumentData, subscription::Subscription,
+};
+use barter_integration::error::SocketError;
+use fnv::FnvHashSet;
+use itertools::Itertools;
+use std::future::Future;
+
+/// Defines how to fetch the markets currently listed on an exchange, enabling
+/// [`Subscription`]s to be validated against the exchange before subscribing.
+///
+/// Subscribing to a market that is not listed often yields no data rather than an error.
+///
+/// See [`StreamBuilder::subscribe_with_symbol_validation`](crate::streams::builder::StreamBuilder::subscribe_with_symbol_validation).
+pub trait MarketListing: Connector {
+    /// Fetch the set of markets (eg/ "BTCUSDT") listed on the exchange that are relevant to the
+    /// provided [`Subscription`]s, using the provided HTTP [`reqwest::Client`].
+    fn fetch_listed_markets<Instrument, Kind>(
+        client: &reqwest::Client,
+        subscriptions: &[Subscription<Self, Instrument, Kind>],
+    ) -> impl Future<Output = Result<FnvHashSet<String>, SocketError>> + Send
+    where
+        Instrument: InstrumentData,
+        Subscription<Self, Instrument, Kind>: Identifier<Self::Market>;
+}
+
+/// Fetch the markets listed on the exchange using the provided HTTP [`reqwest::Client`], and
+/// validate each [`Subscription`] market is listed.
+///
+/// Returns a [`SocketError::Unsupported`] naming every unlisted market if any are found. See
+/// [`unlisted_markets`].
+pub async fn check_listed_markets<Exchange, Instrument, Kind>(
+    client: &reqwest::Client,
+    subscriptions: &[Subscription<Exchange, Instrument, Kind>],
+) -> Result<(), SocketError>
+where
+    Exchange: MarketListing,
+    Instrument: InstrumentData,
+    Subscription<Exchange, Instrument, Kind>: Identifier<Exchange::Market>,
+{
+    let listed = Exchange::fetch_listed_markets(client, subscriptions).await?;
+
+    let unlisted = unlisted_markets(subscriptions, &listed);
+    if unlisted.is_empty() {
+        Ok(())
+    } else {
+        Err(SocketError::Unsupported {
+            entity: Exchange::ID.as_str().to_string(),
+            item: format!("unknown symbols: {}", unlisted.join(", ")),
+        })
+    }
+}
+
+/// Determine the sorted & de-duplicated markets of the provided [`Subscription`]s that are not
+/// contained in the `listed` markets, ignoring case.
+pub fn unlisted_markets<Exchange, Instrument, Kind>(
+    subscriptions: &[Subscription<Exchange, Instrument, Kind>],
+    listed: &FnvHashSet<String>,
+) -> Vec<String>
+where
+    Exchange: Connector,
+    Subscription<Exchange, Instrument, Kind>: Identifier<Exchange::Market>,
+{
+    subscriptions
+        .iter()
+        .map(|subscription| subscription.id().as_ref().to_uppercase())
+        .filter(|market| {
+            !listed
+                .iter()
+                .any(|listed| listed.eq_ignore_ascii_case(market))
+        })
+        .sorted()
+        .dedup()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{exchange::binance::spot::BinanceSpot, subscription::trade::PublicTrades};
+    use barter_instrument::instrument::market_data::{
+        MarketDataInstrument, kind::MarketDataInstrumentKind,
+    };
+
+    #[test]
+    fn test_unlisted_markets() {
+        struct TestCase {
+            subscriptions: Vec<Subscription<BinanceSpot, MarketDataInstrument, PublicTrades>>,
+            expected: Vec<&'static str>,
+        }
+
+        fn sub(base: &str) -> Subscription<BinanceSpot, MarketDataInstrument, PublicTrades> {
+            Subscription::new(
+                BinanceSpot::default(),
+                MarketDataInstrument::new(base, "usdt", MarketDataInstrumentKind::Spot),
+                PublicTrades,
+            )
+        }
+
+        let listed = FnvHashSet::from_iter(["BTCUSDT".to_string(), "ethusdt".to_string()]);
+
+        let tests = vec![
+            TestCase {
+                // TC0: all markets listed, ignoring case
+                subscriptions: vec![sub("btc"), sub("eth")],
+                expected: vec![],
+            },
+            TestCase {
+                // TC1: one unknown market is named
+                subscriptions: vec![sub("btc"), sub("foo")],
+                expected: vec!["FOOUSDT"],
+            },
+            TestCase {
+                // TC2: multiple unknown markets are named once each
+                subscriptions: vec![sub("foo"), sub("bar"), sub("foo")],
+                expected: vec!["BARUSDT", "FOOUSDT"],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = unlisted_markets(&test.subscriptions, &listed);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
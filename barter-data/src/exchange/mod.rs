@@ -30,9 +30,17 @@ pub mod coinbase;
 /// implementations.
 pub mod gateio;
 
+/// Constructors for the keep-alive HTTP [`Client`](reqwest::Client) passed to
+/// [`SnapshotFetcher`](crate::SnapshotFetcher)s to re-use connections across REST snapshot requests.
+pub mod http;
+
 /// `Kraken` [`Connector`] and [`StreamSelector`] implementations.
 pub mod kraken;
 
+/// Defines the [`MarketListing`](listing::MarketListing) trait for validating `Subscription`
+/// markets against those listed on an exchange before subscribing.
+pub mod listing;
+
 /// `Okx` [`Connector`] and [`StreamSelector`] implementations.
 pub mod okx;
 
@@ -24,6 +24,11 @@ impl OkxChannel {
 
     /// [`Okx`] OrderBook Level2 channel.
     pub const ORDER_BOOK_L2: Self = Self("books");
+
+    /// [`Okx`] liquidation orders channel.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-liquidation-orders-channel>
+    pub const LIQUIDATIONS: Self = Self("liquidation-orders");
 }
 
 impl<Instrument> Identifier<OkxChannel> for Subscription<Okx, Instrument, PublicTrades> {
@@ -1,4 +1,8 @@
-use super::{market::OkxMarket, Okx};
+use super::{
+    Okx,
+    market::OkxMarket,
+    message::{OkxEvent, OkxStreamMessage},
+};
 use crate::{
     Identifier, SnapshotFetcher,
     books::{OrderBook, Level},
@@ -25,6 +29,7 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::future::Future;
 use tokio::sync::mpsc::UnboundedSender;
+use tracing::info;
 
 /// [`Okx`] HTTP OrderBook L2 snapshot url.
 ///
@@ -91,6 +96,7 @@ pub struct OkxOrderBooksL2SnapshotFetcher;
 
 impl SnapshotFetcher<Okx, OrderBooksL2> for OkxOrderBooksL2SnapshotFetcher {
     fn fetch_snapshots<Instrument>(
+        client: &reqwest::Client,
         subscriptions: &[Subscription<Okx, Instrument, OrderBooksL2>],
     ) -> impl Future<Output = Result<Vec<MarketEvent<Instrument::Key, OrderBookEvent>>, SocketError>> + Send
     where
@@ -101,9 +107,9 @@ impl SnapshotFetcher<Okx, OrderBooksL2> for OkxOrderBooksL2SnapshotFetcher {
             let market = sub.id();
             let url = format!("{}?instId={}&sz=400", HTTP_BOOK_L2_SNAPSHOT_URL_OKX, market.as_ref());
             async move {
-                let resp = reqwest::get(url).await.map_err(SocketError::Http)?;
+                let resp = client.get(url).send().await.map_err(SocketError::Http)?;
                 let snapshot: RestSnapshotResp = resp.json().await.map_err(SocketError::Http)?;
-                let snap = snapshot.data.into_iter().next().ok_or_else(|| SocketError::GetMessage("snapshot missing".into()))?;
+                let snap = snapshot.data.into_iter().next().ok_or_else(|| SocketError::Exchange("snapshot missing".into()))?;
                 Ok(MarketEvent::from((ExchangeId::Okx, sub.instrument.key().clone(), snap)))
             }
         });
@@ -162,35 +168,50 @@ pub struct OkxOrderBookL2Meta<InstrumentKey, Sequencer> {
 pub struct OkxOrderBookL2Sequencer {
     pub updates_processed: u64,
     pub last_seq_id: u64,
+    pub awaiting_snapshot: bool,
 }
 
 impl OkxOrderBookL2Sequencer {
     pub fn new(seq_id: u64) -> Self {
-        Self { updates_processed: 0, last_seq_id: seq_id }
+        Self { updates_processed: 0, last_seq_id: seq_id, awaiting_snapshot: false }
+    }
+
+    /// Construct an [`OkxOrderBookL2Sequencer`] without a seed snapshot (eg/ after reconnecting
+    /// without a REST snapshot), which ignores `update` actions until reseeded by a `snapshot`
+    /// action.
+    pub fn new_awaiting_snapshot() -> Self {
+        Self { updates_processed: 0, last_seq_id: 0, awaiting_snapshot: true }
     }
 
+    /// Validate the sequence of an [`OkxOrderBookL2Update`].
+    ///
+    /// A `snapshot` action replaces the OrderBook, so it always resets the sequence regardless of
+    /// prior state. Only `update` actions are checked for gaps (`prevSeqId` must equal the last
+    /// `seqId`), and outdated `update` actions are ignored. `update` actions received while
+    /// awaiting a snapshot are ignored, since there is no sequence to validate them against.
     pub fn validate_sequence(
         &mut self,
         mut update: OkxOrderBookL2Update,
     ) -> Result<Option<OkxOrderBookL2Update>, DataError> {
-        let Some(mut data) = update.data.into_iter().next() else { return Ok(None); };
+        let Some(data) = update.data.into_iter().next() else { return Ok(None); };
 
-        if data.seq_id < self.last_seq_id {
-            return Ok(None);
-        }
+        if update.action == "snapshot" {
+            self.awaiting_snapshot = false;
+        } else {
+            if self.awaiting_snapshot {
+                return Ok(None);
+            }
+
+            if data.seq_id < self.last_seq_id {
+                return Ok(None);
+            }
 
-        if self.updates_processed == 0 {
             if data.prev_seq_id != self.last_seq_id {
                 return Err(DataError::InvalidSequence {
                     prev_last_update_id: self.last_seq_id,
                     first_update_id: data.prev_seq_id,
                 });
             }
-        } else if data.prev_seq_id != self.last_seq_id {
-            return Err(DataError::InvalidSequence {
-                prev_last_update_id: self.last_seq_id,
-                first_update_id: data.prev_seq_id,
-            });
         }
 
         self.updates_processed += 1;
@@ -220,21 +241,24 @@ where
             .0
             .into_iter()
             .map(|(sub_id, instrument_key)| {
-                let snapshot = initial_snapshots
+                // Okx sends a snapshot action on (re)subscribing, so without an initial REST
+                // snapshot the sequencer waits for it rather than failing the first update
+                let sequencer = match initial_snapshots
                     .iter()
                     .find(|snapshot| snapshot.instrument == instrument_key)
-                    .ok_or_else(|| DataError::InitialSnapshotMissing(sub_id.clone()))?;
-
-                let OrderBookEvent::Snapshot(snapshot) = &snapshot.kind else {
-                    return Err(DataError::InitialSnapshotInvalid(String::from(
-                        "expected OrderBookEvent::Snapshot but found OrderBookEvent::Update",
-                    )));
+                {
+                    Some(MarketEvent { kind: OrderBookEvent::Snapshot(snapshot), .. }) => {
+                        OkxOrderBookL2Sequencer::new(snapshot.sequence)
+                    }
+                    Some(_) => {
+                        return Err(DataError::InitialSnapshotInvalid(String::from(
+                            "expected OrderBookEvent::Snapshot but found OrderBookEvent::Update",
+                        )));
+                    }
+                    None => OkxOrderBookL2Sequencer::new_awaiting_snapshot(),
                 };
 
-                let meta = OkxOrderBookL2Meta::new(
-                    instrument_key,
-                    OkxOrderBookL2Sequencer::new(snapshot.sequence),
-                );
+                let meta = OkxOrderBookL2Meta::new(instrument_key, sequencer);
 
                 Ok((sub_id, meta))
             })
@@ -249,11 +273,19 @@ where
     InstrumentKey: Clone,
 {
     type Error = DataError;
-    type Input = OkxOrderBookL2Update;
+    type Input = OkxStreamMessage<OkxOrderBookL2Update>;
     type Output = MarketEvent<InstrumentKey, OrderBookEvent>;
     type OutputIter = Vec<Result<Self::Output, Self::Error>>;
 
     fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        let input = match input {
+            OkxStreamMessage::Data(update) => update,
+            OkxStreamMessage::Event(OkxEvent::Notice { code, message }) => {
+                info!(exchange = %Okx::ID, %code, %message, "received Okx notice");
+                return vec![];
+            }
+        };
+
         let subscription_id = match input.id() {
             Some(id) => id,
             None => return vec![],
@@ -358,5 +390,164 @@ mod tests {
         };
         assert!(seq.validate_sequence(invalid).is_err());
     }
-}
 
+    #[test]
+    fn test_sequencer_snapshot_resets_sequence() {
+        fn message(action: &str, seq_id: u64, prev_seq_id: u64) -> OkxOrderBookL2Update {
+            OkxOrderBookL2Update {
+                subscription_id: SubscriptionId::from("id"),
+                action: action.into(),
+                data: vec![OkxOrderBookL2Snapshot {
+                    seq_id,
+                    prev_seq_id,
+                    time_exchange: Utc::now(),
+                    bids: vec![],
+                    asks: vec![],
+                }],
+            }
+        }
+
+        struct TestCase {
+            input: OkxOrderBookL2Update,
+            expected: Result<bool, ()>,
+            expected_last_seq_id: u64,
+        }
+
+        let mut seq = OkxOrderBookL2Sequencer::new(1);
+
+        let tests = vec![
+            TestCase {
+                // TC0: update continuing the sequence is valid
+                input: message("update", 2, 1),
+                expected: Ok(true),
+                expected_last_seq_id: 2,
+            },
+            TestCase {
+                // TC1: mid-stream snapshot resets the sequence despite the gap
+                input: message("snapshot", 10, 0),
+                expected: Ok(true),
+                expected_last_seq_id: 10,
+            },
+            TestCase {
+                // TC2: update continuing from the snapshot is valid
+                input: message("update", 11, 10),
+                expected: Ok(true),
+                expected_last_seq_id: 11,
+            },
+            TestCase {
+                // TC3: outdated update is ignored
+                input: message("update", 5, 4),
+                expected: Ok(false),
+                expected_last_seq_id: 11,
+            },
+            TestCase {
+                // TC4: snapshot with a lower seqId still resets the sequence
+                input: message("snapshot", 3, 0),
+                expected: Ok(true),
+                expected_last_seq_id: 3,
+            },
+            TestCase {
+                // TC5: update with a gap after the snapshot is invalid
+                input: message("update", 5, 4),
+                expected: Err(()),
+                expected_last_seq_id: 3,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = seq
+                .validate_sequence(test.input)
+                .map(|update| update.is_some())
+                .map_err(|_| ());
+            assert_eq!(actual, test.expected, "TC{index} failed");
+            assert_eq!(seq.last_seq_id, test.expected_last_seq_id, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_transformer_ignores_notice() {
+        let mut transformer = OkxOrderBooksL2Transformer {
+            instrument_map: Map::from_iter([(
+                SubscriptionId::from("books|BTC-USDT"),
+                OkxOrderBookL2Meta::new("btc_usdt", OkxOrderBookL2Sequencer::new(1)),
+            )]),
+        };
+
+        let notice = serde_json::from_str::<OkxStreamMessage<OkxOrderBookL2Update>>(
+            r#"{"event": "notice", "code": "64008", "msg": "Service upgrade", "connId": "a4d3ae55"}"#,
+        )
+        .unwrap();
+        assert!(transformer.transform(notice).is_empty());
+
+        let update = OkxStreamMessage::Data(OkxOrderBookL2Update {
+            subscription_id: SubscriptionId::from("books|BTC-USDT"),
+            action: "update".into(),
+            data: vec![OkxOrderBookL2Snapshot {
+                seq_id: 2,
+                prev_seq_id: 1,
+                time_exchange: Utc::now(),
+                bids: vec![],
+                asks: vec![],
+            }],
+        });
+        let output = transformer.transform(update);
+        assert_eq!(output.len(), 1);
+        assert!(output[0].is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_transformer_awaits_snapshot_after_reconnect() {
+        fn message(
+            action: &str,
+            seq_id: u64,
+            prev_seq_id: u64,
+        ) -> OkxStreamMessage<OkxOrderBookL2Update> {
+            OkxStreamMessage::Data(OkxOrderBookL2Update {
+                subscription_id: SubscriptionId::from("books|BTC-USDT"),
+                action: action.into(),
+                data: vec![OkxOrderBookL2Snapshot {
+                    seq_id,
+                    prev_seq_id,
+                    time_exchange: Utc::now(),
+                    bids: vec![OkxLevel { price: dec!(100), amount: dec!(1) }],
+                    asks: vec![OkxLevel { price: dec!(101), amount: dec!(1) }],
+                }],
+            })
+        }
+
+        // Reconnected without re-fetching a REST snapshot
+        let (ws_sink_tx, _ws_sink_rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut transformer = <OkxOrderBooksL2Transformer<&str> as ExchangeTransformer<
+            Okx,
+            &str,
+            OrderBooksL2,
+        >>::init(
+            Map::from_iter([(SubscriptionId::from("books|BTC-USDT"), "btc_usdt")]),
+            &[],
+            ws_sink_tx,
+        )
+        .await
+        .unwrap();
+
+        // Update received before the snapshot is ignored rather than an InvalidSequence
+        assert!(transformer.transform(message("update", 50, 49)).is_empty());
+
+        // Snapshot action reseeds the sequencer
+        let output = transformer.transform(message("snapshot", 100, 0));
+        assert!(matches!(
+            output.as_slice(),
+            [Ok(MarketEvent { kind: OrderBookEvent::Snapshot(_), .. })]
+        ));
+
+        // Subsequent updates are validated against the snapshot
+        let output = transformer.transform(message("update", 101, 100));
+        assert!(matches!(
+            output.as_slice(),
+            [Ok(MarketEvent { kind: OrderBookEvent::Update(_), .. })]
+        ));
+        assert!(matches!(
+            transformer.transform(message("update", 103, 102)).as_slice(),
+            [Err(DataError::InvalidSequence { .. })]
+        ));
+    }
+}
@@ -0,0 +1,233 @@
+use super::{channel::OkxChannel, message::OkxStreamMessage};
+use crate::{
+    Identifier,
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeSub,
+    subscription::liquidation::Liquidation,
+};
+use barter_instrument::{Side, exchange::ExchangeId};
+use barter_integration::subscription::SubscriptionId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Okx`](super::Okx) liquidation orders WebSocket message.
+///
+/// Each [`OkxLiquidation`] entry may contain several liquidation [`OkxLiquidationDetail`]s, which
+/// are each mapped to a [`Liquidation`].
+///
+/// Note that the channel is subscribed to per instrument type (eg/ "SWAP"), so the message is
+/// identified by the `instId` of its first entry.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-liquidation-orders-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "liquidation-orders",
+///     "instType": "SWAP"
+///   },
+///   "data": [
+///     {
+///       "details": [
+///         {
+///           "bkLoss": "0",
+///           "bkPx": "0.007831",
+///           "ccy": "",
+///           "posSide": "short",
+///           "side": "buy",
+///           "sz": "13",
+///           "ts": "1692266434010"
+///         }
+///       ],
+///       "instFamily": "IOST-USDT",
+///       "instId": "IOST-USDT-SWAP",
+///       "instType": "SWAP",
+///       "uly": "IOST-USDT"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxLiquidations {
+    pub data: Vec<OkxLiquidation>,
+}
+
+/// [`Okx`](super::Okx) liquidation orders of a single instrument.
+///
+/// See [`OkxLiquidations`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxLiquidation {
+    #[serde(rename = "instId", deserialize_with = "de_liquidation_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    pub details: Vec<OkxLiquidationDetail>,
+}
+
+/// [`Okx`](super::Okx) liquidation order detail.
+///
+/// See [`OkxLiquidations`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxLiquidationDetail {
+    pub side: Side,
+    #[serde(rename = "bkPx", deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(rename = "sz", deserialize_with = "barter_integration::de::de_str")]
+    pub quantity: f64,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl Identifier<Option<SubscriptionId>> for OkxLiquidations {
+    fn id(&self) -> Option<SubscriptionId> {
+        self.data
+            .first()
+            .map(|liquidation| liquidation.subscription_id.clone())
+    }
+}
+
+impl<InstrumentKey: Clone> From<(ExchangeId, InstrumentKey, OkxLiquidations)>
+    for MarketIter<InstrumentKey, Liquidation>
+{
+    fn from(
+        (exchange, instrument, liquidations): (ExchangeId, InstrumentKey, OkxLiquidations),
+    ) -> Self {
+        liquidations
+            .data
+            .into_iter()
+            .flat_map(|liquidation| liquidation.details)
+            .map(|detail| {
+                Ok(MarketEvent {
+                    time_exchange: detail.time,
+                    time_received: Utc::now(),
+                    exchange,
+                    instrument: instrument.clone(),
+                    kind: Liquidation {
+                        side: detail.side,
+                        price: detail.price,
+                        quantity: detail.quantity,
+                        time: detail.time,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+impl<InstrumentKey: Clone> From<(ExchangeId, InstrumentKey, OkxStreamMessage<OkxLiquidations>)>
+    for MarketIter<InstrumentKey, Liquidation>
+{
+    fn from(
+        (exchange, instrument, message): (
+            ExchangeId,
+            InstrumentKey,
+            OkxStreamMessage<OkxLiquidations>,
+        ),
+    ) -> Self {
+        match message {
+            OkxStreamMessage::Data(liquidations) => {
+                Self::from((exchange, instrument, liquidations))
+            }
+            OkxStreamMessage::Event(_) => Self(vec![]),
+        }
+    }
+}
+
+/// Deserialize an [`OkxLiquidation`] "instId" (eg/ "BTC-USDT-SWAP") as the associated
+/// [`SubscriptionId`].
+///
+/// eg/ "liquidation-orders|BTC-USDT-SWAP"
+pub fn de_liquidation_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    Deserialize::deserialize(deserializer)
+        .map(|instrument: &str| ExchangeSub::from((OkxChannel::LIQUIDATIONS, instrument)).id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use std::time::Duration;
+
+        #[test]
+        fn test_okx_liquidations() {
+            let input = r#"
+            {
+                "arg": {
+                    "channel": "liquidation-orders",
+                    "instType": "SWAP"
+                },
+                "data": [
+                    {
+                        "details": [
+                            {
+                                "bkLoss": "0",
+                                "bkPx": "29340.1",
+                                "ccy": "",
+                                "posSide": "short",
+                                "side": "buy",
+                                "sz": "13",
+                                "ts": "1692266434010"
+                            },
+                            {
+                                "bkLoss": "0",
+                                "bkPx": "29301.5",
+                                "ccy": "",
+                                "posSide": "long",
+                                "side": "sell",
+                                "sz": "2.5",
+                                "ts": "1692266434025"
+                            }
+                        ],
+                        "instFamily": "BTC-USDT",
+                        "instId": "BTC-USDT-SWAP",
+                        "instType": "SWAP",
+                        "uly": "BTC-USDT"
+                    }
+                ]
+            }
+            "#;
+
+            let time = |ms| datetime_utc_from_epoch_duration(Duration::from_millis(ms));
+
+            let liquidations = serde_json::from_str::<OkxLiquidations>(input).unwrap();
+            assert_eq!(
+                liquidations.id(),
+                Some(SubscriptionId::from("liquidation-orders|BTC-USDT-SWAP"))
+            );
+
+            let actual = MarketIter::<&str, Liquidation>::from((
+                ExchangeId::Okx,
+                "btc_usdt_perp",
+                liquidations,
+            ))
+            .0
+            .into_iter()
+            .map(|event| event.unwrap().kind)
+            .collect::<Vec<_>>();
+
+            let expected = vec![
+                Liquidation {
+                    side: Side::Buy,
+                    price: 29340.1,
+                    quantity: 13.0,
+                    time: time(1692266434010),
+                },
+                Liquidation {
+                    side: Side::Sell,
+                    price: 29301.5,
+                    quantity: 2.5,
+                    time: time(1692266434025),
+                },
+            ];
+
+            assert_eq!(actual, expected);
+        }
+    }
+}
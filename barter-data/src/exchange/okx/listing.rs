@@ -0,0 +1,148 @@
+use super::{Okx, market::OkxMarket};
+use crate::{
+    Identifier, exchange::listing::MarketListing, instrument::InstrumentData,
+    subscription::Subscription,
+};
+use barter_instrument::instrument::market_data::kind::MarketDataInstrumentKind;
+use barter_integration::error::SocketError;
+use fnv::FnvHashSet;
+use futures_util::future::try_join_all;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeSet, future::Future};
+
+/// [`Okx`] HTTP instruments url.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-rest-api-get-instruments>
+pub const HTTP_INSTRUMENTS_URL_OKX: &str = "https://www.okx.com/api/v5/public/instruments";
+
+/// [`Okx`] HTTP instruments response, containing every listed instrument of an `instType`.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-rest-api-get-instruments>
+/// ```json
+/// {
+///     "code": "0",
+///     "msg": "",
+///     "data": [
+///         {
+///             "instType": "SPOT",
+///             "instId": "BTC-USDT",
+///             "baseCcy": "BTC",
+///             "quoteCcy": "USDT",
+///             "state": "live"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct OkxInstruments {
+    pub data: Vec<OkxInstrument>,
+}
+
+/// Listed instrument contained in [`OkxInstruments`].
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct OkxInstrument {
+    #[serde(rename = "instId")]
+    pub id: String,
+}
+
+impl MarketListing for Okx {
+    fn fetch_listed_markets<Instrument, Kind>(
+        client: &reqwest::Client,
+        subscriptions: &[Subscription<Self, Instrument, Kind>],
+    ) -> impl Future<Output = Result<FnvHashSet<String>, SocketError>> + Send
+    where
+        Instrument: InstrumentData,
+        Subscription<Self, Instrument, Kind>: Identifier<OkxMarket>,
+    {
+        // Okx lists instruments per instType, so only query those relevant to the Subscriptions
+        let queries = subscriptions
+            .iter()
+            .map(|subscription| match subscription.instrument.kind() {
+                MarketDataInstrumentKind::Spot => "instType=SPOT".to_string(),
+                MarketDataInstrumentKind::Perpetual => "instType=SWAP".to_string(),
+                MarketDataInstrumentKind::Future(_) => "instType=FUTURES".to_string(),
+                MarketDataInstrumentKind::Option(_) => {
+                    // Options must also be queried by instFamily (eg/ "BTC-USD-231229-35000-C")
+                    let market = subscription.id();
+                    let family = market.as_ref().split('-').take(2).join("-");
+                    format!("instType=OPTION&instFamily={family}")
+                }
+            })
+            .collect::<BTreeSet<_>>();
+
+        fetch_listed_markets(client, HTTP_INSTRUMENTS_URL_OKX, queries)
+    }
+}
+
+/// Fetch the instruments listed on [`Okx`] from the HTTP instruments `url` using the provided
+/// HTTP [`reqwest::Client`], for each of the provided query strings (eg/ "instType=SPOT").
+pub async fn fetch_listed_markets<Queries>(
+    client: &reqwest::Client,
+    url: &str,
+    queries: Queries,
+) -> Result<FnvHashSet<String>, SocketError>
+where
+    Queries: IntoIterator<Item = String>,
+{
+    let instruments_futures = queries.into_iter().map(|query| async move {
+        client
+            .get(format!("{url}?{query}"))
+            .send()
+            .await
+            .map_err(SocketError::Http)?
+            .json::<OkxInstruments>()
+            .await
+            .map_err(SocketError::Http)
+    });
+
+    Ok(try_join_all(instruments_futures)
+        .await?
+        .into_iter()
+        .flat_map(|instruments| instruments.data)
+        .map(|instrument| instrument.id)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::listing::unlisted_markets, streams::test_utils::bind_mock_http,
+        subscription::trade::PublicTrades,
+    };
+    use barter_instrument::instrument::market_data::MarketDataInstrument;
+
+    #[tokio::test]
+    async fn test_unknown_symbol_is_named() {
+        let url = bind_mock_http(
+            r#"{
+                "code": "0",
+                "msg": "",
+                "data": [
+                    {"instType": "SPOT", "instId": "BTC-USDT", "baseCcy": "BTC", "quoteCcy": "USDT", "state": "live"},
+                    {"instType": "SPOT", "instId": "ETH-USDT", "baseCcy": "ETH", "quoteCcy": "USDT", "state": "live"}
+                ]
+            }"#
+            .to_string(),
+        )
+        .await;
+
+        let subscriptions = [("btc", "usdt"), ("foo", "usdt")].map(|(base, quote)| {
+            Subscription::<_, MarketDataInstrument, _>::new(
+                Okx,
+                MarketDataInstrument::new(base, quote, MarketDataInstrumentKind::Spot),
+                PublicTrades,
+            )
+        });
+
+        let listed =
+            fetch_listed_markets(&reqwest::Client::new(), &url, ["instType=SPOT".to_string()])
+                .await
+                .unwrap();
+
+        let actual = unlisted_markets(&subscriptions, &listed);
+        assert_eq!(actual, vec!["FOO-USDT"]);
+    }
+}
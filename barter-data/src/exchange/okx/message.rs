@@ -0,0 +1,181 @@
+use crate::Identifier;
+use barter_integration::subscription::SubscriptionId;
+use serde::{Deserialize, Serialize};
+
+/// [`Okx`](super::Okx) message variants that can be received over
+/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) while subscriptions are
+/// active.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#overview-websocket-notification>
+///
+/// #### Data
+/// See [`OkxMessage`](super::trade::OkxMessage) for full raw payload examples.
+///
+/// #### Notice
+/// ```json
+/// {
+///     "event": "notice",
+///     "code": "64008",
+///     "msg": "The connection will soon be closed for a service upgrade. Please reconnect.",
+///     "connId": "a4d3ae55"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum OkxStreamMessage<T> {
+    Data(T),
+    Event(OkxEvent),
+}
+
+impl<T> Identifier<Option<SubscriptionId>> for OkxStreamMessage<T>
+where
+    T: Identifier<Option<SubscriptionId>>,
+{
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            Self::Data(data) => data.id(),
+            Self::Event(_) => None,
+        }
+    }
+}
+
+/// [`Okx`](super::Okx) control messages received over the WebSocket which are not subscription
+/// data.
+///
+/// eg/ [`Okx`](super::Okx) sends an [`OkxEvent::Notice`] ahead of service upgrades and
+/// maintenance. These are benign and should not error the stream.
+///
+/// See [`OkxStreamMessage`] for full raw payload examples.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(tag = "event", rename_all = "lowercase")]
+pub enum OkxEvent {
+    Notice {
+        code: String,
+        #[serde(rename = "msg")]
+        message: String,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::okx::{Okx, trade::OkxTrades},
+        subscription::{Map, trade::PublicTrades},
+        transformer::{ExchangeTransformer, stateless::StatelessTransformer},
+    };
+    use barter_integration::{
+        error::SocketError,
+        protocol::websocket::{WebSocketParser, WsMessage},
+        stream::ExchangeStream,
+    };
+    use futures::StreamExt;
+    use std::collections::VecDeque;
+    use tokio::sync::mpsc;
+
+    const NOTICE: &str = r#"
+    {
+        "event": "notice",
+        "code": "64008",
+        "msg": "The connection will soon be closed for a service upgrade. Please reconnect.",
+        "connId": "a4d3ae55"
+    }
+    "#;
+
+    const TRADES: &str = r#"
+    {
+        "arg": {"channel": "trades", "instId": "BTC-USDT"},
+        "data": [{
+            "instId": "BTC-USDT",
+            "tradeId": "130639474",
+            "px": "42219.9",
+            "sz": "0.12060306",
+            "side": "buy",
+            "ts": "1630048897897"
+        }]
+    }
+    "#;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_okx_stream_message_event() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<OkxStreamMessage<OkxTrades>, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid OkxStreamMessage::Event(OkxEvent::Notice)
+                    input: NOTICE,
+                    expected: Ok(OkxStreamMessage::Event(OkxEvent::Notice {
+                        code: "64008".to_string(),
+                        message: "The connection will soon be closed for a service upgrade. \
+                            Please reconnect."
+                            .to_string(),
+                    })),
+                },
+                TestCase {
+                    // TC1: invalid OkxStreamMessage w/ error event
+                    input: r#"{"event": "error", "code": "60012", "msg": "Invalid request"}"#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "".to_string(),
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<OkxStreamMessage<OkxTrades>>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!(
+                            "TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_okx_stream_continues_after_notice() {
+        let transformer = StatelessTransformer::<
+            Okx,
+            &'static str,
+            PublicTrades,
+            OkxStreamMessage<OkxTrades>,
+        >::init(
+            Map::from_iter([(SubscriptionId::from("trades|BTC-USDT"), "btc_usdt")]),
+            &[],
+            mpsc::unbounded_channel().0,
+        )
+        .await
+        .unwrap();
+
+        let messages = [TRADES, NOTICE, TRADES]
+            .into_iter()
+            .map(WsMessage::text)
+            .map(Ok);
+
+        let stream = ExchangeStream::<WebSocketParser, _, _>::new(
+            futures::stream::iter(messages),
+            transformer,
+            VecDeque::new(),
+        );
+
+        let events = stream.collect::<Vec<_>>().await;
+        assert_eq!(events.len(), 2);
+        assert!(events.iter().all(Result::is_ok));
+    }
+}
@@ -1,8 +1,12 @@
 use self::{
-    channel::OkxChannel, market::OkxMarket, subscription::OkxSubResponse, trade::OkxTrades,
+    channel::OkxChannel,
+    market::OkxMarket,
+    message::OkxStreamMessage,
+    subscription::OkxSubResponse,
+    trade::{OkxTrades, OkxTradesBackfillFetcher},
 };
 use crate::{
-    ExchangeWsStream, NoInitialSnapshots,
+    ExchangeWsStream,
     exchange::{Connector, ExchangeSub, PingInterval, StreamSelector},
     instrument::InstrumentData,
     subscriber::{WebSocketSubscriber, validator::WebSocketSubValidator},
@@ -21,10 +25,19 @@ use url::Url;
 /// into an execution [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
 
+/// Liquidation types for [`Okx`].
+pub mod liquidation;
+
+/// [`MarketListing`](crate::exchange::listing::MarketListing) implementation for [`Okx`].
+pub mod listing;
+
 /// Defines the type that translates a Jackbot [`Subscription`](crate::subscription::Subscription)
 /// into an execution [`Connector`] specific market used for generating [`Connector::requests`].
 pub mod market;
 
+/// Generic [`OkxStreamMessage<T>`] type for [`Okx`].
+pub mod message;
+
 /// [`Subscription`](crate::subscription::Subscription) response type and response
 /// [`Validator`](barter_integration::Validator) for [`Okx`].
 pub mod subscription;
@@ -98,9 +111,10 @@ impl<Instrument> StreamSelector<Instrument, PublicTrades> for Okx
 where
     Instrument: InstrumentData,
 {
-    type SnapFetcher = NoInitialSnapshots;
-    type Stream =
-        ExchangeWsStream<StatelessTransformer<Self, Instrument::Key, PublicTrades, OkxTrades>>;
+    type SnapFetcher = OkxTradesBackfillFetcher;
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, Instrument::Key, PublicTrades, OkxStreamMessage<OkxTrades>>,
+    >;
 }
 
 impl<Instrument> StreamSelector<Instrument, OrderBooksL2> for Okx
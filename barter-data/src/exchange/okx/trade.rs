@@ -1,13 +1,28 @@
+use super::{Okx, market::OkxMarket};
 use crate::{
-    Identifier,
+    Identifier, SnapshotFetcher,
     event::{MarketEvent, MarketIter},
-    exchange::ExchangeSub,
-    subscription::trade::PublicTrade,
+    exchange::{ExchangeSub, okx::message::OkxStreamMessage},
+    instrument::InstrumentData,
+    subscription::{
+        Subscription,
+        trade::{PublicTrade, PublicTrades},
+    },
 };
 use barter_instrument::{Side, exchange::ExchangeId};
-use barter_integration::subscription::SubscriptionId;
+use barter_integration::{error::SocketError, subscription::SubscriptionId};
 use chrono::{DateTime, Utc};
+use futures_util::{TryFutureExt, future::try_join_all};
 use serde::{Deserialize, Serialize};
+use std::future::Future;
+
+/// [`Okx`] HTTP recent trades url.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-get-trades>
+pub const HTTP_RECENT_TRADES_URL_OKX: &str = "https://www.okx.com/api/v5/market/trades";
+
+/// Number of recent trades fetched per instrument by the [`OkxTradesBackfillFetcher`].
+pub const HTTP_RECENT_TRADES_LIMIT_OKX: usize = 100;
 
 /// Terse type alias for an [`Okx`](super::Okx) real-time trades WebSocket message.
 pub type OkxTrades = OkxMessage<OkxTrade>;
@@ -92,6 +107,27 @@ pub struct OkxTrade {
     pub time: DateTime<Utc>,
 }
 
+impl<InstrumentKey> From<(ExchangeId, InstrumentKey, OkxTrade)>
+    for MarketEvent<InstrumentKey, PublicTrade>
+{
+    fn from((exchange, instrument, trade): (ExchangeId, InstrumentKey, OkxTrade)) -> Self {
+        Self {
+            time_exchange: trade.time,
+            time_received: Utc::now(),
+            exchange,
+            instrument,
+            kind: PublicTrade {
+                id: trade.id,
+                price: trade.price,
+                amount: trade.amount,
+                side: trade.side,
+            },
+        }
+    }
+}
+
+/// [`Okx`](super::Okx) may batch multiple trades into a single message, so one [`MarketEvent`]
+/// is generated for each [`OkxTrade`] in the `data` array, preserving the message order.
 impl<InstrumentKey: Clone> From<(ExchangeId, InstrumentKey, OkxTrades)>
     for MarketIter<InstrumentKey, PublicTrade>
 {
@@ -99,24 +135,115 @@ impl<InstrumentKey: Clone> From<(ExchangeId, InstrumentKey, OkxTrades)>
         trades
             .data
             .into_iter()
-            .map(|trade| {
-                Ok(MarketEvent {
-                    time_exchange: trade.time,
-                    time_received: Utc::now(),
-                    exchange,
-                    instrument: instrument.clone(),
-                    kind: PublicTrade {
-                        id: trade.id,
-                        price: trade.price,
-                        amount: trade.amount,
-                        side: trade.side,
-                    },
-                })
-            })
+            .map(|trade| Ok(MarketEvent::from((exchange, instrument.clone(), trade))))
             .collect()
     }
 }
 
+impl<InstrumentKey: Clone> From<(ExchangeId, InstrumentKey, OkxStreamMessage<OkxTrades>)>
+    for MarketIter<InstrumentKey, PublicTrade>
+{
+    fn from(
+        (exchange, instrument, message): (ExchangeId, InstrumentKey, OkxStreamMessage<OkxTrades>),
+    ) -> Self {
+        match message {
+            OkxStreamMessage::Data(trades) => Self::from((exchange, instrument, trades)),
+            OkxStreamMessage::Event(_) => Self(vec![]),
+        }
+    }
+}
+
+/// [`Okx`](super::Okx) HTTP recent trades response.
+///
+/// Note that the [`OkxTrade`]s are ordered from newest to oldest.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-get-trades>
+/// ```json
+/// {
+///   "code": "0",
+///   "msg": "",
+///   "data": [
+///     {
+///       "instId": "BTC-USDT",
+///       "side": "sell",
+///       "sz": "0.00001",
+///       "px": "29963.2",
+///       "tradeId": "242720720",
+///       "ts": "1654161646974"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxRecentTrades {
+    pub data: Vec<OkxTrade>,
+}
+
+/// [`SnapshotFetcher`] that backfills the most recent [`PublicTrade`]s of each
+/// [`Subscription`] via HTTP, so they are yielded before the live [`Okx`] trade stream.
+///
+/// Note that recent trades are re-fetched each time the stream (re)connects.
+#[derive(Debug)]
+pub struct OkxTradesBackfillFetcher;
+
+impl SnapshotFetcher<Okx, PublicTrades> for OkxTradesBackfillFetcher {
+    fn fetch_snapshots<Instrument>(
+        client: &reqwest::Client,
+        subscriptions: &[Subscription<Okx, Instrument, PublicTrades>],
+    ) -> impl Future<Output = Result<Vec<MarketEvent<Instrument::Key, PublicTrade>>, SocketError>> + Send
+    where
+        Instrument: InstrumentData,
+        Subscription<Okx, Instrument, PublicTrades>: Identifier<OkxMarket>,
+    {
+        let trades_futures = subscriptions.iter().map(|subscription| {
+            fetch_recent_trades(
+                client,
+                HTTP_RECENT_TRADES_URL_OKX,
+                subscription.id(),
+                subscription.instrument.key().clone(),
+            )
+        });
+
+        try_join_all(trades_futures).map_ok(|trades| trades.into_iter().flatten().collect())
+    }
+}
+
+/// Fetch the most recent [`PublicTrade`]s for the provided [`OkxMarket`] from the [`Okx`] HTTP
+/// recent trades `url`, ordered from oldest to newest.
+pub async fn fetch_recent_trades<InstrumentKey>(
+    client: &reqwest::Client,
+    url: &str,
+    market: OkxMarket,
+    instrument: InstrumentKey,
+) -> Result<Vec<MarketEvent<InstrumentKey, PublicTrade>>, SocketError>
+where
+    InstrumentKey: Clone,
+{
+    let trades_url = format!(
+        "{}?instId={}&limit={}",
+        url,
+        market.as_ref(),
+        HTTP_RECENT_TRADES_LIMIT_OKX
+    );
+
+    let trades = client
+        .get(trades_url)
+        .send()
+        .await
+        .map_err(SocketError::Http)?
+        .json::<OkxRecentTrades>()
+        .await
+        .map_err(SocketError::Http)?;
+
+    Ok(trades
+        .data
+        .into_iter()
+        .rev()
+        .map(|trade| MarketEvent::from((ExchangeId::Okx, instrument.clone(), trade)))
+        .collect())
+}
+
 /// Deserialize an [`OkxMessage`] "arg" field as a Jackbot [`SubscriptionId`].
 fn de_okx_message_arg_as_subscription_id<'de, D>(
     deserializer: D,
@@ -193,4 +320,157 @@ mod tests {
             }
         }
     }
+
+    mod transform {
+        use super::*;
+        use crate::{
+            subscription::Map,
+            transformer::{ExchangeTransformer, stateless::StatelessTransformer},
+        };
+        use barter_integration::Transformer;
+        use tokio::sync::mpsc;
+
+        #[tokio::test]
+        async fn test_okx_trades_batch_emits_market_event_per_trade() {
+            let input = r#"
+            {
+                "arg": {
+                    "channel": "trades",
+                    "instId": "BTC-USDT"
+                },
+                "data": [
+                    {
+                        "instId": "BTC-USDT",
+                        "tradeId": "130639474",
+                        "px": "42219.9",
+                        "sz": "0.12060306",
+                        "side": "buy",
+                        "ts": "1630048897897"
+                    },
+                    {
+                        "instId": "BTC-USDT",
+                        "tradeId": "130639475",
+                        "px": "42219.8",
+                        "sz": "0.5",
+                        "side": "sell",
+                        "ts": "1630048897898"
+                    }
+                ]
+            }
+            "#;
+
+            let message = serde_json::from_str::<OkxStreamMessage<OkxTrades>>(input).unwrap();
+
+            let mut transformer = StatelessTransformer::<
+                Okx,
+                _,
+                PublicTrades,
+                OkxStreamMessage<OkxTrades>,
+            >::init(
+                Map::from_iter([(SubscriptionId::from("trades|BTC-USDT"), "btc_usdt")]),
+                &[],
+                mpsc::unbounded_channel().0,
+            )
+            .await
+            .unwrap();
+
+            let actual = transformer
+                .transform(message)
+                .into_iter()
+                .map(|event| {
+                    let event = event.unwrap();
+                    assert_eq!(event.exchange, ExchangeId::Okx);
+                    assert_eq!(event.instrument, "btc_usdt");
+                    (event.time_exchange.timestamp_millis(), event.kind)
+                })
+                .collect::<Vec<_>>();
+
+            let expected = vec![
+                (
+                    1630048897897,
+                    PublicTrade {
+                        id: "130639474".to_string(),
+                        price: 42219.9,
+                        amount: 0.12060306,
+                        side: Side::Buy,
+                    },
+                ),
+                (
+                    1630048897898,
+                    PublicTrade {
+                        id: "130639475".to_string(),
+                        price: 42219.8,
+                        amount: 0.5,
+                        side: Side::Sell,
+                    },
+                ),
+            ];
+
+            assert_eq!(actual, expected);
+        }
+    }
+
+    mod backfill {
+        use super::*;
+        use crate::streams::test_utils::bind_mock_http;
+
+        #[tokio::test]
+        async fn test_fetch_recent_trades() {
+            let url = bind_mock_http(
+                r#"{
+                    "code": "0",
+                    "msg": "",
+                    "data": [
+                        {"instId":"BTC-USDT","side":"sell","sz":"0.3","px":"102.0","tradeId":"3","ts":"1654161646003"},
+                        {"instId":"BTC-USDT","side":"buy","sz":"0.2","px":"101.0","tradeId":"2","ts":"1654161646002"},
+                        {"instId":"BTC-USDT","side":"buy","sz":"0.1","px":"100.0","tradeId":"1","ts":"1654161646001"}
+                    ]
+                }"#
+                .to_string(),
+            )
+            .await;
+
+            let trades = fetch_recent_trades(
+                &reqwest::Client::new(),
+                &url,
+                OkxMarket("BTC-USDT".into()),
+                "btc_usdt",
+            )
+            .await
+            .unwrap();
+
+            let actual = trades
+                .into_iter()
+                .map(|event| {
+                    assert_eq!(event.exchange, ExchangeId::Okx);
+                    assert_eq!(event.instrument, "btc_usdt");
+                    event.kind
+                })
+                .collect::<Vec<_>>();
+
+            // Okx returns newest trades first, but they must be emitted oldest first
+            let expected = vec![
+                PublicTrade {
+                    id: "1".to_string(),
+                    price: 100.0,
+                    amount: 0.1,
+                    side: Side::Buy,
+                },
+                PublicTrade {
+                    id: "2".to_string(),
+                    price: 101.0,
+                    amount: 0.2,
+                    side: Side::Buy,
+                },
+                PublicTrade {
+                    id: "3".to_string(),
+                    price: 102.0,
+                    amount: 0.3,
+                    side: Side::Sell,
+                },
+            ];
+
+            assert_eq!(actual, expected);
+        }
+    }
 }
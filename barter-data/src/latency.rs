@@ -0,0 +1,378 @@
+use crate::event::MarketEvent;
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::metric::{Field, Metric, Tag};
+use chrono::{DateTime, TimeDelta, Utc};
+use fnv::FnvHashMap;
+
+/// [`Metric`] name used for emitted [`LatencyMonitor`] metrics.
+pub const METRIC_MARKET_EVENT_LATENCY: &str = "market_event_latency";
+
+/// [`Metric`] name used for emitted [`TimeSkewMonitor`] metrics.
+pub const METRIC_EXCHANGE_TIME_SKEW: &str = "exchange_time_skew_ms";
+
+/// Default inclusive upper bounds (milliseconds) of the [`LatencyHistogram`] buckets.
+pub const DEFAULT_LATENCY_BUCKETS_MS: [i64; 13] = [
+    1, 2, 5, 10, 25, 50, 100, 250, 500, 1_000, 2_500, 5_000, 10_000,
+];
+
+/// Fixed bucket histogram of latencies measured in milliseconds.
+///
+/// Latencies greater than the largest bucket bound are counted in an overflow bucket, and
+/// negative latencies (eg/ due to clock skew) are counted in the first bucket.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LatencyHistogram {
+    bounds_ms: Vec<i64>,
+    counts: Vec<u64>,
+    count: u64,
+    max_ms: i64,
+}
+
+impl LatencyHistogram {
+    /// Construct a new empty [`LatencyHistogram`] with the provided ascending inclusive bucket
+    /// upper bounds (milliseconds).
+    pub fn new(bounds_ms: Vec<i64>) -> Self {
+        let counts = vec![0; bounds_ms.len() + 1];
+        Self {
+            bounds_ms,
+            counts,
+            count: 0,
+            max_ms: 0,
+        }
+    }
+
+    /// Record an observed latency.
+    pub fn record(&mut self, latency: TimeDelta) {
+        let latency_ms = latency.num_milliseconds();
+        let bucket = self.bounds_ms.partition_point(|bound| *bound < latency_ms);
+
+        self.counts[bucket] += 1;
+        self.count += 1;
+        self.max_ms = self.max_ms.max(latency_ms);
+    }
+
+    /// Returns the number of recorded latencies.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the upper bound (milliseconds) of the bucket containing the provided percentile
+    /// (eg/ 0.99), or `None` if no latencies have been recorded.
+    ///
+    /// Percentiles that fall in the overflow bucket return the maximum recorded latency.
+    pub fn percentile(&self, percentile: f64) -> Option<i64> {
+        if self.count == 0 {
+            return None;
+        }
+
+        let rank = ((percentile.clamp(0.0, 1.0) * self.count as f64).ceil() as u64).max(1);
+
+        let mut cumulative = 0;
+        let bucket = self.counts.iter().position(|count| {
+            cumulative += count;
+            cumulative >= rank
+        })?;
+
+        Some(self.bounds_ms.get(bucket).copied().unwrap_or(self.max_ms))
+    }
+
+    /// Remove every recorded latency.
+    pub fn reset(&mut self) {
+        self.counts.iter_mut().for_each(|count| *count = 0);
+        self.count = 0;
+        self.max_ms = 0;
+    }
+}
+
+/// Monitors [`MarketEvent`] latency (`time_received - time_exchange`) by maintaining a
+/// [`LatencyHistogram`] for each exchange.
+///
+/// Each emitted [`Metric`] is tagged by `exchange`, and contains the fields:
+/// - `p50_ms`, `p90_ms`, `p99_ms`: Latency percentile bucket upper bounds in milliseconds.
+/// - `count`: Number of [`MarketEvent`]s observed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LatencyMonitor {
+    bounds_ms: Vec<i64>,
+    exchanges: FnvHashMap<ExchangeId, LatencyHistogram>,
+}
+
+impl Default for LatencyMonitor {
+    fn default() -> Self {
+        Self::new(DEFAULT_LATENCY_BUCKETS_MS.to_vec())
+    }
+}
+
+impl LatencyMonitor {
+    /// Construct a new [`LatencyMonitor`] using the provided ascending inclusive bucket upper
+    /// bounds (milliseconds) for each exchange [`LatencyHistogram`].
+    pub fn new(bounds_ms: Vec<i64>) -> Self {
+        Self {
+            bounds_ms,
+            exchanges: FnvHashMap::default(),
+        }
+    }
+
+    /// Record the latency of the provided [`MarketEvent`].
+    pub fn process<InstrumentKey, Kind>(&mut self, event: &MarketEvent<InstrumentKey, Kind>) {
+        let latency = event
+            .time_received
+            .signed_duration_since(event.time_exchange);
+
+        self.exchanges
+            .entry(event.exchange)
+            .or_insert_with(|| LatencyHistogram::new(self.bounds_ms.clone()))
+            .record(latency);
+    }
+
+    /// Returns the [`LatencyHistogram`] of the provided exchange, if any [`MarketEvent`]s have
+    /// been observed.
+    pub fn histogram(&self, exchange: ExchangeId) -> Option<&LatencyHistogram> {
+        self.exchanges.get(&exchange)
+    }
+
+    /// Generate a latency percentile [`Metric`] for each observed exchange, ordered by
+    /// [`ExchangeId`].
+    pub fn metrics(&self, time: DateTime<Utc>) -> Vec<Metric> {
+        let mut exchanges = self
+            .exchanges
+            .iter()
+            .filter(|(_, histogram)| histogram.count() > 0)
+            .collect::<Vec<_>>();
+        exchanges.sort_by_key(|(exchange, _)| **exchange);
+
+        exchanges
+            .into_iter()
+            .filter_map(|(exchange, histogram)| {
+                Some(Metric {
+                    name: METRIC_MARKET_EVENT_LATENCY,
+                    time: time.timestamp_millis() as u64,
+                    tags: vec![Tag::new("exchange", exchange.as_str())],
+                    fields: vec![
+                        Field::new("p50_ms", histogram.percentile(0.50)?),
+                        Field::new("p90_ms", histogram.percentile(0.90)?),
+                        Field::new("p99_ms", histogram.percentile(0.99)?),
+                        Field::new("count", histogram.count()),
+                    ],
+                })
+            })
+            .collect()
+    }
+
+    /// Remove every recorded latency, starting a new measurement window.
+    pub fn reset(&mut self) {
+        self.exchanges
+            .values_mut()
+            .for_each(LatencyHistogram::reset);
+    }
+}
+
+/// Monitors exchange clock skew & feed lag by tracking the most recent [`MarketEvent`]
+/// `time_exchange` of each exchange, and comparing it against the local clock.
+///
+/// Each emitted [`Metric`] is tagged by `exchange`, and contains the fields:
+/// - `skew_ms`: Local time minus the most recent `time_exchange` in milliseconds. A growing
+///   value indicates a lagging (or silent) feed, and a negative value indicates the exchange
+///   clock is ahead of the local clock.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TimeSkewMonitor {
+    exchanges: FnvHashMap<ExchangeId, DateTime<Utc>>,
+}
+
+impl TimeSkewMonitor {
+    /// Record the `time_exchange` of the provided [`MarketEvent`], if it is the most recent
+    /// observed for the exchange.
+    pub fn process<InstrumentKey, Kind>(&mut self, event: &MarketEvent<InstrumentKey, Kind>) {
+        self.exchanges
+            .entry(event.exchange)
+            .and_modify(|latest| *latest = (*latest).max(event.time_exchange))
+            .or_insert(event.time_exchange);
+    }
+
+    /// Returns the most recent `time_exchange` observed for the provided exchange.
+    pub fn time_exchange_latest(&self, exchange: ExchangeId) -> Option<DateTime<Utc>> {
+        self.exchanges.get(&exchange).copied()
+    }
+
+    /// Generate a [`METRIC_EXCHANGE_TIME_SKEW`] [`Metric`] for each observed exchange relative to
+    /// the provided local `time_now`, ordered by [`ExchangeId`].
+    pub fn metrics(&self, time_now: DateTime<Utc>) -> Vec<Metric> {
+        let mut exchanges = self.exchanges.iter().collect::<Vec<_>>();
+        exchanges.sort_by_key(|(exchange, _)| **exchange);
+
+        exchanges
+            .into_iter()
+            .map(|(exchange, time_exchange)| Metric {
+                name: METRIC_EXCHANGE_TIME_SKEW,
+                time: time_now.timestamp_millis() as u64,
+                tags: vec![Tag::new("exchange", exchange.as_str())],
+                fields: vec![Field::new(
+                    "skew_ms",
+                    time_now
+                        .signed_duration_since(*time_exchange)
+                        .num_milliseconds(),
+                )],
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(exchange: ExchangeId, latency_ms: i64) -> MarketEvent<&'static str, ()> {
+        let time_exchange = DateTime::from_timestamp(1_000, 0).unwrap();
+        MarketEvent {
+            time_exchange,
+            time_received: time_exchange + TimeDelta::milliseconds(latency_ms),
+            exchange,
+            instrument: "btc_usdt",
+            kind: (),
+        }
+    }
+
+    #[test]
+    fn test_latency_histogram_percentile() {
+        struct TestCase {
+            latencies_ms: Vec<i64>,
+            expected: [Option<i64>; 3],
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: no recorded latencies
+                latencies_ms: vec![],
+                expected: [None, None, None],
+            },
+            TestCase {
+                // TC1: single latency on a bucket bound
+                latencies_ms: vec![10],
+                expected: [Some(10), Some(10), Some(10)],
+            },
+            TestCase {
+                // TC2: 100 latencies of 0..100ms
+                latencies_ms: (0..100).collect(),
+                expected: [Some(50), Some(100), Some(100)],
+            },
+            TestCase {
+                // TC3: negative latencies are counted in the first bucket
+                latencies_ms: vec![-5, -1],
+                expected: [Some(1), Some(1), Some(1)],
+            },
+            TestCase {
+                // TC4: overflow bucket returns the maximum recorded latency
+                latencies_ms: vec![3; 98].into_iter().chain([20_000, 30_000]).collect(),
+                expected: [Some(5), Some(5), Some(30_000)],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut histogram = LatencyHistogram::new(DEFAULT_LATENCY_BUCKETS_MS.to_vec());
+            for latency_ms in test.latencies_ms {
+                histogram.record(TimeDelta::milliseconds(latency_ms));
+            }
+
+            let actual = [
+                histogram.percentile(0.50),
+                histogram.percentile(0.90),
+                histogram.percentile(0.99),
+            ];
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_latency_monitor_metrics() {
+        let mut monitor = LatencyMonitor::default();
+
+        for latency_ms in 1..=100 {
+            monitor.process(&event(ExchangeId::Okx, latency_ms));
+        }
+        for latency_ms in [3, 4, 200, 600] {
+            monitor.process(&event(ExchangeId::BinanceSpot, latency_ms));
+        }
+
+        let time = DateTime::from_timestamp(2_000, 0).unwrap();
+        let metrics = monitor.metrics(time);
+
+        assert_eq!(
+            metrics,
+            vec![
+                Metric {
+                    name: METRIC_MARKET_EVENT_LATENCY,
+                    time: 2_000_000,
+                    tags: vec![Tag::new("exchange", "binance_spot")],
+                    fields: vec![
+                        Field::new("p50_ms", 5_i64),
+                        Field::new("p90_ms", 1_000_i64),
+                        Field::new("p99_ms", 1_000_i64),
+                        Field::new("count", 4_u64),
+                    ],
+                },
+                Metric {
+                    name: METRIC_MARKET_EVENT_LATENCY,
+                    time: 2_000_000,
+                    tags: vec![Tag::new("exchange", "okx")],
+                    fields: vec![
+                        Field::new("p50_ms", 50_i64),
+                        Field::new("p90_ms", 100_i64),
+                        Field::new("p99_ms", 100_i64),
+                        Field::new("count", 100_u64),
+                    ],
+                },
+            ]
+        );
+
+        monitor.reset();
+        assert!(monitor.metrics(time).is_empty());
+        assert_eq!(monitor.histogram(ExchangeId::Okx).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_time_skew_monitor_metrics() {
+        let mut monitor = TimeSkewMonitor::default();
+        let time_now = DateTime::from_timestamp(2_000, 0).unwrap();
+
+        // Okx feed lags the local clock by 250ms, with an older out of order event
+        for lag_ms in [400, 250, 300] {
+            monitor.process(&MarketEvent {
+                time_exchange: time_now - TimeDelta::milliseconds(lag_ms),
+                ..event(ExchangeId::Okx, 0)
+            });
+        }
+
+        // BinanceSpot clock is 40ms ahead of the local clock
+        monitor.process(&MarketEvent {
+            time_exchange: time_now + TimeDelta::milliseconds(40),
+            ..event(ExchangeId::BinanceSpot, 0)
+        });
+
+        assert_eq!(
+            monitor.time_exchange_latest(ExchangeId::Okx),
+            Some(time_now - TimeDelta::milliseconds(250))
+        );
+        assert_eq!(
+            monitor.metrics(time_now),
+            vec![
+                Metric {
+                    name: METRIC_EXCHANGE_TIME_SKEW,
+                    time: 2_000_000,
+                    tags: vec![Tag::new("exchange", "binance_spot")],
+                    fields: vec![Field::new("skew_ms", -40_i64)],
+                },
+                Metric {
+                    name: METRIC_EXCHANGE_TIME_SKEW,
+                    time: 2_000_000,
+                    tags: vec![Tag::new("exchange", "okx")],
+                    fields: vec![Field::new("skew_ms", 250_i64)],
+                },
+            ]
+        );
+
+        // Skew of a silent feed grows with the local clock
+        let later = time_now + TimeDelta::seconds(5);
+        assert_eq!(
+            monitor.metrics(later)[1].fields,
+            vec![Field::new("skew_ms", 5_250_i64)]
+        );
+    }
+}
@@ -96,6 +96,7 @@ use crate::{
     exchange::{Connector, PingInterval},
     instrument::InstrumentData,
     subscriber::{Subscribed, Subscriber},
+    streams::raw::{RawFrameLogger, RawFrameTee},
     subscription::{Subscription, SubscriptionKind},
     transformer::ExchangeTransformer,
 };
@@ -146,6 +147,27 @@ pub mod instrument;
 /// a collection of sorted local Instrument [`OrderBook`](books::OrderBook)s
 pub mod books;
 
+/// Rolling-window volume weighted average price [`VwapTracker`](vwap::VwapTracker) computed from
+/// a stream of [`PublicTrade`](subscription::trade::PublicTrade)s.
+pub mod vwap;
+
+/// [`LiquidationAggregator`](liquidation::LiquidationAggregator) tracking rolling long & short
+/// [`Liquidation`](subscription::liquidation::Liquidation) volume across exchanges.
+pub mod liquidation;
+
+/// [`LatencyMonitor`](latency::LatencyMonitor) maintaining a per-exchange histogram of
+/// [`MarketEvent`] latency, emitting percentile [`Metric`](barter_integration::metric::Metric)s,
+/// and [`TimeSkewMonitor`](latency::TimeSkewMonitor) emitting per-exchange clock skew & feed lag.
+pub mod latency;
+
+/// Cumulative volume delta [`CvdTracker`](cvd::CvdTracker) accumulating signed
+/// [`PublicTrade`](subscription::trade::PublicTrade) volume.
+pub mod cvd;
+
+/// [`BarBuilder`](bar::BarBuilder) aggregating [`PublicTrade`](subscription::trade::PublicTrade)s
+/// into tick or volume OHLCV bars.
+pub mod bar;
+
 /// Generic [`ExchangeTransformer`] implementations used by [`MarketStream`]s to translate exchange
 /// specific types to normalised Jackbot types.
 ///
@@ -163,7 +185,10 @@ pub mod transformer;
 
 /// Convenient type alias for an [`ExchangeStream`] utilising a tungstenite
 /// [`WebSocket`](barter_integration::protocol::websocket::WebSocket).
-pub type ExchangeWsStream<Transformer> = ExchangeStream<WebSocketParser, WsStream, Transformer>;
+///
+/// Inbound frames are teed to the [`RawFrameLogger`] provided to [`MarketStream::init`], if any.
+pub type ExchangeWsStream<Transformer> =
+    ExchangeStream<WebSocketParser, RawFrameTee<WsStream>, Transformer>;
 
 /// Defines a generic identification type for the implementor.
 pub trait Identifier<T> {
@@ -184,6 +209,8 @@ where
     Kind: SubscriptionKind,
 {
     async fn init<SnapFetcher>(
+        snapshot_http_client: &reqwest::Client,
+        raw_frame_logger: Option<RawFrameLogger>,
         subscriptions: &[Subscription<Exchange, Instrument, Kind>],
     ) -> Result<Self, DataError>
     where
@@ -199,8 +226,12 @@ where
 /// See examples such as Binance OrderBooksL2: <br>
 /// - [`BinanceSpotOrderBooksL2SnapshotFetcher`](exchange::binance::spot::l2::BinanceSpotOrderBooksL2SnapshotFetcher)
 /// - [`BinanceFuturesUsdOrderBooksL2SnapshotFetcher`](exchange::binance::futures::l2::BinanceFuturesUsdOrderBooksL2SnapshotFetcher)
+///
+/// Snapshots are fetched using the provided HTTP [`reqwest::Client`], which is shared by every
+/// [`MarketStream`] (re)initialised by the same builder so pooled connections are re-used.
 pub trait SnapshotFetcher<Exchange, Kind> {
     fn fetch_snapshots<Instrument>(
+        client: &reqwest::Client,
         subscriptions: &[Subscription<Exchange, Instrument, Kind>],
     ) -> impl Future<Output = Result<Vec<MarketEvent<Instrument::Key, Kind::Event>>, SocketError>> + Send
     where
@@ -222,6 +253,8 @@ where
     Kind::Event: Send,
 {
     async fn init<SnapFetcher>(
+        snapshot_http_client: &reqwest::Client,
+        raw_frame_logger: Option<RawFrameLogger>,
         subscriptions: &[Subscription<Exchange, Instrument, Kind>],
     ) -> Result<Self, DataError>
     where
@@ -237,7 +270,8 @@ where
         } = Exchange::Subscriber::subscribe(subscriptions).await?;
 
         // Fetch any required initial MarketEvent snapshots
-        let initial_snapshots = SnapFetcher::fetch_snapshots(subscriptions).await?;
+        let initial_snapshots =
+            SnapFetcher::fetch_snapshots(snapshot_http_client, subscriptions).await?;
 
         // Split WebSocket into WsStream & WsSink components
         let (ws_sink, ws_stream) = websocket.split();
@@ -272,6 +306,9 @@ where
         // Extend buffered events with any initial snapshot events
         processed.extend(initial_snapshots.into_iter().map(Ok));
 
+        // Tee raw inbound frames to the raw frame logger, if provided
+        let ws_stream = RawFrameTee::new(ws_stream, Exchange::ID, raw_frame_logger);
+
         Ok(ExchangeWsStream::new(ws_stream, transformer, processed))
     }
 }
@@ -283,6 +320,7 @@ pub struct NoInitialSnapshots;
 
 impl<Exchange, Kind> SnapshotFetcher<Exchange, Kind> for NoInitialSnapshots {
     fn fetch_snapshots<Instrument>(
+        _: &reqwest::Client,
         _: &[Subscription<Exchange, Instrument, Kind>],
     ) -> impl Future<Output = Result<Vec<MarketEvent<Instrument::Key, Kind::Event>>, SocketError>> + Send
     where
@@ -0,0 +1,231 @@
+use crate::{event::MarketEvent, subscription::liquidation::Liquidation};
+use barter_instrument::Side;
+use chrono::{DateTime, TimeDelta, Utc};
+use fnv::FnvHashMap;
+use std::{collections::VecDeque, hash::Hash};
+
+/// Aggregates [`Liquidation`]s across exchanges, tracking the rolling long & short liquidation
+/// volume of each instrument over a configurable window.
+///
+/// A [`Liquidation`] [`Side`] is the side of the liquidation order, so a [`Side::Sell`]
+/// liquidates a long position, and a [`Side::Buy`] liquidates a short position.
+///
+/// Volume is measured as the sum of liquidated `quantity`.
+#[derive(Debug, Clone)]
+pub struct LiquidationAggregator<InstrumentKey> {
+    /// Duration of the rolling window, relative to the latest [`Liquidation`] of an instrument.
+    pub window: TimeDelta,
+    instruments: FnvHashMap<InstrumentKey, LiquidationWindow>,
+}
+
+/// Rolling window of [`Liquidation`]s for a single instrument.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct LiquidationWindow {
+    liquidations: VecDeque<Liquidation>,
+    long_volume: f64,
+    short_volume: f64,
+}
+
+impl LiquidationWindow {
+    fn push(&mut self, liquidation: Liquidation) {
+        match liquidation.side {
+            Side::Sell => self.long_volume += liquidation.quantity,
+            Side::Buy => self.short_volume += liquidation.quantity,
+        }
+        self.liquidations.push_back(liquidation);
+    }
+
+    fn expire(&mut self, window_start: DateTime<Utc>) {
+        while let Some(oldest) = self.liquidations.front() {
+            if oldest.time >= window_start {
+                break;
+            }
+
+            match oldest.side {
+                Side::Sell => self.long_volume -= oldest.quantity,
+                Side::Buy => self.short_volume -= oldest.quantity,
+            }
+            self.liquidations.pop_front();
+        }
+
+        // Avoid accumulating floating point error once the window is empty
+        if self.liquidations.is_empty() {
+            self.long_volume = 0.0;
+            self.short_volume = 0.0;
+        }
+    }
+}
+
+impl<InstrumentKey> LiquidationAggregator<InstrumentKey>
+where
+    InstrumentKey: Clone + Eq + Hash,
+{
+    /// Construct a new empty [`LiquidationAggregator`] with the provided rolling `window`.
+    pub fn new(window: TimeDelta) -> Self {
+        Self {
+            window,
+            instruments: FnvHashMap::default(),
+        }
+    }
+
+    /// Update the rolling window of the [`MarketEvent`] instrument with the [`Liquidation`],
+    /// expiring any of its [`Liquidation`]s that fall outside the window.
+    pub fn process(&mut self, event: &MarketEvent<InstrumentKey, Liquidation>) {
+        let window_start = event.kind.time - self.window;
+
+        let window = self
+            .instruments
+            .entry(event.instrument.clone())
+            .or_default();
+
+        window.push(event.kind);
+        window.expire(window_start);
+    }
+
+    /// Expire every [`Liquidation`] that falls outside the window ending at the provided `time`.
+    ///
+    /// Useful to age out instruments that have not observed a recent [`Liquidation`].
+    pub fn expire(&mut self, time: DateTime<Utc>) {
+        let window_start = time - self.window;
+        for window in self.instruments.values_mut() {
+            window.expire(window_start);
+        }
+    }
+
+    /// Returns the liquidated volume of long positions within the window.
+    pub fn long_volume(&self, instrument: &InstrumentKey) -> f64 {
+        self.instruments
+            .get(instrument)
+            .map_or(0.0, |window| window.long_volume)
+    }
+
+    /// Returns the liquidated volume of short positions within the window.
+    pub fn short_volume(&self, instrument: &InstrumentKey) -> f64 {
+        self.instruments
+            .get(instrument)
+            .map_or(0.0, |window| window.short_volume)
+    }
+
+    /// Returns the net liquidated volume within the window (`long_volume - short_volume`).
+    ///
+    /// A positive value indicates longs are being liquidated more heavily than shorts.
+    pub fn net(&self, instrument: &InstrumentKey) -> f64 {
+        self.long_volume(instrument) - self.short_volume(instrument)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_instrument::exchange::ExchangeId;
+
+    fn time(secs: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(secs, 0).unwrap()
+    }
+
+    fn liquidation(
+        exchange: ExchangeId,
+        instrument: &'static str,
+        side: Side,
+        quantity: f64,
+        secs: i64,
+    ) -> MarketEvent<&'static str, Liquidation> {
+        MarketEvent {
+            time_exchange: time(secs),
+            time_received: time(secs),
+            exchange,
+            instrument,
+            kind: Liquidation {
+                side,
+                price: 100.0,
+                quantity,
+                time: time(secs),
+            },
+        }
+    }
+
+    #[test]
+    fn test_liquidation_aggregator() {
+        struct TestCase {
+            input: MarketEvent<&'static str, Liquidation>,
+            expected_long: f64,
+            expected_short: f64,
+        }
+
+        let mut aggregator = LiquidationAggregator::new(TimeDelta::seconds(10));
+
+        let tests = vec![
+            TestCase {
+                // TC0: long liquidation
+                input: liquidation(ExchangeId::BinanceFuturesUsd, "btc", Side::Sell, 1.0, 0),
+                expected_long: 1.0,
+                expected_short: 0.0,
+            },
+            TestCase {
+                // TC1: long liquidation on another exchange is aggregated
+                input: liquidation(ExchangeId::Okx, "btc", Side::Sell, 2.0, 1),
+                expected_long: 3.0,
+                expected_short: 0.0,
+            },
+            TestCase {
+                // TC2: short liquidation
+                input: liquidation(ExchangeId::Okx, "btc", Side::Buy, 0.5, 2),
+                expected_long: 3.0,
+                expected_short: 0.5,
+            },
+            TestCase {
+                // TC3: liquidation of another instrument does not affect btc
+                input: liquidation(ExchangeId::Okx, "eth", Side::Buy, 10.0, 3),
+                expected_long: 3.0,
+                expected_short: 0.5,
+            },
+            TestCase {
+                // TC4: liquidations at the window boundary are retained
+                input: liquidation(ExchangeId::Okx, "btc", Side::Buy, 1.0, 10),
+                expected_long: 3.0,
+                expected_short: 1.5,
+            },
+            TestCase {
+                // TC5: liquidations older than the window expire
+                input: liquidation(ExchangeId::Okx, "btc", Side::Buy, 1.0, 11),
+                expected_long: 2.0,
+                expected_short: 2.5,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            aggregator.process(&test.input);
+            assert_eq!(
+                aggregator.long_volume(&"btc"),
+                test.expected_long,
+                "TC{index} long_volume failed"
+            );
+            assert_eq!(
+                aggregator.short_volume(&"btc"),
+                test.expected_short,
+                "TC{index} short_volume failed"
+            );
+            assert_eq!(
+                aggregator.net(&"btc"),
+                test.expected_long - test.expected_short,
+                "TC{index} net failed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_liquidation_aggregator_expire() {
+        let mut aggregator = LiquidationAggregator::new(TimeDelta::seconds(10));
+        aggregator.process(&liquidation(ExchangeId::Okx, "btc", Side::Sell, 1.0, 0));
+        aggregator.process(&liquidation(ExchangeId::Okx, "eth", Side::Buy, 2.0, 5));
+
+        aggregator.expire(time(12));
+        assert_eq!(aggregator.long_volume(&"btc"), 0.0);
+        assert_eq!(aggregator.short_volume(&"eth"), 2.0);
+
+        aggregator.expire(time(20));
+        assert_eq!(aggregator.short_volume(&"eth"), 0.0);
+        assert_eq!(aggregator.net(&"eth"), 0.0);
+        assert_eq!(aggregator.net(&"unknown"), 0.0);
+    }
+}
@@ -0,0 +1,351 @@
+use super::{SubscribeFuture, snapshot_http_client};
+use crate::{
+    Identifier,
+    error::DataError,
+    event::DataKind,
+    exchange::StreamSelector,
+    instrument::InstrumentData,
+    streams::{
+        consumer::{
+            MarketStreamResult, STREAM_CHANNEL_CONFIG, STREAM_RECONNECTION_POLICY,
+            init_market_stream,
+        },
+        raw::RawFrameLogger,
+        reconnect::stream::ReconnectingStream,
+    },
+    subscription::{Subscription, SubscriptionKind},
+};
+use barter_integration::{
+    Validator,
+    channel::{BoundedChannel, BoundedChannelConfig, UnboundedTx},
+    metric::Metric,
+};
+use futures::Stream;
+use std::fmt::{Debug, Display};
+
+/// Combined multi-exchange, multi-[`SubscriptionKind`] market event `Stream`.
+///
+/// See [`MarketStreams::builder`] for constructing a [`MarketStreamsBuilder`].
+#[derive(Debug, Copy, Clone)]
+pub struct MarketStreams;
+
+impl MarketStreams {
+    /// Construct a [`MarketStreamsBuilder`] for configuring a single merged
+    /// [`MarketStreamResult<InstrumentKey, DataKind>`](MarketStreamResult) `Stream`.
+    pub fn builder<InstrumentKey>() -> MarketStreamsBuilder<InstrumentKey> {
+        MarketStreamsBuilder::new()
+    }
+}
+
+/// Builder to configure and initialise a single merged
+/// [`MarketStreamResult<InstrumentKey, DataKind>`](MarketStreamResult) `Stream` across any
+/// combination of exchanges, instruments and [`SubscriptionKind`]s.
+///
+/// Each [`subscribe`](MarketStreamsBuilder::subscribe) call is actioned on a distinct
+/// reconnecting [`MarketStream`](crate::MarketStream), with every
+/// [`SubscriptionKind::Event`] mapped into a common [`DataKind`].
+///
+/// Recoverable errors are yielded by the merged `Stream`, and can be filtered out using
+/// [`ReconnectingStream::with_error_handler`] to yield
+/// [`MarketStreamEvent`](crate::streams::consumer::MarketStreamEvent)s.
+pub struct MarketStreamsBuilder<InstrumentKey> {
+    pub channel: Option<BoundedChannel<MarketStreamResult<InstrumentKey, DataKind>>>,
+    pub channel_config: BoundedChannelConfig,
+    pub drop_metrics: Option<UnboundedTx<Metric>>,
+    pub snapshot_http_client: Option<reqwest::Client>,
+    pub raw_frame_logger: Option<RawFrameLogger>,
+    pub futures: Vec<SubscribeFuture>,
+}
+
+impl<InstrumentKey> Default for MarketStreamsBuilder<InstrumentKey> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<InstrumentKey> Debug for MarketStreamsBuilder<InstrumentKey>
+where
+    InstrumentKey: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MarketStreamsBuilder<InstrumentKey>")
+            .field("channel", &self.channel)
+            .field("channel_config", &self.channel_config)
+            .field("drop_metrics", &self.drop_metrics)
+            .field("snapshot_http_client", &self.snapshot_http_client)
+            .field("raw_frame_logger", &self.raw_frame_logger)
+            .field("num_futures", &self.futures.len())
+            .finish()
+    }
+}
+
+impl<InstrumentKey> MarketStreamsBuilder<InstrumentKey> {
+    /// Construct a new [`Self`].
+    pub fn new() -> Self {
+        Self {
+            channel: None,
+            channel_config: STREAM_CHANNEL_CONFIG,
+            drop_metrics: None,
+            snapshot_http_client: None,
+            raw_frame_logger: None,
+            futures: Vec::new(),
+        }
+    }
+
+    /// Set the [`BoundedChannelConfig`] of the channel buffering the merged market events,
+    /// defaulting to [`STREAM_CHANNEL_CONFIG`].
+    ///
+    /// Has no effect once the first [`Subscription`] has been added.
+    pub fn with_channel_config(self, channel_config: BoundedChannelConfig) -> Self {
+        Self {
+            channel_config,
+            ..self
+        }
+    }
+
+    /// Send a drop [`Metric`] to the provided [`UnboundedTx`] each time a market event is
+    /// dropped by the configured [`OverflowPolicy`](barter_integration::channel::OverflowPolicy).
+    ///
+    /// Has no effect once the first [`Subscription`] has been added.
+    pub fn with_drop_metrics(self, metrics_tx: UnboundedTx<Metric>) -> Self {
+        Self {
+            drop_metrics: Some(metrics_tx),
+            ..self
+        }
+    }
+
+    /// Set the HTTP [`reqwest::Client`] used to fetch any initial market data snapshots,
+    /// defaulting to the
+    /// [`default_snapshot_http_client`](crate::exchange::http::default_snapshot_http_client).
+    ///
+    /// Only applies to [`Subscription`]s added after this method is invoked.
+    pub fn with_snapshot_http_client(self, snapshot_http_client: reqwest::Client) -> Self {
+        Self {
+            snapshot_http_client: Some(snapshot_http_client),
+            ..self
+        }
+    }
+
+    /// Tee every raw inbound WebSocket text frame to the provided [`RawFrameLogger`], which is
+    /// useful for debugging exchange feeds.
+    ///
+    /// Only applies to [`Subscription`]s added after this method is invoked.
+    pub fn with_raw_frame_logger(self, raw_frame_logger: RawFrameLogger) -> Self {
+        Self {
+            raw_frame_logger: Some(raw_frame_logger),
+            ..self
+        }
+    }
+
+    /// Return the merged [`BoundedChannel`], constructing it on first use.
+    fn channel(&mut self) -> &mut BoundedChannel<MarketStreamResult<InstrumentKey, DataKind>>
+    where
+        InstrumentKey: Send,
+    {
+        self.channel
+            .get_or_insert_with(|| match &self.drop_metrics {
+                Some(metrics_tx) => BoundedChannel::with_drop_metrics(
+                    self.channel_config,
+                    "market_streams",
+                    metrics_tx.clone(),
+                ),
+                None => BoundedChannel::new(self.channel_config),
+            })
+    }
+
+    /// Add a [`Subscription`] for each of the provided instruments to the
+    /// [`MarketStreamsBuilder`], all of which will be actioned on a distinct
+    /// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) connection.
+    ///
+    /// Note that [`Subscription`]s are not actioned until the
+    /// [`build()`](MarketStreamsBuilder::build()) method is invoked.
+    pub fn subscribe<Exchange, Instruments, Instrument, Kind>(
+        mut self,
+        exchange: Exchange,
+        instruments: Instruments,
+        kind: Kind,
+    ) -> Self
+    where
+        Exchange: StreamSelector<Instrument, Kind> + Clone + Ord + Send + Sync + 'static,
+        Instruments: IntoIterator<Item = Instrument>,
+        Instrument: InstrumentData<Key = InstrumentKey> + Ord + Display + 'static,
+        InstrumentKey: Debug + Clone + Send + 'static,
+        Kind: SubscriptionKind + Clone + Ord + Display + Send + Sync + 'static,
+        Kind::Event: Clone + Send,
+        MarketStreamResult<InstrumentKey, Kind::Event>:
+            Into<MarketStreamResult<InstrumentKey, DataKind>>,
+        Subscription<Exchange, Instrument, Kind>:
+            Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        // Construct Vec<Subscriptions> from input Instruments
+        let subscriptions = instruments
+            .into_iter()
+            .map(|instrument| Subscription::new(exchange.clone(), instrument, kind.clone()))
+            .collect::<Vec<_>>();
+
+        // Acquire channel Sender to send MarketStreamResult<DataKind> from consumer loop to user
+        let tx = self.channel().tx.clone();
+
+        // Acquire HTTP Client shared by every SnapshotFetcher initialised by this builder
+        let snapshot_http_client = snapshot_http_client(&mut self.snapshot_http_client);
+        let raw_frame_logger = self.raw_frame_logger.clone();
+
+        // Add Future that once awaited will yield the Result<(), SocketError> of subscribing
+        self.futures.push(Box::pin(async move {
+            // Validate Subscriptions
+            let mut subscriptions = subscriptions
+                .into_iter()
+                .map(Subscription::validate)
+                .collect::<Result<Vec<_>, _>>()?;
+
+            // Remove duplicate Subscriptions
+            subscriptions.sort();
+            subscriptions.dedup();
+
+            // Initialise a MarketEvent `ReconnectingStream`
+            let stream = init_market_stream(
+                STREAM_RECONNECTION_POLICY,
+                snapshot_http_client?,
+                raw_frame_logger,
+                subscriptions,
+            )
+            .await?;
+
+            // Forward MarketEvents to the merged Tx, mapping each into a DataKind
+            tokio::spawn(stream.forward_to_sink(tx));
+
+            Ok(())
+        }));
+
+        self
+    }
+
+    /// Initialise every [`Subscription`] added to the [`MarketStreamsBuilder`] via the
+    /// [`subscribe()`](MarketStreamsBuilder::subscribe()) method, returning a single merged
+    /// [`MarketStreamResult<InstrumentKey, DataKind>`](MarketStreamResult) `Stream`.
+    pub async fn build(
+        self,
+    ) -> Result<impl Stream<Item = MarketStreamResult<InstrumentKey, DataKind>>, DataError>
+    where
+        InstrumentKey: Send,
+    {
+        // Await Stream initialisation perpetual and ensure success
+        futures::future::try_join_all(self.futures).await?;
+
+        let channel = self
+            .channel
+            .unwrap_or_else(|| BoundedChannel::new(self.channel_config));
+
+        Ok(channel.rx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        streams::{
+            reconnect,
+            test_utils::{MockOkx, bind_mock_okx, mock_okx_trade, serve_mock_okx_trades},
+        },
+        subscription::trade::PublicTrades,
+    };
+    use barter_instrument::{
+        exchange::ExchangeId,
+        instrument::market_data::{MarketDataInstrument, kind::MarketDataInstrumentKind},
+    };
+    use barter_integration::protocol::websocket::WsMessage;
+    use futures::StreamExt;
+    use std::{collections::HashSet, time::Duration};
+
+    #[tokio::test]
+    async fn test_market_streams_merges_exchanges() {
+        tokio::spawn(serve_mock_okx_trades(
+            bind_mock_okx::<1>().await,
+            &["BTC-USDT"],
+        ));
+        tokio::spawn(serve_mock_okx_trades(
+            bind_mock_okx::<2>().await,
+            &["BTC-USDT", "ETH-USDT"],
+        ));
+
+        let btc_usdt = MarketDataInstrument::new("btc", "usdt", MarketDataInstrumentKind::Spot);
+        let eth_usdt = MarketDataInstrument::new("eth", "usdt", MarketDataInstrumentKind::Spot);
+
+        let stream = MarketStreams::builder()
+            .subscribe(MockOkx::<1>, [btc_usdt.clone()], PublicTrades)
+            .subscribe(MockOkx::<2>, [btc_usdt, eth_usdt], PublicTrades)
+            .build()
+            .await
+            .unwrap();
+
+        let events = tokio::time::timeout(
+            Duration::from_secs(5),
+            stream
+                .filter_map(|event| {
+                    std::future::ready(match event {
+                        reconnect::Event::Item(Ok(event)) => Some(event),
+                        _ => None,
+                    })
+                })
+                .take(3)
+                .collect::<Vec<_>>(),
+        )
+        .await
+        .unwrap();
+
+        let actual = events
+            .into_iter()
+            .map(|event| {
+                assert!(event.as_public_trade().is_some());
+                (event.exchange, event.instrument.base.to_string())
+            })
+            .collect::<HashSet<_>>();
+
+        let expected = HashSet::from([
+            (ExchangeId::Simulated, "btc".to_string()),
+            (ExchangeId::Other, "btc".to_string()),
+            (ExchangeId::Other, "eth".to_string()),
+        ]);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[tokio::test]
+    async fn test_market_streams_tees_raw_frames_to_raw_frame_logger() {
+        tokio::spawn(serve_mock_okx_trades(
+            bind_mock_okx::<3>().await,
+            &["BTC-USDT"],
+        ));
+
+        let btc_usdt = MarketDataInstrument::new("btc", "usdt", MarketDataInstrumentKind::Spot);
+
+        let (logger_tx, mut logger_rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut stream = MarketStreams::builder()
+            .with_raw_frame_logger(logger_tx)
+            .subscribe(MockOkx::<3>, [btc_usdt], PublicTrades)
+            .build()
+            .await
+            .unwrap();
+
+        let frame = tokio::time::timeout(Duration::from_secs(5), logger_rx.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(frame.exchange, ExchangeId::Bitfinex);
+        assert_eq!(
+            WsMessage::text(frame.payload),
+            mock_okx_trade("BTC-USDT", "100.0")
+        );
+
+        // Inner MarketStream still yields the teed frame as a MarketEvent
+        let event = tokio::time::timeout(Duration::from_secs(5), stream.next())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(matches!(
+            event,
+            reconnect::Event::Item(Ok(event)) if event.as_public_trade().is_some()
+        ));
+    }
+}
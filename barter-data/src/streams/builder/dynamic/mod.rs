@@ -14,12 +14,15 @@ use crate::{
             perpetual::{GateioPerpetualsBtc, GateioPerpetualsUsd},
             spot::GateioSpot,
         },
+        http::default_snapshot_http_client,
         kraken::{Kraken, market::KrakenMarket},
         okx::{Okx, market::OkxMarket},
     },
     instrument::InstrumentData,
     streams::{
-        consumer::{MarketStreamResult, STREAM_RECONNECTION_POLICY, init_market_stream},
+        consumer::{
+            MarketStreamResult, STREAM_CHANNEL_CONFIG, STREAM_RECONNECTION_POLICY, init_market_stream,
+        },
         reconnect::stream::ReconnectingStream,
     },
     subscription::{
@@ -32,7 +35,7 @@ use crate::{
 use barter_instrument::exchange::ExchangeId;
 use barter_integration::{
     Validator,
-    channel::{UnboundedRx, UnboundedTx, mpsc_unbounded},
+    channel::{BoundedChannelConfig, BoundedRx, BoundedTx, mpsc_bounded},
     error::SocketError,
 };
 use fnv::FnvHashMap;
@@ -43,23 +46,16 @@ use std::{
     fmt::{Debug, Display},
     sync::Arc,
 };
-use tokio_stream::wrappers::UnboundedReceiverStream;
 use vecmap::VecMap;
 
 pub mod indexed;
 
 #[derive(Debug)]
 pub struct DynamicStreams<InstrumentKey> {
-    pub trades:
-        VecMap<ExchangeId, UnboundedReceiverStream<MarketStreamResult<InstrumentKey, PublicTrade>>>,
-    pub l1s:
-        VecMap<ExchangeId, UnboundedReceiverStream<MarketStreamResult<InstrumentKey, OrderBookL1>>>,
-    pub l2s: VecMap<
-        ExchangeId,
-        UnboundedReceiverStream<MarketStreamResult<InstrumentKey, OrderBookEvent>>,
-    >,
-    pub liquidations:
-        VecMap<ExchangeId, UnboundedReceiverStream<MarketStreamResult<InstrumentKey, Liquidation>>>,
+    pub trades: VecMap<ExchangeId, BoundedRx<MarketStreamResult<InstrumentKey, PublicTrade>>>,
+    pub l1s: VecMap<ExchangeId, BoundedRx<MarketStreamResult<InstrumentKey, OrderBookL1>>>,
+    pub l2s: VecMap<ExchangeId, BoundedRx<MarketStreamResult<InstrumentKey, OrderBookEvent>>>,
+    pub liquidations: VecMap<ExchangeId, BoundedRx<MarketStreamResult<InstrumentKey, Liquidation>>>,
 }
 
 impl<InstrumentKey> DynamicStreams<InstrumentKey> {
@@ -69,12 +65,59 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
     /// WebSocket `Stream` under the hood. If the batch contains more-than-one [`ExchangeId`] and/or
     /// [`SubKind`], it will be further split under the hood for compile-time reasons.
     ///
+    /// Each [`ExchangeId`]-[`SubKind`] `Stream` is forwarded via a bounded channel constructed
+    /// using the default [`STREAM_CHANNEL_CONFIG`]. See [`Self::init_with_channel_config`] to
+    /// provide a custom [`BoundedChannelConfig`].
+    ///
     /// ## Examples
     /// Please see barter-data-rs/examples/dynamic_multi_stream_multi_exchange.rs for a
     /// comprehensive example of how to use this market data stream initialiser.
     pub async fn init<SubBatchIter, SubIter, Sub, Instrument>(
         subscription_batches: SubBatchIter,
     ) -> Result<Self, DataError>
+    where
+        SubBatchIter: IntoIterator<Item = SubIter>,
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription<ExchangeId, Instrument, SubKind>>,
+        Instrument: InstrumentData<Key = InstrumentKey> + Ord + Display + 'static,
+        InstrumentKey: Debug + Clone + Send + 'static,
+        Subscription<BinanceSpot, Instrument, PublicTrades>: Identifier<BinanceMarket>,
+        Subscription<BinanceSpot, Instrument, PublicTrades>: Identifier<BinanceMarket>,
+        Subscription<BinanceSpot, Instrument, OrderBooksL1>: Identifier<BinanceMarket>,
+        Subscription<BinanceSpot, Instrument, OrderBooksL2>: Identifier<BinanceMarket>,
+        Subscription<BinanceFuturesUsd, Instrument, PublicTrades>: Identifier<BinanceMarket>,
+        Subscription<BinanceFuturesUsd, Instrument, OrderBooksL1>: Identifier<BinanceMarket>,
+        Subscription<BinanceFuturesUsd, Instrument, OrderBooksL2>: Identifier<BinanceMarket>,
+        Subscription<BinanceFuturesUsd, Instrument, Liquidations>: Identifier<BinanceMarket>,
+        Subscription<Bitfinex, Instrument, PublicTrades>: Identifier<BitfinexMarket>,
+        Subscription<Bitmex, Instrument, PublicTrades>: Identifier<BitmexMarket>,
+        Subscription<BybitSpot, Instrument, PublicTrades>: Identifier<BybitMarket>,
+        Subscription<BybitPerpetualsUsd, Instrument, PublicTrades>: Identifier<BybitMarket>,
+        Subscription<Coinbase, Instrument, PublicTrades>: Identifier<CoinbaseMarket>,
+        Subscription<GateioSpot, Instrument, PublicTrades>: Identifier<GateioMarket>,
+        Subscription<GateioFuturesUsd, Instrument, PublicTrades>: Identifier<GateioMarket>,
+        Subscription<GateioFuturesBtc, Instrument, PublicTrades>: Identifier<GateioMarket>,
+        Subscription<GateioPerpetualsUsd, Instrument, PublicTrades>: Identifier<GateioMarket>,
+        Subscription<GateioPerpetualsBtc, Instrument, PublicTrades>: Identifier<GateioMarket>,
+        Subscription<GateioOptions, Instrument, PublicTrades>: Identifier<GateioMarket>,
+        Subscription<Kraken, Instrument, PublicTrades>: Identifier<KrakenMarket>,
+        Subscription<Kraken, Instrument, OrderBooksL1>: Identifier<KrakenMarket>,
+        Subscription<Okx, Instrument, PublicTrades>: Identifier<OkxMarket>,
+    {
+        Self::init_with_channel_config(subscription_batches, STREAM_CHANNEL_CONFIG).await
+    }
+
+    /// Initialise a set of `Streams` by providing one or more [`Subscription`] batches, with
+    /// each [`ExchangeId`]-[`SubKind`] `Stream` forwarded via a bounded channel constructed
+    /// using the provided [`BoundedChannelConfig`].
+    ///
+    /// Any initial snapshots are fetched using a shared [`default_snapshot_http_client`].
+    ///
+    /// See [`Self::init`] for more information.
+    pub async fn init_with_channel_config<SubBatchIter, SubIter, Sub, Instrument>(
+        subscription_batches: SubBatchIter,
+        channel_config: BoundedChannelConfig,
+    ) -> Result<Self, DataError>
     where
         SubBatchIter: IntoIterator<Item = SubIter>,
         SubIter: IntoIterator<Item = Sub>,
@@ -108,7 +151,10 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
         let batches = validate_batches(subscription_batches)?;
 
         // Generate required Channels from Subscription batches
-        let channels = Channels::try_from(&batches)?;
+        let channels = Channels::new(&batches, channel_config)?;
+
+        // Construct HTTP Client shared by every SnapshotFetcher
+        let snapshot_http_client = default_snapshot_http_client().map_err(SocketError::Http)?;
 
         let futures =
             batches.into_iter().map(|mut batch| {
@@ -122,11 +168,14 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                         .map(|((exchange, sub_kind), subs)| {
                             let subs = subs.into_iter().collect::<Vec<_>>();
                             let txs = Arc::clone(&channels.txs);
+                            let snapshot_http_client = snapshot_http_client.clone();
                             async move {
                                 match (exchange, sub_kind) {
                                     (ExchangeId::BinanceSpot, SubKind::PublicTrades) => {
                                         init_market_stream(
                                             STREAM_RECONNECTION_POLICY,
+                                            snapshot_http_client,
+                                            None,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -139,7 +188,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                         )
                                         .await
                                         .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
+                                            tokio::spawn(stream.forward_to_sink(
                                                 txs.trades.get(&exchange).unwrap().clone(),
                                             ))
                                         })
@@ -147,6 +196,8 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     (ExchangeId::BinanceSpot, SubKind::OrderBooksL1) => {
                                         init_market_stream(
                                             STREAM_RECONNECTION_POLICY,
+                                            snapshot_http_client,
+                                            None,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -159,7 +210,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                         )
                                         .await
                                         .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
+                                            tokio::spawn(stream.forward_to_sink(
                                                 txs.l1s.get(&exchange).unwrap().clone(),
                                             ))
                                         })
@@ -167,6 +218,8 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     (ExchangeId::BinanceSpot, SubKind::OrderBooksL2) => {
                                         init_market_stream(
                                             STREAM_RECONNECTION_POLICY,
+                                            snapshot_http_client,
+                                            None,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -179,7 +232,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                         )
                                         .await
                                         .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
+                                            tokio::spawn(stream.forward_to_sink(
                                                 txs.l2s.get(&exchange).unwrap().clone(),
                                             ))
                                         })
@@ -187,6 +240,8 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     (ExchangeId::BinanceFuturesUsd, SubKind::PublicTrades) => {
                                         init_market_stream(
                                             STREAM_RECONNECTION_POLICY,
+                                            snapshot_http_client,
+                                            None,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -199,7 +254,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                         )
                                         .await
                                         .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
+                                            tokio::spawn(stream.forward_to_sink(
                                                 txs.trades.get(&exchange).unwrap().clone(),
                                             ))
                                         })
@@ -207,6 +262,8 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     (ExchangeId::BinanceFuturesUsd, SubKind::OrderBooksL1) => {
                                         init_market_stream(
                                             STREAM_RECONNECTION_POLICY,
+                                            snapshot_http_client,
+                                            None,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::<_, Instrument, _>::new(
@@ -219,7 +276,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                         )
                                         .await
                                         .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
+                                            tokio::spawn(stream.forward_to_sink(
                                                 txs.l1s.get(&exchange).unwrap().clone(),
                                             ))
                                         })
@@ -227,6 +284,8 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     (ExchangeId::BinanceFuturesUsd, SubKind::OrderBooksL2) => {
                                         init_market_stream(
                                             STREAM_RECONNECTION_POLICY,
+                                            snapshot_http_client,
+                                            None,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::<_, Instrument, _>::new(
@@ -239,7 +298,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                         )
                                         .await
                                         .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
+                                            tokio::spawn(stream.forward_to_sink(
                                                 txs.l2s.get(&exchange).unwrap().clone(),
                                             ))
                                         })
@@ -247,6 +306,8 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     (ExchangeId::BinanceFuturesUsd, SubKind::Liquidations) => {
                                         init_market_stream(
                                             STREAM_RECONNECTION_POLICY,
+                                            snapshot_http_client,
+                                            None,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::<_, Instrument, _>::new(
@@ -259,7 +320,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                         )
                                         .await
                                         .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
+                                            tokio::spawn(stream.forward_to_sink(
                                                 txs.liquidations.get(&exchange).unwrap().clone(),
                                             ))
                                         })
@@ -267,6 +328,8 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     (ExchangeId::Bitfinex, SubKind::PublicTrades) => {
                                         init_market_stream(
                                             STREAM_RECONNECTION_POLICY,
+                                            snapshot_http_client,
+                                            None,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -279,7 +342,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                         )
                                         .await
                                         .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
+                                            tokio::spawn(stream.forward_to_sink(
                                                 txs.trades.get(&exchange).unwrap().clone(),
                                             ))
                                         })
@@ -287,6 +350,8 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     (ExchangeId::Bitmex, SubKind::PublicTrades) => {
                                         init_market_stream(
                                             STREAM_RECONNECTION_POLICY,
+                                            snapshot_http_client,
+                                            None,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -299,7 +364,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                         )
                                         .await
                                         .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
+                                            tokio::spawn(stream.forward_to_sink(
                                                 txs.trades.get(&exchange).unwrap().clone(),
                                             ))
                                         })
@@ -307,6 +372,8 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     (ExchangeId::BybitSpot, SubKind::PublicTrades) => {
                                         init_market_stream(
                                             STREAM_RECONNECTION_POLICY,
+                                            snapshot_http_client,
+                                            None,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -319,7 +386,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                         )
                                         .await
                                         .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
+                                            tokio::spawn(stream.forward_to_sink(
                                                 txs.trades.get(&exchange).unwrap().clone(),
                                             ))
                                         })
@@ -327,6 +394,8 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     (ExchangeId::BybitPerpetualsUsd, SubKind::PublicTrades) => {
                                         init_market_stream(
                                             STREAM_RECONNECTION_POLICY,
+                                            snapshot_http_client,
+                                            None,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -339,7 +408,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                         )
                                         .await
                                         .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
+                                            tokio::spawn(stream.forward_to_sink(
                                                 txs.trades.get(&exchange).unwrap().clone(),
                                             ))
                                         })
@@ -347,6 +416,8 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     (ExchangeId::Coinbase, SubKind::PublicTrades) => {
                                         init_market_stream(
                                             STREAM_RECONNECTION_POLICY,
+                                            snapshot_http_client,
+                                            None,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -359,7 +430,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                         )
                                         .await
                                         .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
+                                            tokio::spawn(stream.forward_to_sink(
                                                 txs.trades.get(&exchange).unwrap().clone(),
                                             ))
                                         })
@@ -367,6 +438,8 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     (ExchangeId::GateioSpot, SubKind::PublicTrades) => {
                                         init_market_stream(
                                             STREAM_RECONNECTION_POLICY,
+                                            snapshot_http_client,
+                                            None,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -379,7 +452,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                         )
                                         .await
                                         .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
+                                            tokio::spawn(stream.forward_to_sink(
                                                 txs.trades.get(&exchange).unwrap().clone(),
                                             ))
                                         })
@@ -387,6 +460,8 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     (ExchangeId::GateioFuturesUsd, SubKind::PublicTrades) => {
                                         init_market_stream(
                                             STREAM_RECONNECTION_POLICY,
+                                            snapshot_http_client,
+                                            None,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -399,7 +474,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                         )
                                         .await
                                         .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
+                                            tokio::spawn(stream.forward_to_sink(
                                                 txs.trades.get(&exchange).unwrap().clone(),
                                             ))
                                         })
@@ -407,6 +482,8 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     (ExchangeId::GateioFuturesBtc, SubKind::PublicTrades) => {
                                         init_market_stream(
                                             STREAM_RECONNECTION_POLICY,
+                                            snapshot_http_client,
+                                            None,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -419,7 +496,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                         )
                                         .await
                                         .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
+                                            tokio::spawn(stream.forward_to_sink(
                                                 txs.trades.get(&exchange).unwrap().clone(),
                                             ))
                                         })
@@ -427,6 +504,8 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     (ExchangeId::GateioPerpetualsUsd, SubKind::PublicTrades) => {
                                         init_market_stream(
                                             STREAM_RECONNECTION_POLICY,
+                                            snapshot_http_client,
+                                            None,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -439,7 +518,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                         )
                                         .await
                                         .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
+                                            tokio::spawn(stream.forward_to_sink(
                                                 txs.trades.get(&exchange).unwrap().clone(),
                                             ))
                                         })
@@ -447,6 +526,8 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     (ExchangeId::GateioPerpetualsBtc, SubKind::PublicTrades) => {
                                         init_market_stream(
                                             STREAM_RECONNECTION_POLICY,
+                                            snapshot_http_client,
+                                            None,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -459,7 +540,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                         )
                                         .await
                                         .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
+                                            tokio::spawn(stream.forward_to_sink(
                                                 txs.trades.get(&exchange).unwrap().clone(),
                                             ))
                                         })
@@ -467,6 +548,8 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     (ExchangeId::GateioOptions, SubKind::PublicTrades) => {
                                         init_market_stream(
                                             STREAM_RECONNECTION_POLICY,
+                                            snapshot_http_client,
+                                            None,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -479,7 +562,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                         )
                                         .await
                                         .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
+                                            tokio::spawn(stream.forward_to_sink(
                                                 txs.trades.get(&exchange).unwrap().clone(),
                                             ))
                                         })
@@ -487,6 +570,8 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     (ExchangeId::Kraken, SubKind::PublicTrades) => {
                                         init_market_stream(
                                             STREAM_RECONNECTION_POLICY,
+                                            snapshot_http_client,
+                                            None,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -499,7 +584,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                         )
                                         .await
                                         .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
+                                            tokio::spawn(stream.forward_to_sink(
                                                 txs.trades.get(&exchange).unwrap().clone(),
                                             ))
                                         })
@@ -507,6 +592,8 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     (ExchangeId::Kraken, SubKind::OrderBooksL1) => {
                                         init_market_stream(
                                             STREAM_RECONNECTION_POLICY,
+                                            snapshot_http_client,
+                                            None,
                                             subs.into_iter()
                                                 .map(|sub| {
                                                     Subscription::new(
@@ -519,13 +606,15 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                         )
                                         .await
                                         .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
+                                            tokio::spawn(stream.forward_to_sink(
                                                 txs.l1s.get(&exchange).unwrap().clone(),
                                             ))
                                         })
                                     }
                                     (ExchangeId::Okx, SubKind::PublicTrades) => init_market_stream(
                                         STREAM_RECONNECTION_POLICY,
+                                        snapshot_http_client,
+                                        None,
                                         subs.into_iter()
                                             .map(|sub| {
                                                 Subscription::new(Okx, sub.instrument, PublicTrades)
@@ -535,7 +624,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
                                     .await
                                     .map(|stream| {
                                         tokio::spawn(
-                                            stream.forward_to(
+                                            stream.forward_to_sink(
                                                 txs.trades.get(&exchange).unwrap().clone(),
                                             ),
                                         )
@@ -553,30 +642,10 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
         try_join_all(futures).await?;
 
         Ok(Self {
-            trades: channels
-                .rxs
-                .trades
-                .into_iter()
-                .map(|(exchange, rx)| (exchange, rx.into_stream()))
-                .collect(),
-            l1s: channels
-                .rxs
-                .l1s
-                .into_iter()
-                .map(|(exchange, rx)| (exchange, rx.into_stream()))
-                .collect(),
-            l2s: channels
-                .rxs
-                .l2s
-                .into_iter()
-                .map(|(exchange, rx)| (exchange, rx.into_stream()))
-                .collect(),
-            liquidations: channels
-                .rxs
-                .liquidations
-                .into_iter()
-                .map(|(exchange, rx)| (exchange, rx.into_stream()))
-                .collect(),
+            trades: channels.rxs.trades.into_iter().collect(),
+            l1s: channels.rxs.l1s.into_iter().collect(),
+            l2s: channels.rxs.l2s.into_iter().collect(),
+            liquidations: channels.rxs.liquidations.into_iter().collect(),
         })
     }
 
@@ -586,7 +655,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
     pub fn select_trades(
         &mut self,
         exchange: ExchangeId,
-    ) -> Option<UnboundedReceiverStream<MarketStreamResult<InstrumentKey, PublicTrade>>> {
+    ) -> Option<BoundedRx<MarketStreamResult<InstrumentKey, PublicTrade>>> {
         self.trades.remove(&exchange)
     }
 
@@ -594,7 +663,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
     /// [`SelectAll`](futures_util::stream::select_all::select_all).
     pub fn select_all_trades(
         &mut self,
-    ) -> SelectAll<UnboundedReceiverStream<MarketStreamResult<InstrumentKey, PublicTrade>>> {
+    ) -> SelectAll<BoundedRx<MarketStreamResult<InstrumentKey, PublicTrade>>> {
         futures_util::stream::select_all::select_all(std::mem::take(&mut self.trades).into_values())
     }
 
@@ -604,7 +673,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
     pub fn select_l1s(
         &mut self,
         exchange: ExchangeId,
-    ) -> Option<UnboundedReceiverStream<MarketStreamResult<InstrumentKey, OrderBookL1>>> {
+    ) -> Option<BoundedRx<MarketStreamResult<InstrumentKey, OrderBookL1>>> {
         self.l1s.remove(&exchange)
     }
 
@@ -612,7 +681,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
     /// [`SelectAll`](futures_util::stream::select_all::select_all).
     pub fn select_all_l1s(
         &mut self,
-    ) -> SelectAll<UnboundedReceiverStream<MarketStreamResult<InstrumentKey, OrderBookL1>>> {
+    ) -> SelectAll<BoundedRx<MarketStreamResult<InstrumentKey, OrderBookL1>>> {
         futures_util::stream::select_all::select_all(std::mem::take(&mut self.l1s).into_values())
     }
 
@@ -622,7 +691,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
     pub fn select_l2s(
         &mut self,
         exchange: ExchangeId,
-    ) -> Option<UnboundedReceiverStream<MarketStreamResult<InstrumentKey, OrderBookEvent>>> {
+    ) -> Option<BoundedRx<MarketStreamResult<InstrumentKey, OrderBookEvent>>> {
         self.l2s.remove(&exchange)
     }
 
@@ -630,7 +699,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
     /// [`SelectAll`](futures_util::stream::select_all::select_all).
     pub fn select_all_l2s(
         &mut self,
-    ) -> SelectAll<UnboundedReceiverStream<MarketStreamResult<InstrumentKey, OrderBookEvent>>> {
+    ) -> SelectAll<BoundedRx<MarketStreamResult<InstrumentKey, OrderBookEvent>>> {
         futures_util::stream::select_all::select_all(std::mem::take(&mut self.l2s).into_values())
     }
 
@@ -640,7 +709,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
     pub fn select_liquidations(
         &mut self,
         exchange: ExchangeId,
-    ) -> Option<UnboundedReceiverStream<MarketStreamResult<InstrumentKey, Liquidation>>> {
+    ) -> Option<BoundedRx<MarketStreamResult<InstrumentKey, Liquidation>>> {
         self.liquidations.remove(&exchange)
     }
 
@@ -648,7 +717,7 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
     /// [`SelectAll`](futures_util::stream::select_all::select_all).
     pub fn select_all_liquidations(
         &mut self,
-    ) -> SelectAll<UnboundedReceiverStream<MarketStreamResult<InstrumentKey, Liquidation>>> {
+    ) -> SelectAll<BoundedRx<MarketStreamResult<InstrumentKey, Liquidation>>> {
         futures_util::stream::select_all::select_all(
             std::mem::take(&mut self.liquidations).into_values(),
         )
@@ -738,16 +807,19 @@ struct Channels<InstrumentKey> {
     rxs: Rxs<InstrumentKey>,
 }
 
-impl<'a, Instrument> TryFrom<&'a Vec<Vec<Subscription<ExchangeId, Instrument, SubKind>>>>
-    for Channels<Instrument::Key>
+impl<InstrumentKey> Channels<InstrumentKey>
 where
-    Instrument: InstrumentData,
+    InstrumentKey: Send,
 {
-    type Error = DataError;
-
-    fn try_from(
-        value: &'a Vec<Vec<Subscription<ExchangeId, Instrument, SubKind>>>,
-    ) -> Result<Self, Self::Error> {
+    /// Construct a bounded channel, using the provided [`BoundedChannelConfig`], for every
+    /// [`ExchangeId`]-[`SubKind`] combination found in the [`Subscription`] batches.
+    fn new<Instrument>(
+        value: &[Vec<Subscription<ExchangeId, Instrument, SubKind>>],
+        channel_config: BoundedChannelConfig,
+    ) -> Result<Self, DataError>
+    where
+        Instrument: InstrumentData<Key = InstrumentKey>,
+    {
         let mut txs = Txs::default();
         let mut rxs = Rxs::default();
 
@@ -757,21 +829,21 @@ where
                     if let (None, None) =
                         (txs.trades.get(&sub.exchange), rxs.trades.get(&sub.exchange))
                     {
-                        let (tx, rx) = mpsc_unbounded();
+                        let (tx, rx) = mpsc_bounded(channel_config, None);
                         txs.trades.insert(sub.exchange, tx);
                         rxs.trades.insert(sub.exchange, rx);
                     }
                 }
                 SubKind::OrderBooksL1 => {
                     if let (None, None) = (txs.l1s.get(&sub.exchange), rxs.l1s.get(&sub.exchange)) {
-                        let (tx, rx) = mpsc_unbounded();
+                        let (tx, rx) = mpsc_bounded(channel_config, None);
                         txs.l1s.insert(sub.exchange, tx);
                         rxs.l1s.insert(sub.exchange, rx);
                     }
                 }
                 SubKind::OrderBooksL2 => {
                     if let (None, None) = (txs.l2s.get(&sub.exchange), rxs.l2s.get(&sub.exchange)) {
-                        let (tx, rx) = mpsc_unbounded();
+                        let (tx, rx) = mpsc_bounded(channel_config, None);
                         txs.l2s.insert(sub.exchange, tx);
                         rxs.l2s.insert(sub.exchange, rx);
                     }
@@ -781,7 +853,7 @@ where
                         txs.liquidations.get(&sub.exchange),
                         rxs.liquidations.get(&sub.exchange),
                     ) {
-                        let (tx, rx) = mpsc_unbounded();
+                        let (tx, rx) = mpsc_bounded(channel_config, None);
                         txs.liquidations.insert(sub.exchange, tx);
                         rxs.liquidations.insert(sub.exchange, rx);
                     }
@@ -798,11 +870,11 @@ where
 }
 
 struct Txs<InstrumentKey> {
-    trades: FnvHashMap<ExchangeId, UnboundedTx<MarketStreamResult<InstrumentKey, PublicTrade>>>,
-    l1s: FnvHashMap<ExchangeId, UnboundedTx<MarketStreamResult<InstrumentKey, OrderBookL1>>>,
-    l2s: FnvHashMap<ExchangeId, UnboundedTx<MarketStreamResult<InstrumentKey, OrderBookEvent>>>,
+    trades: FnvHashMap<ExchangeId, BoundedTx<MarketStreamResult<InstrumentKey, PublicTrade>>>,
+    l1s: FnvHashMap<ExchangeId, BoundedTx<MarketStreamResult<InstrumentKey, OrderBookL1>>>,
+    l2s: FnvHashMap<ExchangeId, BoundedTx<MarketStreamResult<InstrumentKey, OrderBookEvent>>>,
     liquidations:
-        FnvHashMap<ExchangeId, UnboundedTx<MarketStreamResult<InstrumentKey, Liquidation>>>,
+        FnvHashMap<ExchangeId, BoundedTx<MarketStreamResult<InstrumentKey, Liquidation>>>,
 }
 
 impl<InstrumentKey> Default for Txs<InstrumentKey> {
@@ -817,11 +889,11 @@ impl<InstrumentKey> Default for Txs<InstrumentKey> {
 }
 
 struct Rxs<InstrumentKey> {
-    trades: FnvHashMap<ExchangeId, UnboundedRx<MarketStreamResult<InstrumentKey, PublicTrade>>>,
-    l1s: FnvHashMap<ExchangeId, UnboundedRx<MarketStreamResult<InstrumentKey, OrderBookL1>>>,
-    l2s: FnvHashMap<ExchangeId, UnboundedRx<MarketStreamResult<InstrumentKey, OrderBookEvent>>>,
+    trades: FnvHashMap<ExchangeId, BoundedRx<MarketStreamResult<InstrumentKey, PublicTrade>>>,
+    l1s: FnvHashMap<ExchangeId, BoundedRx<MarketStreamResult<InstrumentKey, OrderBookL1>>>,
+    l2s: FnvHashMap<ExchangeId, BoundedRx<MarketStreamResult<InstrumentKey, OrderBookEvent>>>,
     liquidations:
-        FnvHashMap<ExchangeId, UnboundedRx<MarketStreamResult<InstrumentKey, Liquidation>>>,
+        FnvHashMap<ExchangeId, BoundedRx<MarketStreamResult<InstrumentKey, Liquidation>>>,
 }
 
 impl<InstrumentKey> Default for Rxs<InstrumentKey> {
@@ -2,16 +2,29 @@ use super::Streams;
 use crate::{
     Identifier,
     error::DataError,
-    exchange::StreamSelector,
+    exchange::{
+        StreamSelector,
+        http::default_snapshot_http_client,
+        listing::{MarketListing, check_listed_markets},
+    },
     instrument::InstrumentData,
     streams::{
-        consumer::{MarketStreamResult, STREAM_RECONNECTION_POLICY, init_market_stream},
+        consumer::{
+            MarketStreamResult, STREAM_CHANNEL_CONFIG, STREAM_RECONNECTION_POLICY,
+            init_market_stream,
+        },
+        raw::RawFrameLogger,
         reconnect::stream::ReconnectingStream,
     },
     subscription::{Subscription, SubscriptionKind},
 };
 use barter_instrument::exchange::ExchangeId;
-use barter_integration::{Validator, channel::Channel};
+use barter_integration::{
+    Validator,
+    channel::{BoundedChannel, BoundedChannelConfig, UnboundedTx},
+    error::SocketError,
+    metric::Metric,
+};
 use std::{
     collections::HashMap,
     fmt::{Debug, Display},
@@ -29,21 +42,70 @@ pub mod multi;
 /// types.
 pub mod dynamic;
 
+/// Defines the [`MarketStreams`](combined::MarketStreams) API for ergonomically initialising a
+/// single merged market event `Stream` across multiple exchanges, instruments and
+/// [`SubscriptionKind`]s.
+pub mod combined;
+
 /// Communicative type alias representing the [`Future`] result of a [`Subscription`] validation
 /// call generated whilst executing [`StreamBuilder::subscribe`].
 pub type SubscribeFuture = Pin<Box<dyn Future<Output = Result<(), DataError>>>>;
 
+/// Construct a [`BoundedChannel`] for buffering the market events of an exchange, emitting a
+/// drop [`Metric`] tagged with the `exchange` to the `drop_metrics` [`UnboundedTx`] if provided.
+pub fn exchange_channel<T>(
+    config: BoundedChannelConfig,
+    drop_metrics: Option<&UnboundedTx<Metric>>,
+    exchange: ExchangeId,
+) -> BoundedChannel<T>
+where
+    T: Send,
+{
+    match drop_metrics {
+        Some(metrics_tx) => {
+            BoundedChannel::with_drop_metrics(config, exchange.as_str(), metrics_tx.clone())
+        }
+        None => BoundedChannel::new(config),
+    }
+}
+
+/// Return the configured snapshot HTTP [`reqwest::Client`], building and storing the
+/// [`default_snapshot_http_client`] if none is configured.
+fn snapshot_http_client(
+    configured: &mut Option<reqwest::Client>,
+) -> Result<reqwest::Client, DataError> {
+    if let Some(client) = configured {
+        return Ok(client.clone());
+    }
+
+    let client = default_snapshot_http_client().map_err(SocketError::Http)?;
+    Ok(configured.insert(client).clone())
+}
+
 /// Builder to configure and initialise a [`Streams<MarketEvent<SubscriptionKind::Event>`](Streams) instance
 /// for a specific [`SubscriptionKind`].
-#[derive(Default)]
 pub struct StreamBuilder<InstrumentKey, Kind>
 where
     Kind: SubscriptionKind,
 {
-    pub channels: HashMap<ExchangeId, Channel<MarketStreamResult<InstrumentKey, Kind::Event>>>,
+    pub channels:
+        HashMap<ExchangeId, BoundedChannel<MarketStreamResult<InstrumentKey, Kind::Event>>>,
+    pub channel_config: BoundedChannelConfig,
+    pub drop_metrics: Option<UnboundedTx<Metric>>,
+    pub snapshot_http_client: Option<reqwest::Client>,
+    pub raw_frame_logger: Option<RawFrameLogger>,
     pub futures: Vec<SubscribeFuture>,
 }
 
+impl<InstrumentKey, Kind> Default for StreamBuilder<InstrumentKey, Kind>
+where
+    Kind: SubscriptionKind,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<InstrumentKey, Kind> Debug for StreamBuilder<InstrumentKey, Kind>
 where
     InstrumentKey: Debug,
@@ -52,6 +114,10 @@ where
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("StreamBuilder<InstrumentKey, SubscriptionKind>")
             .field("channels", &self.channels)
+            .field("channel_config", &self.channel_config)
+            .field("drop_metrics", &self.drop_metrics)
+            .field("snapshot_http_client", &self.snapshot_http_client)
+            .field("raw_frame_logger", &self.raw_frame_logger)
             .field("num_futures", &self.futures.len())
             .finish()
     }
@@ -65,16 +131,124 @@ where
     pub fn new() -> Self {
         Self {
             channels: HashMap::new(),
+            channel_config: STREAM_CHANNEL_CONFIG,
+            drop_metrics: None,
+            snapshot_http_client: None,
+            raw_frame_logger: None,
             futures: Vec::new(),
         }
     }
 
+    /// Set the [`BoundedChannelConfig`] of the channel buffering each exchange's market events,
+    /// defaulting to [`STREAM_CHANNEL_CONFIG`].
+    ///
+    /// Only applies to exchanges first subscribed to after this method is invoked.
+    pub fn with_channel_config(self, channel_config: BoundedChannelConfig) -> Self {
+        Self {
+            channel_config,
+            ..self
+        }
+    }
+
+    /// Send a drop [`Metric`] tagged with the exchange to the provided [`UnboundedTx`] each time
+    /// a market event is dropped by the configured
+    /// [`OverflowPolicy`](barter_integration::channel::OverflowPolicy).
+    ///
+    /// Only applies to exchanges first subscribed to after this method is invoked.
+    pub fn with_drop_metrics(self, metrics_tx: UnboundedTx<Metric>) -> Self {
+        Self {
+            drop_metrics: Some(metrics_tx),
+            ..self
+        }
+    }
+
+    /// Set the HTTP [`reqwest::Client`] used to fetch any initial market data snapshots and
+    /// listed markets, defaulting to the [`default_snapshot_http_client`].
+    ///
+    /// Only applies to [`Subscription`]s added after this method is invoked.
+    pub fn with_snapshot_http_client(self, snapshot_http_client: reqwest::Client) -> Self {
+        Self {
+            snapshot_http_client: Some(snapshot_http_client),
+            ..self
+        }
+    }
+
+    /// Tee every raw inbound WebSocket text frame to the provided [`RawFrameLogger`], which is
+    /// useful for debugging exchange feeds.
+    ///
+    /// Only applies to [`Subscription`]s added after this method is invoked.
+    pub fn with_raw_frame_logger(self, raw_frame_logger: RawFrameLogger) -> Self {
+        Self {
+            raw_frame_logger: Some(raw_frame_logger),
+            ..self
+        }
+    }
+
     /// Add a collection of [`Subscription`]s to the [`StreamBuilder`] that will be actioned on
     /// a distinct [`WebSocket`](barter_integration::protocol::websocket::WebSocket) connection.
     ///
     /// Note that [`Subscription`]s are not actioned until the
     /// [`init()`](StreamBuilder::init()) method is invoked.
-    pub fn subscribe<SubIter, Sub, Exchange, Instrument>(mut self, subscriptions: SubIter) -> Self
+    pub fn subscribe<SubIter, Sub, Exchange, Instrument>(self, subscriptions: SubIter) -> Self
+    where
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription<Exchange, Instrument, Kind>>,
+        Exchange: StreamSelector<Instrument, Kind> + Ord + Send + Sync + 'static,
+        Instrument: InstrumentData<Key = InstrumentKey> + Ord + Display + 'static,
+        Instrument::Key: Debug + Clone + Send + 'static,
+        Kind: Ord + Display + Send + Sync + 'static,
+        Kind::Event: Clone + Send,
+        Subscription<Exchange, Instrument, Kind>:
+            Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        self.subscribe_with_pre_flight(subscriptions, |subscriptions| {
+            std::future::ready(Ok(subscriptions))
+        })
+    }
+
+    /// Add a collection of [`Subscription`]s to the [`StreamBuilder`] that will be actioned on
+    /// a distinct [`WebSocket`](barter_integration::protocol::websocket::WebSocket) connection,
+    /// first validating that every [`Subscription`] market is listed on the exchange.
+    ///
+    /// Unknown markets cause [`init()`](StreamBuilder::init()) to fail with a
+    /// [`SocketError::Unsupported`](barter_integration::error::SocketError::Unsupported) naming
+    /// each of them, rather than silently yielding no data.
+    ///
+    /// Listed markets are fetched using the configured
+    /// [`snapshot_http_client`](Self::with_snapshot_http_client).
+    ///
+    /// See [`MarketListing`] for the exchanges that support this pre-flight check.
+    pub fn subscribe_with_symbol_validation<SubIter, Sub, Exchange, Instrument>(
+        mut self,
+        subscriptions: SubIter,
+    ) -> Self
+    where
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription<Exchange, Instrument, Kind>>,
+        Exchange: StreamSelector<Instrument, Kind> + MarketListing + Ord + Send + Sync + 'static,
+        Instrument: InstrumentData<Key = InstrumentKey> + Ord + Display + 'static,
+        Instrument::Key: Debug + Clone + Send + 'static,
+        Kind: Ord + Display + Send + Sync + 'static,
+        Kind::Event: Clone + Send,
+        Subscription<Exchange, Instrument, Kind>:
+            Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        // Listed markets are fetched using the same HTTP Client as any snapshots
+        let http_client = snapshot_http_client(&mut self.snapshot_http_client);
+
+        self.subscribe_with_pre_flight(subscriptions, |subscriptions| async move {
+            check_listed_markets(&http_client?, &subscriptions).await?;
+            Ok(subscriptions)
+        })
+    }
+
+    /// Add a collection of [`Subscription`]s to the [`StreamBuilder`], running the provided
+    /// `pre_flight` check on the validated [`Subscription`]s before they are actioned.
+    fn subscribe_with_pre_flight<SubIter, Sub, Exchange, Instrument, PreFlight, PreFlightFut>(
+        mut self,
+        subscriptions: SubIter,
+        pre_flight: PreFlight,
+    ) -> Self
     where
         SubIter: IntoIterator<Item = Sub>,
         Sub: Into<Subscription<Exchange, Instrument, Kind>>,
@@ -85,13 +259,31 @@ where
         Kind::Event: Clone + Send,
         Subscription<Exchange, Instrument, Kind>:
             Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+        PreFlight: FnOnce(Vec<Subscription<Exchange, Instrument, Kind>>) -> PreFlightFut + 'static,
+        PreFlightFut:
+            Future<Output = Result<Vec<Subscription<Exchange, Instrument, Kind>>, DataError>>,
     {
         // Construct Vec<Subscriptions> from input SubIter
         let subscriptions = subscriptions.into_iter().map(Sub::into).collect::<Vec<_>>();
 
         // Acquire channel Sender to send Market<Kind::Event> from consumer loop to user
         // '--> Add ExchangeChannel Entry if this Exchange <--> SubscriptionKind combination is new
-        let exchange_tx = self.channels.entry(Exchange::ID).or_default().tx.clone();
+        let exchange_tx = self
+            .channels
+            .entry(Exchange::ID)
+            .or_insert_with(|| {
+                exchange_channel(
+                    self.channel_config,
+                    self.drop_metrics.as_ref(),
+                    Exchange::ID,
+                )
+            })
+            .tx
+            .clone();
+
+        // Acquire HTTP Client shared by every SnapshotFetcher initialised by this builder
+        let snapshot_http_client = snapshot_http_client(&mut self.snapshot_http_client);
+        let raw_frame_logger = self.raw_frame_logger.clone();
 
         // Add Future that once awaited will yield the Result<(), SocketError> of subscribing
         self.futures.push(Box::pin(async move {
@@ -105,11 +297,20 @@ where
             subscriptions.sort();
             subscriptions.dedup();
 
+            // Run any pre-flight checks (eg/ validating markets are listed on the exchange)
+            let subscriptions = pre_flight(subscriptions).await?;
+
             // Initialise a MarketEvent `ReconnectingStream`
-            let stream = init_market_stream(STREAM_RECONNECTION_POLICY, subscriptions).await?;
+            let stream = init_market_stream(
+                STREAM_RECONNECTION_POLICY,
+                snapshot_http_client?,
+                raw_frame_logger,
+                subscriptions,
+            )
+            .await?;
 
             // Forward MarketEvents to ExchangeTx
-            tokio::spawn(stream.forward_to(exchange_tx));
+            tokio::spawn(stream.forward_to_sink(exchange_tx));
 
             Ok(())
         }));
@@ -139,3 +340,102 @@ where
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::{Connector, http::build_snapshot_http_client},
+        streams::{
+            reconnect,
+            test_utils::{
+                MockOkx, bind_mock_http, bind_mock_okx, serve_mock_okx_trades,
+                set_mock_okx_http_url,
+            },
+        },
+        subscription::trade::PublicTrades,
+    };
+    use barter_instrument::instrument::market_data::{
+        MarketDataInstrument, kind::MarketDataInstrumentKind,
+    };
+    use futures::StreamExt;
+    use std::time::Duration;
+    use tokio::net::TcpListener;
+
+    #[tokio::test]
+    async fn test_stream_builder_fetches_snapshots_with_snapshot_http_client() {
+        tokio::spawn(serve_mock_okx_trades(
+            bind_mock_okx::<4>().await,
+            &["BTC-USDT"],
+        ));
+        set_mock_okx_http_url::<4>(
+            bind_mock_http(
+                r#"{"code":"0","msg":"","data":[{"instId":"BTC-USDT","side":"sell","sz":"0.3","px":"99.0","tradeId":"1","ts":"1654161646001"}]}"#
+                .to_string(),
+            )
+            .await,
+        );
+
+        let btc_usdt = MarketDataInstrument::new("btc", "usdt", MarketDataInstrumentKind::Spot);
+
+        let mut streams = StreamBuilder::<_, PublicTrades>::new()
+            .with_snapshot_http_client(reqwest::Client::new())
+            .subscribe([Subscription::new(MockOkx::<4>, btc_usdt, PublicTrades)])
+            .init()
+            .await
+            .unwrap();
+
+        let prices = tokio::time::timeout(
+            Duration::from_secs(5),
+            streams
+                .select(MockOkx::<4>::ID)
+                .unwrap()
+                .filter_map(|event| {
+                    std::future::ready(match event {
+                        reconnect::Event::Item(Ok(event)) => Some(event.kind.price),
+                        _ => None,
+                    })
+                })
+                .take(2)
+                .collect::<Vec<_>>(),
+        )
+        .await
+        .unwrap();
+
+        // Snapshot trade is yielded before the live trade
+        assert_eq!(prices, vec![99.0, 100.0]);
+    }
+
+    #[tokio::test]
+    async fn test_stream_builder_snapshot_http_client_timeout() {
+        tokio::spawn(serve_mock_okx_trades(
+            bind_mock_okx::<5>().await,
+            &["BTC-USDT"],
+        ));
+
+        // Mock HTTP snapshot server accepts connections but never responds
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        set_mock_okx_http_url::<5>(format!("http://{}", listener.local_addr().unwrap()));
+        tokio::spawn(async move {
+            let (_tcp, _) = listener.accept().await.unwrap();
+            std::future::pending::<()>().await;
+        });
+
+        let btc_usdt = MarketDataInstrument::new("btc", "usdt", MarketDataInstrumentKind::Spot);
+
+        // Injected client times out well before the DEFAULT_SNAPSHOT_HTTP_TIMEOUT
+        let client = build_snapshot_http_client(Duration::from_millis(50)).unwrap();
+
+        let result = tokio::time::timeout(
+            Duration::from_secs(5),
+            StreamBuilder::<_, PublicTrades>::new()
+                .with_snapshot_http_client(client)
+                .subscribe([Subscription::new(MockOkx::<5>, btc_usdt, PublicTrades)])
+                .init(),
+        )
+        .await
+        .expect("injected snapshot HTTP client timeout was not honoured");
+
+        assert!(result.is_err());
+    }
+}
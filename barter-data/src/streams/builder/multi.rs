@@ -1,11 +1,17 @@
-use super::{StreamBuilder, Streams};
+use super::{StreamBuilder, Streams, exchange_channel};
 use crate::{
     error::DataError,
-    streams::{consumer::MarketStreamResult, reconnect::stream::ReconnectingStream},
+    streams::{
+        consumer::{MarketStreamResult, STREAM_CHANNEL_CONFIG},
+        reconnect::stream::ReconnectingStream,
+    },
     subscription::SubscriptionKind,
 };
 use barter_instrument::exchange::ExchangeId;
-use barter_integration::channel::Channel;
+use barter_integration::{
+    channel::{BoundedChannel, BoundedChannelConfig, UnboundedTx},
+    metric::Metric,
+};
 use futures_util::StreamExt;
 use std::{collections::HashMap, fmt::Debug, future::Future, pin::Pin};
 
@@ -15,12 +21,19 @@ pub type BuilderInitFuture = Pin<Box<dyn Future<Output = Result<(), DataError>>>
 
 /// Builder to configure and initialise a common [`Streams<Output>`](Streams) instance from
 /// multiple [`StreamBuilder<SubscriptionKind>`](StreamBuilder)s.
-#[derive(Default)]
 pub struct MultiStreamBuilder<Output> {
-    pub channels: HashMap<ExchangeId, Channel<Output>>,
+    pub channels: HashMap<ExchangeId, BoundedChannel<Output>>,
+    pub channel_config: BoundedChannelConfig,
+    pub drop_metrics: Option<UnboundedTx<Metric>>,
     pub futures: Vec<BuilderInitFuture>,
 }
 
+impl<Output> Default for MultiStreamBuilder<Output> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<Output> Debug for MultiStreamBuilder<Output>
 where
     Output: Debug,
@@ -28,6 +41,8 @@ where
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MultiStreamBuilder<Output>")
             .field("channels", &self.channels)
+            .field("channel_config", &self.channel_config)
+            .field("drop_metrics", &self.drop_metrics)
             .field("num_futures", &self.futures.len())
             .finish()
     }
@@ -38,10 +53,35 @@ impl<Output> MultiStreamBuilder<Output> {
     pub fn new() -> Self {
         Self {
             channels: HashMap::new(),
+            channel_config: STREAM_CHANNEL_CONFIG,
+            drop_metrics: None,
             futures: Vec::new(),
         }
     }
 
+    /// Set the [`BoundedChannelConfig`] of the channel buffering each exchange's `Output`s,
+    /// defaulting to [`STREAM_CHANNEL_CONFIG`].
+    ///
+    /// Only applies to exchanges first added after this method is invoked.
+    pub fn with_channel_config(self, channel_config: BoundedChannelConfig) -> Self {
+        Self {
+            channel_config,
+            ..self
+        }
+    }
+
+    /// Send a drop [`Metric`] tagged with the exchange to the provided [`UnboundedTx`] each time
+    /// an `Output` is dropped by the configured
+    /// [`OverflowPolicy`](barter_integration::channel::OverflowPolicy).
+    ///
+    /// Only applies to exchanges first added after this method is invoked.
+    pub fn with_drop_metrics(self, metrics_tx: UnboundedTx<Metric>) -> Self {
+        Self {
+            drop_metrics: Some(metrics_tx),
+            ..self
+        }
+    }
+
     /// Add a [`StreamBuilder<SubscriptionKind>`](StreamBuilder) to the [`MultiStreamBuilder`]. Creates a
     /// [`Future`] that calls [`StreamBuilder::init`] and maps the [`SubscriptionKind::Event`](SubscriptionKind)
     /// into a common `Output`.
@@ -63,7 +103,14 @@ impl<Output> MultiStreamBuilder<Output> {
         // Iterate over each StreamBuilder exchange present
         for exchange in builder.channels.keys().cloned() {
             // Insert ExchangeChannel<Output> Entry to Self for each exchange
-            let exchange_tx = self.channels.entry(exchange).or_default().tx.clone();
+            let exchange_tx = self
+                .channels
+                .entry(exchange)
+                .or_insert_with(|| {
+                    exchange_channel(self.channel_config, self.drop_metrics.as_ref(), exchange)
+                })
+                .tx
+                .clone();
 
             // Insert new exchange_tx<Output> into HashMap for each exchange
             exchange_txs.insert(exchange, exchange_tx);
@@ -84,12 +131,7 @@ impl<Output> MultiStreamBuilder<Output> {
                         .expect("all exchange_txs should be present here");
 
                     // Task to receive MarketStreamResult<SubscriptionKind::Event> and send Outputs via exchange_tx
-                    tokio::spawn(
-                        exchange_rx
-                            .into_stream()
-                            .map(Output::from)
-                            .forward_to(exchange_tx),
-                    );
+                    tokio::spawn(exchange_rx.map(Output::from).forward_to_sink(exchange_tx));
                 });
 
             Ok(())
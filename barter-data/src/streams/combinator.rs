@@ -0,0 +1,303 @@
+use crate::{
+    books::{Level, OrderBook},
+    event::MarketEvent,
+    subscription::book::OrderBookEvent,
+};
+use barter_instrument::exchange::ExchangeId;
+use fnv::FnvHashMap;
+use futures::{Stream, StreamExt};
+use std::{
+    future::ready,
+    hash::Hash,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+use tokio::time::{Instant, Sleep};
+
+/// Best bid & ask [`Level`]s of an [`OrderBook`].
+type Bbo = (Option<Level>, Option<Level>);
+
+/// Utilities for filtering and rate limiting [`MarketEvent`] `Stream`s.
+pub trait MarketStreamExt
+where
+    Self: Stream + Sized,
+{
+    /// Only yield [`OrderBookEvent`]s that change the best bid or ask [`Level`] (price or
+    /// amount) of the instrument [`OrderBook`].
+    ///
+    /// A local [`OrderBook`] is maintained for each exchange instrument in order to determine the
+    /// best bid & ask after every [`OrderBookEvent`].
+    fn filter_bbo_changes<InstrumentKey>(self) -> impl Stream<Item = Self::Item>
+    where
+        Self: Stream<Item = MarketEvent<InstrumentKey, OrderBookEvent>>,
+        InstrumentKey: Clone + Eq + Hash,
+    {
+        let mut books = FnvHashMap::<(ExchangeId, InstrumentKey), (OrderBook, Bbo)>::default();
+
+        self.filter(move |event| {
+            let (book, bbo) = books
+                .entry((event.exchange, event.instrument.clone()))
+                .or_default();
+
+            book.update(event.kind.clone());
+
+            let next = (
+                book.bids().levels().first().copied(),
+                book.asks().levels().first().copied(),
+            );
+            let changed = *bbo != next;
+            *bbo = next;
+
+            ready(changed)
+        })
+    }
+
+    /// Yield at most one [`MarketEvent`] per exchange instrument every `period`, keeping only
+    /// the latest event of each exchange instrument received during the `period`.
+    ///
+    /// The first event of an exchange instrument is yielded immediately. Any pending events are
+    /// flushed when the inner stream ends.
+    fn throttle<InstrumentKey, Kind>(self, period: Duration) -> Throttle<Self, InstrumentKey, Kind>
+    where
+        Self: Stream<Item = MarketEvent<InstrumentKey, Kind>>,
+    {
+        Throttle::new(self, period)
+    }
+}
+
+impl<St> MarketStreamExt for St where St: Stream {}
+
+/// [`MarketEvent`] `Stream` adapter returned by [`MarketStreamExt::throttle`].
+#[derive(Debug)]
+pub struct Throttle<St, InstrumentKey, Kind> {
+    stream: St,
+    stream_ended: bool,
+    period: Duration,
+    emitted: FnvHashMap<(ExchangeId, InstrumentKey), Instant>,
+    pending: Vec<(Instant, MarketEvent<InstrumentKey, Kind>)>,
+    sleep: Option<Pin<Box<Sleep>>>,
+}
+
+impl<St, InstrumentKey, Kind> Throttle<St, InstrumentKey, Kind> {
+    /// Construct a new [`Throttle`] yielding at most one [`MarketEvent`] per exchange instrument
+    /// every `period`.
+    pub fn new(stream: St, period: Duration) -> Self {
+        Self {
+            stream,
+            stream_ended: false,
+            period,
+            emitted: FnvHashMap::default(),
+            pending: Vec::new(),
+            sleep: None,
+        }
+    }
+}
+
+impl<St, InstrumentKey, Kind> Throttle<St, InstrumentKey, Kind>
+where
+    InstrumentKey: Clone + Eq + Hash,
+{
+    /// Return the [`MarketEvent`] if it can be yielded immediately, otherwise replace any
+    /// pending event of the same exchange instrument with it.
+    fn throttle(
+        &mut self,
+        event: MarketEvent<InstrumentKey, Kind>,
+        now: Instant,
+    ) -> Option<MarketEvent<InstrumentKey, Kind>> {
+        let key = (event.exchange, event.instrument.clone());
+
+        match self.emitted.get(&key) {
+            Some(emitted) if now < *emitted + self.period => {
+                let deadline = *emitted + self.period;
+                match self.pending.iter_mut().find(|(_, pending)| {
+                    pending.exchange == event.exchange && pending.instrument == event.instrument
+                }) {
+                    Some((_, pending)) => *pending = event,
+                    None => self.pending.push((deadline, event)),
+                }
+                None
+            }
+            _ => {
+                self.emitted.insert(key, now);
+                Some(event)
+            }
+        }
+    }
+
+    /// Remove the first pending [`MarketEvent`] due at `now`, if any.
+    fn pop_due(&mut self, now: Instant) -> Option<MarketEvent<InstrumentKey, Kind>> {
+        let index = self
+            .pending
+            .iter()
+            .position(|(deadline, _)| *deadline <= now)?;
+        let (_, event) = self.pending.remove(index);
+        self.emitted
+            .insert((event.exchange, event.instrument.clone()), now);
+        Some(event)
+    }
+}
+
+impl<St, InstrumentKey, Kind> Stream for Throttle<St, InstrumentKey, Kind>
+where
+    St: Stream<Item = MarketEvent<InstrumentKey, Kind>> + Unpin,
+    InstrumentKey: Clone + Eq + Hash + Unpin,
+    Kind: Unpin,
+{
+    type Item = MarketEvent<InstrumentKey, Kind>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            while !this.stream_ended {
+                match Pin::new(&mut this.stream).poll_next(cx) {
+                    Poll::Ready(Some(event)) => {
+                        if let Some(event) = this.throttle(event, Instant::now()) {
+                            return Poll::Ready(Some(event));
+                        }
+                    }
+                    Poll::Ready(None) => this.stream_ended = true,
+                    Poll::Pending => break,
+                }
+            }
+
+            if this.stream_ended {
+                return Poll::Ready((!this.pending.is_empty()).then(|| this.pending.remove(0).1));
+            }
+
+            if let Some(event) = this.pop_due(Instant::now()) {
+                return Poll::Ready(Some(event));
+            }
+
+            let Some(deadline) = this.pending.iter().map(|(deadline, _)| *deadline).min() else {
+                this.sleep = None;
+                return Poll::Pending;
+            };
+
+            let sleep = match &mut this.sleep {
+                Some(sleep) => {
+                    sleep.as_mut().reset(deadline);
+                    sleep
+                }
+                None => this
+                    .sleep
+                    .insert(Box::pin(tokio::time::sleep_until(deadline))),
+            };
+
+            if sleep.as_mut().poll(cx).is_pending() {
+                return Poll::Pending;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::UnboundedReceiverStream;
+
+    fn event<Kind>(instrument: &'static str, kind: Kind) -> MarketEvent<&'static str, Kind> {
+        MarketEvent {
+            time_exchange: DateTime::<Utc>::MIN_UTC,
+            time_received: DateTime::<Utc>::MIN_UTC,
+            exchange: ExchangeId::BinanceSpot,
+            instrument,
+            kind,
+        }
+    }
+
+    fn book_event(bids: Vec<Level>, asks: Vec<Level>) -> MarketEvent<&'static str, OrderBookEvent> {
+        event(
+            "btc_usdt",
+            OrderBookEvent::Update(OrderBook::new(0, None, bids, asks)),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_filter_bbo_changes() {
+        let events = vec![
+            // TC0: initial snapshot sets the BBO
+            event(
+                "btc_usdt",
+                OrderBookEvent::Snapshot(OrderBook::new(
+                    0,
+                    None,
+                    vec![
+                        Level::new(dec!(100), dec!(1)),
+                        Level::new(dec!(99), dec!(1)),
+                    ],
+                    vec![
+                        Level::new(dec!(101), dec!(1)),
+                        Level::new(dec!(102), dec!(1)),
+                    ],
+                )),
+            ),
+            // TC1: update below the best bid is filtered
+            book_event(vec![Level::new(dec!(98), dec!(5))], vec![]),
+            // TC2: update above the best ask is filtered
+            book_event(vec![], vec![Level::new(dec!(103), dec!(5))]),
+            // TC3: new best bid price passes
+            book_event(vec![Level::new(dec!(100.5), dec!(1))], vec![]),
+            // TC4: change in best ask amount passes
+            book_event(vec![], vec![Level::new(dec!(101), dec!(2))]),
+            // TC5: removing a non-best level is filtered
+            book_event(vec![Level::new(dec!(99), dec!(0))], vec![]),
+            // TC6: removing the best ask passes
+            book_event(vec![], vec![Level::new(dec!(101), dec!(0))]),
+        ];
+
+        let actual = futures::stream::iter(events.clone())
+            .filter_bbo_changes()
+            .collect::<Vec<_>>()
+            .await;
+
+        let expected = [0, 3, 4, 6]
+            .into_iter()
+            .map(|index| events[index].clone())
+            .collect::<Vec<_>>();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_throttle() {
+        const PERIOD: Duration = Duration::from_millis(100);
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        let mut stream = UnboundedReceiverStream::new(rx).throttle(PERIOD);
+        let start = Instant::now();
+
+        // Burst of events for two instruments
+        for sequence in 1..=5 {
+            tx.send(event("btc_usdt", sequence)).unwrap();
+        }
+        tx.send(event("eth_usdt", 10)).unwrap();
+        tx.send(event("eth_usdt", 11)).unwrap();
+
+        // First event of each instrument passes immediately
+        assert_eq!(stream.next().await.unwrap().kind, 1);
+        assert_eq!(stream.next().await.unwrap().kind, 10);
+        assert_eq!(start.elapsed(), Duration::ZERO);
+
+        // Latest event of each instrument is yielded once the period elapses
+        assert_eq!(stream.next().await.unwrap().kind, 5);
+        assert_eq!(stream.next().await.unwrap().kind, 11);
+        assert_eq!(start.elapsed(), PERIOD);
+
+        // Next event within the following period is delayed until it elapses
+        tx.send(event("btc_usdt", 6)).unwrap();
+        let next = stream.next().await.unwrap();
+        assert_eq!((next.instrument, next.kind), ("btc_usdt", 6));
+        assert_eq!(start.elapsed(), PERIOD * 2);
+
+        // Pending events are flushed when the inner stream ends
+        tx.send(event("btc_usdt", 7)).unwrap();
+        drop(tx);
+        assert_eq!(stream.next().await.unwrap().kind, 7);
+        assert!(stream.next().await.is_none());
+    }
+}
@@ -0,0 +1,218 @@
+use crate::{event::MarketEvent, subscription::trade::PublicTrade};
+use futures::{
+    Stream,
+    stream::{SelectAll, select_all},
+};
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+use tokio::time::{Instant, Sleep};
+
+/// Merge per-exchange [`PublicTrade`] `Stream`s into a single consolidated trade tape, ordered by
+/// [`MarketEvent::time_exchange`].
+///
+/// Each trade is buffered for the reordering `window` after it is received, tolerating jitter
+/// between exchange feeds. See [`Consolidated`] for ordering guarantees.
+pub fn consolidated_trades<Streams, St, InstrumentKey>(
+    streams: Streams,
+    window: Duration,
+) -> Consolidated<St, InstrumentKey, PublicTrade>
+where
+    Streams: IntoIterator<Item = St>,
+    St: Stream<Item = MarketEvent<InstrumentKey, PublicTrade>> + Unpin,
+{
+    Consolidated::new(streams, window)
+}
+
+/// [`MarketEvent`] `Stream` merging several inner `Stream`s, ordered by
+/// [`MarketEvent::time_exchange`].
+///
+/// Every event is held for the reordering `window` after it is received. Once an event is due,
+/// it is yielded along with every pending event with an earlier or equal `time_exchange`.
+///
+/// Output is time ordered as long as no event is received more than `window` after an event
+/// with a later `time_exchange`. Such late events are yielded once due, out of order. Any pending
+/// events are flushed in order when every inner stream ends.
+#[derive(Debug)]
+pub struct Consolidated<St, InstrumentKey, Kind> {
+    streams: SelectAll<St>,
+    stream_ended: bool,
+    window: Duration,
+    pending: Vec<(Instant, MarketEvent<InstrumentKey, Kind>)>,
+    sleep: Option<Pin<Box<Sleep>>>,
+}
+
+impl<St, InstrumentKey, Kind> Consolidated<St, InstrumentKey, Kind>
+where
+    St: Stream<Item = MarketEvent<InstrumentKey, Kind>> + Unpin,
+{
+    /// Construct a new [`Consolidated`] `Stream` merging the provided `streams`, buffering each
+    /// event for the reordering `window`.
+    pub fn new<Streams>(streams: Streams, window: Duration) -> Self
+    where
+        Streams: IntoIterator<Item = St>,
+    {
+        Self {
+            streams: select_all(streams),
+            stream_ended: false,
+            window,
+            pending: Vec::new(),
+            sleep: None,
+        }
+    }
+}
+
+impl<St, InstrumentKey, Kind> Consolidated<St, InstrumentKey, Kind> {
+    /// Insert the [`MarketEvent`] into the pending buffer, ordered by `time_exchange` and then
+    /// by arrival.
+    fn buffer(&mut self, event: MarketEvent<InstrumentKey, Kind>, now: Instant) {
+        let index = self
+            .pending
+            .partition_point(|(_, pending)| pending.time_exchange <= event.time_exchange);
+        self.pending.insert(index, (now + self.window, event));
+    }
+
+    /// Remove the earliest pending [`MarketEvent`] if any pending event with an equal or later
+    /// `time_exchange` is due at `now`.
+    fn pop_due(&mut self, now: Instant) -> Option<MarketEvent<InstrumentKey, Kind>> {
+        let watermark = self
+            .pending
+            .iter()
+            .filter(|(deadline, _)| *deadline <= now)
+            .map(|(_, event)| event.time_exchange)
+            .max()?;
+
+        let (_, earliest) = self.pending.first()?;
+        (earliest.time_exchange <= watermark).then(|| self.pending.remove(0).1)
+    }
+}
+
+impl<St, InstrumentKey, Kind> Stream for Consolidated<St, InstrumentKey, Kind>
+where
+    St: Stream<Item = MarketEvent<InstrumentKey, Kind>> + Unpin,
+    InstrumentKey: Unpin,
+    Kind: Unpin,
+{
+    type Item = MarketEvent<InstrumentKey, Kind>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            while !this.stream_ended {
+                match Pin::new(&mut this.streams).poll_next(cx) {
+                    Poll::Ready(Some(event)) => this.buffer(event, Instant::now()),
+                    Poll::Ready(None) => this.stream_ended = true,
+                    Poll::Pending => break,
+                }
+            }
+
+            if this.stream_ended {
+                return Poll::Ready((!this.pending.is_empty()).then(|| this.pending.remove(0).1));
+            }
+
+            if let Some(event) = this.pop_due(Instant::now()) {
+                return Poll::Ready(Some(event));
+            }
+
+            let Some(deadline) = this.pending.iter().map(|(deadline, _)| *deadline).min() else {
+                this.sleep = None;
+                return Poll::Pending;
+            };
+
+            let sleep = match &mut this.sleep {
+                Some(sleep) => {
+                    sleep.as_mut().reset(deadline);
+                    sleep
+                }
+                None => this
+                    .sleep
+                    .insert(Box::pin(tokio::time::sleep_until(deadline))),
+            };
+
+            if sleep.as_mut().poll(cx).is_pending() {
+                return Poll::Pending;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_instrument::{Side, exchange::ExchangeId};
+    use chrono::{DateTime, TimeDelta, Utc};
+    use futures::StreamExt;
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::UnboundedReceiverStream;
+
+    const WINDOW: Duration = Duration::from_millis(100);
+
+    fn trade(exchange: ExchangeId, time_ms: i64) -> MarketEvent<&'static str, PublicTrade> {
+        let time_exchange = DateTime::<Utc>::MIN_UTC + TimeDelta::milliseconds(time_ms);
+        MarketEvent {
+            time_exchange,
+            time_received: time_exchange,
+            exchange,
+            instrument: "btc_usdt",
+            kind: PublicTrade {
+                id: time_ms.to_string(),
+                price: 100.0,
+                amount: 1.0,
+                side: Side::Buy,
+            },
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_consolidated_trades() {
+        let (tx_binance, rx_binance) = mpsc::unbounded_channel();
+        let (tx_okx, rx_okx) = mpsc::unbounded_channel();
+        let tape = consolidated_trades(
+            [
+                UnboundedReceiverStream::new(rx_binance),
+                UnboundedReceiverStream::new(rx_okx),
+            ],
+            WINDOW,
+        );
+
+        tokio::spawn(async move {
+            // Okx trades arrive after a later Binance trade, but within the window
+            tx_binance.send(trade(ExchangeId::BinanceSpot, 1)).unwrap();
+            tx_binance.send(trade(ExchangeId::BinanceSpot, 3)).unwrap();
+            tokio::time::sleep(WINDOW / 2).await;
+            tx_okx.send(trade(ExchangeId::Okx, 2)).unwrap();
+            tx_okx.send(trade(ExchangeId::Okx, 4)).unwrap();
+
+            // Trades pending when every inner stream ends are flushed in order
+            tokio::time::sleep(WINDOW * 4).await;
+            tx_okx.send(trade(ExchangeId::Okx, 6)).unwrap();
+            tx_binance.send(trade(ExchangeId::BinanceSpot, 5)).unwrap();
+        });
+
+        let start = Instant::now();
+        let actual = tape
+            .map(|event| (event.exchange, event.kind.id, start.elapsed()))
+            .collect::<Vec<_>>()
+            .await;
+
+        let expected = vec![
+            // Binance trades are due after the window, releasing the earlier Okx trade in order
+            (ExchangeId::BinanceSpot, "1".to_string(), WINDOW),
+            (ExchangeId::Okx, "2".to_string(), WINDOW),
+            (ExchangeId::BinanceSpot, "3".to_string(), WINDOW),
+            // Remaining Okx trade is held until its own window elapses
+            (ExchangeId::Okx, "4".to_string(), WINDOW / 2 + WINDOW),
+            (
+                ExchangeId::BinanceSpot,
+                "5".to_string(),
+                WINDOW / 2 + WINDOW * 4,
+            ),
+            (ExchangeId::Okx, "6".to_string(), WINDOW / 2 + WINDOW * 4),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -5,6 +5,7 @@ use crate::{
     exchange::StreamSelector,
     instrument::InstrumentData,
     streams::{
+        raw::RawFrameLogger,
         reconnect,
         reconnect::stream::{
             ReconnectingStream, ReconnectionBackoffPolicy, init_reconnecting_stream,
@@ -13,8 +14,9 @@ use crate::{
     subscription::{Subscription, SubscriptionKind, display_subscriptions_without_exchange},
 };
 use barter_instrument::exchange::ExchangeId;
+use barter_integration::channel::{BoundedChannelConfig, OverflowPolicy};
 use derive_more::Constructor;
-use futures::Stream;
+use futures::{Stream, StreamExt};
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 use tracing::info;
@@ -24,6 +26,17 @@ pub const STREAM_RECONNECTION_POLICY: ReconnectionBackoffPolicy = ReconnectionBa
     backoff_ms_initial: 125,
     backoff_multiplier: 2,
     backoff_ms_max: 60000,
+    max_reconnect_attempts: None,
+};
+
+/// Default [`BoundedChannelConfig`] of the channel buffering [`MarketStreamResult`]s between a
+/// [`MarketStream`] and its consumer.
+///
+/// A full channel applies backpressure to the [`MarketStream`], rather than buffering without
+/// bound for a slow consumer.
+pub const STREAM_CHANNEL_CONFIG: BoundedChannelConfig = BoundedChannelConfig {
+    capacity: 65_536,
+    policy: OverflowPolicy::Block,
 };
 
 /// Convenient type alias for a [`MarketEvent`] [`Result`] consumed via a
@@ -41,8 +54,26 @@ pub type MarketStreamEvent<InstrumentKey, Kind> =
 ///
 /// The provided [`ReconnectionBackoffPolicy`] dictates how the exponential backoff scales
 /// between reconnections.
+///
+/// The provided `snapshot_http_client` is passed to the
+/// [`SnapshotFetcher`](crate::SnapshotFetcher) on every (re)connection, so its pooled
+/// connections are re-used.
+///
+/// If provided, every raw inbound WebSocket text frame of each (re)connection is teed to the
+/// `raw_frame_logger`.
+///
+/// Each reconnection fully re-initialises the [`MarketStream`] via [`MarketStream::init`]. This
+/// means the [`Connector::requests`](crate::exchange::Connector::requests) subscription payloads
+/// are re-sent over the new socket, any initial snapshots are re-fetched, and a fresh
+/// [`ExchangeTransformer`](crate::transformer::ExchangeTransformer) is initialised (eg/ resetting
+/// any OrderBook sequencers).
+///
+/// If the [`ReconnectionBackoffPolicy`] reconnection attempts are exhausted, a final
+/// [`DataError::ReconnectAttemptsExhausted`] is yielded before the stream ends.
 pub async fn init_market_stream<Exchange, Instrument, Kind>(
     policy: ReconnectionBackoffPolicy,
+    snapshot_http_client: reqwest::Client,
+    raw_frame_logger: Option<RawFrameLogger>,
     subscriptions: Vec<Subscription<Exchange, Instrument, Kind>>,
 ) -> Result<impl Stream<Item = MarketStreamResult<Instrument::Key, Kind::Event>>, DataError>
 where
@@ -69,14 +100,28 @@ where
         "MarketStream with auto reconnect initialising"
     );
 
+    let max_reconnect_attempts = policy.max_reconnect_attempts;
+
     Ok(init_reconnecting_stream(move || {
         let subscriptions = subscriptions.clone();
-        async move { Exchange::Stream::init::<Exchange::SnapFetcher>(&subscriptions).await }
+        let snapshot_http_client = snapshot_http_client.clone();
+        let raw_frame_logger = raw_frame_logger.clone();
+        async move {
+            Exchange::Stream::init::<Exchange::SnapFetcher>(
+                &snapshot_http_client,
+                raw_frame_logger,
+                &subscriptions,
+            )
+            .await
+        }
     })
     .await?
     .with_reconnect_backoff(policy, stream_key)
     .with_termination_on_error(|error| error.is_terminal(), stream_key)
-    .with_reconnection_events(exchange))
+    .with_reconnection_events(exchange)
+    .chain(futures::stream::iter(max_reconnect_attempts.map(
+        |attempts| reconnect::Event::Item(Err(DataError::ReconnectAttemptsExhausted(attempts))),
+    ))))
 }
 
 #[derive(
@@ -102,3 +147,106 @@ impl std::fmt::Debug for StreamKey {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        streams::test_utils::{MockOkx, bind_mock_okx, mock_okx_sub_response, mock_okx_trade},
+        subscription::trade::PublicTrades,
+    };
+    use barter_instrument::instrument::market_data::{
+        MarketDataInstrument, kind::MarketDataInstrumentKind,
+    };
+    use futures::SinkExt;
+    use std::time::Duration;
+    use tokio::sync::mpsc;
+
+    #[tokio::test]
+    async fn test_init_market_stream_resubscribes_after_reconnect() {
+        let listener = bind_mock_okx::<0>().await;
+
+        // Mock server drops the first connection after the subscription succeeds, and sends a
+        // trade over the second connection
+        let (request_tx, mut request_rx) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            for connection in 0..2 {
+                let (tcp, _) = listener.accept().await.unwrap();
+                let mut websocket = tokio_tungstenite::accept_async(tcp).await.unwrap();
+
+                let request = websocket.next().await.unwrap().unwrap();
+                request_tx
+                    .send(request.into_text().unwrap().to_string())
+                    .unwrap();
+
+                websocket
+                    .send(mock_okx_sub_response("BTC-USDT"))
+                    .await
+                    .unwrap();
+
+                if connection == 0 {
+                    websocket.close(None).await.unwrap();
+                    continue;
+                }
+
+                websocket
+                    .send(mock_okx_trade("BTC-USDT", "42219.9"))
+                    .await
+                    .unwrap();
+
+                std::future::pending::<()>().await;
+            }
+        });
+
+        let policy = ReconnectionBackoffPolicy {
+            backoff_ms_initial: 1,
+            backoff_multiplier: 2,
+            backoff_ms_max: 10,
+            max_reconnect_attempts: None,
+        };
+
+        let instrument = MarketDataInstrument::new("btc", "usdt", MarketDataInstrumentKind::Spot);
+
+        let stream = init_market_stream(
+            policy,
+            reqwest::Client::new(),
+            None,
+            vec![Subscription::new(MockOkx::<0>, instrument, PublicTrades)],
+        )
+        .await
+        .unwrap();
+
+        let mut reconnected = false;
+        let trade = tokio::time::timeout(
+            Duration::from_secs(5),
+            stream
+                .filter_map(|event| {
+                    let event = match event {
+                        reconnect::Event::Reconnecting(_) => {
+                            reconnected = true;
+                            None
+                        }
+                        reconnect::Event::Item(Ok(event)) => Some(event),
+                        reconnect::Event::Item(Err(_)) => None,
+                    };
+                    std::future::ready(event)
+                })
+                .boxed()
+                .next(),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+        assert!(reconnected);
+        assert_eq!(trade.exchange, ExchangeId::Mock);
+        assert_eq!(trade.kind.price, 42219.9);
+
+        // Subscription requests were re-sent over the second connection
+        let first = request_rx.recv().await.unwrap();
+        let second = request_rx.recv().await.unwrap();
+        assert_eq!(first, second);
+        assert!(first.contains(r#""channel":"trades""#));
+        assert!(first.contains(r#""instId":"BTC-USDT""#));
+    }
+}
@@ -1,7 +1,7 @@
 use self::builder::{StreamBuilder, multi::MultiStreamBuilder};
 use crate::subscription::SubscriptionKind;
 use barter_instrument::exchange::ExchangeId;
-use barter_integration::channel::UnboundedRx;
+use barter_integration::channel::BoundedRx;
 use fnv::FnvHashMap;
 use futures::Stream;
 
@@ -17,10 +17,25 @@ pub mod consumer;
 /// for generating an auto reconnecting `Stream`.
 pub mod reconnect;
 
+/// [`MarketStreamExt`](combinator::MarketStreamExt) combinators for filtering and throttling
+/// [`MarketEvent`](crate::event::MarketEvent) `Stream`s.
+pub mod combinator;
+
+/// [`Consolidated`](consolidated::Consolidated) `Stream` merging per-exchange
+/// [`MarketEvent`](crate::event::MarketEvent) `Stream`s into a single time ordered tape.
+pub mod consolidated;
+
+/// Opt-in [`RawFrameTee`](raw::RawFrameTee) for logging every raw inbound WebSocket text frame
+/// when debugging exchange feeds.
+pub mod raw;
+
+#[cfg(test)]
+pub(crate) mod test_utils;
+
 /// Ergonomic collection of exchange market event receivers.
 #[derive(Debug)]
 pub struct Streams<T> {
-    pub streams: FnvHashMap<ExchangeId, UnboundedRx<T>>,
+    pub streams: FnvHashMap<ExchangeId, BoundedRx<T>>,
 }
 
 impl<T> Streams<T> {
@@ -40,13 +55,12 @@ impl<T> Streams<T> {
 
     /// Remove an exchange market event [`Stream`] from the [`Streams`] `HashMap`.
     pub fn select(&mut self, exchange: ExchangeId) -> Option<impl Stream<Item = T> + '_> {
-        self.streams.remove(&exchange).map(UnboundedRx::into_stream)
+        self.streams.remove(&exchange)
     }
 
     /// Select and merge every exchange `Stream` using
     /// [`select_all`](futures_util::stream::select_all::select_all).
     pub fn select_all(self) -> impl Stream<Item = T> {
-        let all = self.streams.into_values().map(UnboundedRx::into_stream);
-        futures_util::stream::select_all::select_all(all)
+        futures_util::stream::select_all::select_all(self.streams.into_values())
     }
 }
@@ -0,0 +1,128 @@
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::protocol::websocket::WsMessage;
+use chrono::{DateTime, Utc};
+use futures::Stream;
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tokio::sync::mpsc;
+
+/// Raw inbound WebSocket text frame captured by a [`RawFrameTee`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RawFrame {
+    pub exchange: ExchangeId,
+    pub time_received: DateTime<Utc>,
+    pub payload: String,
+}
+
+/// Sender of the [`RawFrame`]s teed from each [`MarketStream`](crate::MarketStream) it is
+/// provided to (eg/ via
+/// [`StreamBuilder::with_raw_frame_logger`](crate::streams::builder::StreamBuilder::with_raw_frame_logger)).
+///
+/// Useful for debugging exchange feeds.
+pub type RawFrameLogger = mpsc::UnboundedSender<RawFrame>;
+
+/// [`Stream`] wrapper that forwards a [`RawFrame`] copy of every inbound WebSocket text frame to
+/// an optional logger, yielding the inner [`Stream`] items unchanged.
+#[derive(Debug)]
+pub struct RawFrameTee<St> {
+    stream: St,
+    exchange: ExchangeId,
+    logger: Option<RawFrameLogger>,
+}
+
+impl<St> RawFrameTee<St> {
+    /// Construct a new [`RawFrameTee`] that forwards the [`RawFrame`]s of the provided `exchange`
+    /// to the `logger`, if present.
+    pub fn new(stream: St, exchange: ExchangeId, logger: Option<RawFrameLogger>) -> Self {
+        Self {
+            stream,
+            exchange,
+            logger,
+        }
+    }
+}
+
+impl<St, Error> Stream for RawFrameTee<St>
+where
+    St: Stream<Item = Result<WsMessage, Error>> + Unpin,
+{
+    type Item = St::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let poll = Pin::new(&mut self.stream).poll_next(cx);
+
+        if let Poll::Ready(Some(Ok(WsMessage::Text(text)))) = &poll
+            && let Some(logger) = &self.logger
+        {
+            let frame = RawFrame {
+                exchange: self.exchange,
+                time_received: Utc::now(),
+                payload: text.as_str().to_owned(),
+            };
+
+            // Stop logging once the receiver has been dropped
+            if logger.send(frame).is_err() {
+                self.logger = None;
+            }
+        }
+
+        poll
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::protocol::websocket::connect;
+    use futures::{SinkExt, StreamExt};
+    use tokio::net::TcpListener;
+
+    #[tokio::test]
+    async fn test_raw_frame_tee_captures_inbound_text_frames() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+
+        let frames = vec![
+            r#"{"event":"subscribe","arg":{"channel":"trades"}}"#,
+            r#"{"data":[{"px":"42219.9","sz":"0.1"}]}"#,
+        ];
+
+        let server_frames = frames.clone();
+        tokio::spawn(async move {
+            let (tcp, _) = listener.accept().await.unwrap();
+            let mut websocket = tokio_tungstenite::accept_async(tcp).await.unwrap();
+            for frame in server_frames {
+                websocket.send(WsMessage::text(frame)).await.unwrap();
+            }
+            websocket
+                .send(WsMessage::Ping(vec![].into()))
+                .await
+                .unwrap();
+            websocket.close(None).await.unwrap();
+        });
+
+        let (_, ws_stream) = connect(url).await.unwrap().split();
+        let (logger_tx, mut logger_rx) = mpsc::unbounded_channel();
+        let tee = RawFrameTee::new(ws_stream, ExchangeId::Okx, Some(logger_tx));
+
+        // Inner stream items are yielded unchanged
+        let messages = tee
+            .filter_map(|message| std::future::ready(message.ok()))
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(
+            &messages[..2],
+            &[WsMessage::text(frames[0]), WsMessage::text(frames[1])]
+        );
+
+        // Only text frames are captured, in the exact order they were sent
+        let mut captured = vec![];
+        while let Ok(frame) = logger_rx.try_recv() {
+            assert_eq!(frame.exchange, ExchangeId::Okx);
+            captured.push(frame.payload);
+        }
+        assert_eq!(captured, frames);
+    }
+}
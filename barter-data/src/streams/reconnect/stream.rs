@@ -1,8 +1,8 @@
 use crate::streams::{consumer::StreamKey, reconnect::Event};
 use barter_integration::channel::Tx;
 use derive_more::Constructor;
-use futures::Stream;
-use futures_util::StreamExt;
+use futures::{Sink, Stream};
+use futures_util::{FutureExt, StreamExt};
 use serde::{Deserialize, Serialize};
 use std::{convert, fmt::Debug, future, future::Future};
 use tracing::{error, info, warn};
@@ -15,6 +15,9 @@ where
 {
     /// Add an exponential backoff policy to an initialised [`ReconnectingStream`] using the
     /// provided [`ReconnectionBackoffPolicy`].
+    ///
+    /// If the policy configures `max_reconnect_attempts`, the [`ReconnectingStream`] ends once
+    /// that many consecutive re-initialisation attempts have failed.
     fn with_reconnect_backoff<St, InitError>(
         self,
         policy: ReconnectionBackoffPolicy,
@@ -41,6 +44,18 @@ where
                             ?error,
                             "failed to re-initialise Stream"
                         );
+
+                        state.failed_attempts += 1;
+                        if state.is_exhausted() {
+                            error!(
+                                attempt,
+                                ?stream_key,
+                                max_reconnect_attempts = ?state.policy.max_reconnect_attempts,
+                                "exhausted Stream reconnection attempts - terminating"
+                            );
+                            return futures::future::Either::Left(future::ready(None));
+                        }
+
                         let sleep_fut = state.generate_sleep_future();
                         state.multiply_backoff();
                         futures::future::Either::Right(Box::pin(async move {
@@ -136,6 +151,19 @@ where
     {
         tokio_stream::StreamExt::map_while(self, move |event| tx.send(event.into()).ok()).collect()
     }
+
+    /// Future for forwarding items in [`Self`] to the provided [`Sink`] (eg/ a
+    /// [`BoundedTx`](barter_integration::channel::BoundedTx)), respecting any backpressure it
+    /// applies. Completes once [`Self`] ends or the [`Sink`] errors.
+    fn forward_to_sink<Si, Item>(self, sink: Si) -> impl Future<Output = ()> + Send
+    where
+        Self: Stream + Sized + Send,
+        Self::Item: Into<Item>,
+        Si: Sink<Item> + Send + 'static,
+        Item: Send,
+    {
+        self.map(|event| Ok(event.into())).forward(sink).map(|_| ())
+    }
 }
 
 impl<T> ReconnectingStream for T where T: Stream {}
@@ -171,12 +199,20 @@ pub struct ReconnectionBackoffPolicy {
 
     /// Maximum possible backoff duration between reconnection attempts.
     pub backoff_ms_max: u64,
+
+    /// Maximum number of consecutive failed reconnection attempts before giving up and ending
+    /// the `Stream`.
+    ///
+    /// `None` retries forever.
+    #[serde(default)]
+    pub max_reconnect_attempts: Option<usize>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
 struct ReconnectionState {
     policy: ReconnectionBackoffPolicy,
     backoff_ms_current: u64,
+    failed_attempts: usize,
 }
 
 impl From<ReconnectionBackoffPolicy> for ReconnectionState {
@@ -184,6 +220,7 @@ impl From<ReconnectionBackoffPolicy> for ReconnectionState {
         Self {
             backoff_ms_current: policy.backoff_ms_initial,
             policy,
+            failed_attempts: 0,
         }
     }
 }
@@ -191,6 +228,13 @@ impl From<ReconnectionBackoffPolicy> for ReconnectionState {
 impl ReconnectionState {
     fn reset_backoff(&mut self) {
         self.backoff_ms_current = self.policy.backoff_ms_initial;
+        self.failed_attempts = 0;
+    }
+
+    fn is_exhausted(&self) -> bool {
+        self.policy
+            .max_reconnect_attempts
+            .is_some_and(|max| self.failed_attempts >= max)
     }
 
     fn multiply_backoff(&mut self) {
@@ -204,3 +248,72 @@ impl ReconnectionState {
         tokio::time::sleep(sleep_duration)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_instrument::exchange::ExchangeId;
+    use barter_integration::{error::SocketError, protocol::websocket::connect};
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        time::Duration,
+    };
+
+    #[tokio::test]
+    async fn test_with_reconnect_backoff_terminates_after_max_reconnect_attempts() {
+        // Determine a local address that refuses connections
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let unreachable = format!("ws://{}", listener.local_addr().unwrap());
+        drop(listener);
+
+        // Initial Stream succeeds, but every reconnection targets the unreachable address
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let stream = init_reconnecting_stream({
+            let attempts = Arc::clone(&attempts);
+            move || {
+                let attempt = attempts.fetch_add(1, Ordering::SeqCst);
+                let unreachable = unreachable.clone();
+                async move {
+                    if attempt == 0 {
+                        Ok(futures::stream::iter(vec![1, 2]))
+                    } else {
+                        connect(unreachable)
+                            .await
+                            .map(|_| futures::stream::iter(vec![3]))
+                    }
+                }
+            }
+        })
+        .await
+        .unwrap()
+        .with_reconnect_backoff::<_, SocketError>(
+            ReconnectionBackoffPolicy {
+                backoff_ms_initial: 1,
+                backoff_multiplier: 2,
+                backoff_ms_max: 10,
+                max_reconnect_attempts: Some(3),
+            },
+            StreamKey::new_general("test", ExchangeId::Mock),
+        )
+        .with_reconnection_events(ExchangeId::Mock);
+
+        let events = tokio::time::timeout(Duration::from_secs(5), stream.collect::<Vec<_>>())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            events,
+            vec![
+                Event::Item(1),
+                Event::Item(2),
+                Event::Reconnecting(ExchangeId::Mock)
+            ]
+        );
+
+        // Initial connection, followed by 3 failed reconnection attempts
+        assert_eq!(attempts.load(Ordering::SeqCst), 4);
+    }
+}
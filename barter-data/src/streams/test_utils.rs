@@ -0,0 +1,242 @@
+use crate::{
+    ExchangeWsStream, Identifier, SnapshotFetcher,
+    event::MarketEvent,
+    exchange::{
+        Connector, StreamSelector,
+        okx::{
+            channel::OkxChannel,
+            market::OkxMarket,
+            message::OkxStreamMessage,
+            subscription::OkxSubResponse,
+            trade::{OkxTrades, fetch_recent_trades},
+        },
+        subscription::ExchangeSub,
+    },
+    instrument::InstrumentData,
+    subscriber::{WebSocketSubscriber, validator::WebSocketSubValidator},
+    subscription::{
+        Subscription,
+        trade::{PublicTrade, PublicTrades},
+    },
+    transformer::stateless::StatelessTransformer,
+};
+use barter_instrument::{exchange::ExchangeId, instrument::market_data::MarketDataInstrument};
+use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
+use futures::{SinkExt, StreamExt, future::try_join_all};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::{future::Future, sync::OnceLock};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+use url::Url;
+
+/// [`ExchangeId`] of each [`MockOkx`] server.
+const MOCK_EXCHANGES: [ExchangeId; 6] = [
+    ExchangeId::Mock,
+    ExchangeId::Simulated,
+    ExchangeId::Other,
+    ExchangeId::Bitfinex,
+    ExchangeId::Bitazza,
+    ExchangeId::Bitflyer,
+];
+
+/// Url of each bound [`MockOkx`] server.
+static MOCK_SERVER_URLS: [OnceLock<String>; 6] = [
+    OnceLock::new(),
+    OnceLock::new(),
+    OnceLock::new(),
+    OnceLock::new(),
+    OnceLock::new(),
+    OnceLock::new(),
+];
+
+/// Url of each [`MockOkx`] HTTP recent trades snapshot server, if any.
+static MOCK_HTTP_URLS: [OnceLock<String>; 6] = [
+    OnceLock::new(),
+    OnceLock::new(),
+    OnceLock::new(),
+    OnceLock::new(),
+    OnceLock::new(),
+    OnceLock::new(),
+];
+
+/// [`Connector`] for a local mock server that speaks the Okx public trades protocol.
+///
+/// Each `SERVER` index is a distinct mock exchange, allowing concurrently running tests to bind
+/// their own server.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct MockOkx<const SERVER: usize>;
+
+impl<const SERVER: usize> Connector for MockOkx<SERVER> {
+    const ID: ExchangeId = MOCK_EXCHANGES[SERVER];
+    type Channel = OkxChannel;
+    type Market = OkxMarket;
+    type Subscriber = WebSocketSubscriber;
+    type SubValidator = WebSocketSubValidator;
+    type SubResponse = OkxSubResponse;
+
+    fn url() -> Result<Url, SocketError> {
+        Url::parse(MOCK_SERVER_URLS[SERVER].get().unwrap()).map_err(SocketError::UrlParse)
+    }
+
+    fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        vec![WsMessage::text(
+            json!({"op": "subscribe", "args": &exchange_subs}).to_string(),
+        )]
+    }
+}
+
+impl<const SERVER: usize> StreamSelector<MarketDataInstrument, PublicTrades> for MockOkx<SERVER> {
+    type SnapFetcher = MockOkxSnapshots;
+    type Stream = ExchangeWsStream<
+        StatelessTransformer<Self, MarketDataInstrument, PublicTrades, OkxStreamMessage<OkxTrades>>,
+    >;
+}
+
+impl<const SERVER: usize> Identifier<OkxChannel>
+    for Subscription<MockOkx<SERVER>, MarketDataInstrument, PublicTrades>
+{
+    fn id(&self) -> OkxChannel {
+        OkxChannel::TRADES
+    }
+}
+
+impl<const SERVER: usize> Identifier<OkxMarket>
+    for Subscription<MockOkx<SERVER>, MarketDataInstrument, PublicTrades>
+{
+    fn id(&self) -> OkxMarket {
+        OkxMarket(
+            format!("{}-{}", self.instrument.base, self.instrument.quote)
+                .to_uppercase()
+                .into(),
+        )
+    }
+}
+
+/// [`SnapshotFetcher`] that fetches the Okx recent trades of each [`MockOkx<SERVER>`]
+/// [`Subscription`] from the [`MockOkx<SERVER>`] HTTP snapshot server, if one is set.
+///
+/// Fetches no initial snapshots otherwise.
+#[derive(Debug)]
+pub struct MockOkxSnapshots;
+
+impl<const SERVER: usize> SnapshotFetcher<MockOkx<SERVER>, PublicTrades> for MockOkxSnapshots {
+    fn fetch_snapshots<Instrument>(
+        client: &reqwest::Client,
+        subscriptions: &[Subscription<MockOkx<SERVER>, Instrument, PublicTrades>],
+    ) -> impl Future<Output = Result<Vec<MarketEvent<Instrument::Key, PublicTrade>>, SocketError>> + Send
+    where
+        Instrument: InstrumentData,
+        Subscription<MockOkx<SERVER>, Instrument, PublicTrades>:
+            Identifier<<MockOkx<SERVER> as Connector>::Market>,
+    {
+        let url = MOCK_HTTP_URLS[SERVER].get();
+
+        let snapshot_futures = subscriptions.iter().map(move |subscription| async move {
+            match url {
+                Some(url) => {
+                    fetch_recent_trades(
+                        client,
+                        url,
+                        subscription.id(),
+                        subscription.instrument.key().clone(),
+                    )
+                    .await
+                }
+                None => Ok(vec![]),
+            }
+        });
+
+        async move {
+            let snapshots = try_join_all(snapshot_futures).await?;
+            Ok(snapshots.into_iter().flatten().collect())
+        }
+    }
+}
+
+/// Set the url of the [`MockOkx<SERVER>`] HTTP snapshot server used by [`MockOkxSnapshots`].
+pub fn set_mock_okx_http_url<const SERVER: usize>(url: String) {
+    MOCK_HTTP_URLS[SERVER].set(url).unwrap();
+}
+
+/// Bind the [`MockOkx<SERVER>`] server to a local port.
+pub async fn bind_mock_okx<const SERVER: usize>() -> TcpListener {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    MOCK_SERVER_URLS[SERVER]
+        .set(format!("ws://{}", listener.local_addr().unwrap()))
+        .unwrap();
+    listener
+}
+
+/// Successful [`MockOkx`] subscription response for the provided `market`.
+pub fn mock_okx_sub_response(market: &str) -> WsMessage {
+    WsMessage::text(
+        json!({"event": "subscribe", "arg": {"channel": "trades", "instId": market}}).to_string(),
+    )
+}
+
+/// [`MockOkx`] public trade message for the provided `market`.
+pub fn mock_okx_trade(market: &str, price: &str) -> WsMessage {
+    WsMessage::text(
+        json!({
+            "arg": {"channel": "trades", "instId": market},
+            "data": [{
+                "instId": market,
+                "tradeId": "1",
+                "px": price,
+                "sz": "0.1",
+                "side": "buy",
+                "ts": "1630048897897"
+            }]
+        })
+        .to_string(),
+    )
+}
+
+/// Serve a single [`MockOkx`] connection, responding to the subscription and then sending a
+/// trade for each subscribed market.
+pub async fn serve_mock_okx_trades(listener: TcpListener, markets: &'static [&'static str]) {
+    let (tcp, _) = listener.accept().await.unwrap();
+    let mut websocket = tokio_tungstenite::accept_async(tcp).await.unwrap();
+
+    let _subscription = websocket.next().await.unwrap().unwrap();
+
+    for market in markets {
+        websocket.send(mock_okx_sub_response(market)).await.unwrap();
+    }
+
+    for market in markets {
+        websocket
+            .send(mock_okx_trade(market, "100.0"))
+            .await
+            .unwrap();
+    }
+
+    std::future::pending::<()>().await;
+}
+
+/// Bind a local mock HTTP server that responds to a single request with the provided JSON
+/// `body`, returning the server base url.
+pub async fn bind_mock_http(body: String) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    tokio::spawn(async move {
+        let (mut tcp, _) = listener.accept().await.unwrap();
+
+        let mut request = [0u8; 4096];
+        let _ = tcp.read(&mut request).await.unwrap();
+
+        let response = format!(
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+            body.len()
+        );
+        tcp.write_all(response.as_bytes()).await.unwrap();
+    });
+
+    url
+}
@@ -1,5 +1,5 @@
 use super::SubscriptionKind;
-use crate::books::{Level, OrderBook, mid_price, volume_weighted_mid_price};
+use crate::books::{ApplyMode, Level, OrderBook, mid_price, volume_weighted_mid_price};
 use barter_macro::{DeSubKind, SerSubKind};
 use chrono::{DateTime, Utc};
 use derive_more::Constructor;
@@ -108,8 +108,38 @@ impl std::fmt::Display for OrderBooksL3 {
     }
 }
 
+/// L2 [`OrderBook`] event, where the variant determines the [`ApplyMode`] used to apply it to a
+/// local [`OrderBook`].
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 pub enum OrderBookEvent {
+    /// Full [`OrderBook`] that replaces the local [`OrderBook`] (see [`ApplyMode::Replace`]).
     Snapshot(OrderBook),
+    /// Changed [`Level`]s merged into the local [`OrderBook`] (see [`ApplyMode::Merge`]).
     Update(OrderBook),
 }
+
+impl OrderBookEvent {
+    /// Construct an [`OrderBookEvent`] that is applied using the provided [`ApplyMode`].
+    pub fn new(mode: ApplyMode, book: OrderBook) -> Self {
+        match mode {
+            ApplyMode::Replace => Self::Snapshot(book),
+            ApplyMode::Merge => Self::Update(book),
+        }
+    }
+
+    /// [`ApplyMode`] used to apply this [`OrderBookEvent`] to a local [`OrderBook`].
+    pub fn apply_mode(&self) -> ApplyMode {
+        match self {
+            Self::Snapshot(_) => ApplyMode::Replace,
+            Self::Update(_) => ApplyMode::Merge,
+        }
+    }
+
+    /// Deconstruct this [`OrderBookEvent`] into its [`ApplyMode`] and [`OrderBook`].
+    pub fn into_parts(self) -> (ApplyMode, OrderBook) {
+        let mode = self.apply_mode();
+        match self {
+            Self::Snapshot(book) | Self::Update(book) => (mode, book),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use super::SubscriptionKind;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Jackbot [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields [`MarkPrice`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct MarkPrices;
+
+impl SubscriptionKind for MarkPrices {
+    type Event = MarkPrice;
+
+    fn as_str(&self) -> &'static str {
+        "mark_prices"
+    }
+}
+
+impl std::fmt::Display for MarkPrices {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Normalised Jackbot perpetual [`MarkPrice`] model.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct MarkPrice {
+    pub mark_price: Decimal,
+    pub index_price: Decimal,
+    pub funding_rate: Decimal,
+    pub next_funding_time: DateTime<Utc>,
+    pub time: DateTime<Utc>,
+}
@@ -23,6 +23,9 @@ pub mod candle;
 /// Liquidation [`SubscriptionKind`] and the associated Jackbot output data model.
 pub mod liquidation;
 
+/// Perpetual mark price [`SubscriptionKind`] and the associated Jackbot output data model.
+pub mod mark_price;
+
 /// Public trade [`SubscriptionKind`] and the associated Jackbot output data model.
 pub mod trade;
 
@@ -87,6 +90,7 @@ pub enum SubKind {
     OrderBooksL3,
     Liquidations,
     Candles,
+    MarkPrices,
 }
 
 impl<Exchange, S, Kind> From<(Exchange, S, S, MarketDataInstrumentKind, Kind)>
@@ -263,7 +267,7 @@ pub fn exchange_supports_instrument_kind_sub_kind(
         (Bitfinex, Spot, PublicTrades) => true,
         (Bitmex, Perpetual, PublicTrades) => true,
         (BybitSpot, Spot, PublicTrades | OrderBooksL2) => true,
-        (BybitPerpetualsUsd, Perpetual, PublicTrades) => true,
+        (BybitPerpetualsUsd, Perpetual, PublicTrades | OrderBooksL2) => true,
         (Coinbase, Spot, PublicTrades) => true,
         (GateioSpot, Spot, PublicTrades) => true,
         (GateioFuturesUsd, Future { .. }, PublicTrades) => true,
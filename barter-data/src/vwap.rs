@@ -0,0 +1,236 @@
+use crate::{event::MarketEvent, subscription::trade::PublicTrade};
+use barter_instrument::Side;
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Rolling window over which a [`VwapTracker`] computes the volume weighted average price.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub enum VwapWindow {
+    /// Include trades that occurred within the provided duration of the latest trade.
+    Time(TimeDelta),
+
+    /// Include the provided number of most recent trades.
+    Trades(usize),
+}
+
+/// Maintains a rolling-window volume weighted average price (VWAP) by consuming
+/// [`PublicTrade`]s.
+///
+/// VWAP is calculated as `sum(price * amount) / sum(amount)` over the trades in the
+/// [`VwapWindow`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct VwapTracker {
+    pub window: VwapWindow,
+    trades: VecDeque<VwapTrade>,
+    notional: f64,
+    volume: f64,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct VwapTrade {
+    time: DateTime<Utc>,
+    notional: f64,
+    amount: f64,
+}
+
+impl VwapTracker {
+    /// Construct a new empty [`VwapTracker`] using the provided [`VwapWindow`].
+    pub fn new(window: VwapWindow) -> Self {
+        Self {
+            window,
+            trades: VecDeque::new(),
+            notional: 0.0,
+            volume: 0.0,
+        }
+    }
+
+    /// Update the [`VwapTracker`] with the [`PublicTrade`] contained in the [`MarketEvent`],
+    /// using the `time_exchange` as the trade time.
+    pub fn process<InstrumentKey>(&mut self, event: &MarketEvent<InstrumentKey, PublicTrade>) {
+        self.update(event.time_exchange, &event.kind)
+    }
+
+    /// Update the [`VwapTracker`] with a [`PublicTrade`] that occurred at the provided `time`,
+    /// evicting any trades that fall outside the [`VwapWindow`].
+    pub fn update(&mut self, time: DateTime<Utc>, trade: &PublicTrade) {
+        let trade = VwapTrade {
+            time,
+            notional: trade.price * trade.amount,
+            amount: trade.amount,
+        };
+
+        self.notional += trade.notional;
+        self.volume += trade.amount;
+        self.trades.push_back(trade);
+
+        match self.window {
+            VwapWindow::Time(duration) => {
+                let window_start = time - duration;
+                while self
+                    .trades
+                    .front()
+                    .is_some_and(|oldest| oldest.time < window_start)
+                {
+                    self.evict_oldest();
+                }
+            }
+            VwapWindow::Trades(max) => {
+                while self.trades.len() > max {
+                    self.evict_oldest();
+                }
+            }
+        }
+    }
+
+    fn evict_oldest(&mut self) {
+        if let Some(oldest) = self.trades.pop_front() {
+            self.notional -= oldest.notional;
+            self.volume -= oldest.amount;
+        }
+
+        // Avoid accumulating floating point error once the window is empty
+        if self.trades.is_empty() {
+            self.notional = 0.0;
+            self.volume = 0.0;
+        }
+    }
+
+    /// Returns the current VWAP, or `None` if the window contains no traded volume.
+    pub fn vwap(&self) -> Option<f64> {
+        (self.volume > 0.0).then(|| self.notional / self.volume)
+    }
+
+    /// Returns the total traded volume contained in the window.
+    pub fn volume(&self) -> f64 {
+        self.volume
+    }
+
+    /// Calculate the slippage of a fill at the provided `price` relative to the current VWAP, as
+    /// a fraction of the VWAP.
+    ///
+    /// Positive slippage indicates the fill was worse than the market VWAP (ie/ a
+    /// [`Side::Buy`] above, or a [`Side::Sell`] below).
+    pub fn slippage(&self, side: Side, price: f64) -> Option<f64> {
+        let vwap = self.vwap()?;
+        let slippage = (price - vwap) / vwap;
+
+        Some(match side {
+            Side::Buy => slippage,
+            Side::Sell => -slippage,
+        })
+    }
+
+    /// Remove every trade from the window.
+    pub fn reset(&mut self) {
+        self.trades.clear();
+        self.notional = 0.0;
+        self.volume = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trade(price: f64, amount: f64) -> PublicTrade {
+        PublicTrade {
+            id: "id".to_string(),
+            price,
+            amount,
+            side: Side::Buy,
+        }
+    }
+
+    fn time(secs: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(secs, 0).unwrap()
+    }
+
+    #[test]
+    fn test_vwap_tracker() {
+        struct TestCase {
+            window: VwapWindow,
+            trades: Vec<(i64, PublicTrade)>,
+            expected: Option<f64>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: no trades
+                window: VwapWindow::Trades(3),
+                trades: vec![],
+                expected: None,
+            },
+            TestCase {
+                // TC1: volume weighted across all trades within the count window
+                window: VwapWindow::Trades(3),
+                trades: vec![(0, trade(100.0, 1.0)), (1, trade(110.0, 3.0))],
+                expected: Some(107.5),
+            },
+            TestCase {
+                // TC2: count window rolls over, evicting the oldest trade
+                window: VwapWindow::Trades(2),
+                trades: vec![
+                    (0, trade(1000.0, 10.0)),
+                    (1, trade(100.0, 1.0)),
+                    (2, trade(110.0, 3.0)),
+                ],
+                expected: Some(107.5),
+            },
+            TestCase {
+                // TC3: time window includes trades exactly at the window boundary
+                window: VwapWindow::Time(TimeDelta::seconds(10)),
+                trades: vec![(0, trade(100.0, 1.0)), (10, trade(110.0, 3.0))],
+                expected: Some(107.5),
+            },
+            TestCase {
+                // TC4: time window rolls over, evicting trades older than the window
+                window: VwapWindow::Time(TimeDelta::seconds(10)),
+                trades: vec![
+                    (0, trade(1000.0, 10.0)),
+                    (5, trade(100.0, 1.0)),
+                    (11, trade(110.0, 3.0)),
+                ],
+                expected: Some(107.5),
+            },
+            TestCase {
+                // TC5: time window rolls over, evicting every prior trade
+                window: VwapWindow::Time(TimeDelta::seconds(10)),
+                trades: vec![(0, trade(100.0, 1.0)), (60, trade(120.0, 2.0))],
+                expected: Some(120.0),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut tracker = VwapTracker::new(test.window);
+            for (secs, trade) in &test.trades {
+                tracker.update(time(*secs), trade);
+            }
+            assert_eq!(tracker.vwap(), test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_vwap_tracker_reset() {
+        let mut tracker = VwapTracker::new(VwapWindow::Trades(10));
+        tracker.update(time(0), &trade(100.0, 1.0));
+        assert_eq!(tracker.vwap(), Some(100.0));
+
+        tracker.reset();
+        assert_eq!(tracker.vwap(), None);
+        assert_eq!(tracker.volume(), 0.0);
+
+        tracker.update(time(1), &trade(50.0, 2.0));
+        assert_eq!(tracker.vwap(), Some(50.0));
+    }
+
+    #[test]
+    fn test_vwap_tracker_slippage() {
+        let mut tracker = VwapTracker::new(VwapWindow::Trades(10));
+        tracker.update(time(0), &trade(100.0, 1.0));
+
+        assert_eq!(tracker.slippage(Side::Buy, 101.0), Some(0.01));
+        assert_eq!(tracker.slippage(Side::Sell, 101.0), Some(-0.01));
+        assert_eq!(tracker.slippage(Side::Sell, 99.0), Some(0.01));
+    }
+}
@@ -0,0 +1,294 @@
+use crate::{AccountEvent, AccountEventKind};
+use fnv::FnvHashMap;
+use futures::Stream;
+use std::{
+    collections::VecDeque,
+    hash::Hash,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+use tokio::time::{Instant, Interval, MissedTickBehavior};
+
+/// [`AccountEvent`] stream adapter that coalesces high frequency balance updates into a single
+/// update per interval.
+///
+/// - [`AccountEventKind::BalanceSnapshot`]s are buffered, keeping only the latest update for each
+///   exchange asset, and flushed in arrival order once every interval.
+/// - [`AccountEventKind::Snapshot`]s replace all account state, so discard any buffered balance
+///   updates of the same exchange, and are yielded immediately.
+/// - Every other [`AccountEventKind`] (eg/ trades, order updates) is yielded immediately.
+///
+/// Any buffered balance updates are flushed when the inner stream ends.
+#[derive(Debug)]
+pub struct BatchedAccountStream<St, ExchangeKey, AssetKey, InstrumentKey> {
+    stream: St,
+    stream_ended: bool,
+    period: Duration,
+    interval: Option<Interval>,
+    pending: Vec<(
+        (ExchangeKey, AssetKey),
+        AccountEvent<ExchangeKey, AssetKey, InstrumentKey>,
+    )>,
+    pending_index: FnvHashMap<(ExchangeKey, AssetKey), usize>,
+    flushed: VecDeque<AccountEvent<ExchangeKey, AssetKey, InstrumentKey>>,
+}
+
+impl<St, ExchangeKey, AssetKey, InstrumentKey>
+    BatchedAccountStream<St, ExchangeKey, AssetKey, InstrumentKey>
+{
+    /// Construct a new [`BatchedAccountStream`] that flushes coalesced balance updates once
+    /// every `period`.
+    ///
+    /// The flush interval starts on the first poll, so the stream must be polled within a Tokio
+    /// runtime.
+    pub fn new(stream: St, period: Duration) -> Self {
+        Self {
+            stream,
+            stream_ended: false,
+            period,
+            interval: None,
+            pending: Vec::new(),
+            pending_index: FnvHashMap::default(),
+            flushed: VecDeque::new(),
+        }
+    }
+
+    fn flush(&mut self) {
+        self.pending_index.clear();
+        self.flushed
+            .extend(self.pending.drain(..).map(|(_, event)| event));
+    }
+}
+
+impl<St, ExchangeKey, AssetKey, InstrumentKey>
+    BatchedAccountStream<St, ExchangeKey, AssetKey, InstrumentKey>
+where
+    ExchangeKey: Clone + Eq + Hash,
+    AssetKey: Clone + Eq + Hash,
+{
+    /// Buffer the [`AccountEvent`] if it can be coalesced, otherwise return it to be yielded
+    /// immediately.
+    fn buffer(
+        &mut self,
+        event: AccountEvent<ExchangeKey, AssetKey, InstrumentKey>,
+    ) -> Option<AccountEvent<ExchangeKey, AssetKey, InstrumentKey>> {
+        match &event.kind {
+            AccountEventKind::BalanceSnapshot(balance) => {
+                let key = (event.exchange.clone(), balance.value().asset.clone());
+                match self.pending_index.get(&key) {
+                    Some(index) => self.pending[*index].1 = event,
+                    None => {
+                        self.pending_index.insert(key.clone(), self.pending.len());
+                        self.pending.push((key, event));
+                    }
+                }
+                None
+            }
+            AccountEventKind::Snapshot(_) => {
+                let exchange = &event.exchange;
+                self.pending.retain(|((pending, _), _)| pending != exchange);
+                self.pending_index = self
+                    .pending
+                    .iter()
+                    .enumerate()
+                    .map(|(index, (key, _))| (key.clone(), index))
+                    .collect();
+                Some(event)
+            }
+            _ => Some(event),
+        }
+    }
+}
+
+impl<St, ExchangeKey, AssetKey, InstrumentKey> Stream
+    for BatchedAccountStream<St, ExchangeKey, AssetKey, InstrumentKey>
+where
+    St: Stream<Item = AccountEvent<ExchangeKey, AssetKey, InstrumentKey>> + Unpin,
+    ExchangeKey: Clone + Eq + Hash + Unpin,
+    AssetKey: Clone + Eq + Hash + Unpin,
+    InstrumentKey: Unpin,
+{
+    type Item = AccountEvent<ExchangeKey, AssetKey, InstrumentKey>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        if let Some(event) = this.flushed.pop_front() {
+            return Poll::Ready(Some(event));
+        }
+
+        while !this.stream_ended {
+            match Pin::new(&mut this.stream).poll_next(cx) {
+                Poll::Ready(Some(event)) => {
+                    if let Some(event) = this.buffer(event) {
+                        return Poll::Ready(Some(event));
+                    }
+                }
+                Poll::Ready(None) => {
+                    this.stream_ended = true;
+                    this.flush();
+                }
+                Poll::Pending => break,
+            }
+        }
+
+        if this.stream_ended {
+            return Poll::Ready(this.flushed.pop_front());
+        }
+
+        let period = this.period;
+        let interval = this.interval.get_or_insert_with(|| {
+            let mut interval = tokio::time::interval_at(Instant::now() + period, period);
+            interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            interval
+        });
+
+        while interval.poll_tick(cx).is_ready() {
+            if !this.pending.is_empty() {
+                this.flush();
+                return Poll::Ready(this.flushed.pop_front());
+            }
+        }
+
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        UnindexedAccountEvent, UnindexedAccountSnapshot,
+        balance::{AssetBalance, Balance},
+        order::id::{OrderId, StrategyId},
+        trade::{AssetFees, Trade, TradeId},
+    };
+    use barter_instrument::{
+        Side, asset::name::AssetNameExchange, exchange::ExchangeId,
+        instrument::name::InstrumentNameExchange,
+    };
+    use barter_integration::snapshot::Snapshot;
+    use chrono::{DateTime, Utc};
+    use futures::StreamExt;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::UnboundedReceiverStream;
+
+    const PERIOD: Duration = Duration::from_millis(100);
+
+    fn balance(asset: &str, total: Decimal) -> UnindexedAccountEvent {
+        AccountEvent::new(
+            ExchangeId::Mock,
+            AccountEventKind::BalanceSnapshot(Snapshot(AssetBalance::new(
+                AssetNameExchange::new(asset),
+                Balance::new(total, total),
+                DateTime::<Utc>::MIN_UTC,
+            ))),
+        )
+    }
+
+    fn trade() -> UnindexedAccountEvent {
+        AccountEvent::new(
+            ExchangeId::Mock,
+            AccountEventKind::Trade(Trade {
+                id: TradeId::new("trade"),
+                order_id: OrderId::new("order"),
+                instrument: InstrumentNameExchange::new("btc_usdt"),
+                strategy: StrategyId::new("strategy"),
+                time_exchange: DateTime::<Utc>::MIN_UTC,
+                side: Side::Buy,
+                price: dec!(100),
+                quantity: dec!(1),
+                fees: AssetFees::new(AssetNameExchange::new("usdt"), Decimal::ZERO),
+            }),
+        )
+    }
+
+    fn total(event: UnindexedAccountEvent) -> (AssetNameExchange, Decimal) {
+        match event.kind {
+            AccountEventKind::BalanceSnapshot(Snapshot(balance)) => {
+                (balance.asset, balance.balance.total)
+            }
+            kind => panic!("expected BalanceSnapshot, got: {kind:?}"),
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_batched_account_stream_coalesces_balances_per_interval() {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let mut stream = BatchedAccountStream::new(UnboundedReceiverStream::new(rx), PERIOD);
+        let start = Instant::now();
+
+        tx.send(balance("btc", dec!(1))).unwrap();
+        tx.send(balance("btc", dec!(2))).unwrap();
+        tx.send(trade()).unwrap();
+        tx.send(balance("usdt", dec!(10))).unwrap();
+        tx.send(balance("btc", dec!(3))).unwrap();
+
+        // Trades pass through immediately
+        let event = stream.next().await.unwrap();
+        assert!(matches!(event.kind, AccountEventKind::Trade(_)));
+        assert_eq!(start.elapsed(), Duration::ZERO);
+
+        // Only the latest balance of each asset is flushed on the interval
+        let flushed = [
+            total(stream.next().await.unwrap()),
+            total(stream.next().await.unwrap()),
+        ];
+        assert_eq!(
+            flushed,
+            [
+                (AssetNameExchange::new("btc"), dec!(3)),
+                (AssetNameExchange::new("usdt"), dec!(10)),
+            ]
+        );
+        assert_eq!(start.elapsed(), PERIOD);
+
+        // Next flush is on the following interval
+        tx.send(balance("btc", dec!(4))).unwrap();
+        tx.send(balance("btc", dec!(5))).unwrap();
+        assert_eq!(
+            total(stream.next().await.unwrap()),
+            (AssetNameExchange::new("btc"), dec!(5))
+        );
+        assert_eq!(start.elapsed(), PERIOD * 2);
+
+        // Buffered balances are flushed when the inner stream ends
+        tx.send(balance("btc", dec!(6))).unwrap();
+        drop(tx);
+        assert_eq!(
+            total(stream.next().await.unwrap()),
+            (AssetNameExchange::new("btc"), dec!(6))
+        );
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_batched_account_stream_snapshot_discards_pending_balances() {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let mut stream = BatchedAccountStream::new(UnboundedReceiverStream::new(rx), PERIOD);
+
+        tx.send(balance("btc", dec!(1))).unwrap();
+        tx.send(AccountEvent::new(
+            ExchangeId::Mock,
+            AccountEventKind::Snapshot(UnindexedAccountSnapshot {
+                exchange: ExchangeId::Mock,
+                balances: vec![],
+                instruments: vec![],
+            }),
+        ))
+        .unwrap();
+        tx.send(balance("usdt", dec!(10))).unwrap();
+        drop(tx);
+
+        let event = stream.next().await.unwrap();
+        assert!(matches!(event.kind, AccountEventKind::Snapshot(_)));
+        assert_eq!(
+            total(stream.next().await.unwrap()),
+            (AssetNameExchange::new("usdt"), dec!(10))
+        );
+        assert!(stream.next().await.is_none());
+    }
+}
@@ -3,7 +3,10 @@ use crate::{
     balance::AssetBalance,
     client::ExecutionClient,
     error::{ConnectivityError, UnindexedClientError, UnindexedOrderError},
-    exchange::mock::request::MockExchangeRequest,
+    exchange::mock::{
+        fees::{FeeAsset, TieredFeeSchedule},
+        request::MockExchangeRequest,
+    },
     order::{
         Order, OrderEvent, OrderKey,
         request::{OrderRequestCancel, OrderRequestOpen, UnindexedOrderResponseCancel},
@@ -12,15 +15,14 @@ use crate::{
     trade::Trade,
 };
 use barter_instrument::{
-    asset::{QuoteAsset, name::AssetNameExchange},
-    exchange::ExchangeId,
-    instrument::name::InstrumentNameExchange,
+    asset::name::AssetNameExchange, exchange::ExchangeId, instrument::name::InstrumentNameExchange,
 };
 use chrono::{DateTime, Utc};
 use derive_more::Constructor;
 use futures::stream::BoxStream;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
 use tokio::sync::{broadcast, mpsc, oneshot};
 use tokio_stream::{StreamExt, wrappers::BroadcastStream};
 use tracing::error;
@@ -33,6 +35,12 @@ pub struct MockExecutionConfig {
     pub initial_state: UnindexedAccountSnapshot,
     pub latency_ms: u64,
     pub fees_percent: Decimal,
+    /// Optional volume [`TieredFeeSchedule`] used instead of the flat `fees_percent`.
+    #[serde(default)]
+    pub fee_schedule: Option<TieredFeeSchedule>,
+    /// [`FeeAsset`] fill fees are charged in, defaulting to the quote asset.
+    #[serde(default)]
+    pub fee_asset: FeeAsset,
 }
 
 #[derive(Debug, Constructor)]
@@ -285,7 +293,7 @@ where
     async fn fetch_trades(
         &self,
         time_since: DateTime<Utc>,
-    ) -> Result<Vec<Trade<QuoteAsset, InstrumentNameExchange>>, UnindexedClientError> {
+    ) -> Result<Vec<Trade<AssetNameExchange, InstrumentNameExchange>>, UnindexedClientError> {
         let (response_tx, response_rx) = oneshot::channel();
 
         self.request_tx
@@ -306,6 +314,27 @@ where
             ))
         })
     }
+
+    async fn ping(&self) -> Result<Duration, UnindexedClientError> {
+        let (response_tx, response_rx) = oneshot::channel();
+        let start = Instant::now();
+
+        self.request_tx
+            .send(MockExchangeRequest::ping(self.time_request(), response_tx))
+            .map_err(|_| {
+                UnindexedClientError::Connectivity(ConnectivityError::ExchangeOffline(
+                    self.mocked_exchange,
+                ))
+            })?;
+
+        response_rx.await.map_err(|_| {
+            UnindexedClientError::Connectivity(ConnectivityError::ExchangeOffline(
+                self.mocked_exchange,
+            ))
+        })?;
+
+        Ok(start.elapsed())
+    }
 }
 
 fn into_owned_request<Kind>(
@@ -3,23 +3,25 @@ use crate::{
     balance::AssetBalance,
     error::{UnindexedClientError, UnindexedOrderError},
     order::{
-        Order,
-        request::{OrderRequestCancel, OrderRequestOpen, UnindexedOrderResponseCancel},
+        Order, OrderEvent, OrderKey,
+        request::{
+            OrderRequestAmend, OrderRequestCancel, OrderRequestOpen, RequestCancel, RequestOpen,
+            UnindexedOrderResponseCancel,
+        },
         state::Open,
     },
     trade::Trade,
 };
 use barter_instrument::{
-    asset::{QuoteAsset, name::AssetNameExchange},
-    exchange::ExchangeId,
-    instrument::name::InstrumentNameExchange,
+    asset::name::AssetNameExchange, exchange::ExchangeId, instrument::name::InstrumentNameExchange,
 };
 use chrono::{DateTime, Utc};
-use futures::Stream;
-use std::future::Future;
+use futures::{Stream, StreamExt};
+use std::{future::Future, time::Duration};
 
 mod binance;
 pub mod mock;
+pub mod signing;
 
 pub trait ExecutionClient
 where
@@ -77,6 +79,58 @@ where
         )
     }
 
+    /// Amend the price and/or quantity of an open order.
+    ///
+    /// Defaults to cancelling the existing order and opening a replacement with the amended
+    /// price and quantity, which loses queue priority. Clients for exchanges that support
+    /// in-place order amendments should override this.
+    fn amend_order(
+        &self,
+        request: OrderRequestAmend<ExchangeId, &InstrumentNameExchange>,
+    ) -> impl Future<
+        Output = Order<ExchangeId, InstrumentNameExchange, Result<Open, UnindexedOrderError>>,
+    > + Send
+    where
+        Self: Sync,
+    {
+        async move {
+            let OrderEvent { key, state } = request;
+
+            let cancelled = self
+                .cancel_order(OrderRequestCancel {
+                    key: key.clone(),
+                    state: RequestCancel::new(state.id),
+                })
+                .await;
+
+            if let Err(error) = cancelled.state {
+                return Order {
+                    key: cancelled.key,
+                    side: state.side,
+                    price: state.new_price,
+                    quantity: state.new_quantity,
+                    kind: state.kind,
+                    time_in_force: state.time_in_force,
+                    state: Err(error),
+                };
+            }
+
+            self.open_order(OrderRequestOpen {
+                key,
+                state: RequestOpen::new(
+                    state.side,
+                    state.new_price,
+                    state.new_quantity,
+                    state.kind,
+                    state.time_in_force,
+                    state.reduce_only,
+                    state.position_side,
+                ),
+            })
+            .await
+        }
+    }
+
     fn fetch_balances(
         &self,
     ) -> impl Future<Output = Result<Vec<AssetBalance<AssetNameExchange>>, UnindexedClientError>>;
@@ -90,5 +144,49 @@ where
     fn fetch_trades(
         &self,
         time_since: DateTime<Utc>,
-    ) -> impl Future<Output = Result<Vec<Trade<QuoteAsset, InstrumentNameExchange>>, UnindexedClientError>>;
+    ) -> impl Future<
+        Output = Result<
+            Vec<Trade<AssetNameExchange, InstrumentNameExchange>>,
+            UnindexedClientError,
+        >,
+    >;
+
+    /// Cancel every open order, returning the number of orders successfully cancelled.
+    ///
+    /// Useful as a kill-switch on shutdown to avoid leaving resting orders live on the exchange.
+    /// Defaults to fetching the open orders and cancelling each individually. Clients for
+    /// exchanges that support bulk cancellation should override this.
+    fn cancel_all_open_orders(&self) -> impl Future<Output = Result<usize, UnindexedClientError>> {
+        async move {
+            let open_orders = self.fetch_open_orders().await?;
+
+            let cancelled = self
+                .cancel_orders(open_orders.iter().map(|order| OrderRequestCancel {
+                    key: OrderKey {
+                        exchange: order.key.exchange,
+                        instrument: &order.key.instrument,
+                        strategy: order.key.strategy.clone(),
+                        cid: order.key.cid.clone(),
+                    },
+                    state: RequestCancel::new(Some(order.state.id.clone())),
+                }))
+                .filter(|response| std::future::ready(response.state.is_ok()))
+                .count()
+                .await;
+
+            Ok(cancelled)
+        }
+    }
+
+    /// Round-trip a lightweight request to the exchange, returning the measured latency.
+    ///
+    /// Useful as a connectivity health-check. Defaults to returning a
+    /// [`ClientError::Unsupported`](crate::error::ClientError::Unsupported) for clients that do
+    /// not implement it.
+    fn ping(&self) -> impl Future<Output = Result<Duration, UnindexedClientError>> + Send {
+        std::future::ready(Err(UnindexedClientError::Unsupported(format!(
+            "{} ExecutionClient does not support ping",
+            Self::EXCHANGE
+        ))))
+    }
 }
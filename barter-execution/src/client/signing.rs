@@ -0,0 +1,108 @@
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64_STANDARD};
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256, Sha512};
+
+/// Calculate the lowercase hex encoded HMAC-SHA256 of the `message` using the provided `secret`.
+pub fn hmac_sha256_hex(secret: &str, message: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC can take a key of any size");
+    mac.update(message.as_bytes());
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// Build the signature of a Binance signed (`TRADE` & `USER_DATA`) request.
+///
+/// `params` is the total request parameters (ie/ query string concatenated with request body),
+/// eg/ `symbol=LTCBTC&side=BUY&timestamp=1499827319559`.
+///
+/// See docs: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/request-security>
+pub fn build_binance_signature(params: &str, secret: &str) -> String {
+    hmac_sha256_hex(secret, params)
+}
+
+/// Build the `API-Sign` header of a Kraken private REST request.
+///
+/// The signature is the base64 encoded `HMAC-SHA512(path + SHA256(nonce + postdata))`, keyed by
+/// the base64 decoded API `secret`.
+///
+/// See docs: <https://docs.kraken.com/api/docs/guides/spot-rest-auth>
+pub fn build_kraken_signature(
+    path: &str,
+    nonce: &str,
+    postdata: &str,
+    secret: &str,
+) -> Result<String, base64::DecodeError> {
+    let secret = BASE64_STANDARD.decode(secret)?;
+
+    let mut sha256 = Sha256::new();
+    sha256.update(nonce.as_bytes());
+    sha256.update(postdata.as_bytes());
+
+    let mut mac = Hmac::<Sha512>::new_from_slice(&secret).expect("HMAC can take a key of any size");
+    mac.update(path.as_bytes());
+    mac.update(&sha256.finalize());
+
+    Ok(BASE64_STANDARD.encode(mac.finalize().into_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hmac_sha256_hex() {
+        struct TestCase {
+            secret: &'static str,
+            message: &'static str,
+            expected: &'static str,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: RFC 4231 test case 2
+                secret: "Jefe",
+                message: "what do ya want for nothing?",
+                expected: "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843",
+            },
+            TestCase {
+                // TC1: empty message
+                secret: "key",
+                message: "",
+                expected: "5d5d139563c95b5967b9bd9a8c9b233a9dedb45072794cd232dc1b74832607d0",
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = hmac_sha256_hex(test.secret, test.message);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_build_binance_signature() {
+        // Example from Binance "SIGNED Endpoint Examples for POST /api/v3/order"
+        let params = "symbol=LTCBTC&side=BUY&type=LIMIT&timeInForce=GTC&quantity=1&price=0.1&recvWindow=5000&timestamp=1499827319559";
+        let secret = "NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j";
+
+        assert_eq!(
+            build_binance_signature(params, secret),
+            "c8db56825ae71d6d79447849e617115f4a920fa2acdcab2b053c4b2838bd6b71"
+        );
+    }
+
+    #[test]
+    fn test_build_kraken_signature() {
+        // Example from Kraken "Spot REST API Authentication" guide
+        let secret = "kQH5HW/8p1uGOVjbgWA7FunAmGO8lsSUXNsu3eow76sz84Q18fWxnyRzBHCd3pd5nE9qa99HAZtuZuj6F1huXg==";
+        let nonce = "1616492376594";
+        let postdata =
+            "nonce=1616492376594&ordertype=limit&pair=XBTUSD&price=37500&type=buy&volume=1.25";
+
+        assert_eq!(
+            build_kraken_signature("/0/private/AddOrder", nonce, postdata, secret).unwrap(),
+            "4/dpxb3iT4tp/ZCVEwSnEsLxx0bqyhLpdfOpc6fn7OR8+UClSV5n9E6aSS8MPtnRfp32bAb0nmbRn6H8ndwLUQ=="
+        );
+
+        assert!(build_kraken_signature("/0/private/AddOrder", nonce, postdata, "!").is_err());
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{client::ExecutionClient, error::UnindexedClientError};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tracing::{info, warn};
+
+/// Local watchdog that cancels every open order via
+/// [`ExecutionClient::cancel_all_open_orders`] if heartbeats stop.
+///
+/// The switch triggers if [`DeadMansSwitchHandle::keepalive`] is not called within the `timeout`,
+/// or if the [`DeadMansSwitchHandle`] is dropped without being
+/// [`disarmed`](DeadMansSwitchHandle::disarm) (eg/ the strategy task panicked).
+#[derive(Debug)]
+pub struct DeadMansSwitch<Client> {
+    pub client: Client,
+    pub timeout: Duration,
+    signal_rx: mpsc::UnboundedReceiver<DeadMansSwitchSignal>,
+}
+
+/// Handle used to send heartbeats to an associated [`DeadMansSwitch`].
+#[derive(Debug)]
+pub struct DeadMansSwitchHandle {
+    signal_tx: mpsc::UnboundedSender<DeadMansSwitchSignal>,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum DeadMansSwitchSignal {
+    Keepalive,
+    Disarm,
+}
+
+impl<Client> DeadMansSwitch<Client>
+where
+    Client: ExecutionClient,
+{
+    /// Construct a new [`DeadMansSwitch`] that triggers if no keepalive is received within the
+    /// `timeout`, along with the [`DeadMansSwitchHandle`] used to send keepalives.
+    pub fn new(client: Client, timeout: Duration) -> (Self, DeadMansSwitchHandle) {
+        let (signal_tx, signal_rx) = mpsc::unbounded_channel();
+
+        (
+            Self {
+                client,
+                timeout,
+                signal_rx,
+            },
+            DeadMansSwitchHandle { signal_tx },
+        )
+    }
+
+    /// Run the [`DeadMansSwitch`] until it is disarmed or triggered.
+    ///
+    /// Returns `Ok(None)` if disarmed, otherwise the number of orders cancelled once triggered.
+    pub async fn run(mut self) -> Result<Option<usize>, UnindexedClientError> {
+        loop {
+            match tokio::time::timeout(self.timeout, self.signal_rx.recv()).await {
+                Ok(Some(DeadMansSwitchSignal::Keepalive)) => continue,
+                Ok(Some(DeadMansSwitchSignal::Disarm)) => {
+                    info!(exchange = %Client::EXCHANGE, "DeadMansSwitch disarmed");
+                    return Ok(None);
+                }
+                Ok(None) => {
+                    warn!(
+                        exchange = %Client::EXCHANGE,
+                        "DeadMansSwitchHandle dropped without disarming - cancelling all open orders"
+                    );
+                    break;
+                }
+                Err(_elapsed) => {
+                    warn!(
+                        exchange = %Client::EXCHANGE,
+                        timeout = ?self.timeout,
+                        "DeadMansSwitch keepalive timed out - cancelling all open orders"
+                    );
+                    break;
+                }
+            }
+        }
+
+        self.client.cancel_all_open_orders().await.map(Some)
+    }
+}
+
+impl DeadMansSwitchHandle {
+    /// Send a heartbeat, resetting the [`DeadMansSwitch`] timeout.
+    pub fn keepalive(&self) {
+        let _ = self.signal_tx.send(DeadMansSwitchSignal::Keepalive);
+    }
+
+    /// Disarm the [`DeadMansSwitch`] so it stops without cancelling any orders.
+    pub fn disarm(self) {
+        let _ = self.signal_tx.send(DeadMansSwitchSignal::Disarm);
+    }
+}
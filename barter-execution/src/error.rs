@@ -41,6 +41,11 @@ pub enum ClientError<AssetKey = AssetIndex, InstrumentKey = InstrumentIndex> {
     /// Failed to initialise an AccountStream.
     #[error("failed to init AccountStream: {0}")]
     AccountStream(String),
+
+    /// Requested operation is not supported by the
+    /// [`ExecutionClient`](super::client::ExecutionClient).
+    #[error("unsupported: {0}")]
+    Unsupported(String),
 }
 
 /// Represents all connectivity-centric errors.
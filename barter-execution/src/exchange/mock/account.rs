@@ -1,17 +1,16 @@
 use crate::{
     UnindexedAccountSnapshot,
     balance::AssetBalance,
+    exchange::mock::position::MockPosition,
     order::{
-        Order,
+        Order, UnindexedOrderSnapshot,
         id::ClientOrderId,
         state::{ActiveOrderState, Cancelled, InactiveOrderState, Open, OrderState},
     },
     trade::Trade,
 };
 use barter_instrument::{
-    asset::{QuoteAsset, name::AssetNameExchange},
-    exchange::ExchangeId,
-    instrument::name::InstrumentNameExchange,
+    asset::name::AssetNameExchange, exchange::ExchangeId, instrument::name::InstrumentNameExchange,
 };
 use chrono::{DateTime, Utc};
 use derive_more::Constructor;
@@ -23,7 +22,8 @@ pub struct AccountState {
     orders_open: FnvHashMap<ClientOrderId, Order<ExchangeId, InstrumentNameExchange, Open>>,
     orders_cancelled:
         FnvHashMap<ClientOrderId, Order<ExchangeId, InstrumentNameExchange, Cancelled>>,
-    trades: Vec<Trade<QuoteAsset, InstrumentNameExchange>>,
+    trades: Vec<Trade<AssetNameExchange, InstrumentNameExchange>>,
+    positions: FnvHashMap<InstrumentNameExchange, MockPosition>,
 }
 
 impl AccountState {
@@ -56,12 +56,21 @@ impl AccountState {
     pub fn trades(
         &self,
         time_since: DateTime<Utc>,
-    ) -> impl Iterator<Item = &Trade<QuoteAsset, InstrumentNameExchange>> + '_ {
+    ) -> impl Iterator<Item = &Trade<AssetNameExchange, InstrumentNameExchange>> + '_ {
         self.trades
             .iter()
             .filter(move |trade| trade.time_exchange >= time_since)
     }
 
+    /// [`MockPosition`] of the provided instrument, which is flat if it has never been traded.
+    pub fn position(&self, instrument: &InstrumentNameExchange) -> MockPosition {
+        self.positions.get(instrument).copied().unwrap_or_default()
+    }
+
+    pub fn position_mut(&mut self, instrument: &InstrumentNameExchange) -> &mut MockPosition {
+        self.positions.entry(instrument.clone()).or_default()
+    }
+
     pub fn balance_mut(
         &mut self,
         asset: &AssetNameExchange,
@@ -69,7 +78,81 @@ impl AccountState {
         self.balances.get_mut(asset)
     }
 
-    pub fn ack_trade(&mut self, trade: Trade<QuoteAsset, InstrumentNameExchange>) {
+    /// Insert the [`AssetBalance`], replacing any existing balance of the same asset.
+    pub fn upsert_balance(&mut self, balance: AssetBalance<AssetNameExchange>) {
+        self.balances.insert(balance.asset.clone(), balance);
+    }
+
+    /// Upsert the order based on its [`OrderState`].
+    ///
+    /// Open orders are inserted, cancelled orders are moved from open to cancelled, and any
+    /// other inactive orders (eg/ fully filled) are removed from the open orders. In flight
+    /// orders are ignored.
+    pub fn upsert_order(&mut self, order: UnindexedOrderSnapshot) {
+        let Order {
+            key,
+            side,
+            price,
+            quantity,
+            kind,
+            time_in_force,
+            state,
+        } = order;
+
+        match state {
+            OrderState::Active(ActiveOrderState::Open(open)) => {
+                self.orders_open.insert(
+                    key.cid.clone(),
+                    Order {
+                        key,
+                        side,
+                        price,
+                        quantity,
+                        kind,
+                        time_in_force,
+                        state: open,
+                    },
+                );
+            }
+            OrderState::Active(_) => {}
+            OrderState::Inactive(InactiveOrderState::Cancelled(cancelled)) => {
+                self.orders_open.remove(&key.cid);
+                self.orders_cancelled.insert(
+                    key.cid.clone(),
+                    Order {
+                        key,
+                        side,
+                        price,
+                        quantity,
+                        kind,
+                        time_in_force,
+                        state: cancelled,
+                    },
+                );
+            }
+            OrderState::Inactive(_) => {
+                self.orders_open.remove(&key.cid);
+            }
+        }
+    }
+
+    /// Move the open order with the provided [`ClientOrderId`] to the cancelled orders, if it
+    /// exists.
+    pub fn cancel_open_order(&mut self, cid: &ClientOrderId, cancelled: Cancelled) {
+        if let Some(open_order) = self.orders_open.remove(cid) {
+            self.insert_cancelled_order(Order {
+                key: open_order.key,
+                side: open_order.side,
+                price: open_order.price,
+                quantity: open_order.quantity,
+                kind: open_order.kind,
+                time_in_force: open_order.time_in_force,
+                state: cancelled,
+            });
+        }
+    }
+
+    pub fn ack_trade(&mut self, trade: Trade<AssetNameExchange, InstrumentNameExchange>) {
         self.trades.push(trade);
     }
 
@@ -105,52 +188,18 @@ impl From<UnindexedAccountSnapshot> for AccountState {
             .map(|asset_balance| (asset_balance.asset.clone(), asset_balance))
             .collect();
 
-        let (orders_open, orders_cancelled) = instruments.into_iter().fold(
-            (FnvHashMap::default(), FnvHashMap::default()),
-            |(mut orders_open, mut orders_cancelled), snapshot| {
-                for order in snapshot.orders {
-                    match order.state {
-                        OrderState::Active(ActiveOrderState::Open(open)) => {
-                            orders_open.insert(
-                                order.key.cid.clone(),
-                                Order {
-                                    key: order.key,
-                                    side: order.side,
-                                    price: order.price,
-                                    quantity: order.quantity,
-                                    kind: order.kind,
-                                    time_in_force: order.time_in_force,
-                                    state: open,
-                                },
-                            );
-                        }
-                        OrderState::Inactive(InactiveOrderState::Cancelled(cancelled)) => {
-                            orders_cancelled.insert(
-                                order.key.cid.clone(),
-                                Order {
-                                    key: order.key,
-                                    side: order.side,
-                                    price: order.price,
-                                    quantity: order.quantity,
-                                    kind: order.kind,
-                                    time_in_force: order.time_in_force,
-                                    state: cancelled,
-                                },
-                            );
-                        }
-                        _ => {}
-                    }
-                }
-
-                (orders_open, orders_cancelled)
-            },
-        );
-
-        Self {
+        let mut state = Self {
             balances,
-            orders_open,
-            orders_cancelled,
+            orders_open: FnvHashMap::default(),
+            orders_cancelled: FnvHashMap::default(),
             trades: vec![],
+            positions: FnvHashMap::default(),
+        };
+
+        for order in instruments.into_iter().flat_map(|snapshot| snapshot.orders) {
+            state.upsert_order(order);
         }
+
+        state
     }
 }
@@ -0,0 +1,247 @@
+use barter_instrument::{Side, Underlying, asset::name::AssetNameExchange};
+use chrono::{DateTime, TimeDelta, Utc};
+use derive_more::Constructor;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::{collections::VecDeque, fmt::Debug};
+
+/// Defines the fees charged by a [`MockExchange`](super::MockExchange) for each fill.
+pub trait FeeSchedule: Debug + Send {
+    /// Returns the fee charged for a fill of the provided `notional`, given the rolling 30 day
+    /// quote volume traded by the account prior to this fill.
+    ///
+    /// The returned fee is denominated in the same asset as the provided `notional`.
+    fn fee_for(&self, side: Side, notional: Decimal, rolling_30d_volume: Decimal) -> Decimal;
+}
+
+/// Asset a [`MockExchange`](super::MockExchange) charges fill fees in.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize)]
+pub enum FeeAsset {
+    /// Fees are charged in the instrument quote asset.
+    #[default]
+    Quote,
+
+    /// Fees are charged in the instrument base asset, converted at the fill price.
+    Base,
+
+    /// Fees are charged in a separate asset (eg/ BNB), converted at the provided `price` of the
+    /// asset denominated in the instrument quote asset.
+    Native {
+        asset: AssetNameExchange,
+        price: Decimal,
+    },
+}
+
+impl FeeAsset {
+    /// Returns the asset and amount charged for fees worth `fees_quote` in the quote asset, for a
+    /// fill at the provided `price`.
+    ///
+    /// Returns `None` if the conversion price is zero.
+    pub fn convert(
+        &self,
+        underlying: &Underlying<AssetNameExchange>,
+        fees_quote: Decimal,
+        price: Decimal,
+    ) -> Option<(AssetNameExchange, Decimal)> {
+        match self {
+            Self::Quote => Some((underlying.quote.clone(), fees_quote)),
+            Self::Base => fees_quote
+                .checked_div(price)
+                .map(|fees| (underlying.base.clone(), fees)),
+            Self::Native { asset, price } => fees_quote
+                .checked_div(*price)
+                .map(|fees| (asset.clone(), fees)),
+        }
+    }
+}
+
+/// [`FeeSchedule`] that charges a flat `fees_percent` of notional, regardless of volume.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct FlatFeeSchedule {
+    pub fees_percent: Decimal,
+}
+
+impl FeeSchedule for FlatFeeSchedule {
+    fn fee_for(&self, _: Side, notional: Decimal, _: Decimal) -> Decimal {
+        notional * self.fees_percent
+    }
+}
+
+/// [`FeeSchedule`] that charges a `fees_percent` of notional determined by the rolling 30 day
+/// quote volume traded by the account.
+///
+/// The [`FeeTier`] with the largest `volume_min` that is less than or equal to the rolling
+/// volume is applied. If no [`FeeTier`] applies, no fees are charged.
+#[derive(
+    Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct TieredFeeSchedule {
+    pub tiers: Vec<FeeTier>,
+}
+
+/// Volume tier of a [`TieredFeeSchedule`].
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct FeeTier {
+    /// Minimum rolling 30 day quote volume required for this tier to apply.
+    pub volume_min: Decimal,
+    /// Percentage of notional charged as fees.
+    pub fees_percent: Decimal,
+}
+
+impl TieredFeeSchedule {
+    /// Returns the [`FeeTier`] that applies to the provided rolling 30 day quote volume.
+    pub fn tier(&self, rolling_30d_volume: Decimal) -> Option<&FeeTier> {
+        self.tiers
+            .iter()
+            .filter(|tier| tier.volume_min <= rolling_30d_volume)
+            .max_by_key(|tier| tier.volume_min)
+    }
+}
+
+impl FeeSchedule for TieredFeeSchedule {
+    fn fee_for(&self, _: Side, notional: Decimal, rolling_30d_volume: Decimal) -> Decimal {
+        self.tier(rolling_30d_volume)
+            .map(|tier| notional * tier.fees_percent)
+            .unwrap_or_default()
+    }
+}
+
+/// Rolling 30 day quote volume traded by a [`MockExchange`](super::MockExchange) account.
+#[derive(Debug, Clone, Default)]
+pub struct RollingVolume {
+    fills: VecDeque<(DateTime<Utc>, Decimal)>,
+}
+
+impl RollingVolume {
+    /// Duration of the rolling volume window.
+    pub const WINDOW: TimeDelta = TimeDelta::days(30);
+
+    /// Record a fill of the provided quote `volume` at the provided `time`.
+    pub fn record(&mut self, time: DateTime<Utc>, volume: Decimal) {
+        self.fills.push_back((time, volume));
+    }
+
+    /// Returns the total quote volume filled within the [`Self::WINDOW`] ending at `time_now`,
+    /// removing any fills that have rolled out of the window.
+    pub fn volume(&mut self, time_now: DateTime<Utc>) -> Decimal {
+        while self
+            .fills
+            .front()
+            .is_some_and(|(time, _)| time_now.signed_duration_since(*time) >= Self::WINDOW)
+        {
+            self.fills.pop_front();
+        }
+
+        self.fills.iter().map(|(_, volume)| *volume).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_tiered_fee_schedule_fee_for() {
+        struct TestCase {
+            rolling_30d_volume: Decimal,
+            expected: Decimal,
+        }
+
+        let schedule = TieredFeeSchedule::new(vec![
+            FeeTier::new(dec!(1_000_000), dec!(0.0005)),
+            FeeTier::new(dec!(0), dec!(0.001)),
+            FeeTier::new(dec!(10_000_000), dec!(0.0002)),
+        ]);
+
+        let tests = vec![
+            TestCase {
+                // TC0: lowest tier
+                rolling_30d_volume: dec!(0),
+                expected: dec!(10),
+            },
+            TestCase {
+                // TC1: volume exactly on tier boundary
+                rolling_30d_volume: dec!(1_000_000),
+                expected: dec!(5),
+            },
+            TestCase {
+                // TC2: highest tier
+                rolling_30d_volume: dec!(50_000_000),
+                expected: dec!(2),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = schedule.fee_for(Side::Buy, dec!(10_000), test.rolling_30d_volume);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_fee_asset_convert() {
+        struct TestCase {
+            fee_asset: FeeAsset,
+            price: Decimal,
+            expected: Option<(AssetNameExchange, Decimal)>,
+        }
+
+        let underlying = Underlying::new(
+            AssetNameExchange::from("btc"),
+            AssetNameExchange::from("usdt"),
+        );
+
+        let tests = vec![
+            TestCase {
+                // TC0: quote asset fees are not converted
+                fee_asset: FeeAsset::Quote,
+                price: dec!(100),
+                expected: Some((AssetNameExchange::from("usdt"), dec!(2))),
+            },
+            TestCase {
+                // TC1: base asset fees are converted at the fill price
+                fee_asset: FeeAsset::Base,
+                price: dec!(100),
+                expected: Some((AssetNameExchange::from("btc"), dec!(0.02))),
+            },
+            TestCase {
+                // TC2: base asset fees cannot be converted at a zero fill price
+                fee_asset: FeeAsset::Base,
+                price: dec!(0),
+                expected: None,
+            },
+            TestCase {
+                // TC3: native asset fees are converted at the configured price
+                fee_asset: FeeAsset::Native {
+                    asset: AssetNameExchange::from("bnb"),
+                    price: dec!(400),
+                },
+                price: dec!(100),
+                expected: Some((AssetNameExchange::from("bnb"), dec!(0.005))),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.fee_asset.convert(&underlying, dec!(2), test.price);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_rolling_volume() {
+        let mut volume = RollingVolume::default();
+        let time_base = DateTime::<Utc>::MIN_UTC;
+
+        volume.record(time_base, dec!(100));
+        volume.record(time_base + TimeDelta::days(10), dec!(50));
+        assert_eq!(volume.volume(time_base + TimeDelta::days(10)), dec!(150));
+
+        // First fill rolls out of the window
+        assert_eq!(volume.volume(time_base + TimeDelta::days(30)), dec!(50));
+        assert_eq!(volume.volume(time_base + TimeDelta::days(40)), dec!(0));
+    }
+}
@@ -0,0 +1,70 @@
+use crate::order::id::OrderId;
+use chrono::{DateTime, Utc};
+use derive_more::Constructor;
+use smol_str::ToSmolStr;
+use std::fmt::Debug;
+
+/// Generates the [`OrderId`] assigned to each order opened on a
+/// [`MockExchange`](super::MockExchange).
+///
+/// The associated [`TradeId`](crate::trade::TradeId) of each fill re-uses the generated
+/// [`OrderId`].
+pub trait OrderIdGenerator: Debug + Send {
+    /// Returns the next [`OrderId`] for an order opened at the provided `time_exchange`.
+    fn next_id(&mut self, time_exchange: DateTime<Utc>) -> OrderId;
+}
+
+/// Default [`OrderIdGenerator`] producing incrementing sequence numbers, starting from the
+/// provided `sequence`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Constructor)]
+pub struct SequenceOrderIdGenerator {
+    pub sequence: u64,
+}
+
+impl OrderIdGenerator for SequenceOrderIdGenerator {
+    fn next_id(&mut self, _: DateTime<Utc>) -> OrderId {
+        let sequence = self.sequence;
+        self.sequence += 1;
+        OrderId::new(sequence.to_smolstr())
+    }
+}
+
+/// [`OrderIdGenerator`] producing ids from the `time_exchange` in milliseconds, mirroring
+/// exchanges that assign timestamp based order ids.
+///
+/// Orders opened within the same millisecond are assigned the next unused millisecond, so ids
+/// remain unique and increasing.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub struct TimestampOrderIdGenerator {
+    last: Option<i64>,
+}
+
+impl OrderIdGenerator for TimestampOrderIdGenerator {
+    fn next_id(&mut self, time_exchange: DateTime<Utc>) -> OrderId {
+        let timestamp = time_exchange.timestamp_millis();
+        let id = match self.last {
+            Some(last) if timestamp <= last => last + 1,
+            _ => timestamp,
+        };
+        self.last = Some(id);
+        OrderId::new(id.to_smolstr())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order_id_generators() {
+        let time = |millis| DateTime::from_timestamp_millis(millis).unwrap();
+
+        let mut sequence = SequenceOrderIdGenerator::default();
+        let actual = [time(5), time(5), time(1)].map(|time| sequence.next_id(time));
+        assert_eq!(actual, ["0", "1", "2"].map(OrderId::new));
+
+        let mut timestamp = TimestampOrderIdGenerator::default();
+        let actual = [time(5), time(5), time(1), time(10)].map(|time| timestamp.next_id(time));
+        assert_eq!(actual, ["5", "6", "7", "10"].map(OrderId::new));
+    }
+}
@@ -1,23 +1,27 @@
 use crate::{
     AccountEventKind, InstrumentAccountSnapshot, UnindexedAccountEvent, UnindexedAccountSnapshot,
-    balance::AssetBalance,
+    balance::{AssetBalance, Balance},
     client::mock::MockExecutionConfig,
     error::{ApiError, UnindexedApiError, UnindexedOrderError},
     exchange::mock::{
         account::AccountState,
+        fees::{FeeAsset, FeeSchedule, FlatFeeSchedule, RollingVolume},
+        id::{OrderIdGenerator, SequenceOrderIdGenerator},
+        position::PositionMode,
         request::{MockExchangeRequest, MockExchangeRequestKind},
     },
     order::{
-        Order, OrderKind, TimeInForce, UnindexedOrder,
-        id::OrderId,
-        request::{OrderRequestCancel, OrderRequestOpen, OrderResponseCancel},
+        Order, OrderKind, PositionSide, TimeInForce, UnindexedOrder,
+        request::{
+            OrderRequestCancel, OrderRequestOpen, OrderResponseCancel, RequestCancel, RequestOpen,
+        },
         state::{Cancelled, Open},
     },
     trade::{AssetFees, Trade, TradeId},
 };
 use barter_instrument::{
-    Side,
-    asset::{QuoteAsset, name::AssetNameExchange},
+    Side, Underlying,
+    asset::name::AssetNameExchange,
     exchange::ExchangeId,
     instrument::{Instrument, name::InstrumentNameExchange},
 };
@@ -27,25 +31,30 @@ use fnv::FnvHashMap;
 use futures::stream::BoxStream;
 use itertools::Itertools;
 use rust_decimal::Decimal;
-use smol_str::ToSmolStr;
 use std::fmt::Debug;
 use tokio::sync::{broadcast, mpsc, oneshot};
 use tokio_stream::{StreamExt, wrappers::BroadcastStream};
 use tracing::{error, info};
 
 pub mod account;
+pub mod fees;
+pub mod id;
+pub mod position;
 pub mod request;
 
 #[derive(Debug)]
 pub struct MockExchange {
     pub exchange: ExchangeId,
     pub latency_ms: u64,
-    pub fees_percent: Decimal,
+    pub fee_schedule: Box<dyn FeeSchedule>,
+    pub fee_asset: FeeAsset,
+    pub volume: RollingVolume,
+    pub position_mode: PositionMode,
     pub request_rx: mpsc::UnboundedReceiver<MockExchangeRequest>,
     pub event_tx: broadcast::Sender<UnindexedAccountEvent>,
     pub instruments: FnvHashMap<InstrumentNameExchange, Instrument<ExchangeId, AssetNameExchange>>,
     pub account: AccountState,
-    pub order_sequence: u64,
+    pub order_id_generator: Box<dyn OrderIdGenerator>,
     pub time_exchange_latest: DateTime<Utc>,
 }
 
@@ -56,22 +65,61 @@ impl MockExchange {
         event_tx: broadcast::Sender<UnindexedAccountEvent>,
         instruments: FnvHashMap<InstrumentNameExchange, Instrument<ExchangeId, AssetNameExchange>>,
     ) -> Self {
+        let fee_schedule: Box<dyn FeeSchedule> = match config.fee_schedule {
+            Some(tiered) => Box::new(tiered),
+            None => Box::new(FlatFeeSchedule::new(config.fees_percent)),
+        };
+
         Self {
             exchange: config.mocked_exchange,
             latency_ms: config.latency_ms,
-            fees_percent: config.fees_percent,
+            fee_schedule,
+            fee_asset: config.fee_asset,
+            volume: RollingVolume::default(),
+            position_mode: PositionMode::default(),
             request_rx,
             event_tx,
             instruments,
             account: AccountState::from(config.initial_state),
-            order_sequence: 0,
+            order_id_generator: Box::new(SequenceOrderIdGenerator::default()),
             time_exchange_latest: Default::default(),
         }
     }
 
+    /// Replace the [`FeeSchedule`] used to charge fees for each fill.
+    pub fn with_fee_schedule<Fees>(self, fee_schedule: Fees) -> Self
+    where
+        Fees: FeeSchedule + 'static,
+    {
+        Self {
+            fee_schedule: Box::new(fee_schedule),
+            ..self
+        }
+    }
+
+    /// Replace the [`OrderIdGenerator`] used to assign ids to opened orders and their fills.
+    pub fn with_order_id_generator<Generator>(self, order_id_generator: Generator) -> Self
+    where
+        Generator: OrderIdGenerator + 'static,
+    {
+        Self {
+            order_id_generator: Box::new(order_id_generator),
+            ..self
+        }
+    }
+
+    /// Replace the [`PositionMode`] used to track instrument positions.
+    pub fn with_position_mode(self, position_mode: PositionMode) -> Self {
+        Self {
+            position_mode,
+            ..self
+        }
+    }
+
     pub async fn run(mut self) {
         while let Some(request) = self.request_rx.recv().await {
             self.update_time_exchange(request.time_request);
+            self.cancel_expired_orders();
 
             match request.kind {
                 MockExchangeRequestKind::FetchAccountSnapshot { response_tx } => {
@@ -93,15 +141,15 @@ impl MockExchange {
                     let trades = self.account.trades(time_since).cloned().collect();
                     self.respond_with_latency(response_tx, trades);
                 }
+                MockExchangeRequestKind::Ping { response_tx } => {
+                    self.respond_with_latency(response_tx, ());
+                }
                 MockExchangeRequestKind::CancelOrder {
-                    response_tx: _,
+                    response_tx,
                     request,
                 } => {
-                    error!(
-                        exchange = %self.exchange,
-                        ?request,
-                        "MockExchange received cancel request but only Market orders are supported"
-                    );
+                    let Order { key, state, .. } = self.cancel_order(request);
+                    self.respond_with_latency(response_tx, OrderResponseCancel { key, state });
                 }
                 MockExchangeRequestKind::OpenOrder {
                     response_tx,
@@ -112,9 +160,14 @@ impl MockExchange {
 
                     if let Some(notifications) = notifications {
                         self.account.ack_trade(notifications.trade.clone());
-                        let balance = self.build_account_event(notifications.balance);
                         let trade = self.build_account_event(notifications.trade);
-                        self.send_notifications_with_latency([balance, trade]);
+                        let events = notifications
+                            .balances
+                            .into_iter()
+                            .map(|balance| self.build_account_event(balance))
+                            .chain([trade])
+                            .collect::<Vec<_>>();
+                        self.send_notifications_with_latency(events);
                     }
                 }
             }
@@ -295,9 +348,38 @@ impl MockExchange {
         }
     }
 
+    /// Cancel every open order with a [`TimeInForce::GoodTillDate`] expiry at or before the
+    /// current exchange time, notifying the client of each cancellation.
+    pub fn cancel_expired_orders(
+        &mut self,
+    ) -> Vec<Order<ExchangeId, InstrumentNameExchange, Result<Cancelled, UnindexedOrderError>>>
+    {
+        let time_exchange = self.time_exchange();
+
+        let expired = self
+            .account
+            .orders_open()
+            .filter(|order| {
+                order
+                    .time_in_force
+                    .expiry()
+                    .is_some_and(|expiry| expiry <= time_exchange)
+            })
+            .map(|order| OrderRequestCancel {
+                key: order.key.clone(),
+                state: RequestCancel::new(Some(order.state.id.clone())),
+            })
+            .collect::<Vec<_>>();
+
+        expired
+            .into_iter()
+            .map(|request| self.cancel_order(request))
+            .collect()
+    }
+
     pub fn open_order(
         &mut self,
-        request: OrderRequestOpen<ExchangeId, InstrumentNameExchange>,
+        mut request: OrderRequestOpen<ExchangeId, InstrumentNameExchange>,
     ) -> (
         Order<ExchangeId, InstrumentNameExchange, Result<Open, UnindexedOrderError>>,
         Option<OpenOrderNotifications>,
@@ -306,88 +388,58 @@ impl MockExchange {
             return (build_open_order_err_response(request, error), None);
         }
 
-        let underlying = match self.find_instrument_data(&request.key.instrument) {
-            Ok(instrument) => instrument.underlying.clone(),
+        let (underlying, spec) = match self.find_instrument_data(&request.key.instrument) {
+            Ok(instrument) => (instrument.underlying.clone(), instrument.spec.clone()),
             Err(error) => return (build_open_order_err_response(request, error), None),
         };
 
-        let time_exchange = self.time_exchange();
+        // Round to the instrument tick & step sizes, as an exchange would require
+        if let Some(spec) = spec {
+            request.state = request.state.round_to_spec(&spec);
 
-        let balance_change_result = match request.state.side {
-            Side::Buy => {
-                // Buying Instrument requires sufficient QuoteAsset Balance
-                let current = self
-                    .account
-                    .balance_mut(&underlying.quote)
-                    .expect("MockExchange has Balance for all configured Instrument assets");
-
-                // Currently we only supported MarketKind orders, so they should be identical
-                assert_eq!(current.balance.total, current.balance.free);
-
-                let order_value_quote = request.state.price * request.state.quantity.abs();
-                let order_fees_quote = order_value_quote * self.fees_percent;
-                let quote_required = order_value_quote + order_fees_quote;
-
-                let maybe_new_balance = current.balance.free - quote_required;
-
-                if maybe_new_balance >= Decimal::ZERO {
-                    current.balance.free = maybe_new_balance;
-                    current.balance.total = maybe_new_balance;
-                    current.time_exchange = time_exchange;
-
-                    Ok((current.clone(), AssetFees::quote_fees(order_fees_quote)))
-                } else {
-                    Err(ApiError::BalanceInsufficient(
-                        underlying.quote,
-                        format!(
-                            "Available Balance: {}, Required Balance inc. fees: {}",
-                            current.balance.free, quote_required
-                        ),
-                    ))
-                }
+            if request.state.quantity.is_zero() {
+                let error = ApiError::OrderRejected(format!(
+                    "MockExchange order quantity rounds to zero with step size: {}",
+                    spec.quantity.increment
+                ));
+                return (build_open_order_err_response(request, error), None);
             }
-            Side::Sell => {
-                // Selling Instrument requires sufficient BaseAsset Balance
-                let current = self
-                    .account
-                    .balance_mut(&underlying.quote)
-                    .expect("MockExchange has Balance for all configured Instrument assets");
-
-                // Currently we only supported MarketKind orders, so they should be identical
-                assert_eq!(current.balance.total, current.balance.free);
-
-                let order_value_base = request.state.quantity.abs();
-                let order_fees_base = order_value_base * self.fees_percent;
-                let base_required = order_value_base + order_fees_base;
-
-                let maybe_new_balance = current.balance.free - base_required;
-
-                if maybe_new_balance >= Decimal::ZERO {
-                    current.balance.free = maybe_new_balance;
-                    current.balance.total = maybe_new_balance;
-                    current.time_exchange = time_exchange;
-
-                    let fees_quote = order_fees_base * request.state.price;
-
-                    Ok((current.clone(), AssetFees::quote_fees(fees_quote)))
-                } else {
-                    Err(ApiError::BalanceInsufficient(
-                        underlying.quote,
-                        format!(
-                            "Available Balance: {}, Required Balance inc. fees: {}",
-                            current.balance.free, base_required
-                        ),
-                    ))
-                }
+        }
+
+        if self.position_mode == PositionMode::Hedge && request.state.position_side.is_none() {
+            let error = ApiError::OrderRejected(
+                "MockExchange hedge mode order requires a position side".to_string(),
+            );
+            return (build_open_order_err_response(request, error), None);
+        }
+
+        if self.is_reducing(&request.state) {
+            match self.reduce_only_quantity(&request) {
+                Ok(quantity) => request.state.quantity = quantity,
+                Err(error) => return (build_open_order_err_response(request, error), None),
             }
-        };
+        }
 
-        let (balance_snapshot, fees) = match balance_change_result {
-            Ok((balance_snapshot, fees)) => (Snapshot(balance_snapshot), fees),
+        let time_exchange = self.time_exchange();
+        let rolling_30d_volume = self.volume.volume(time_exchange);
+        let order_value_quote = request.state.price * request.state.quantity.abs();
+
+        let (balances, fees) = match self.settle_fill(&request, &underlying, rolling_30d_volume) {
+            Ok(debited) => debited,
             Err(error) => return (build_open_order_err_response(request, error), None),
         };
 
-        let order_id = self.order_id_sequence_fetch_add();
+        self.volume.record(time_exchange, order_value_quote);
+        self.account
+            .position_mut(&request.key.instrument)
+            .apply_fill(
+                self.position_mode,
+                request.state.side,
+                request.state.position_side,
+                request.state.quantity,
+            );
+
+        let order_id = self.order_id_generator.next_id(time_exchange);
         let trade_id = TradeId(order_id.0.clone());
 
         let order_response = Order {
@@ -405,7 +457,7 @@ impl MockExchange {
         };
 
         let notifications = OpenOrderNotifications {
-            balance: balance_snapshot,
+            balances,
             trade: Trade {
                 id: trade_id,
                 order_id: order_id.clone(),
@@ -422,6 +474,145 @@ impl MockExchange {
         (order_response, Some(notifications))
     }
 
+    /// Settle a fill against the account balances, returning the updated balances and the fees
+    /// denominated in the [`FeeAsset`] they were charged in.
+    ///
+    /// A [`Side::Buy`] debits the order value from the quote asset and credits the quantity to
+    /// the base asset, and a [`Side::Sell`] debits the quantity from the base asset and credits
+    /// the order value to the quote asset. Fees are charged in the configured [`FeeAsset`], netted
+    /// against any other change to the same asset. Every debited balance must be sufficient for
+    /// the fill to succeed.
+    fn settle_fill(
+        &mut self,
+        request: &OrderRequestOpen<ExchangeId, InstrumentNameExchange>,
+        underlying: &Underlying<AssetNameExchange>,
+        rolling_30d_volume: Decimal,
+    ) -> Result<
+        (
+            Vec<Snapshot<AssetBalance<AssetNameExchange>>>,
+            AssetFees<AssetNameExchange>,
+        ),
+        UnindexedApiError,
+    > {
+        let price = request.state.price;
+        let quantity = request.state.quantity.abs();
+        let order_value_quote = price * quantity;
+        let fees_quote =
+            self.fee_schedule
+                .fee_for(request.state.side, order_value_quote, rolling_30d_volume);
+
+        let mut changes = match request.state.side {
+            Side::Buy => vec![
+                (underlying.quote.clone(), -order_value_quote),
+                (underlying.base.clone(), quantity),
+            ],
+            Side::Sell => vec![
+                (underlying.base.clone(), -quantity),
+                (underlying.quote.clone(), order_value_quote),
+            ],
+        };
+
+        let Some((fee_asset, fees)) = self.fee_asset.convert(underlying, fees_quote, price) else {
+            return Err(ApiError::OrderRejected(format!(
+                "MockExchange cannot convert fees into {:?} at price: {price}",
+                self.fee_asset
+            )));
+        };
+
+        match changes.iter_mut().find(|(asset, _)| *asset == fee_asset) {
+            Some((_, change)) => *change -= fees,
+            None => changes.push((fee_asset.clone(), -fees)),
+        }
+
+        // Validate every debited Balance is sufficient before changing any
+        for (asset, change) in changes
+            .iter()
+            .filter(|(_, change)| change.is_sign_negative())
+        {
+            let current = self.account.balance_mut(asset).ok_or_else(|| {
+                ApiError::BalanceInsufficient(
+                    asset.clone(),
+                    "MockExchange has no Balance for asset".to_string(),
+                )
+            })?;
+
+            if current.balance.free < -*change {
+                return Err(ApiError::BalanceInsufficient(
+                    asset.clone(),
+                    format!(
+                        "Available Balance: {}, Required Balance inc. fees: {}",
+                        current.balance.free, -*change
+                    ),
+                ));
+            }
+        }
+
+        let time_exchange = self.time_exchange();
+        let balances = changes
+            .into_iter()
+            .map(|(asset, change)| {
+                if self.account.balance_mut(&asset).is_none() {
+                    self.account.upsert_balance(AssetBalance::new(
+                        asset.clone(),
+                        Balance::new(Decimal::ZERO, Decimal::ZERO),
+                        time_exchange,
+                    ));
+                }
+
+                let current = self
+                    .account
+                    .balance_mut(&asset)
+                    .expect("MockExchange validated Balance exists");
+
+                current.balance.free += change;
+                current.balance.total += change;
+                current.time_exchange = time_exchange;
+
+                Snapshot(current.clone())
+            })
+            .collect();
+
+        Ok((balances, AssetFees::new(fee_asset, fees)))
+    }
+
+    /// Determines if an order can only reduce a position, either because it is reduce-only, or
+    /// because it acts on the opposing [`PositionSide`] in [`PositionMode::Hedge`].
+    pub fn is_reducing(&self, request: &RequestOpen) -> bool {
+        request.reduce_only
+            || (self.position_mode == PositionMode::Hedge
+                && request
+                    .position_side
+                    .is_some_and(|side| side != PositionSide::increased_by(request.side)))
+    }
+
+    /// Determine the fill quantity of a reducing order, clamped to the current position so
+    /// the position is closed but never flipped.
+    ///
+    /// Orders that would not reduce the current position (eg/ flat, or on the same side as the
+    /// position in [`PositionMode::OneWay`]) are rejected.
+    pub fn reduce_only_quantity(
+        &self,
+        request: &OrderRequestOpen<ExchangeId, InstrumentNameExchange>,
+    ) -> Result<Decimal, UnindexedOrderError> {
+        let position = self.account.position(&request.key.instrument);
+        let reducible = position.reducible(
+            self.position_mode,
+            request.state.side,
+            request.state.position_side,
+        );
+
+        if reducible.is_zero() {
+            return Err(UnindexedOrderError::Rejected(ApiError::OrderRejected(
+                format!(
+                    "MockExchange reduce-only {} order would not reduce position: {position:?}",
+                    request.state.side
+                ),
+            )));
+        }
+
+        Ok(request.state.quantity.abs().min(reducible))
+    }
+
     pub fn validate_order_kind_supported(
         &self,
         order_kind: OrderKind,
@@ -435,6 +626,46 @@ impl MockExchange {
         }
     }
 
+    /// Apply a recorded [`UnindexedAccountEvent`] to the [`AccountState`], without notifying
+    /// the client.
+    ///
+    /// Enables seeding the [`MockExchange`] from a captured live session, so strategy & risk
+    /// logic can be tested under specific balance & position states.
+    ///
+    /// [`AccountEventKind::Trade`]s update positions & trade history, but not balances, since
+    /// exchanges report the associated balance changes via
+    /// [`AccountEventKind::BalanceSnapshot`]s. Hedge mode trades do not carry a [`PositionSide`],
+    /// so are assumed to increase the position on their own side. [`AccountEventKind::Funding`]
+    /// payments are ignored.
+    pub fn apply_account_event(&mut self, event: UnindexedAccountEvent) {
+        match event.kind {
+            AccountEventKind::Snapshot(snapshot) => {
+                self.account = AccountState::from(snapshot);
+            }
+            AccountEventKind::BalanceSnapshot(Snapshot(balance)) => {
+                self.account.upsert_balance(balance);
+            }
+            AccountEventKind::OrderSnapshot(Snapshot(order)) => {
+                self.account.upsert_order(order);
+            }
+            AccountEventKind::OrderCancelled(response) => {
+                if let Ok(cancelled) = response.state {
+                    self.account.cancel_open_order(&response.key.cid, cancelled);
+                }
+            }
+            AccountEventKind::Trade(trade) => {
+                self.account.position_mut(&trade.instrument).apply_fill(
+                    self.position_mode,
+                    trade.side,
+                    None,
+                    trade.quantity,
+                );
+                self.account.ack_trade(trade);
+            }
+            AccountEventKind::Funding(_) => {}
+        }
+    }
+
     pub fn find_instrument_data(
         &self,
         instrument: &InstrumentNameExchange,
@@ -447,12 +678,6 @@ impl MockExchange {
         })
     }
 
-    fn order_id_sequence_fetch_add(&mut self) -> OrderId {
-        let sequence = self.order_sequence;
-        self.order_sequence += 1;
-        OrderId::new(sequence.to_smolstr())
-    }
-
     fn build_account_event<Kind>(&self, kind: Kind) -> UnindexedAccountEvent
     where
         Kind: Into<AccountEventKind<ExchangeId, AssetNameExchange, InstrumentNameExchange>>,
@@ -484,6 +709,6 @@ where
 
 #[derive(Debug)]
 pub struct OpenOrderNotifications {
-    pub balance: Snapshot<AssetBalance<AssetNameExchange>>,
-    pub trade: Trade<QuoteAsset, InstrumentNameExchange>,
+    pub balances: Vec<Snapshot<AssetBalance<AssetNameExchange>>>,
+    pub trade: Trade<AssetNameExchange, InstrumentNameExchange>,
 }
@@ -0,0 +1,206 @@
+use crate::order::PositionSide;
+use barter_instrument::Side;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Position mode of a [`MockExchange`](super::MockExchange) account.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub enum PositionMode {
+    /// Single net position per instrument, where opposing fills offset each other.
+    #[default]
+    OneWay,
+
+    /// Independent long and short positions per instrument.
+    ///
+    /// Orders must specify the [`PositionSide`] they act on.
+    Hedge,
+}
+
+/// Long & short quantities of an instrument position held by a
+/// [`MockExchange`](super::MockExchange).
+///
+/// In [`PositionMode::OneWay`] at most one of `long` and `short` is non-zero.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub struct MockPosition {
+    pub long: Decimal,
+    pub short: Decimal,
+}
+
+impl MockPosition {
+    /// Net position quantity (positive if net long, negative if net short).
+    pub fn net(&self) -> Decimal {
+        self.long - self.short
+    }
+
+    /// Quantity of the provided [`PositionSide`].
+    pub fn quantity(&self, side: PositionSide) -> Decimal {
+        match side {
+            PositionSide::Long => self.long,
+            PositionSide::Short => self.short,
+        }
+    }
+
+    /// Maximum quantity a reducing order of the provided [`Side`] & [`PositionSide`] can fill
+    /// without increasing or flipping the position.
+    pub fn reducible(
+        &self,
+        mode: PositionMode,
+        side: Side,
+        position_side: Option<PositionSide>,
+    ) -> Decimal {
+        match mode {
+            PositionMode::OneWay => match side {
+                Side::Buy => (-self.net()).max(Decimal::ZERO),
+                Side::Sell => self.net().max(Decimal::ZERO),
+            },
+            PositionMode::Hedge => match position_side {
+                Some(position_side) if position_side != PositionSide::increased_by(side) => {
+                    self.quantity(position_side)
+                }
+                _ => Decimal::ZERO,
+            },
+        }
+    }
+
+    /// Update the [`MockPosition`] with a fill of the provided order [`Side`] & `quantity`.
+    ///
+    /// In [`PositionMode::Hedge`] the fill increases or reduces the provided [`PositionSide`],
+    /// defaulting to the side increased by the order if none is provided.
+    pub fn apply_fill(
+        &mut self,
+        mode: PositionMode,
+        side: Side,
+        position_side: Option<PositionSide>,
+        quantity: Decimal,
+    ) {
+        let quantity = quantity.abs();
+
+        match mode {
+            PositionMode::OneWay => {
+                let net = match side {
+                    Side::Buy => self.net() + quantity,
+                    Side::Sell => self.net() - quantity,
+                };
+                self.long = net.max(Decimal::ZERO);
+                self.short = (-net).max(Decimal::ZERO);
+            }
+            PositionMode::Hedge => {
+                let increased = PositionSide::increased_by(side);
+                let position_side = position_side.unwrap_or(increased);
+
+                let position = match position_side {
+                    PositionSide::Long => &mut self.long,
+                    PositionSide::Short => &mut self.short,
+                };
+
+                *position = if position_side == increased {
+                    *position + quantity
+                } else {
+                    (*position - quantity).max(Decimal::ZERO)
+                };
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_mock_position_apply_fill() {
+        struct TestCase {
+            mode: PositionMode,
+            fills: Vec<(Side, Option<PositionSide>, Decimal)>,
+            expected: MockPosition,
+        }
+
+        let position = |long, short| MockPosition { long, short };
+
+        let tests = vec![
+            TestCase {
+                // TC0: one-way mode opposing fills offset to a net short
+                mode: PositionMode::OneWay,
+                fills: vec![(Side::Buy, None, dec!(1)), (Side::Sell, None, dec!(3))],
+                expected: position(dec!(0), dec!(2)),
+            },
+            TestCase {
+                // TC1: hedge mode opposing fills open independent long & short positions
+                mode: PositionMode::Hedge,
+                fills: vec![
+                    (Side::Buy, Some(PositionSide::Long), dec!(1)),
+                    (Side::Sell, Some(PositionSide::Short), dec!(3)),
+                ],
+                expected: position(dec!(1), dec!(3)),
+            },
+            TestCase {
+                // TC2: hedge mode fills acting on the opposing position side reduce it
+                mode: PositionMode::Hedge,
+                fills: vec![
+                    (Side::Buy, Some(PositionSide::Long), dec!(2)),
+                    (Side::Sell, Some(PositionSide::Short), dec!(3)),
+                    (Side::Sell, Some(PositionSide::Long), dec!(1)),
+                    (Side::Buy, Some(PositionSide::Short), dec!(3)),
+                ],
+                expected: position(dec!(1), dec!(0)),
+            },
+            TestCase {
+                // TC3: hedge mode fills without a position side increase their own side
+                mode: PositionMode::Hedge,
+                fills: vec![(Side::Buy, None, dec!(1)), (Side::Sell, None, dec!(3))],
+                expected: position(dec!(1), dec!(3)),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut actual = MockPosition::default();
+            for (side, position_side, quantity) in test.fills {
+                actual.apply_fill(test.mode, side, position_side, quantity);
+            }
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_mock_position_reducible() {
+        let position = MockPosition {
+            long: dec!(1),
+            short: dec!(3),
+        };
+
+        assert_eq!(
+            position.reducible(PositionMode::Hedge, Side::Sell, Some(PositionSide::Long)),
+            dec!(1)
+        );
+        assert_eq!(
+            position.reducible(PositionMode::Hedge, Side::Buy, Some(PositionSide::Short)),
+            dec!(3)
+        );
+        assert_eq!(
+            position.reducible(PositionMode::Hedge, Side::Buy, Some(PositionSide::Long)),
+            dec!(0)
+        );
+        assert_eq!(
+            position.reducible(PositionMode::Hedge, Side::Buy, None),
+            dec!(0)
+        );
+
+        let position = MockPosition {
+            long: dec!(0),
+            short: dec!(2),
+        };
+        assert_eq!(
+            position.reducible(PositionMode::OneWay, Side::Sell, None),
+            dec!(0)
+        );
+        assert_eq!(
+            position.reducible(PositionMode::OneWay, Side::Buy, None),
+            dec!(2)
+        );
+    }
+}
@@ -10,9 +10,7 @@ use crate::{
     trade::Trade,
 };
 use barter_instrument::{
-    asset::{QuoteAsset, name::AssetNameExchange},
-    exchange::ExchangeId,
-    instrument::name::InstrumentNameExchange,
+    asset::name::AssetNameExchange, exchange::ExchangeId, instrument::name::InstrumentNameExchange,
 };
 use chrono::{DateTime, Utc};
 use tokio::sync::oneshot;
@@ -60,7 +58,7 @@ impl MockExchangeRequest {
 
     pub fn fetch_trades(
         time_request: DateTime<Utc>,
-        response_tx: oneshot::Sender<Vec<Trade<QuoteAsset, InstrumentNameExchange>>>,
+        response_tx: oneshot::Sender<Vec<Trade<AssetNameExchange, InstrumentNameExchange>>>,
         time_since: DateTime<Utc>,
     ) -> Self {
         Self::new(
@@ -72,6 +70,10 @@ impl MockExchangeRequest {
         )
     }
 
+    pub fn ping(time_request: DateTime<Utc>, response_tx: oneshot::Sender<()>) -> Self {
+        Self::new(time_request, MockExchangeRequestKind::Ping { response_tx })
+    }
+
     pub fn cancel_order(
         time_request: DateTime<Utc>,
         response_tx: oneshot::Sender<UnindexedOrderResponseCancel>,
@@ -115,9 +117,12 @@ pub enum MockExchangeRequestKind {
         response_tx: oneshot::Sender<Vec<Order<ExchangeId, InstrumentNameExchange, Open>>>,
     },
     FetchTrades {
-        response_tx: oneshot::Sender<Vec<Trade<QuoteAsset, InstrumentNameExchange>>>,
+        response_tx: oneshot::Sender<Vec<Trade<AssetNameExchange, InstrumentNameExchange>>>,
         time_since: DateTime<Utc>,
     },
+    Ping {
+        response_tx: oneshot::Sender<()>,
+    },
     CancelOrder {
         response_tx: oneshot::Sender<UnindexedOrderResponseCancel>,
         request: OrderRequestCancel<ExchangeId, InstrumentNameExchange>,
@@ -0,0 +1,54 @@
+use chrono::{DateTime, Utc};
+use derive_more::Constructor;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Funding payment settled on a perpetual futures position.
+///
+/// The `amount` is denominated in the quote asset of the instrument. A positive `amount` is
+/// received by the account, and a negative `amount` is paid by the account.
+#[derive(
+    Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct FundingPayment<InstrumentKey> {
+    pub instrument: InstrumentKey,
+    pub amount: Decimal,
+    pub time_exchange: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AccountEventKind, UnindexedAccountEvent};
+    use barter_instrument::{exchange::ExchangeId, instrument::name::InstrumentNameExchange};
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_de_account_event_funding() {
+        let input = r#"
+        {
+            "exchange": "binance_futures_usd",
+            "kind": {
+                "Funding": {
+                    "instrument": "BTCUSDT",
+                    "amount": "-1.25",
+                    "time_exchange": "2024-01-01T08:00:00Z"
+                }
+            }
+        }
+        "#;
+
+        let actual = serde_json::from_str::<UnindexedAccountEvent>(input).unwrap();
+
+        let expected = UnindexedAccountEvent::new(
+            ExchangeId::BinanceFuturesUsd,
+            AccountEventKind::Funding(FundingPayment::new(
+                InstrumentNameExchange::from("BTCUSDT"),
+                dec!(-1.25),
+                DateTime::from_timestamp(1704096000, 0).unwrap(),
+            )),
+        );
+
+        assert_eq!(actual, expected);
+    }
+}
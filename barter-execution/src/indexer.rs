@@ -6,16 +6,17 @@ use crate::{
         ApiError, ClientError, KeyError, OrderError, UnindexedApiError, UnindexedClientError,
         UnindexedOrderError,
     },
+    funding::FundingPayment,
     map::ExecutionInstrumentMap,
     order::{
         Order, OrderEvent, OrderKey, OrderSnapshot, UnindexedOrderKey, UnindexedOrderSnapshot,
-        request::OrderResponseCancel,
+        request::{OrderRequestOpen, OrderResponseCancel},
         state::{InactiveOrderState, OrderState, UnindexedOrderState},
     },
-    trade::Trade,
+    trade::{AssetFees, Trade},
 };
 use barter_instrument::{
-    asset::{AssetIndex, QuoteAsset, name::AssetNameExchange},
+    asset::{AssetIndex, name::AssetNameExchange},
     exchange::{ExchangeId, ExchangeIndex},
     index::error::IndexError,
     instrument::{InstrumentIndex, name::InstrumentNameExchange},
@@ -63,6 +64,7 @@ impl AccountEventIndexer {
                 AccountEventKind::OrderCancelled(self.order_response_cancel(response)?)
             }
             AccountEventKind::Trade(trade) => AccountEventKind::Trade(self.trade(trade)?),
+            AccountEventKind::Funding(funding) => AccountEventKind::Funding(self.funding(funding)?),
         };
 
         Ok(AccountEvent { exchange, kind })
@@ -251,6 +253,24 @@ impl AccountEventIndexer {
         })
     }
 
+    /// Map an [`OrderRequestOpen`] into its exchange representation, rounding the price and
+    /// quantity to the instrument tick & step sizes if an
+    /// [`InstrumentSpec`](barter_instrument::instrument::spec::InstrumentSpec) is available.
+    pub fn order_request_open(
+        &self,
+        order: &OrderRequestOpen,
+    ) -> Result<OrderRequestOpen<ExchangeId, &InstrumentNameExchange>, KeyError> {
+        let request = self.order_request(order)?;
+
+        Ok(match self.map.find_instrument_spec(order.key.instrument) {
+            Some(spec) => OrderEvent {
+                key: request.key,
+                state: request.state.round_to_spec(spec),
+            },
+            None => request,
+        })
+    }
+
     pub fn order_error(&self, error: UnindexedOrderError) -> Result<OrderError, IndexError> {
         Ok(match error {
             UnindexedOrderError::Connectivity(error) => OrderError::Connectivity(error),
@@ -264,13 +284,14 @@ impl AccountEventIndexer {
             UnindexedClientError::Api(error) => ClientError::Api(self.api_error(error)?),
             UnindexedClientError::AccountSnapshot(value) => ClientError::AccountSnapshot(value),
             UnindexedClientError::AccountStream(value) => ClientError::AccountStream(value),
+            UnindexedClientError::Unsupported(value) => ClientError::Unsupported(value),
         })
     }
 
     pub fn trade(
         &self,
-        trade: Trade<QuoteAsset, InstrumentNameExchange>,
-    ) -> Result<Trade<QuoteAsset, InstrumentIndex>, IndexError> {
+        trade: Trade<AssetNameExchange, InstrumentNameExchange>,
+    ) -> Result<Trade<AssetIndex, InstrumentIndex>, IndexError> {
         let Trade {
             id,
             order_id,
@@ -284,6 +305,7 @@ impl AccountEventIndexer {
         } = trade;
 
         let instrument_index = self.map.find_instrument_index(&instrument)?;
+        let fee_asset_index = self.map.find_asset_index(&fees.asset)?;
 
         Ok(Trade {
             id,
@@ -294,7 +316,26 @@ impl AccountEventIndexer {
             side,
             price,
             quantity,
-            fees,
+            fees: AssetFees::new(fee_asset_index, fees.fees),
+        })
+    }
+
+    pub fn funding(
+        &self,
+        funding: FundingPayment<InstrumentNameExchange>,
+    ) -> Result<FundingPayment<InstrumentIndex>, IndexError> {
+        let FundingPayment {
+            instrument,
+            amount,
+            time_exchange,
+        } = funding;
+
+        let instrument_index = self.map.find_instrument_index(&instrument)?;
+
+        Ok(FundingPayment {
+            instrument: instrument_index,
+            amount,
+            time_exchange,
         })
     }
 }
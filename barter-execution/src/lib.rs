@@ -25,11 +25,12 @@
 
 use crate::{
     balance::AssetBalance,
+    funding::FundingPayment,
     order::{Order, OrderSnapshot, request::OrderResponseCancel},
     trade::Trade,
 };
 use barter_instrument::{
-    asset::{AssetIndex, QuoteAsset, name::AssetNameExchange},
+    asset::{AssetIndex, name::AssetNameExchange},
     exchange::{ExchangeId, ExchangeIndex},
     instrument::{InstrumentIndex, name::InstrumentNameExchange},
 };
@@ -40,9 +41,12 @@ use order::state::OrderState;
 use serde::{Deserialize, Serialize};
 
 pub mod balance;
+pub mod batch;
 pub mod client;
+pub mod dead_mans_switch;
 pub mod error;
 pub mod exchange;
+pub mod funding;
 pub mod indexer;
 pub mod map;
 pub mod order;
@@ -97,7 +101,10 @@ pub enum AccountEventKind<ExchangeKey, AssetKey, InstrumentKey> {
     OrderCancelled(OrderResponseCancel<ExchangeKey, AssetKey, InstrumentKey>),
 
     /// [`Order<ExchangeKey, InstrumentKey, Open>`] partial or full-fill.
-    Trade(Trade<QuoteAsset, InstrumentKey>),
+    Trade(Trade<AssetKey, InstrumentKey>),
+
+    /// [`FundingPayment`] settled on a perpetual futures position.
+    Funding(FundingPayment<InstrumentKey>),
 }
 
 impl<ExchangeKey, AssetKey, InstrumentKey> AccountEvent<ExchangeKey, AssetKey, InstrumentKey>
@@ -4,7 +4,7 @@ use barter_instrument::{
     asset::{AssetIndex, name::AssetNameExchange},
     exchange::{ExchangeId, ExchangeIndex},
     index::{IndexedInstruments, error::IndexError},
-    instrument::{InstrumentIndex, name::InstrumentNameExchange},
+    instrument::{InstrumentIndex, name::InstrumentNameExchange, spec::InstrumentSpec},
 };
 use barter_integration::collection::{FnvIndexMap, FnvIndexSet};
 use fnv::FnvHashMap;
@@ -25,6 +25,7 @@ pub struct ExecutionInstrumentMap {
     pub instruments: FnvIndexSet<InstrumentNameExchange>,
     pub asset_names: FnvHashMap<AssetNameExchange, AssetIndex>,
     pub instrument_names: FnvHashMap<InstrumentNameExchange, InstrumentIndex>,
+    pub instrument_specs: FnvHashMap<InstrumentIndex, InstrumentSpec<AssetIndex>>,
 }
 
 impl ExecutionInstrumentMap {
@@ -46,6 +47,19 @@ impl ExecutionInstrumentMap {
                 .collect(),
             assets: assets.into_values().collect(),
             instruments: instruments.into_values().collect(),
+            instrument_specs: FnvHashMap::default(),
+        }
+    }
+
+    /// Provide the [`InstrumentSpec`]s used to round order requests to each instrument's tick &
+    /// step sizes before they are sent to the execution API.
+    pub fn with_instrument_specs<Iter>(self, instrument_specs: Iter) -> Self
+    where
+        Iter: IntoIterator<Item = (InstrumentIndex, InstrumentSpec<AssetIndex>)>,
+    {
+        Self {
+            instrument_specs: instrument_specs.into_iter().collect(),
+            ..self
         }
     }
 
@@ -105,6 +119,13 @@ impl ExecutionInstrumentMap {
             })
     }
 
+    pub fn find_instrument_spec(
+        &self,
+        instrument: InstrumentIndex,
+    ) -> Option<&InstrumentSpec<AssetIndex>> {
+        self.instrument_specs.get(&instrument)
+    }
+
     pub fn find_instrument_index(
         &self,
         instrument: &InstrumentNameExchange,
@@ -134,6 +155,13 @@ pub fn generate_execution_instrument_map(
             ))
         })?;
 
+    let instrument_specs = instruments
+        .instruments()
+        .iter()
+        .filter(|instrument| instrument.value.exchange.value == exchange)
+        .filter_map(|instrument| Some((instrument.key, instrument.value.spec?)))
+        .collect::<Vec<_>>();
+
     Ok(ExecutionInstrumentMap::new(
         Keyed::new(exchange_index, exchange),
         instruments
@@ -152,5 +180,6 @@ pub fn generate_execution_instrument_map(
                     .then_some((instrument.key, instrument.value.name_exchange.clone()))
             })
             .collect(),
-    ))
+    )
+    .with_instrument_specs(instrument_specs))
 }
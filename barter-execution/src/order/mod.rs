@@ -9,6 +9,7 @@ use barter_instrument::{
     exchange::{ExchangeId, ExchangeIndex},
     instrument::{InstrumentIndex, name::InstrumentNameExchange},
 };
+use chrono::{DateTime, Utc};
 use derive_more::{Constructor, Display};
 use id::ClientOrderId;
 use rust_decimal::Decimal;
@@ -164,10 +165,45 @@ pub enum OrderKind {
     Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Display,
 )]
 pub enum TimeInForce {
-    GoodUntilCancelled { post_only: bool },
+    GoodUntilCancelled {
+        post_only: bool,
+    },
     GoodUntilEndOfDay,
     FillOrKill,
     ImmediateOrCancel,
+    /// Rests until cancelled, or automatically cancelled once the provided expiry time passes.
+    GoodTillDate(DateTime<Utc>),
+}
+
+impl TimeInForce {
+    /// Returns the wall-clock expiry time, if this is a [`TimeInForce::GoodTillDate`].
+    pub fn expiry(&self) -> Option<DateTime<Utc>> {
+        match self {
+            Self::GoodTillDate(expiry) => Some(*expiry),
+            _ => None,
+        }
+    }
+}
+
+/// Side of a hedge mode position an order acts on.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Display,
+)]
+pub enum PositionSide {
+    Long,
+    Short,
+}
+
+impl PositionSide {
+    /// Returns the [`PositionSide`] increased by an order of the provided [`Side`].
+    ///
+    /// eg/ a [`Side::Buy`] increases a long, whereas a [`Side::Buy`] acting on a short closes it.
+    pub fn increased_by(side: Side) -> Self {
+        match side {
+            Side::Buy => Self::Long,
+            Side::Sell => Self::Short,
+        }
+    }
 }
 
 impl<ExchangeKey, InstrumentKey> From<&OrderRequestOpen<ExchangeKey, InstrumentKey>>
@@ -186,6 +222,8 @@ where
                     quantity,
                     kind,
                     time_in_force,
+                    reduce_only: _,
+                    position_side: _,
                 },
         } = value;
 
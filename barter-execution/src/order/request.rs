@@ -1,12 +1,12 @@
 use crate::{
     error::OrderError,
-    order::{OrderEvent, OrderKind, TimeInForce, id::OrderId, state::Cancelled},
+    order::{OrderEvent, OrderKind, PositionSide, TimeInForce, id::OrderId, state::Cancelled},
 };
 use barter_instrument::{
     Side,
     asset::{AssetIndex, name::AssetNameExchange},
     exchange::{ExchangeId, ExchangeIndex},
-    instrument::{InstrumentIndex, name::InstrumentNameExchange},
+    instrument::{InstrumentIndex, name::InstrumentNameExchange, spec::InstrumentSpec},
 };
 use derive_more::Constructor;
 use rust_decimal::Decimal;
@@ -18,6 +18,9 @@ pub type OrderRequestOpen<ExchangeKey = ExchangeIndex, InstrumentKey = Instrumen
 pub type OrderRequestCancel<ExchangeKey = ExchangeIndex, InstrumentKey = InstrumentIndex> =
     OrderEvent<RequestCancel, ExchangeKey, InstrumentKey>;
 
+pub type OrderRequestAmend<ExchangeKey = ExchangeIndex, InstrumentKey = InstrumentIndex> =
+    OrderEvent<RequestAmend, ExchangeKey, InstrumentKey>;
+
 pub type OrderResponseCancel<
     ExchangeKey = ExchangeIndex,
     AssetKey = AssetIndex,
@@ -36,6 +39,26 @@ pub struct RequestOpen {
     pub quantity: Decimal,
     pub kind: OrderKind,
     pub time_in_force: TimeInForce,
+
+    /// Only reduce an existing position, never increase it or flip it to the opposite side.
+    #[serde(default)]
+    pub reduce_only: bool,
+
+    /// [`PositionSide`] the order acts on, required when the account is in hedge mode.
+    #[serde(default)]
+    pub position_side: Option<PositionSide>,
+}
+
+impl RequestOpen {
+    /// Round the `price` to the [`InstrumentSpec`] tick size conservatively by [`Side`], and the
+    /// `quantity` down to the [`InstrumentSpec`] step size.
+    pub fn round_to_spec<AssetKey>(self, spec: &InstrumentSpec<AssetKey>) -> Self {
+        Self {
+            price: spec.price.round(self.price, self.side),
+            quantity: spec.quantity.round(self.quantity),
+            ..self
+        }
+    }
 }
 
 #[derive(
@@ -44,3 +67,24 @@ pub struct RequestOpen {
 pub struct RequestCancel {
     pub id: Option<OrderId>,
 }
+
+/// Request to amend the price and/or quantity of an open order.
+///
+/// The `side`, `kind`, `time_in_force`, `reduce_only` flag and `position_side` of the existing
+/// order are included so that exchanges without native order amendments can cancel and re-open
+/// the order.
+#[derive(
+    Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct RequestAmend {
+    pub id: Option<OrderId>,
+    pub side: Side,
+    pub kind: OrderKind,
+    pub time_in_force: TimeInForce,
+    pub new_price: Decimal,
+    pub new_quantity: Decimal,
+    #[serde(default)]
+    pub reduce_only: bool,
+    #[serde(default)]
+    pub position_side: Option<PositionSide>,
+}
@@ -7,6 +7,7 @@ use chrono::{DateTime, Utc};
 use derive_more::{Constructor, From};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 /// Convenient type alias for an [`OrderState`] keyed with [`AssetNameExchange`]
 /// and [`InstrumentNameExchange`].
@@ -41,6 +42,64 @@ impl<AssetKey, InstrumentKey> OrderState<AssetKey, InstrumentKey> {
         Self::Inactive(InactiveOrderState::Expired)
     }
 
+    /// Returns the [`OrderStateKind`] of this `OrderState`.
+    pub fn kind(&self) -> OrderStateKind {
+        match self {
+            Self::Active(ActiveOrderState::OpenInFlight(_)) => OrderStateKind::OpenInFlight,
+            Self::Active(ActiveOrderState::Open(_)) => OrderStateKind::Open,
+            Self::Active(ActiveOrderState::CancelInFlight(_)) => OrderStateKind::CancelInFlight,
+            Self::Inactive(InactiveOrderState::Cancelled(_)) => OrderStateKind::Cancelled,
+            Self::Inactive(InactiveOrderState::FullyFilled) => OrderStateKind::FullyFilled,
+            Self::Inactive(InactiveOrderState::OpenFailed(_)) => OrderStateKind::OpenFailed,
+            Self::Inactive(InactiveOrderState::Expired) => OrderStateKind::Expired,
+        }
+    }
+
+    /// Validate that transitioning from this `OrderState` to the `update` is legal, returning
+    /// the `update` if so.
+    ///
+    /// Legal transitions follow the order lifecycle (eg/ `OpenInFlight` -> `Open` ->
+    /// partially filled `Open` -> `FullyFilled`, or -> `Cancelled`), so updates from stale or
+    /// out-of-order account events are rejected:
+    /// - Inactive (ie/ finished) orders cannot transition.
+    /// - `Open` & `CancelInFlight` orders cannot transition back to `OpenInFlight`.
+    /// - Filled quantity cannot decrease.
+    pub fn transition(&self, update: Self) -> Result<Self, OrderStateError> {
+        let illegal = || OrderStateError::IllegalTransition {
+            from: self.kind(),
+            to: update.kind(),
+        };
+
+        let Self::Active(current) = self else {
+            return Err(illegal());
+        };
+
+        let Self::Active(next) = &update else {
+            return Ok(update);
+        };
+
+        if matches!(
+            (current, next),
+            (
+                ActiveOrderState::Open(_) | ActiveOrderState::CancelInFlight(_),
+                ActiveOrderState::OpenInFlight(_)
+            )
+        ) {
+            return Err(illegal());
+        }
+
+        if let (Some(current), Some(next)) = (current.open_meta(), next.open_meta())
+            && next.filled_quantity < current.filled_quantity
+        {
+            return Err(OrderStateError::FilledQuantityDecreased {
+                current: current.filled_quantity,
+                update: next.filled_quantity,
+            });
+        }
+
+        Ok(update)
+    }
+
     pub fn time_exchange(&self) -> Option<DateTime<Utc>> {
         match self {
             Self::Active(active) => match active {
@@ -58,6 +117,31 @@ impl<AssetKey, InstrumentKey> OrderState<AssetKey, InstrumentKey> {
     }
 }
 
+/// Variant of an [`OrderState`], without any associated data.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub enum OrderStateKind {
+    OpenInFlight,
+    Open,
+    CancelInFlight,
+    Cancelled,
+    FullyFilled,
+    OpenFailed,
+    Expired,
+}
+
+/// Illegal [`OrderState`] transition, typically caused by a stale or out-of-order update.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Error)]
+pub enum OrderStateError {
+    #[error("illegal OrderState transition: {from:?} -> {to:?}")]
+    IllegalTransition {
+        from: OrderStateKind,
+        to: OrderStateKind,
+    },
+
+    #[error("OrderState filled quantity decreased: {current} -> {update}")]
+    FilledQuantityDecreased { current: Decimal, update: Decimal },
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, From)]
 pub enum ActiveOrderState {
     OpenInFlight(OpenInFlight),
@@ -115,3 +199,105 @@ pub struct Cancelled {
     pub id: OrderId,
     pub time_exchange: DateTime<Utc>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn open(filled_quantity: Decimal) -> OrderState {
+        OrderState::active(Open::new(
+            OrderId::new("order"),
+            DateTime::<Utc>::MIN_UTC,
+            filled_quantity,
+        ))
+    }
+
+    fn cancelled() -> OrderState {
+        OrderState::inactive(Cancelled::new(
+            OrderId::new("order"),
+            DateTime::<Utc>::MIN_UTC,
+        ))
+    }
+
+    #[test]
+    fn test_order_state_transition() {
+        struct TestCase {
+            current: OrderState,
+            update: OrderState,
+            expected: Result<OrderState, OrderStateError>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: OpenInFlight -> Open
+                current: OrderState::active(OpenInFlight),
+                update: open(dec!(0)),
+                expected: Ok(open(dec!(0))),
+            },
+            TestCase {
+                // TC1: Open -> partially filled Open
+                current: open(dec!(0)),
+                update: open(dec!(0.5)),
+                expected: Ok(open(dec!(0.5))),
+            },
+            TestCase {
+                // TC2: partially filled Open -> FullyFilled
+                current: open(dec!(0.5)),
+                update: OrderState::fully_filled(),
+                expected: Ok(OrderState::fully_filled()),
+            },
+            TestCase {
+                // TC3: Open -> CancelInFlight -> Cancelled
+                current: OrderState::active(CancelInFlight::new(Some(Open::new(
+                    OrderId::new("order"),
+                    DateTime::<Utc>::MIN_UTC,
+                    dec!(0.5),
+                )))),
+                update: cancelled(),
+                expected: Ok(cancelled()),
+            },
+            TestCase {
+                // TC4: FullyFilled -> Open regression is illegal
+                current: OrderState::fully_filled(),
+                update: open(dec!(0.5)),
+                expected: Err(OrderStateError::IllegalTransition {
+                    from: OrderStateKind::FullyFilled,
+                    to: OrderStateKind::Open,
+                }),
+            },
+            TestCase {
+                // TC5: Cancelled -> FullyFilled is illegal
+                current: cancelled(),
+                update: OrderState::fully_filled(),
+                expected: Err(OrderStateError::IllegalTransition {
+                    from: OrderStateKind::Cancelled,
+                    to: OrderStateKind::FullyFilled,
+                }),
+            },
+            TestCase {
+                // TC6: Open -> OpenInFlight regression is illegal
+                current: open(dec!(0)),
+                update: OrderState::active(OpenInFlight),
+                expected: Err(OrderStateError::IllegalTransition {
+                    from: OrderStateKind::Open,
+                    to: OrderStateKind::OpenInFlight,
+                }),
+            },
+            TestCase {
+                // TC7: stale Open update with lower filled quantity is illegal
+                current: open(dec!(0.5)),
+                update: open(dec!(0.25)),
+                expected: Err(OrderStateError::FilledQuantityDecreased {
+                    current: dec!(0.5),
+                    update: dec!(0.25),
+                }),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.current.transition(test.update);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
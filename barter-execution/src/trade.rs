@@ -1,5 +1,5 @@
 use crate::order::id::{OrderId, StrategyId};
-use barter_instrument::{Side, asset::QuoteAsset};
+use barter_instrument::{Side, Underlying, asset::QuoteAsset};
 use chrono::{DateTime, Utc};
 use derive_more::{Constructor, From};
 use rust_decimal::Decimal;
@@ -35,6 +35,42 @@ impl<AssetKey, InstrumentKey> Trade<AssetKey, InstrumentKey> {
     pub fn value_quote(&self) -> Decimal {
         self.price * self.quantity.abs()
     }
+
+    /// Returns the [`Trade`] `fees` denominated in the quote asset of the provided [`Underlying`],
+    /// converting fees charged in the base asset at the [`Trade`] `price`.
+    ///
+    /// Returns `None` if the fees were charged in an asset outside the [`Underlying`] (eg/ an
+    /// exchange native token), since no price is available to convert them.
+    pub fn fees_quote(&self, underlying: &Underlying<AssetKey>) -> Option<Decimal>
+    where
+        AssetKey: PartialEq,
+    {
+        if self.fees.asset == underlying.quote {
+            Some(self.fees.fees)
+        } else if self.fees.asset == underlying.base {
+            Some(self.fees.fees * self.price)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a copy of the [`Trade`] with the provided `fees` denominated in the [`QuoteAsset`].
+    pub fn with_quote_fees(&self, fees: Decimal) -> Trade<QuoteAsset, InstrumentKey>
+    where
+        InstrumentKey: Clone,
+    {
+        Trade {
+            id: self.id.clone(),
+            order_id: self.order_id.clone(),
+            instrument: self.instrument.clone(),
+            strategy: self.strategy.clone(),
+            time_exchange: self.time_exchange,
+            side: self.side,
+            price: self.price,
+            quantity: self.quantity,
+            fees: AssetFees::quote_fees(fees),
+        }
+    }
 }
 
 impl<AssetKey, InstrumentKey> Display for Trade<AssetKey, InstrumentKey>
@@ -85,3 +121,57 @@ impl<AssetKey> Default for AssetFees<Option<AssetKey>> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_trade_fees_quote() {
+        struct TestCase {
+            fee_asset: &'static str,
+            expected: Option<Decimal>,
+        }
+
+        let cases = vec![
+            // TC0: fees charged in quote asset are returned as is
+            TestCase {
+                fee_asset: "usdt",
+                expected: Some(dec!(0.2)),
+            },
+            // TC1: fees charged in base asset are converted at the trade price
+            TestCase {
+                fee_asset: "btc",
+                expected: Some(dec!(20)),
+            },
+            // TC2: fees charged in an asset outside the underlying cannot be converted
+            TestCase {
+                fee_asset: "bnb",
+                expected: None,
+            },
+        ];
+
+        let underlying = Underlying::new("btc", "usdt");
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let trade = Trade {
+                id: TradeId::new("trade"),
+                order_id: OrderId::new("order"),
+                instrument: "btc_usdt",
+                strategy: StrategyId::new("strategy"),
+                time_exchange: DateTime::<Utc>::MIN_UTC,
+                side: Side::Buy,
+                price: dec!(100),
+                quantity: dec!(2),
+                fees: AssetFees::new(test.fee_asset, dec!(0.2)),
+            };
+
+            assert_eq!(
+                trade.fees_quote(&underlying),
+                test.expected,
+                "TC{index} failed"
+            );
+        }
+    }
+}
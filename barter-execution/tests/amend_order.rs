@@ -0,0 +1,96 @@
+use barter_execution::{
+    client::{ExecutionClient, mock::MockExecution},
+    exchange::mock::request::{MockExchangeRequest, MockExchangeRequestKind},
+    order::{
+        Order, OrderKey, OrderKind, TimeInForce,
+        id::{ClientOrderId, OrderId, StrategyId},
+        request::{OrderRequestAmend, RequestAmend, UnindexedOrderResponseCancel},
+        state::{Cancelled, Open},
+    },
+};
+use barter_instrument::{Side, exchange::ExchangeId, instrument::name::InstrumentNameExchange};
+use chrono::Utc;
+use rust_decimal::Decimal;
+use tokio::sync::{broadcast, mpsc};
+
+#[tokio::test]
+async fn test_amend_order_default_cancels_and_reopens() {
+    let (request_tx, mut request_rx) = mpsc::unbounded_channel();
+    let (_event_tx, event_rx) = broadcast::channel(16);
+
+    let client = MockExecution::new(ExchangeId::Mock, Utc::now, request_tx, event_rx);
+
+    // Respond to requests as an exchange without native amendments, recording the request order
+    let exchange = tokio::spawn(async move {
+        let mut received = Vec::new();
+
+        while let Some(MockExchangeRequest { kind, .. }) = request_rx.recv().await {
+            match kind {
+                MockExchangeRequestKind::CancelOrder {
+                    response_tx,
+                    request,
+                } => {
+                    received.push("cancel");
+                    let _ = response_tx.send(UnindexedOrderResponseCancel {
+                        key: request.key,
+                        state: Ok(Cancelled {
+                            id: OrderId::new("id1"),
+                            time_exchange: Utc::now(),
+                        }),
+                    });
+                }
+                MockExchangeRequestKind::OpenOrder {
+                    response_tx,
+                    request,
+                } => {
+                    received.push("open");
+                    let _ = response_tx.send(Order {
+                        key: request.key,
+                        side: request.state.side,
+                        price: request.state.price,
+                        quantity: request.state.quantity,
+                        kind: request.state.kind,
+                        time_in_force: request.state.time_in_force,
+                        state: Ok(Open {
+                            id: OrderId::new("id2"),
+                            time_exchange: Utc::now(),
+                            filled_quantity: Decimal::ZERO,
+                        }),
+                    });
+                    break;
+                }
+                _ => unreachable!("unexpected MockExchangeRequest"),
+            }
+        }
+
+        received
+    });
+
+    let instrument = InstrumentNameExchange::from("btc_usdt");
+    let request = OrderRequestAmend {
+        key: OrderKey {
+            exchange: ExchangeId::Mock,
+            instrument: &instrument,
+            strategy: StrategyId::new("strategy"),
+            cid: ClientOrderId::new("cid1"),
+        },
+        state: RequestAmend {
+            id: Some(OrderId::new("id1")),
+            side: Side::Buy,
+            kind: OrderKind::Limit,
+            time_in_force: TimeInForce::GoodUntilCancelled { post_only: true },
+            new_price: Decimal::TEN,
+            new_quantity: Decimal::TWO,
+            reduce_only: false,
+            position_side: None,
+        },
+    };
+
+    let amended = client.amend_order(request).await;
+
+    assert_eq!(exchange.await.unwrap(), vec!["cancel", "open"]);
+    assert_eq!(amended.key.cid, ClientOrderId::new("cid1"));
+    assert_eq!(amended.price, Decimal::TEN);
+    assert_eq!(amended.quantity, Decimal::TWO);
+    assert_eq!(amended.state.unwrap().id, OrderId::new("id2"));
+}
@@ -0,0 +1,128 @@
+mod common;
+
+use barter_execution::{
+    AccountEventKind, UnindexedAccountEvent,
+    balance::{AssetBalance, Balance},
+    exchange::mock::MockExchange,
+    order::{
+        OrderKey, OrderKind, TimeInForce,
+        id::{ClientOrderId, OrderId, StrategyId},
+        request::{OrderRequestOpen, RequestOpen},
+    },
+    trade::{AssetFees, Trade, TradeId},
+};
+use barter_instrument::{
+    Side, asset::name::AssetNameExchange, exchange::ExchangeId,
+    instrument::name::InstrumentNameExchange,
+};
+use barter_integration::snapshot::Snapshot;
+use chrono::{DateTime, Utc};
+use common::MockExchangeBuilder;
+use rust_decimal_macros::dec;
+
+fn build_exchange() -> MockExchange {
+    MockExchangeBuilder::default().build()
+}
+
+fn event(
+    kind: impl Into<AccountEventKind<ExchangeId, AssetNameExchange, InstrumentNameExchange>>,
+) -> UnindexedAccountEvent {
+    UnindexedAccountEvent {
+        exchange: ExchangeId::Mock,
+        kind: kind.into(),
+    }
+}
+
+#[test]
+fn test_apply_account_event_replays_balance_snapshot_then_trade() {
+    let mut exchange = build_exchange();
+    let time_exchange = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+    let instrument = InstrumentNameExchange::from("btc_usdt");
+
+    exchange.apply_account_event(event(AccountEventKind::BalanceSnapshot(Snapshot(
+        AssetBalance {
+            asset: AssetNameExchange::from("usdt"),
+            balance: Balance::new(dec!(5_000), dec!(5_000)),
+            time_exchange,
+        },
+    ))));
+
+    exchange.apply_account_event(event(Trade {
+        id: TradeId::new("trade"),
+        order_id: OrderId::new("order"),
+        instrument: instrument.clone(),
+        strategy: StrategyId::new("strategy"),
+        time_exchange,
+        side: Side::Buy,
+        price: dec!(10_000),
+        quantity: dec!(0.5),
+        fees: AssetFees::new(AssetNameExchange::from("usdt"), dec!(5)),
+    }));
+
+    // Balance is replaced by the snapshot, and unaffected by the subsequent Trade
+    let balances = exchange.account.balances().cloned().collect::<Vec<_>>();
+    assert_eq!(
+        balances,
+        vec![AssetBalance {
+            asset: AssetNameExchange::from("usdt"),
+            balance: Balance::new(dec!(5_000), dec!(5_000)),
+            time_exchange,
+        }]
+    );
+
+    // Trade opens a long position and is recorded in the trade history
+    assert_eq!(exchange.account.position(&instrument).net(), dec!(0.5));
+    assert_eq!(
+        exchange
+            .account
+            .trades(DateTime::<Utc>::MIN_UTC)
+            .map(|trade| trade.id.clone())
+            .collect::<Vec<_>>(),
+        vec![TradeId::new("trade")]
+    );
+}
+
+#[test]
+fn test_apply_account_event_locked_balance_snapshot_then_fill_order() {
+    let mut exchange = build_exchange();
+    let time_exchange = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+    let instrument = InstrumentNameExchange::from("btc_usdt");
+
+    // Replay a Balance with 2_000 usdt locked (eg/ by an open order on the real exchange)
+    exchange.apply_account_event(event(AccountEventKind::BalanceSnapshot(Snapshot(
+        AssetBalance {
+            asset: AssetNameExchange::from("usdt"),
+            balance: Balance::new(dec!(5_000), dec!(3_000)),
+            time_exchange,
+        },
+    ))));
+
+    // Buy 0.2 btc @ 10_000: cost 2_000 usdt settled against the free Balance
+    let (response, notifications) = exchange.open_order(OrderRequestOpen {
+        key: OrderKey {
+            exchange: ExchangeId::Mock,
+            instrument: instrument.clone(),
+            strategy: StrategyId::new("strategy"),
+            cid: ClientOrderId::new("cid"),
+        },
+        state: RequestOpen {
+            side: Side::Buy,
+            kind: OrderKind::Market,
+            time_in_force: TimeInForce::ImmediateOrCancel,
+            price: dec!(10_000),
+            quantity: dec!(0.2),
+            reduce_only: false,
+            position_side: None,
+        },
+    });
+    assert!(response.state.is_ok());
+    assert!(notifications.is_some());
+
+    let usdt = exchange
+        .account
+        .balances()
+        .find(|balance| balance.asset == AssetNameExchange::from("usdt"))
+        .unwrap();
+    assert_eq!(usdt.balance, Balance::new(dec!(3_000), dec!(1_000)));
+    assert_eq!(exchange.account.position(&instrument).net(), dec!(0.2));
+}
@@ -0,0 +1,81 @@
+mod common;
+
+use barter_execution::{
+    AccountEventKind,
+    client::ExecutionClient,
+    order::{
+        Order, OrderKey, OrderKind, TimeInForce, UnindexedOrderSnapshot,
+        id::{ClientOrderId, OrderId, StrategyId},
+        state::{ActiveOrderState, Open, OrderState},
+    },
+};
+use barter_instrument::{Side, exchange::ExchangeId, instrument::name::InstrumentNameExchange};
+use chrono::{DateTime, Utc};
+use common::MockExchangeBuilder;
+use futures::StreamExt;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn order_open(cid: &str, side: Side, time_exchange: DateTime<Utc>) -> UnindexedOrderSnapshot {
+    Order {
+        key: OrderKey {
+            exchange: ExchangeId::Mock,
+            instrument: InstrumentNameExchange::from("btc_usdt"),
+            strategy: StrategyId::new("strategy"),
+            cid: ClientOrderId::new(cid),
+        },
+        side,
+        price: dec!(10_000),
+        quantity: dec!(1),
+        kind: OrderKind::Limit,
+        time_in_force: TimeInForce::GoodUntilCancelled { post_only: false },
+        state: OrderState::Active(ActiveOrderState::Open(Open {
+            id: OrderId::new(cid),
+            time_exchange,
+            filled_quantity: Decimal::ZERO,
+        })),
+    }
+}
+
+#[tokio::test]
+async fn test_cancel_all_open_orders() {
+    let time_start = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+
+    let client = MockExchangeBuilder::default()
+        .orders([
+            order_open("1", Side::Buy, time_start),
+            order_open("2", Side::Buy, time_start),
+            order_open("3", Side::Sell, time_start),
+        ])
+        .spawn(move || time_start);
+    let mut account_stream = client.account_stream(&[], &[]).await.unwrap();
+
+    assert_eq!(client.fetch_open_orders().await.unwrap().len(), 3);
+
+    // Every open order is cancelled, returning the number cancelled
+    assert_eq!(client.cancel_all_open_orders().await.unwrap(), 3);
+    assert!(client.fetch_open_orders().await.unwrap().is_empty());
+
+    let mut cancelled = Vec::new();
+    for _ in 0..3 {
+        match account_stream.next().await.unwrap().kind {
+            AccountEventKind::OrderCancelled(response) => {
+                assert!(response.state.is_ok());
+                cancelled.push(response.key.cid);
+            }
+            other => panic!("unexpected AccountEventKind: {other:?}"),
+        }
+    }
+    cancelled.sort();
+    assert_eq!(
+        cancelled,
+        vec![
+            ClientOrderId::new("1"),
+            ClientOrderId::new("2"),
+            ClientOrderId::new("3")
+        ]
+    );
+
+    // Subsequent calls with no open orders cancel nothing
+    assert_eq!(client.cancel_all_open_orders().await.unwrap(), 0);
+}
@@ -1,5 +1,5 @@
 use barter_execution::{
-    exchange::mock::MockExchange,
+    exchange::mock::{MockExchange, fees::FeeAsset},
     client::mock::MockExecutionConfig,
     balance::{AssetBalance, Balance},
     order::{
@@ -25,7 +25,15 @@ fn build_exchange() -> MockExchange {
     let exchange = ExchangeId::Mock;
     let instrument = test_instrument(exchange, "btc", "usdt");
     let mut instruments = FnvHashMap::default();
-    instruments.insert(instrument.name_exchange.clone(), instrument.clone());
+    instruments.insert(
+        instrument.name_exchange.clone(),
+        instrument
+            .clone()
+            .map_asset_key_with_lookup(|asset| {
+                Ok::<_, ()>(asset.name_exchange.clone())
+            })
+            .unwrap(),
+    );
 
     let cid = ClientOrderId::new("cid1");
     let open_order = Order {
@@ -69,6 +77,8 @@ fn build_exchange() -> MockExchange {
             initial_state: snapshot,
             latency_ms: 0,
             fees_percent: Decimal::ZERO,
+            fee_schedule: None,
+            fee_asset: FeeAsset::Quote,
         },
         rx,
         event_tx,
@@ -76,8 +86,8 @@ fn build_exchange() -> MockExchange {
     )
 }
 
-#[test]
-fn test_cancel_order_success_and_fail() {
+#[tokio::test]
+async fn test_cancel_order_success_and_fail() {
     let mut exchange = build_exchange();
     let instrument_key = exchange.instruments.keys().next().unwrap().clone();
     let cid = ClientOrderId::new("cid1");
@@ -0,0 +1,160 @@
+#![allow(dead_code)]
+
+use barter_execution::{
+    InstrumentAccountSnapshot, UnindexedAccountEvent, UnindexedAccountSnapshot,
+    balance::{AssetBalance, Balance},
+    client::mock::{MockExecution, MockExecutionConfig},
+    exchange::mock::{
+        MockExchange,
+        fees::{FeeAsset, TieredFeeSchedule},
+        request::MockExchangeRequest,
+    },
+    order::UnindexedOrderSnapshot,
+};
+use barter_instrument::{
+    asset::name::AssetNameExchange,
+    exchange::ExchangeId,
+    instrument::{Instrument, spec::InstrumentSpec},
+    test_utils::instrument as test_instrument,
+};
+use chrono::Utc;
+use fnv::FnvHashMap;
+use rust_decimal::Decimal;
+use tokio::sync::{broadcast, mpsc};
+
+/// Builder for a [`MockExchange`] trading a single [`ExchangeId::Mock`] "btc_usdt" instrument,
+/// shared by the integration tests.
+///
+/// Defaults to no balances, no open orders, zero latency and zero fees.
+#[derive(Debug, Clone)]
+pub struct MockExchangeBuilder {
+    pub instrument: Instrument<ExchangeId, AssetNameExchange>,
+    pub balances: Vec<AssetBalance<AssetNameExchange>>,
+    pub orders: Vec<UnindexedOrderSnapshot>,
+    pub latency_ms: u64,
+    pub fees_percent: Decimal,
+    pub fee_schedule: Option<TieredFeeSchedule>,
+    pub fee_asset: FeeAsset,
+}
+
+impl Default for MockExchangeBuilder {
+    fn default() -> Self {
+        Self {
+            instrument: test_instrument(ExchangeId::Mock, "btc", "usdt")
+                .map_asset_key_with_lookup(|asset| Ok::<_, ()>(asset.name_exchange.clone()))
+                .unwrap(),
+            balances: vec![],
+            orders: vec![],
+            latency_ms: 0,
+            fees_percent: Decimal::ZERO,
+            fee_schedule: None,
+            fee_asset: FeeAsset::Quote,
+        }
+    }
+}
+
+impl MockExchangeBuilder {
+    /// Add an initial `asset` [`Balance`] with no funds locked.
+    pub fn balance(self, asset: &str, amount: Decimal) -> Self {
+        self.balance_locked(asset, amount, amount)
+    }
+
+    /// Add an initial `asset` [`Balance`] with `total - free` funds locked.
+    pub fn balance_locked(mut self, asset: &str, total: Decimal, free: Decimal) -> Self {
+        self.balances.push(AssetBalance {
+            asset: AssetNameExchange::from(asset),
+            balance: Balance::new(total, free),
+            time_exchange: Utc::now(),
+        });
+        self
+    }
+
+    /// Add initial open orders for the "btc_usdt" instrument.
+    pub fn orders(mut self, orders: impl IntoIterator<Item = UnindexedOrderSnapshot>) -> Self {
+        self.orders.extend(orders);
+        self
+    }
+
+    /// Set the [`InstrumentSpec`] of the "btc_usdt" instrument.
+    pub fn spec(mut self, spec: InstrumentSpec<AssetNameExchange>) -> Self {
+        self.instrument.spec = Some(spec);
+        self
+    }
+
+    pub fn latency_ms(self, latency_ms: u64) -> Self {
+        Self { latency_ms, ..self }
+    }
+
+    pub fn fees_percent(self, fees_percent: Decimal) -> Self {
+        Self {
+            fees_percent,
+            ..self
+        }
+    }
+
+    pub fn fee_schedule(self, fee_schedule: TieredFeeSchedule) -> Self {
+        Self {
+            fee_schedule: Some(fee_schedule),
+            ..self
+        }
+    }
+
+    pub fn fee_asset(self, fee_asset: FeeAsset) -> Self {
+        Self { fee_asset, ..self }
+    }
+
+    /// Construct the [`MockExecutionConfig`] of the [`MockExchange`].
+    pub fn config(&self) -> MockExecutionConfig {
+        let instruments = if self.orders.is_empty() {
+            vec![]
+        } else {
+            vec![InstrumentAccountSnapshot {
+                instrument: self.instrument.name_exchange.clone(),
+                orders: self.orders.clone(),
+            }]
+        };
+
+        MockExecutionConfig {
+            mocked_exchange: ExchangeId::Mock,
+            initial_state: UnindexedAccountSnapshot {
+                exchange: ExchangeId::Mock,
+                balances: self.balances.clone(),
+                instruments,
+            },
+            latency_ms: self.latency_ms,
+            fees_percent: self.fees_percent,
+            fee_schedule: self.fee_schedule.clone(),
+            fee_asset: self.fee_asset.clone(),
+        }
+    }
+
+    /// Build the [`MockExchange`], driven directly rather than via request channels.
+    pub fn build(self) -> MockExchange {
+        let (_request_tx, request_rx) = mpsc::unbounded_channel();
+        let (event_tx, _event_rx) = broadcast::channel(16);
+        self.build_with_channels(request_rx, event_tx)
+    }
+
+    /// Build the [`MockExchange`] using the provided request and event channels.
+    pub fn build_with_channels(
+        self,
+        request_rx: mpsc::UnboundedReceiver<MockExchangeRequest>,
+        event_tx: broadcast::Sender<UnindexedAccountEvent>,
+    ) -> MockExchange {
+        let config = self.config();
+        let instruments =
+            FnvHashMap::from_iter([(self.instrument.name_exchange.clone(), self.instrument)]);
+
+        MockExchange::new(config, request_rx, event_tx, instruments)
+    }
+
+    /// Spawn the [`MockExchange`] run loop, returning a [`MockExecution`] client connected to it.
+    pub fn spawn<FnTime>(self, clock: FnTime) -> MockExecution<FnTime> {
+        let (request_tx, request_rx) = mpsc::unbounded_channel();
+        let (event_tx, event_rx) = broadcast::channel(16);
+
+        tokio::spawn(self.build_with_channels(request_rx, event_tx).run());
+
+        MockExecution::new(ExchangeId::Mock, clock, request_tx, event_rx)
+    }
+}
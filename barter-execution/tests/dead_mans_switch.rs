@@ -0,0 +1,101 @@
+mod common;
+
+use barter_execution::{
+    client::{ExecutionClient, mock::MockExecution},
+    dead_mans_switch::DeadMansSwitch,
+    order::{
+        Order, OrderKey, OrderKind, TimeInForce, UnindexedOrderSnapshot,
+        id::{ClientOrderId, OrderId, StrategyId},
+        state::{ActiveOrderState, Open, OrderState},
+    },
+};
+use barter_instrument::{Side, exchange::ExchangeId, instrument::name::InstrumentNameExchange};
+use chrono::{DateTime, Utc};
+use common::MockExchangeBuilder;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use std::time::Duration;
+use tokio::time::Instant;
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+fn order_open(cid: &str, time_exchange: DateTime<Utc>) -> UnindexedOrderSnapshot {
+    Order {
+        key: OrderKey {
+            exchange: ExchangeId::Mock,
+            instrument: InstrumentNameExchange::from("btc_usdt"),
+            strategy: StrategyId::new("strategy"),
+            cid: ClientOrderId::new(cid),
+        },
+        side: Side::Buy,
+        price: dec!(10_000),
+        quantity: dec!(1),
+        kind: OrderKind::Limit,
+        time_in_force: TimeInForce::GoodUntilCancelled { post_only: false },
+        state: OrderState::Active(ActiveOrderState::Open(Open {
+            id: OrderId::new(cid),
+            time_exchange,
+            filled_quantity: Decimal::ZERO,
+        })),
+    }
+}
+
+/// Run a [`MockExchange`] with two resting orders, returning an associated [`MockExecution`].
+fn build_client() -> MockExecution<impl Fn() -> DateTime<Utc> + Clone + Sync> {
+    let time_start = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+
+    MockExchangeBuilder::default()
+        .orders([order_open("1", time_start), order_open("2", time_start)])
+        .spawn(move || time_start)
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_dead_mans_switch_cancels_all_open_orders_if_keepalive_missed() {
+    let client = build_client();
+    let (switch, handle) = DeadMansSwitch::new(client.clone(), TIMEOUT);
+
+    let start = Instant::now();
+    let switch = async {
+        let cancelled = switch.run().await;
+        (cancelled, start.elapsed())
+    };
+
+    // Send keepalives within the timeout, then stop while keeping the handle alive
+    let heartbeats = async {
+        for _ in 0..3 {
+            tokio::time::sleep(TIMEOUT / 2).await;
+            handle.keepalive();
+        }
+        tokio::time::sleep(TIMEOUT * 2).await;
+    };
+
+    let ((cancelled, elapsed), ()) = tokio::join!(switch, heartbeats);
+
+    // Triggered one timeout after the last keepalive
+    assert_eq!(cancelled.unwrap(), Some(2));
+    assert_eq!(elapsed, TIMEOUT / 2 * 3 + TIMEOUT);
+    assert!(client.fetch_open_orders().await.unwrap().is_empty());
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_dead_mans_switch_cancels_all_open_orders_if_handle_dropped() {
+    let client = build_client();
+    let (switch, handle) = DeadMansSwitch::new(client.clone(), TIMEOUT);
+
+    drop(handle);
+
+    assert_eq!(switch.run().await.unwrap(), Some(2));
+    assert!(client.fetch_open_orders().await.unwrap().is_empty());
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_dead_mans_switch_disarmed_does_not_cancel_orders() {
+    let client = build_client();
+    let (switch, handle) = DeadMansSwitch::new(client.clone(), TIMEOUT);
+
+    handle.keepalive();
+    handle.disarm();
+
+    assert_eq!(switch.run().await.unwrap(), None);
+    assert_eq!(client.fetch_open_orders().await.unwrap().len(), 2);
+}
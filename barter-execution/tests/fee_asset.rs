@@ -0,0 +1,140 @@
+mod common;
+
+use barter_execution::{
+    error::{ApiError, OrderError},
+    exchange::mock::{MockExchange, fees::FeeAsset},
+    order::{
+        OrderKey, OrderKind, TimeInForce,
+        id::{ClientOrderId, StrategyId},
+        request::{OrderRequestOpen, RequestOpen},
+    },
+    trade::AssetFees,
+};
+use barter_instrument::{
+    Side, asset::name::AssetNameExchange, exchange::ExchangeId,
+    instrument::name::InstrumentNameExchange,
+};
+use common::MockExchangeBuilder;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn build_exchange(fee_asset: FeeAsset, balance_bnb: Decimal) -> MockExchange {
+    MockExchangeBuilder::default()
+        .balance("usdt", dec!(10_000))
+        .balance("btc", dec!(1))
+        .balance("bnb", balance_bnb)
+        .fees_percent(dec!(0.001))
+        .fee_asset(fee_asset)
+        .build()
+}
+
+fn request_buy(exchange: &MockExchange) -> OrderRequestOpen<ExchangeId, InstrumentNameExchange> {
+    OrderRequestOpen {
+        key: OrderKey {
+            exchange: ExchangeId::Mock,
+            instrument: exchange.instruments.keys().next().unwrap().clone(),
+            strategy: StrategyId::new("strategy"),
+            cid: ClientOrderId::new("cid"),
+        },
+        state: RequestOpen {
+            side: Side::Buy,
+            kind: OrderKind::Market,
+            time_in_force: TimeInForce::ImmediateOrCancel,
+            price: dec!(100),
+            quantity: dec!(2),
+            reduce_only: false,
+            position_side: None,
+        },
+    }
+}
+
+fn free_balance(exchange: &mut MockExchange, asset: &str) -> Decimal {
+    exchange
+        .account
+        .balance_mut(&AssetNameExchange::from(asset))
+        .unwrap()
+        .balance
+        .free
+}
+
+#[test]
+fn test_fees_charged_in_configured_fee_asset() {
+    struct TestCase {
+        fee_asset: FeeAsset,
+        expected_balances: Vec<(&'static str, Decimal)>,
+        expected_fees: AssetFees<AssetNameExchange>,
+    }
+
+    let cases = vec![
+        // TC0: fees charged in quote asset alongside order value
+        TestCase {
+            fee_asset: FeeAsset::Quote,
+            expected_balances: vec![("usdt", dec!(9_799.8)), ("btc", dec!(3))],
+            expected_fees: AssetFees::new(AssetNameExchange::from("usdt"), dec!(0.2)),
+        },
+        // TC1: fees charged in base asset, converted at the fill price: 0.2 / 100, and netted
+        // against the bought quantity
+        TestCase {
+            fee_asset: FeeAsset::Base,
+            expected_balances: vec![("usdt", dec!(9_800)), ("btc", dec!(2.998))],
+            expected_fees: AssetFees::new(AssetNameExchange::from("btc"), dec!(0.002)),
+        },
+        // TC2: fees charged in native asset, converted at the configured price: 0.2 / 50
+        TestCase {
+            fee_asset: FeeAsset::Native {
+                asset: AssetNameExchange::from("bnb"),
+                price: dec!(50),
+            },
+            expected_balances: vec![
+                ("usdt", dec!(9_800)),
+                ("btc", dec!(3)),
+                ("bnb", dec!(0.996)),
+            ],
+            expected_fees: AssetFees::new(AssetNameExchange::from("bnb"), dec!(0.004)),
+        },
+    ];
+
+    for (index, test) in cases.into_iter().enumerate() {
+        let mut exchange = build_exchange(test.fee_asset, dec!(1));
+
+        let (response, notifications) = exchange.open_order(request_buy(&exchange));
+        assert!(response.state.is_ok(), "TC{index} failed");
+
+        let notifications = notifications.unwrap();
+        let actual = notifications
+            .balances
+            .iter()
+            .map(|snapshot| (snapshot.0.asset.name().as_str(), snapshot.0.balance.free))
+            .collect::<Vec<_>>();
+        assert_eq!(actual, test.expected_balances, "TC{index} failed");
+
+        // Trade fees are denominated in the asset they were charged in
+        assert_eq!(
+            notifications.trade.fees, test.expected_fees,
+            "TC{index} failed"
+        );
+    }
+}
+
+#[test]
+fn test_order_rejected_if_fee_asset_balance_insufficient() {
+    let mut exchange = build_exchange(
+        FeeAsset::Native {
+            asset: AssetNameExchange::from("bnb"),
+            price: dec!(50),
+        },
+        dec!(0.001),
+    );
+
+    let (response, notifications) = exchange.open_order(request_buy(&exchange));
+    assert!(matches!(
+        response.state,
+        Err(OrderError::Rejected(ApiError::BalanceInsufficient(asset, _)))
+            if asset == AssetNameExchange::from("bnb")
+    ));
+    assert!(notifications.is_none());
+
+    // No balance is debited if any debit is insufficient
+    assert_eq!(free_balance(&mut exchange, "usdt"), dec!(10_000));
+    assert_eq!(free_balance(&mut exchange, "bnb"), dec!(0.001));
+}
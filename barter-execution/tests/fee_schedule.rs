@@ -0,0 +1,72 @@
+mod common;
+
+use barter_execution::{
+    exchange::mock::{
+        MockExchange,
+        fees::{FeeTier, TieredFeeSchedule},
+    },
+    order::{
+        OrderKey, OrderKind, TimeInForce,
+        id::{ClientOrderId, StrategyId},
+        request::{OrderRequestOpen, RequestOpen},
+    },
+};
+use barter_instrument::{Side, exchange::ExchangeId, instrument::name::InstrumentNameExchange};
+use common::MockExchangeBuilder;
+use rust_decimal_macros::dec;
+
+fn build_exchange() -> MockExchange {
+    MockExchangeBuilder::default()
+        .balance("usdt", dec!(1_000_000))
+        .fee_schedule(TieredFeeSchedule::new(vec![
+            FeeTier::new(dec!(0), dec!(0.001)),
+            FeeTier::new(dec!(25_000), dec!(0.0005)),
+        ]))
+        .build()
+}
+
+fn request_buy(
+    exchange: &MockExchange,
+    cid: &str,
+) -> OrderRequestOpen<ExchangeId, InstrumentNameExchange> {
+    OrderRequestOpen {
+        key: OrderKey {
+            exchange: ExchangeId::Mock,
+            instrument: exchange.instruments.keys().next().unwrap().clone(),
+            strategy: StrategyId::new("strategy"),
+            cid: ClientOrderId::new(cid),
+        },
+        state: RequestOpen {
+            side: Side::Buy,
+            kind: OrderKind::Market,
+            time_in_force: TimeInForce::ImmediateOrCancel,
+            price: dec!(10_000),
+            quantity: dec!(1),
+            reduce_only: false,
+            position_side: None,
+        },
+    }
+}
+
+#[test]
+fn test_tiered_fee_schedule_lowers_fees_after_crossing_volume_tier() {
+    let mut exchange = build_exchange();
+
+    // Each fill is 10k notional, so rolling volume prior to each fill is 0, 10k, 20k & 30k
+    let fees = ["cid1", "cid2", "cid3", "cid4"]
+        .into_iter()
+        .map(|cid| {
+            let request = request_buy(&exchange, cid);
+            let (response, notifications) = exchange.open_order(request);
+            assert!(response.state.is_ok());
+            notifications.unwrap().trade.fees.fees
+        })
+        .collect::<Vec<_>>();
+
+    // Fills prior to crossing 25k rolling volume pay the base tier, subsequent fills pay less
+    assert_eq!(fees, vec![dec!(10), dec!(10), dec!(10), dec!(5)]);
+    assert_eq!(
+        exchange.volume.volume(exchange.time_exchange()),
+        dec!(40_000)
+    );
+}
@@ -0,0 +1,99 @@
+mod common;
+
+use barter_execution::{
+    AccountEventKind,
+    client::{ExecutionClient, mock::MockExecution},
+    order::{
+        Order, OrderKey, OrderKind, TimeInForce, UnindexedOrderSnapshot,
+        id::{ClientOrderId, OrderId, StrategyId},
+        state::{ActiveOrderState, Open, OrderState},
+    },
+};
+use barter_instrument::{Side, exchange::ExchangeId, instrument::name::InstrumentNameExchange};
+use chrono::{DateTime, TimeDelta, Utc};
+use common::MockExchangeBuilder;
+use futures::StreamExt;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn order_open(
+    cid: &str,
+    time_in_force: TimeInForce,
+    time_exchange: DateTime<Utc>,
+) -> UnindexedOrderSnapshot {
+    Order {
+        key: OrderKey {
+            exchange: ExchangeId::Mock,
+            instrument: InstrumentNameExchange::from("btc_usdt"),
+            strategy: StrategyId::new("strategy"),
+            cid: ClientOrderId::new(cid),
+        },
+        side: Side::Buy,
+        price: dec!(10_000),
+        quantity: dec!(1),
+        kind: OrderKind::Limit,
+        time_in_force,
+        state: OrderState::Active(ActiveOrderState::Open(Open {
+            id: OrderId::new(cid),
+            time_exchange,
+            filled_quantity: Decimal::ZERO,
+        })),
+    }
+}
+
+#[tokio::test]
+async fn test_good_till_date_order_cancelled_at_expiry() {
+    let time_start = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+    let expiry = time_start + TimeDelta::minutes(1);
+
+    let client_before_expiry = MockExchangeBuilder::default()
+        .orders([
+            order_open("gtd", TimeInForce::GoodTillDate(expiry), time_start),
+            order_open(
+                "gtc",
+                TimeInForce::GoodUntilCancelled { post_only: false },
+                time_start,
+            ),
+        ])
+        .spawn(move || time_start);
+    let mut account_stream = client_before_expiry.account_stream(&[], &[]).await.unwrap();
+
+    // Prior to expiry, both orders are resting
+    let mut open = client_before_expiry
+        .fetch_open_orders()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|order| order.key.cid)
+        .collect::<Vec<_>>();
+    open.sort();
+    assert_eq!(
+        open,
+        vec![ClientOrderId::new("gtc"), ClientOrderId::new("gtd")]
+    );
+
+    // Once the exchange time passes the expiry, only the GoodTillDate order is cancelled
+    let client_after_expiry = MockExecution::new(
+        ExchangeId::Mock,
+        move || expiry + TimeDelta::seconds(1),
+        client_before_expiry.request_tx.clone(),
+        client_before_expiry.event_rx.resubscribe(),
+    );
+    let open = client_after_expiry
+        .fetch_open_orders()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|order| order.key.cid)
+        .collect::<Vec<_>>();
+    assert_eq!(open, vec![ClientOrderId::new("gtc")]);
+
+    let event = account_stream.next().await.unwrap();
+    match event.kind {
+        AccountEventKind::OrderCancelled(response) => {
+            assert_eq!(response.key.cid, ClientOrderId::new("gtd"));
+            assert!(response.state.is_ok());
+        }
+        other => panic!("unexpected AccountEventKind: {other:?}"),
+    }
+}
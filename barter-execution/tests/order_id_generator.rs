@@ -0,0 +1,95 @@
+mod common;
+
+use barter_execution::{
+    exchange::mock::{MockExchange, id::OrderIdGenerator},
+    order::{
+        OrderKey, OrderKind, TimeInForce,
+        id::{ClientOrderId, OrderId, StrategyId},
+        request::{OrderRequestOpen, RequestOpen},
+    },
+    trade::TradeId,
+};
+use barter_instrument::{Side, exchange::ExchangeId, instrument::name::InstrumentNameExchange};
+use chrono::{DateTime, Utc};
+use common::MockExchangeBuilder;
+use rust_decimal_macros::dec;
+
+/// Deterministic [`OrderIdGenerator`] producing prefixed ids.
+#[derive(Debug, Default)]
+struct PrefixedOrderIdGenerator {
+    count: u64,
+}
+
+impl OrderIdGenerator for PrefixedOrderIdGenerator {
+    fn next_id(&mut self, _: DateTime<Utc>) -> OrderId {
+        self.count += 1;
+        OrderId::new(format!("paper-{}", self.count))
+    }
+}
+
+fn build_exchange() -> MockExchange {
+    MockExchangeBuilder::default()
+        .balance("usdt", dec!(1_000_000))
+        .build()
+}
+
+fn request_buy(
+    exchange: &MockExchange,
+    cid: &str,
+) -> OrderRequestOpen<ExchangeId, InstrumentNameExchange> {
+    OrderRequestOpen {
+        key: OrderKey {
+            exchange: ExchangeId::Mock,
+            instrument: exchange.instruments.keys().next().unwrap().clone(),
+            strategy: StrategyId::new("strategy"),
+            cid: ClientOrderId::new(cid),
+        },
+        state: RequestOpen {
+            side: Side::Buy,
+            kind: OrderKind::Market,
+            time_in_force: TimeInForce::ImmediateOrCancel,
+            price: dec!(10_000),
+            quantity: dec!(1),
+            reduce_only: false,
+            position_side: None,
+        },
+    }
+}
+
+fn open_ids(exchange: &mut MockExchange) -> Vec<(OrderId, TradeId)> {
+    ["cid1", "cid2"]
+        .into_iter()
+        .map(|cid| {
+            let request = request_buy(exchange, cid);
+            let (response, notifications) = exchange.open_order(request);
+            (response.state.unwrap().id, notifications.unwrap().trade.id)
+        })
+        .collect()
+}
+
+#[test]
+fn test_default_order_id_generator_uses_sequence() {
+    let mut exchange = build_exchange();
+
+    assert_eq!(
+        open_ids(&mut exchange),
+        vec![
+            (OrderId::new("0"), TradeId::new("0")),
+            (OrderId::new("1"), TradeId::new("1")),
+        ]
+    );
+}
+
+#[test]
+fn test_injected_order_id_generator_assigns_order_and_trade_ids() {
+    let mut exchange =
+        build_exchange().with_order_id_generator(PrefixedOrderIdGenerator::default());
+
+    assert_eq!(
+        open_ids(&mut exchange),
+        vec![
+            (OrderId::new("paper-1"), TradeId::new("paper-1")),
+            (OrderId::new("paper-2"), TradeId::new("paper-2")),
+        ]
+    );
+}
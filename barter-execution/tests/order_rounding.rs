@@ -0,0 +1,157 @@
+mod common;
+
+use barter_execution::{
+    error::{ApiError, OrderError},
+    exchange::mock::MockExchange,
+    indexer::AccountEventIndexer,
+    map::generate_execution_instrument_map,
+    order::{
+        OrderKey, OrderKind, TimeInForce,
+        id::{ClientOrderId, StrategyId},
+        request::{OrderRequestOpen, RequestOpen},
+    },
+};
+use barter_instrument::{
+    Side,
+    exchange::{ExchangeId, ExchangeIndex},
+    index::IndexedInstruments,
+    instrument::{
+        InstrumentIndex,
+        name::InstrumentNameExchange,
+        spec::{
+            InstrumentSpec, InstrumentSpecNotional, InstrumentSpecPrice, InstrumentSpecQuantity,
+            OrderQuantityUnits,
+        },
+    },
+    test_utils::instrument as test_instrument,
+};
+use chrono::{DateTime, Utc};
+use common::MockExchangeBuilder;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use std::sync::Arc;
+
+fn spec<AssetKey>() -> InstrumentSpec<AssetKey> {
+    InstrumentSpec {
+        price: InstrumentSpecPrice::new(dec!(0.5), dec!(0.5)),
+        quantity: InstrumentSpecQuantity::new(OrderQuantityUnits::Contract, dec!(0.01), dec!(0.01)),
+        notional: InstrumentSpecNotional::new(Decimal::ZERO),
+    }
+}
+
+fn build_exchange() -> MockExchange {
+    MockExchangeBuilder::default()
+        .balance("usdt", dec!(1_000_000))
+        .balance("btc", dec!(10))
+        .spec(spec())
+        .build()
+}
+
+fn request(
+    exchange: &MockExchange,
+    side: Side,
+    price: Decimal,
+    quantity: Decimal,
+) -> OrderRequestOpen<ExchangeId, InstrumentNameExchange> {
+    OrderRequestOpen {
+        key: OrderKey {
+            exchange: ExchangeId::Mock,
+            instrument: exchange.instruments.keys().next().unwrap().clone(),
+            strategy: StrategyId::new("strategy"),
+            cid: ClientOrderId::new("cid"),
+        },
+        state: RequestOpen {
+            side,
+            kind: OrderKind::Market,
+            time_in_force: TimeInForce::ImmediateOrCancel,
+            price,
+            quantity,
+            reduce_only: false,
+            position_side: None,
+        },
+    }
+}
+
+#[test]
+fn test_open_order_rounds_price_to_tick_and_quantity_to_step() {
+    let mut exchange = build_exchange();
+
+    // Buy price is rounded down to tick, quantity down to step
+    let buy = request(&exchange, Side::Buy, dec!(10_000.3), dec!(1.23456));
+    let (response, _) = exchange.open_order(buy);
+    assert!(response.state.is_ok());
+    assert_eq!(response.price, dec!(10_000.0));
+    assert_eq!(response.quantity, dec!(1.23));
+
+    // Sell price is rounded up to tick
+    let sell = request(&exchange, Side::Sell, dec!(10_000.3), dec!(0.019));
+    let (response, _) = exchange.open_order(sell);
+    assert!(response.state.is_ok());
+    assert_eq!(response.price, dec!(10_000.5));
+    assert_eq!(response.quantity, dec!(0.01));
+}
+
+#[test]
+fn test_open_order_rejected_if_quantity_rounds_to_zero() {
+    let mut exchange = build_exchange();
+
+    // Quantity below the step size truncates to zero
+    let buy = request(&exchange, Side::Buy, dec!(10_000), dec!(0.009));
+    let (response, notifications) = exchange.open_order(buy);
+
+    assert!(matches!(
+        response.state,
+        Err(OrderError::Rejected(ApiError::OrderRejected(_)))
+    ));
+    assert!(notifications.is_none());
+    assert!(
+        exchange
+            .account
+            .trades(DateTime::<Utc>::MIN_UTC)
+            .next()
+            .is_none()
+    );
+}
+
+#[test]
+fn test_order_request_open_rounded_before_sending_to_client() {
+    let mut instrument = test_instrument(ExchangeId::Mock, "btc", "usdt");
+    instrument.spec = Some(spec());
+    let instruments = IndexedInstruments::builder()
+        .add_instrument(instrument)
+        .build();
+
+    let indexer = AccountEventIndexer::new(Arc::new(
+        generate_execution_instrument_map(&instruments, ExchangeId::Mock).unwrap(),
+    ));
+
+    let request = |side| OrderRequestOpen {
+        key: OrderKey {
+            exchange: ExchangeIndex(0),
+            instrument: InstrumentIndex(0),
+            strategy: StrategyId::new("strategy"),
+            cid: ClientOrderId::new("cid"),
+        },
+        state: RequestOpen {
+            side,
+            kind: OrderKind::Limit,
+            time_in_force: TimeInForce::GoodUntilCancelled { post_only: false },
+            price: dec!(10_000.3),
+            quantity: dec!(1.23456),
+            reduce_only: false,
+            position_side: None,
+        },
+    };
+
+    let buy = indexer.order_request_open(&request(Side::Buy)).unwrap();
+    assert_eq!(
+        buy.key.instrument,
+        &InstrumentNameExchange::from("btc_usdt")
+    );
+    assert_eq!(buy.state.price, dec!(10_000.0));
+    assert_eq!(buy.state.quantity, dec!(1.23));
+
+    let sell = indexer.order_request_open(&request(Side::Sell)).unwrap();
+    assert_eq!(sell.state.price, dec!(10_000.5));
+    assert_eq!(sell.state.quantity, dec!(1.23));
+}
@@ -0,0 +1,18 @@
+mod common;
+
+use barter_execution::client::ExecutionClient;
+use chrono::Utc;
+use common::MockExchangeBuilder;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_ping_measures_round_trip_latency() {
+    const LATENCY_MS: u64 = 20;
+
+    let client = MockExchangeBuilder::default()
+        .latency_ms(LATENCY_MS)
+        .spawn(Utc::now);
+
+    let latency = client.ping().await.unwrap();
+    assert!(latency >= Duration::from_millis(LATENCY_MS));
+}
@@ -0,0 +1,156 @@
+mod common;
+
+use barter_execution::{
+    error::{ApiError, OrderError},
+    exchange::mock::{
+        MockExchange,
+        position::{MockPosition, PositionMode},
+    },
+    order::{
+        OrderKey, OrderKind, PositionSide, TimeInForce,
+        id::{ClientOrderId, StrategyId},
+        request::{OrderRequestOpen, RequestOpen},
+    },
+};
+use barter_instrument::{Side, exchange::ExchangeId, instrument::name::InstrumentNameExchange};
+use common::MockExchangeBuilder;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn build_exchange(position_mode: PositionMode) -> MockExchange {
+    MockExchangeBuilder::default()
+        .balance("usdt", dec!(1_000_000))
+        .balance("btc", dec!(1_000_000))
+        .build()
+        .with_position_mode(position_mode)
+}
+
+fn request(
+    exchange: &MockExchange,
+    cid: &str,
+    side: Side,
+    quantity: Decimal,
+    position_side: Option<PositionSide>,
+) -> OrderRequestOpen<ExchangeId, InstrumentNameExchange> {
+    OrderRequestOpen {
+        key: OrderKey {
+            exchange: ExchangeId::Mock,
+            instrument: exchange.instruments.keys().next().unwrap().clone(),
+            strategy: StrategyId::new("strategy"),
+            cid: ClientOrderId::new(cid),
+        },
+        state: RequestOpen {
+            side,
+            kind: OrderKind::Market,
+            time_in_force: TimeInForce::ImmediateOrCancel,
+            price: dec!(100),
+            quantity,
+            reduce_only: false,
+            position_side,
+        },
+    }
+}
+
+fn open_long_and_short(position_mode: PositionMode) -> MockPosition {
+    let mut exchange = build_exchange(position_mode);
+    let instrument = exchange.instruments.keys().next().unwrap().clone();
+
+    let (long, short) = match position_mode {
+        PositionMode::OneWay => (None, None),
+        PositionMode::Hedge => (Some(PositionSide::Long), Some(PositionSide::Short)),
+    };
+
+    for request in [
+        request(&exchange, "cid1", Side::Buy, dec!(2), long),
+        request(&exchange, "cid2", Side::Sell, dec!(3), short),
+    ] {
+        let (response, _) = exchange.open_order(request);
+        assert!(response.state.is_ok());
+    }
+
+    exchange.account.position(&instrument)
+}
+
+#[test]
+fn test_hedge_mode_tracks_independent_long_and_short_positions() {
+    let position = open_long_and_short(PositionMode::Hedge);
+
+    assert_eq!(
+        position,
+        MockPosition {
+            long: dec!(2),
+            short: dec!(3),
+        }
+    );
+    assert_eq!(position.net(), dec!(-1));
+}
+
+#[test]
+fn test_one_way_mode_tracks_net_position() {
+    let position = open_long_and_short(PositionMode::OneWay);
+
+    assert_eq!(
+        position,
+        MockPosition {
+            long: dec!(0),
+            short: dec!(1),
+        }
+    );
+    assert_eq!(position.net(), dec!(-1));
+}
+
+#[test]
+fn test_hedge_mode_order_acting_on_opposing_position_side_closes_it() {
+    let mut exchange = build_exchange(PositionMode::Hedge);
+    let instrument = exchange.instruments.keys().next().unwrap().clone();
+    let long = Some(PositionSide::Long);
+    let short = Some(PositionSide::Short);
+
+    exchange.open_order(request(&exchange, "cid1", Side::Buy, dec!(2), long));
+    exchange.open_order(request(&exchange, "cid2", Side::Sell, dec!(3), short));
+
+    // Sell acting on the long closes it, clamped to the long quantity, leaving the short intact
+    let (response, _) = exchange.open_order(request(&exchange, "cid3", Side::Sell, dec!(5), long));
+    assert_eq!(response.state.unwrap().filled_quantity, dec!(2));
+    assert_eq!(
+        exchange.account.position(&instrument),
+        MockPosition {
+            long: dec!(0),
+            short: dec!(3),
+        }
+    );
+
+    // Buy acting on the short reduces it without opening a long
+    let (response, _) = exchange.open_order(request(&exchange, "cid4", Side::Buy, dec!(1), short));
+    assert_eq!(response.state.unwrap().filled_quantity, dec!(1));
+    assert_eq!(
+        exchange.account.position(&instrument),
+        MockPosition {
+            long: dec!(0),
+            short: dec!(2),
+        }
+    );
+
+    // Sell acting on the closed long is rejected
+    let (response, _) = exchange.open_order(request(&exchange, "cid5", Side::Sell, dec!(1), long));
+    assert!(response.state.is_err());
+}
+
+#[test]
+fn test_hedge_mode_order_without_position_side_is_rejected() {
+    let mut exchange = build_exchange(PositionMode::Hedge);
+    let instrument = exchange.instruments.keys().next().unwrap().clone();
+
+    let (response, notifications) =
+        exchange.open_order(request(&exchange, "cid1", Side::Buy, dec!(1), None));
+
+    assert!(matches!(
+        response.state,
+        Err(OrderError::Rejected(ApiError::OrderRejected(_)))
+    ));
+    assert!(notifications.is_none());
+    assert_eq!(
+        exchange.account.position(&instrument),
+        MockPosition::default()
+    );
+}
@@ -0,0 +1,100 @@
+mod common;
+
+use barter_execution::{
+    error::{ApiError, OrderError},
+    exchange::mock::MockExchange,
+    order::{
+        OrderKey, OrderKind, TimeInForce,
+        id::{ClientOrderId, StrategyId},
+        request::{OrderRequestOpen, RequestOpen},
+    },
+};
+use barter_instrument::{Side, exchange::ExchangeId, instrument::name::InstrumentNameExchange};
+use common::MockExchangeBuilder;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn build_exchange() -> MockExchange {
+    MockExchangeBuilder::default()
+        .balance("usdt", dec!(1_000_000))
+        .balance("btc", dec!(1_000_000))
+        .build()
+}
+
+fn request(
+    exchange: &MockExchange,
+    cid: &str,
+    side: Side,
+    quantity: Decimal,
+    reduce_only: bool,
+) -> OrderRequestOpen<ExchangeId, InstrumentNameExchange> {
+    OrderRequestOpen {
+        key: OrderKey {
+            exchange: ExchangeId::Mock,
+            instrument: exchange.instruments.keys().next().unwrap().clone(),
+            strategy: StrategyId::new("strategy"),
+            cid: ClientOrderId::new(cid),
+        },
+        state: RequestOpen {
+            side,
+            kind: OrderKind::Market,
+            time_in_force: TimeInForce::ImmediateOrCancel,
+            price: dec!(100),
+            quantity,
+            reduce_only,
+            position_side: None,
+        },
+    }
+}
+
+#[test]
+fn test_reduce_only_sell_larger_than_long_position_only_closes_position() {
+    let mut exchange = build_exchange();
+    let instrument = exchange.instruments.keys().next().unwrap().clone();
+
+    // Open a long position
+    let (response, notifications) =
+        exchange.open_order(request(&exchange, "cid1", Side::Buy, dec!(2), false));
+    assert!(response.state.is_ok());
+    exchange.account.ack_trade(notifications.unwrap().trade);
+    assert_eq!(exchange.account.position(&instrument).net(), dec!(2));
+
+    // Reduce-only sell larger than the long position is clamped to the position
+    let (response, notifications) =
+        exchange.open_order(request(&exchange, "cid2", Side::Sell, dec!(5), true));
+    let open = response.state.unwrap();
+    assert_eq!(open.filled_quantity, dec!(2));
+    assert_eq!(response.quantity, dec!(2));
+
+    let trade = notifications.unwrap().trade;
+    assert_eq!(trade.quantity, dec!(2));
+    exchange.account.ack_trade(trade);
+    assert_eq!(exchange.account.position(&instrument).net(), Decimal::ZERO);
+}
+
+#[test]
+fn test_reduce_only_order_rejected_if_it_would_not_reduce_position() {
+    let mut exchange = build_exchange();
+
+    // Reduce-only order on a flat position is rejected
+    let (response, notifications) =
+        exchange.open_order(request(&exchange, "cid1", Side::Sell, dec!(1), true));
+    assert!(matches!(
+        response.state,
+        Err(OrderError::Rejected(ApiError::OrderRejected(_)))
+    ));
+    assert!(notifications.is_none());
+
+    // Reduce-only order on the same side as the position is rejected
+    let (_, notifications) =
+        exchange.open_order(request(&exchange, "cid2", Side::Buy, dec!(1), false));
+    exchange.account.ack_trade(notifications.unwrap().trade);
+
+    let (response, notifications) =
+        exchange.open_order(request(&exchange, "cid3", Side::Buy, dec!(1), true));
+    assert!(matches!(
+        response.state,
+        Err(OrderError::Rejected(ApiError::OrderRejected(_)))
+    ));
+    assert!(notifications.is_none());
+}
@@ -0,0 +1,148 @@
+mod common;
+
+use barter_execution::{
+    error::{ApiError, OrderError},
+    exchange::mock::MockExchange,
+    order::{
+        OrderKey, OrderKind, TimeInForce,
+        id::{ClientOrderId, StrategyId},
+        request::{OrderRequestOpen, RequestOpen},
+    },
+};
+use barter_instrument::{
+    Side, asset::name::AssetNameExchange, exchange::ExchangeId,
+    instrument::name::InstrumentNameExchange,
+};
+use common::MockExchangeBuilder;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+fn build_exchange(balances: &[(&str, Decimal)]) -> MockExchange {
+    balances
+        .iter()
+        .fold(
+            MockExchangeBuilder::default(),
+            |builder, (asset, amount)| builder.balance(asset, *amount),
+        )
+        .fees_percent(dec!(0.001))
+        .build()
+}
+
+fn request(
+    exchange: &MockExchange,
+    side: Side,
+    quantity: Decimal,
+) -> OrderRequestOpen<ExchangeId, InstrumentNameExchange> {
+    OrderRequestOpen {
+        key: OrderKey {
+            exchange: ExchangeId::Mock,
+            instrument: exchange.instruments.keys().next().unwrap().clone(),
+            strategy: StrategyId::new("strategy"),
+            cid: ClientOrderId::new("cid"),
+        },
+        state: RequestOpen {
+            side,
+            kind: OrderKind::Market,
+            time_in_force: TimeInForce::ImmediateOrCancel,
+            price: dec!(100),
+            quantity,
+            reduce_only: false,
+            position_side: None,
+        },
+    }
+}
+
+fn balance(exchange: &MockExchange, asset: &str) -> Option<Decimal> {
+    exchange
+        .account
+        .balances()
+        .find(|balance| balance.asset == AssetNameExchange::from(asset))
+        .map(|balance| balance.balance.free)
+}
+
+#[test]
+fn test_sell_debits_base_and_credits_quote_minus_fees() {
+    let mut exchange = build_exchange(&[("usdt", dec!(1_000)), ("btc", dec!(5))]);
+
+    // Sell 2 btc @ 100: proceeds 200 usdt, fees 0.2 usdt
+    let request = request(&exchange, Side::Sell, dec!(2));
+    let (response, notifications) = exchange.open_order(request);
+    assert!(response.state.is_ok());
+
+    let notifications = notifications.unwrap();
+    assert_eq!(notifications.trade.fees.fees, dec!(0.2));
+    assert_eq!(notifications.balances.len(), 2);
+
+    assert_eq!(balance(&exchange, "btc"), Some(dec!(3)));
+    assert_eq!(balance(&exchange, "usdt"), Some(dec!(1_199.8)));
+}
+
+#[test]
+fn test_buy_credits_base_balance_when_none_exists() {
+    let mut exchange = build_exchange(&[("usdt", dec!(1_000))]);
+
+    // Buy 2 btc @ 100: cost 200 usdt, fees 0.2 usdt
+    let request = request(&exchange, Side::Buy, dec!(2));
+    let (response, _) = exchange.open_order(request);
+    assert!(response.state.is_ok());
+
+    assert_eq!(balance(&exchange, "btc"), Some(dec!(2)));
+    assert_eq!(balance(&exchange, "usdt"), Some(dec!(799.8)));
+}
+
+#[test]
+fn test_sell_rejected_with_insufficient_base_balance() {
+    let mut exchange = build_exchange(&[("usdt", dec!(1_000)), ("btc", dec!(1))]);
+
+    let request = request(&exchange, Side::Sell, dec!(2));
+    let (response, notifications) = exchange.open_order(request);
+
+    assert!(matches!(
+        response.state,
+        Err(OrderError::Rejected(ApiError::BalanceInsufficient(asset, _)))
+            if asset == AssetNameExchange::from("btc")
+    ));
+    assert!(notifications.is_none());
+    assert_eq!(balance(&exchange, "btc"), Some(dec!(1)));
+    assert_eq!(balance(&exchange, "usdt"), Some(dec!(1_000)));
+}
+
+#[test]
+fn test_buy_settles_against_free_balance_with_funds_locked() {
+    let mut exchange = MockExchangeBuilder::default()
+        .balance_locked("usdt", dec!(1_500), dec!(1_000))
+        .fees_percent(dec!(0.001))
+        .build();
+
+    // Buy 2 btc @ 100: cost 200 usdt, fees 0.2 usdt, with 500 usdt remaining locked
+    let request = request(&exchange, Side::Buy, dec!(2));
+    let (response, _) = exchange.open_order(request);
+    assert!(response.state.is_ok());
+
+    let usdt = exchange
+        .account
+        .balances()
+        .find(|balance| balance.asset == AssetNameExchange::from("usdt"))
+        .unwrap();
+    assert_eq!(usdt.balance.free, dec!(799.8));
+    assert_eq!(usdt.balance.total, dec!(1_299.8));
+}
+
+#[test]
+fn test_buy_rejected_when_only_locked_balance_covers_cost() {
+    let mut exchange = MockExchangeBuilder::default()
+        .balance_locked("usdt", dec!(1_500), dec!(100))
+        .fees_percent(dec!(0.001))
+        .build();
+
+    let request = request(&exchange, Side::Buy, dec!(2));
+    let (response, notifications) = exchange.open_order(request);
+
+    assert!(matches!(
+        response.state,
+        Err(OrderError::Rejected(ApiError::BalanceInsufficient(asset, _)))
+            if asset == AssetNameExchange::from("usdt")
+    ));
+    assert!(notifications.is_none());
+    assert_eq!(balance(&exchange, "usdt"), Some(dec!(100)));
+}
@@ -100,6 +100,22 @@ impl Asset {
             name_exchange,
         }
     }
+
+    /// Construct a new [`Self`] from the [`AssetNameExchange`] used by the provided
+    /// [`ExchangeId`], resolving any exchange specific alias via
+    /// [`AssetNameExchange::to_internal`].
+    ///
+    /// For example, [`ExchangeId::Kraken`] "XBT" has an [`AssetNameInternal`] of "btc".
+    pub fn new_from_exchange_id<S>(exchange: ExchangeId, name_exchange: S) -> Self
+    where
+        S: Into<AssetNameExchange>,
+    {
+        let name_exchange = name_exchange.into();
+        Self {
+            name_internal: name_exchange.to_internal(exchange),
+            name_exchange,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Display)]
@@ -1,3 +1,4 @@
+use crate::exchange::ExchangeId;
 use derive_more::Display;
 use serde::Serialize;
 use smol_str::{SmolStr, StrExt};
@@ -92,6 +93,34 @@ impl AssetNameExchange {
     pub fn name(&self) -> &SmolStr {
         &self.0
     }
+
+    /// Normalise this exchange asset name into the [`AssetNameInternal`] used across exchanges,
+    /// resolving any exchange specific aliases.
+    ///
+    /// For example, [`ExchangeId::Kraken`] "XBT" is normalised to "btc".
+    pub fn to_internal(&self, exchange: ExchangeId) -> AssetNameInternal {
+        match asset_name_alias(exchange, &self.0) {
+            Some(internal) => AssetNameInternal::new(internal),
+            None => AssetNameInternal::new(self.0.clone()),
+        }
+    }
+}
+
+/// Lookup the [`AssetNameInternal`] name of an exchange specific asset name alias, ignoring case.
+fn asset_name_alias(exchange: ExchangeId, name: &str) -> Option<&'static str> {
+    let name = name.to_ascii_uppercase();
+
+    match (exchange, name.as_str()) {
+        (ExchangeId::Kraken, "XBT" | "XXBT") => Some("btc"),
+        (ExchangeId::Kraken, "XDG" | "XXDG") => Some("doge"),
+        (ExchangeId::Kraken, "XETH") => Some("eth"),
+        (ExchangeId::Kraken, "XLTC") => Some("ltc"),
+        (ExchangeId::Kraken, "XXRP") => Some("xrp"),
+        (ExchangeId::Kraken, "ZUSD") => Some("usd"),
+        (ExchangeId::Kraken, "ZEUR") => Some("eur"),
+        (ExchangeId::Bitmex, "XBT") => Some("btc"),
+        _ => None,
+    }
 }
 
 impl From<&str> for AssetNameExchange {
@@ -133,3 +162,61 @@ impl<'de> serde::de::Deserialize<'de> for AssetNameExchange {
         Ok(AssetNameExchange::new(name))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_asset_name_exchange_to_internal() {
+        struct TestCase {
+            exchange: ExchangeId,
+            input: AssetNameExchange,
+            expected: AssetNameInternal,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: Kraken XBT is normalised to btc
+                exchange: ExchangeId::Kraken,
+                input: AssetNameExchange::from("XBT"),
+                expected: AssetNameInternal::from("btc"),
+            },
+            TestCase {
+                // TC1: Kraken legacy prefixed XXBT is normalised to btc
+                exchange: ExchangeId::Kraken,
+                input: AssetNameExchange::from("XXBT"),
+                expected: AssetNameInternal::from("btc"),
+            },
+            TestCase {
+                // TC2: Kraken XDG is normalised to doge, ignoring case
+                exchange: ExchangeId::Kraken,
+                input: AssetNameExchange::from("xdg"),
+                expected: AssetNameInternal::from("doge"),
+            },
+            TestCase {
+                // TC3: Kraken asset without an alias is lowercased
+                exchange: ExchangeId::Kraken,
+                input: AssetNameExchange::from("SOL"),
+                expected: AssetNameInternal::from("sol"),
+            },
+            TestCase {
+                // TC4: aliases are exchange specific
+                exchange: ExchangeId::BinanceSpot,
+                input: AssetNameExchange::from("XDG"),
+                expected: AssetNameInternal::from("xdg"),
+            },
+            TestCase {
+                // TC5: normal asset is lowercased
+                exchange: ExchangeId::BinanceSpot,
+                input: AssetNameExchange::from("BTC"),
+                expected: AssetNameInternal::from("btc"),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.input.to_internal(test.exchange);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
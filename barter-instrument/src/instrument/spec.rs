@@ -1,3 +1,4 @@
+use crate::Side;
 use derive_more::Constructor;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
@@ -19,6 +20,15 @@ pub struct InstrumentSpecPrice {
     pub tick_size: Decimal,
 }
 
+impl InstrumentSpecPrice {
+    /// Round an order `price` to the `tick_size`, conservatively for the order [`Side`].
+    ///
+    /// See [`round_to_tick`].
+    pub fn round(&self, price: Decimal, side: Side) -> Decimal {
+        round_to_tick(price, self.tick_size, side)
+    }
+}
+
 #[derive(
     Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
 )]
@@ -28,6 +38,15 @@ pub struct InstrumentSpecQuantity<AssetKey> {
     pub increment: Decimal,
 }
 
+impl<AssetKey> InstrumentSpecQuantity<AssetKey> {
+    /// Round an order `quantity` down to the `increment`.
+    ///
+    /// See [`round_to_step`].
+    pub fn round(&self, quantity: Decimal) -> Decimal {
+        round_to_step(quantity, self.increment)
+    }
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
 pub enum OrderQuantityUnits<AssetKey> {
     Asset(AssetKey),
@@ -41,3 +60,128 @@ pub enum OrderQuantityUnits<AssetKey> {
 pub struct InstrumentSpecNotional {
     pub min: Decimal,
 }
+
+/// Round a `price` to a multiple of the `tick` size, conservatively for the order [`Side`].
+///
+/// [`Side::Buy`] prices are rounded down and [`Side::Sell`] prices are rounded up, so the rounded
+/// price is never more aggressive than the requested price.
+///
+/// A non-positive `tick` returns the `price` unchanged.
+pub fn round_to_tick(price: Decimal, tick: Decimal, side: Side) -> Decimal {
+    if tick <= Decimal::ZERO {
+        return price;
+    }
+
+    let ticks = price / tick;
+    let ticks = match side {
+        Side::Buy => ticks.floor(),
+        Side::Sell => ticks.ceil(),
+    };
+
+    ticks * tick
+}
+
+/// Round a `quantity` towards zero to a multiple of the `step` size, so the rounded quantity
+/// never exceeds the requested quantity.
+///
+/// A non-positive `step` returns the `quantity` unchanged.
+pub fn round_to_step(quantity: Decimal, step: Decimal) -> Decimal {
+    if step <= Decimal::ZERO {
+        return quantity;
+    }
+
+    (quantity / step).trunc() * step
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_round_to_tick() {
+        struct TestCase {
+            price: Decimal,
+            tick: Decimal,
+            side: Side,
+            expected: Decimal,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: buy price rounded down to tick
+                price: dec!(100.17),
+                tick: dec!(0.05),
+                side: Side::Buy,
+                expected: dec!(100.15),
+            },
+            TestCase {
+                // TC1: sell price rounded up to tick
+                price: dec!(100.17),
+                tick: dec!(0.05),
+                side: Side::Sell,
+                expected: dec!(100.20),
+            },
+            TestCase {
+                // TC2: price already on tick is unchanged
+                price: dec!(100.15),
+                tick: dec!(0.05),
+                side: Side::Sell,
+                expected: dec!(100.15),
+            },
+            TestCase {
+                // TC3: zero tick returns price unchanged
+                price: dec!(100.17),
+                tick: dec!(0),
+                side: Side::Buy,
+                expected: dec!(100.17),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = round_to_tick(test.price, test.tick, test.side);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_round_to_step() {
+        struct TestCase {
+            quantity: Decimal,
+            step: Decimal,
+            expected: Decimal,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: quantity rounded down to step
+                quantity: dec!(1.23456),
+                step: dec!(0.001),
+                expected: dec!(1.234),
+            },
+            TestCase {
+                // TC1: negative quantity rounded towards zero
+                quantity: dec!(-1.23456),
+                step: dec!(0.001),
+                expected: dec!(-1.234),
+            },
+            TestCase {
+                // TC2: quantity smaller than step rounds to zero
+                quantity: dec!(0.0004),
+                step: dec!(0.001),
+                expected: dec!(0),
+            },
+            TestCase {
+                // TC3: zero step returns quantity unchanged
+                quantity: dec!(1.23456),
+                step: dec!(0),
+                expected: dec!(1.23456),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = round_to_step(test.quantity, test.step);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
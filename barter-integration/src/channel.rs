@@ -1,14 +1,27 @@
-use crate::Unrecoverable;
+use crate::{
+    Unrecoverable,
+    metric::{Field, Metric, Tag},
+};
+use chrono::Utc;
 use derive_more::{Constructor, Display};
 use futures::{Sink, Stream};
 use serde::{Deserialize, Serialize};
 use std::{
     fmt::Debug,
     pin::Pin,
+    sync::{
+        Arc, Mutex, MutexGuard, PoisonError,
+        atomic::{AtomicU64, Ordering},
+    },
     task::{Context, Poll},
 };
+use tokio::sync::mpsc::error::{SendError, TrySendError};
+use tokio_util::sync::PollSender;
 use tracing::warn;
 
+/// [`Metric`] name emitted by a [`BoundedTx`] each time an item is dropped.
+pub const METRIC_CHANNEL_DROPPED: &str = "channel_dropped";
+
 pub trait Tx
 where
     Self: Debug + Clone + Send,
@@ -176,3 +189,408 @@ pub fn mpsc_unbounded<T>() -> (UnboundedTx<T>, UnboundedRx<T>) {
     let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
     (UnboundedTx::new(tx), UnboundedRx::new(rx))
 }
+
+/// Policy applied by a [`BoundedTx`] when sending to a full channel.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Display,
+)]
+pub enum OverflowPolicy {
+    /// Wait until the receiver frees capacity, applying backpressure to the sender.
+    Block,
+    /// Drop the oldest buffered item to make room for the new item.
+    DropOldest,
+    /// Drop the new item, keeping every buffered item.
+    DropNewest,
+}
+
+/// Capacity and [`OverflowPolicy`] of a bounded channel constructed via [`mpsc_bounded`].
+///
+/// A `capacity` of zero is treated as one.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct BoundedChannelConfig {
+    pub capacity: usize,
+    pub policy: OverflowPolicy,
+}
+
+/// Convenience type that holds the [`BoundedTx`] and [`BoundedRx`].
+#[derive(Debug)]
+pub struct BoundedChannel<T> {
+    pub tx: BoundedTx<T>,
+    pub rx: BoundedRx<T>,
+}
+
+impl<T> BoundedChannel<T>
+where
+    T: Send,
+{
+    /// Construct a new [`BoundedChannel`] using the provided [`BoundedChannelConfig`].
+    pub fn new(config: BoundedChannelConfig) -> Self {
+        let (tx, rx) = mpsc_bounded(config, None);
+        Self { tx, rx }
+    }
+
+    /// Construct a new [`BoundedChannel`] using the provided [`BoundedChannelConfig`], sending
+    /// a [`METRIC_CHANNEL_DROPPED`] [`Metric`] tagged with the `channel` name to the `metrics_tx`
+    /// each time an item is dropped.
+    pub fn with_drop_metrics<S>(
+        config: BoundedChannelConfig,
+        channel: S,
+        metrics_tx: UnboundedTx<Metric>,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        let (tx, rx) = mpsc_bounded(config, Some((channel.into(), metrics_tx)));
+        Self { tx, rx }
+    }
+}
+
+/// Sending half of a bounded [`tokio::sync::mpsc`] channel, applying the configured
+/// [`OverflowPolicy`] when full.
+///
+/// Items are sent via the [`Sink`] implementation. With [`OverflowPolicy::Block`],
+/// [`Sink::poll_ready`] is pending until the [`BoundedRx`] frees capacity. Other policies
+/// [`try_send`](tokio::sync::mpsc::Sender::try_send), dropping an item if the channel is full.
+#[derive(Debug)]
+pub struct BoundedTx<T> {
+    tx: PollSender<T>,
+    shared: Arc<BoundedShared<T>>,
+}
+
+/// Receiving half of a bounded [`tokio::sync::mpsc`] channel.
+#[derive(Debug)]
+pub struct BoundedRx<T> {
+    shared: Arc<BoundedShared<T>>,
+}
+
+#[derive(Debug)]
+struct BoundedShared<T> {
+    config: BoundedChannelConfig,
+    drop_metrics: Option<(String, UnboundedTx<Metric>)>,
+    dropped: AtomicU64,
+    // Shared with the BoundedTx so OverflowPolicy::DropOldest can discard the oldest item
+    rx: Mutex<tokio::sync::mpsc::Receiver<T>>,
+}
+
+impl<T> BoundedShared<T> {
+    fn rx(&self) -> MutexGuard<'_, tokio::sync::mpsc::Receiver<T>> {
+        self.rx.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    fn record_drop(&self) {
+        let dropped = self.dropped.fetch_add(1, Ordering::Relaxed) + 1;
+
+        let Some((channel, metrics_tx)) = &self.drop_metrics else {
+            return;
+        };
+
+        let _ = metrics_tx.tx.send(Metric {
+            name: METRIC_CHANNEL_DROPPED,
+            time: Utc::now().timestamp_millis() as u64,
+            tags: vec![
+                Tag::new("channel", channel.as_str()),
+                Tag::new("policy", self.config.policy.to_string()),
+            ],
+            fields: vec![Field::new("dropped", dropped)],
+        });
+    }
+}
+
+impl<T> BoundedTx<T> {
+    /// Total number of items dropped by the channel [`OverflowPolicy`].
+    pub fn dropped(&self) -> u64 {
+        self.shared.dropped()
+    }
+}
+
+impl<T> Clone for BoundedTx<T> {
+    fn clone(&self) -> Self {
+        Self {
+            tx: self.tx.clone(),
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<T> Sink<T> for BoundedTx<T>
+where
+    T: Send,
+{
+    type Error = SendError<T>;
+
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // Only OverflowPolicy::Block waits for capacity, other policies drop items
+        if self.shared.config.policy != OverflowPolicy::Block {
+            return Poll::Ready(Ok(()));
+        }
+
+        // If the BoundedRx is dropped, start_send returns the item in a SendError
+        self.tx.poll_reserve(cx).map(|_| Ok(()))
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        if self.shared.config.policy == OverflowPolicy::Block {
+            return self.tx.send_item(item).map_err(|error| {
+                SendError(
+                    error
+                        .into_inner()
+                        .expect("closed PollSender returns the unsent item"),
+                )
+            });
+        }
+
+        let Some(tx) = self.tx.get_ref() else {
+            return Err(SendError(item));
+        };
+
+        let item = match tx.try_send(item) {
+            Ok(()) => return Ok(()),
+            Err(TrySendError::Closed(item)) => return Err(SendError(item)),
+            Err(TrySendError::Full(item)) => item,
+        };
+
+        // Drop the oldest buffered item to make room, retrying if a concurrent BoundedTx fills
+        // the freed capacity first
+        let mut item = item;
+        if self.shared.config.policy == OverflowPolicy::DropOldest {
+            while self.shared.rx().try_recv().is_ok() {
+                self.shared.record_drop();
+
+                item = match tx.try_send(item) {
+                    Ok(()) => return Ok(()),
+                    Err(TrySendError::Closed(item)) => return Err(SendError(item)),
+                    Err(TrySendError::Full(item)) => item,
+                };
+            }
+        }
+
+        // Drop the new item
+        self.shared.record_drop();
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // Items are buffered for the BoundedRx, so no flushing is required
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // BoundedTx requires no closing logic, the channel closes when every BoundedTx is dropped
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<T> BoundedRx<T> {
+    /// Total number of items dropped by the channel [`OverflowPolicy`].
+    pub fn dropped(&self) -> u64 {
+        self.shared.dropped()
+    }
+}
+
+impl<T> Drop for BoundedRx<T> {
+    fn drop(&mut self) {
+        // Receiver is shared with every BoundedTx, so close it to release blocked senders
+        self.shared.rx().close();
+    }
+}
+
+impl<T> Stream for BoundedRx<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.shared.rx().poll_recv(cx)
+    }
+}
+
+/// Construct a bounded channel using the provided [`BoundedChannelConfig`], optionally sending a
+/// [`METRIC_CHANNEL_DROPPED`] [`Metric`] tagged with the channel name each time an item is
+/// dropped.
+pub fn mpsc_bounded<T>(
+    config: BoundedChannelConfig,
+    drop_metrics: Option<(String, UnboundedTx<Metric>)>,
+) -> (BoundedTx<T>, BoundedRx<T>)
+where
+    T: Send,
+{
+    let (tx, rx) = tokio::sync::mpsc::channel(config.capacity.max(1));
+
+    let shared = Arc::new(BoundedShared {
+        config,
+        drop_metrics,
+        dropped: AtomicU64::new(0),
+        rx: Mutex::new(rx),
+    });
+
+    (
+        BoundedTx {
+            tx: PollSender::new(tx),
+            shared: Arc::clone(&shared),
+        },
+        BoundedRx { shared },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{FutureExt, SinkExt, StreamExt};
+
+    const CAPACITY: usize = 2;
+
+    async fn send_all(
+        tx: &mut BoundedTx<u64>,
+        items: impl IntoIterator<Item = u64>,
+    ) -> Result<(), SendError<u64>> {
+        for item in items {
+            tx.send(item).await?;
+        }
+        Ok(())
+    }
+
+    fn drained(rx: &mut BoundedRx<u64>) -> Vec<u64> {
+        std::iter::from_fn(|| rx.next().now_or_never().flatten()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_bounded_channel_drop_policies_with_stalled_consumer() {
+        struct TestCase {
+            policy: OverflowPolicy,
+            expected: Vec<u64>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: oldest items are dropped, keeping the latest
+                policy: OverflowPolicy::DropOldest,
+                expected: vec![4, 5],
+            },
+            TestCase {
+                // TC1: new items are dropped, keeping the earliest
+                policy: OverflowPolicy::DropNewest,
+                expected: vec![1, 2],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let (metrics_tx, mut metrics_rx) = mpsc_unbounded();
+            let BoundedChannel { mut tx, mut rx } = BoundedChannel::with_drop_metrics(
+                BoundedChannelConfig::new(CAPACITY, test.policy),
+                "test",
+                metrics_tx,
+            );
+
+            // Consumer is stalled whilst every item is sent
+            send_all(&mut tx, 1..=5).await.unwrap();
+            assert_eq!(tx.dropped(), 3, "TC{} failed", index);
+            assert_eq!(drained(&mut rx), test.expected, "TC{} failed", index);
+
+            // A drop Metric is emitted for every dropped item
+            let metrics = std::iter::from_fn(|| metrics_rx.rx.try_recv().ok()).collect::<Vec<_>>();
+            assert_eq!(metrics.len(), 3, "TC{} failed", index);
+
+            let last = metrics.last().unwrap();
+            assert_eq!(last.name, METRIC_CHANNEL_DROPPED, "TC{} failed", index);
+            assert_eq!(
+                last.tags,
+                vec![
+                    Tag::new("channel", "test"),
+                    Tag::new("policy", test.policy.to_string())
+                ],
+                "TC{} failed",
+                index
+            );
+            assert_eq!(
+                last.fields,
+                vec![Field::new("dropped", 3u64)],
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_bounded_channel_drop_policies_count_every_drop_with_concurrent_senders() {
+        const SENDERS: u64 = 8;
+        const ITEMS: u64 = 1_000;
+
+        for (index, policy) in [OverflowPolicy::DropOldest, OverflowPolicy::DropNewest]
+            .into_iter()
+            .enumerate()
+        {
+            let BoundedChannel { tx, mut rx } =
+                BoundedChannel::new(BoundedChannelConfig::new(CAPACITY, policy));
+
+            // Consumer is stalled whilst senders race to fill the channel
+            let senders = (0..SENDERS)
+                .map(|sender| {
+                    let mut tx = tx.clone();
+                    tokio::spawn(async move {
+                        send_all(&mut tx, (0..ITEMS).map(|item| sender * ITEMS + item)).await
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            for sender in senders {
+                sender.await.unwrap().unwrap();
+            }
+
+            // Every item is either received or counted as dropped
+            let received = drained(&mut rx).len() as u64;
+            assert_eq!(received, CAPACITY as u64, "TC{} failed", index);
+            assert_eq!(
+                received + rx.dropped(),
+                SENDERS * ITEMS,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bounded_channel_block_policy_applies_backpressure() {
+        let (metrics_tx, mut metrics_rx) = mpsc_unbounded();
+        let BoundedChannel { mut tx, mut rx } = BoundedChannel::with_drop_metrics(
+            BoundedChannelConfig::new(CAPACITY, OverflowPolicy::Block),
+            "test",
+            metrics_tx,
+        );
+
+        send_all(&mut tx, 1..=2).await.unwrap();
+
+        // Sending to a full channel waits whilst the consumer is stalled
+        let mut blocked = Box::pin(send_all(&mut tx, [3]));
+        assert!((&mut blocked).now_or_never().is_none());
+
+        // Consuming an item frees capacity for the blocked send
+        assert_eq!(rx.next().await, Some(1));
+        blocked.await.unwrap();
+        assert_eq!(drained(&mut rx), vec![2, 3]);
+
+        assert_eq!(rx.dropped(), 0);
+        assert!(metrics_rx.rx.try_recv().is_err());
+
+        // Stream ends once every BoundedTx is dropped
+        drop(tx);
+        assert_eq!(rx.next().await, None);
+    }
+
+    #[tokio::test]
+    async fn test_bounded_channel_send_fails_if_receiver_dropped() {
+        let (mut tx, rx) = mpsc_bounded::<u64>(
+            BoundedChannelConfig::new(CAPACITY, OverflowPolicy::Block),
+            None,
+        );
+
+        send_all(&mut tx, 1..=2).await.unwrap();
+        drop(rx);
+
+        // Blocked sender is released with an error rather than waiting forever
+        assert_eq!(send_all(&mut tx, [3]).await, Err(SendError(3)));
+    }
+}
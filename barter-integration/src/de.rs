@@ -64,6 +64,64 @@ where
     })
 }
 
+/// Leniently deserialize a [`Decimal`](rust_decimal::Decimal) from either a JSON string or a
+/// JSON number, including scientific notation (eg/ "0.1", 0.1, "1e-8", 1e-8).
+///
+/// Useful for exchanges that occasionally send prices as numbers rather than strings.
+pub fn de_decimal_flexible<'de, D>(deserializer: D) -> Result<rust_decimal::Decimal, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    struct DecimalVisitor;
+
+    impl serde::de::Visitor<'_> for DecimalVisitor {
+        type Value = rust_decimal::Decimal;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            formatter.write_str("a decimal number or a string containing a decimal number")
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            let value = value.trim();
+            value
+                .parse::<rust_decimal::Decimal>()
+                .or_else(|_| rust_decimal::Decimal::from_scientific(value))
+                .map_err(|_| E::invalid_value(serde::de::Unexpected::Str(value), &self))
+        }
+
+        fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            Ok(rust_decimal::Decimal::from(value))
+        }
+
+        fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            Ok(rust_decimal::Decimal::from(value))
+        }
+
+        fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            // f64 Display is the shortest representation that round trips, never in scientific
+            // notation, so "0.1" is parsed rather than the nearest binary approximation
+            value
+                .to_string()
+                .parse::<rust_decimal::Decimal>()
+                .map_err(|_| E::invalid_value(serde::de::Unexpected::Float(value), &self))
+        }
+    }
+
+    deserializer.deserialize_any(DecimalVisitor)
+}
+
 /// Assists deserialisation of sequences by attempting to extract & parse the next element in the
 /// provided sequence.
 ///
@@ -96,3 +154,85 @@ where
     sequence.serialize_element(&element)?;
     sequence.end()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::Decimal;
+    use serde::Deserialize;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_de_decimal_flexible() {
+        #[derive(Debug, Deserialize)]
+        struct Price(#[serde(deserialize_with = "de_decimal_flexible")] Decimal);
+
+        struct TestCase {
+            input: &'static str,
+            expected: Option<&'static str>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: string
+                input: r#""0.00000001""#,
+                expected: Some("0.00000001"),
+            },
+            TestCase {
+                // TC1: float number
+                input: "0.00000001",
+                expected: Some("0.00000001"),
+            },
+            TestCase {
+                // TC2: scientific notation string
+                input: r#""1e-8""#,
+                expected: Some("0.00000001"),
+            },
+            TestCase {
+                // TC3: scientific notation number
+                input: "1e-8",
+                expected: Some("0.00000001"),
+            },
+            TestCase {
+                // TC4: integer number
+                input: "42",
+                expected: Some("42"),
+            },
+            TestCase {
+                // TC5: negative integer number
+                input: "-42",
+                expected: Some("-42"),
+            },
+            TestCase {
+                // TC6: float number without an exact binary representation
+                input: "16578.1",
+                expected: Some("16578.1"),
+            },
+            TestCase {
+                // TC7: uppercase scientific notation string
+                input: r#""1.5E+3""#,
+                expected: Some("1500"),
+            },
+            TestCase {
+                // TC8: invalid string
+                input: r#""not a number""#,
+                expected: None,
+            },
+            TestCase {
+                // TC9: invalid type
+                input: "true",
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = serde_json::from_str::<Price>(test.input)
+                .ok()
+                .map(|price| price.0);
+            let expected = test
+                .expected
+                .map(|expected| Decimal::from_str(expected).unwrap());
+            assert_eq!(actual, expected, "TC{index} failed");
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use barter::{
     backtest,
-    backtest::{BacktestArgsConstant, BacktestArgsDynamic, market_data::MarketDataInMemory},
+    backtest::{
+        BacktestArgsConstant, BacktestArgsDynamic, BacktestInstrumentFilter,
+        market_data::MarketDataInMemory,
+    },
     engine::{
         Engine, Processor,
         clock::HistoricalClock,
@@ -323,6 +326,8 @@ impl AlgoStrategy for LoseMoneyStrategy {
                         quantity: Decimal::from_f64(trade_not_sent_as_order_open.amount).unwrap(),
                         kind: OrderKind::Market,
                         time_in_force: TimeInForce::ImmediateOrCancel,
+                        reduce_only: false,
+                        position_side: None,
                     },
                 })
             });
@@ -479,6 +484,8 @@ fn args_constant(
         market_data,
         summary_interval: Daily,
         engine_state,
+        time_source: Utc::now,
+        instrument_filter: BacktestInstrumentFilter::All,
     })
 }
 
@@ -1,6 +1,6 @@
 use barter::{
     backtest::{
-        BacktestArgsConstant, BacktestArgsDynamic,
+        BacktestArgsConstant, BacktestArgsDynamic, BacktestInstrumentFilter,
         market_data::{BacktestMarketData, MarketDataInMemory},
         run_backtests,
     },
@@ -15,6 +15,7 @@ use barter::{
 };
 use barter_data::streams::consumer::MarketStreamEvent;
 use barter_instrument::index::IndexedInstruments;
+use chrono::Utc;
 use rust_decimal::Decimal;
 use serde::Deserialize;
 use smol_str::{SmolStr, ToSmolStr};
@@ -73,6 +74,8 @@ async fn main() {
         market_data,
         summary_interval: Daily,
         engine_state,
+        time_source: Utc::now,
+        instrument_filter: BacktestInstrumentFilter::All,
     });
 
     // Define dummy dynamic backtest arguments
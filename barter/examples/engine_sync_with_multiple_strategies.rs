@@ -278,6 +278,9 @@ impl Processor<&AccountEvent> for MultiStrategyCustomInstrumentData {
             return;
         };
 
+        // MockExchange is configured to charge fees in the quote asset
+        let trade = &trade.with_quote_fees(trade.fees.fees);
+
         if trade.strategy == StrategyA::ID {
             self.strategy_a
                 .position
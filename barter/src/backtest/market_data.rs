@@ -2,8 +2,10 @@ use crate::error::JackbotError;
 use barter_data::streams::consumer::MarketStreamEvent;
 use barter_instrument::instrument::InstrumentIndex;
 use chrono::{DateTime, Utc};
-use futures::Stream;
+use futures::{Stream, StreamExt, future::Either};
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use tracing::warn;
 
 /// Interface that provides the backtest MarketStream and associated [`HistoricalClock`].
 pub trait BacktestMarketData {
@@ -24,6 +26,58 @@ pub trait BacktestMarketData {
     >;
 }
 
+/// Speed at which backtest market data is replayed.
+#[derive(Debug, Copy, Clone, PartialEq, Default, Deserialize, Serialize)]
+pub enum ReplaySpeed {
+    /// Replay every market event as fast as possible.
+    #[default]
+    Instant,
+
+    /// Sleep for the `time_exchange` gap between consecutive market events, divided by the
+    /// provided factor (eg/ `Realtime(2.0)` replays twice as fast as real time).
+    ///
+    /// Non-positive factors replay instantly.
+    Realtime(f64),
+}
+
+/// Replay the provided `Stream` of [`MarketStreamEvent`]s at the provided [`ReplaySpeed`].
+///
+/// [`ReplaySpeed::Realtime`] sleeps before yielding each [`MarketStreamEvent::Item`] in
+/// proportion to its `time_exchange` gap from the previous item. Other events are yielded
+/// without sleeping.
+pub fn replay_with_speed<St, InstrumentKey, Kind>(
+    stream: St,
+    speed: ReplaySpeed,
+) -> impl Stream<Item = MarketStreamEvent<InstrumentKey, Kind>>
+where
+    St: Stream<Item = MarketStreamEvent<InstrumentKey, Kind>>,
+{
+    let factor = match speed {
+        ReplaySpeed::Realtime(factor) if factor > 0.0 && factor.is_finite() => factor,
+        _ => return Either::Left(stream),
+    };
+
+    let mut time_previous: Option<DateTime<Utc>> = None;
+    Either::Right(stream.then(move |event| {
+        let delay = match &event {
+            MarketStreamEvent::Item(market) => {
+                let gap = time_previous.map(|previous| market.time_exchange - previous);
+                time_previous = Some(market.time_exchange);
+                gap.and_then(|gap| gap.to_std().ok())
+                    .map(|gap| gap.div_f64(factor))
+            }
+            MarketStreamEvent::Reconnecting(_) => None,
+        };
+
+        async move {
+            if let Some(delay) = delay {
+                tokio::time::sleep(delay).await;
+            }
+            event
+        }
+    }))
+}
+
 /// In-memory market data.
 ///
 /// Stores all market events in memory and generates a `Stream` of [`MarketStreamEvent`] by
@@ -32,6 +86,7 @@ pub trait BacktestMarketData {
 pub struct MarketDataInMemory<Kind> {
     time_first_event: DateTime<Utc>,
     events: Arc<Vec<MarketStreamEvent<InstrumentIndex, Kind>>>,
+    replay_speed: ReplaySpeed,
 }
 
 impl<Kind> BacktestMarketData for MarketDataInMemory<Kind>
@@ -53,13 +108,26 @@ where
         let events = Arc::clone(&self.events);
         let lazy_clone_iter = (0..events.len()).map(move |index| events[index].clone());
         let stream = futures::stream::iter(lazy_clone_iter);
-        Ok(stream)
+        Ok(replay_with_speed(stream, self.replay_speed))
     }
 }
 
 impl<Kind> MarketDataInMemory<Kind> {
     /// Create a new in-memory market data source from a vector of market events.
-    pub fn new(events: Arc<Vec<MarketStreamEvent<InstrumentIndex, Kind>>>) -> Self {
+    ///
+    /// Market events that are not in `time_exchange` order are stable sorted by `time_exchange`,
+    /// use [`Self::try_new`] to reject them instead.
+    pub fn new(events: Arc<Vec<MarketStreamEvent<InstrumentIndex, Kind>>>) -> Self
+    where
+        Kind: Clone,
+    {
+        let events = if find_time_regression(&events).is_some() {
+            warn!("MarketDataInMemory sorting market events that are not in time_exchange order");
+            Arc::new(sort_by_time_exchange(Arc::unwrap_or_clone(events)))
+        } else {
+            events
+        };
+
         let time_first_event = events
             .iter()
             .find_map(|event| match event {
@@ -71,6 +139,243 @@ impl<Kind> MarketDataInMemory<Kind> {
         Self {
             time_first_event,
             events,
+            replay_speed: ReplaySpeed::default(),
+        }
+    }
+
+    /// Create a new in-memory market data source from a vector of market events, validating
+    /// that the market events are in `time_exchange` order.
+    ///
+    /// Returns a [`JackbotError::BacktestMarketData`] if the vector contains no market events, or
+    /// if the `time_exchange` of any market event regresses from the previous market event.
+    pub fn try_new(
+        events: Arc<Vec<MarketStreamEvent<InstrumentIndex, Kind>>>,
+    ) -> Result<Self, JackbotError>
+    where
+        Kind: Clone,
+    {
+        if let Some((index, time, previous)) = find_time_regression(&events) {
+            return Err(JackbotError::BacktestMarketData(format!(
+                "market event at index {index} has time_exchange {time} before previous market \
+                 event time_exchange {previous}"
+            )));
+        }
+
+        if !events
+            .iter()
+            .any(|event| matches!(event, MarketStreamEvent::Item(_)))
+        {
+            return Err(JackbotError::BacktestMarketData(
+                "cannot construct MarketDataInMemory without any market events".to_string(),
+            ));
+        }
+
+        Ok(Self::new(events))
+    }
+
+    /// Set the [`ReplaySpeed`] of the market data `Stream` (defaults to [`ReplaySpeed::Instant`]).
+    pub fn with_replay_speed(self, replay_speed: ReplaySpeed) -> Self {
+        Self {
+            replay_speed,
+            ..self
+        }
+    }
+}
+
+/// Find the first market event with a `time_exchange` before the previous market event,
+/// returning its index, `time_exchange`, and the previous `time_exchange`.
+fn find_time_regression<Kind>(
+    events: &[MarketStreamEvent<InstrumentIndex, Kind>],
+) -> Option<(usize, DateTime<Utc>, DateTime<Utc>)> {
+    let mut time_previous: Option<DateTime<Utc>> = None;
+    for (index, event) in events.iter().enumerate() {
+        let MarketStreamEvent::Item(event) = event else {
+            continue;
+        };
+
+        if let Some(previous) = time_previous
+            && event.time_exchange < previous
+        {
+            return Some((index, event.time_exchange, previous));
+        }
+
+        time_previous = Some(event.time_exchange);
+    }
+
+    None
+}
+
+/// Stable sort market events by `time_exchange`.
+///
+/// Non-market events (eg/ `MarketStreamEvent::Reconnecting`) remain directly after the market
+/// event they originally followed.
+fn sort_by_time_exchange<Kind>(
+    events: Vec<MarketStreamEvent<InstrumentIndex, Kind>>,
+) -> Vec<MarketStreamEvent<InstrumentIndex, Kind>> {
+    let mut time_previous: Option<DateTime<Utc>> = None;
+    let mut keyed = events
+        .into_iter()
+        .map(|event| {
+            if let MarketStreamEvent::Item(item) = &event {
+                time_previous = Some(item.time_exchange);
+            }
+            (time_previous, event)
+        })
+        .collect::<Vec<_>>();
+
+    keyed.sort_by_key(|(time, _)| *time);
+    keyed.into_iter().map(|(_, event)| event).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_data::{event::DataKind, test_utils::market_event_trade_buy};
+    use std::time::Duration;
+
+    fn market_events(secs: &[i64]) -> Vec<MarketStreamEvent<InstrumentIndex, DataKind>> {
+        let time_base = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+        secs.iter()
+            .map(|secs| {
+                let time = time_base + chrono::TimeDelta::seconds(*secs);
+                MarketStreamEvent::Item(market_event_trade_buy(
+                    time,
+                    time,
+                    InstrumentIndex::new(0),
+                    100.0,
+                    1.0,
+                ))
+            })
+            .collect()
+    }
+
+    fn market_data(secs: &[i64]) -> MarketDataInMemory<DataKind> {
+        MarketDataInMemory::new(Arc::new(market_events(secs)))
+    }
+
+    #[test]
+    fn test_market_data_in_memory_try_new() {
+        struct TestCase {
+            secs: Vec<i64>,
+            expected: Result<(), &'static str>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: no market events
+                secs: vec![],
+                expected: Err("cannot construct MarketDataInMemory without any market events"),
+            },
+            TestCase {
+                // TC1: ordered market events, including equal times
+                secs: vec![0, 5, 5, 10],
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC2: regressing market event
+                secs: vec![0, 10, 5],
+                expected: Err("market event at index 2 has time_exchange \
+                     2023-11-14 22:13:25 UTC before previous market event time_exchange \
+                     2023-11-14 22:13:30 UTC"),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = MarketDataInMemory::try_new(Arc::new(market_events(&test.secs)))
+                .map(|_| ())
+                .map_err(|error| error.to_string());
+            let expected = test
+                .expected
+                .map_err(|error| format!("backtest market data: {error}"));
+            assert_eq!(actual, expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_market_data_in_memory_new_sorts_unordered_events() {
+        struct TestCase {
+            secs: Vec<i64>,
+            expected: Vec<i64>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: ordered market events are unchanged
+                secs: vec![0, 5, 5, 10],
+                expected: vec![0, 5, 5, 10],
+            },
+            TestCase {
+                // TC1: regressing market events are sorted
+                secs: vec![10, 0, 20, 5],
+                expected: vec![0, 5, 10, 20],
+            },
+        ];
+
+        let time_base = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let market_data = market_data(&test.secs);
+
+            let actual = market_data
+                .events
+                .iter()
+                .filter_map(|event| match event {
+                    MarketStreamEvent::Item(event) => {
+                        Some((event.time_exchange - time_base).num_seconds())
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+
+            assert_eq!(actual, test.expected, "TC{index} failed");
+            assert_eq!(
+                market_data.time_first_event,
+                time_base + chrono::TimeDelta::seconds(test.expected[0]),
+                "TC{index} failed"
+            );
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_market_data_in_memory_replay_speed() {
+        struct TestCase {
+            replay_speed: ReplaySpeed,
+            expected: Duration,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: instant replay does not sleep
+                replay_speed: ReplaySpeed::Instant,
+                expected: Duration::ZERO,
+            },
+            TestCase {
+                // TC1: realtime replay sleeps the full gap
+                replay_speed: ReplaySpeed::Realtime(1.0),
+                expected: Duration::from_secs(10),
+            },
+            TestCase {
+                // TC2: 2x realtime replay sleeps half the gap
+                replay_speed: ReplaySpeed::Realtime(2.0),
+                expected: Duration::from_secs(5),
+            },
+            TestCase {
+                // TC3: non-positive factor replays instantly
+                replay_speed: ReplaySpeed::Realtime(0.0),
+                expected: Duration::ZERO,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let market_data = market_data(&[0, 10]).with_replay_speed(test.replay_speed);
+            let mut stream = std::pin::pin!(market_data.stream().await.unwrap());
+
+            stream.next().await.unwrap();
+            let time_first = tokio::time::Instant::now();
+            stream.next().await.unwrap();
+
+            assert_eq!(time_first.elapsed(), test.expected, "TC{index} failed");
+            assert!(stream.next().await.is_none(), "TC{index} failed");
         }
     }
 }
@@ -9,9 +9,12 @@ use crate::{
     },
     engine::{
         Processor,
-        clock::HistoricalClock,
+        clock::{HistoricalClock, TimeSource},
         execution_tx::MultiExchangeTxMap,
-        state::{EngineState, instrument::data::InstrumentDataState},
+        state::{
+            EngineState,
+            instrument::{data::InstrumentDataState, filter::InstrumentFilter},
+        },
     },
     error::JackbotError,
     risk::RiskManager,
@@ -27,11 +30,13 @@ use crate::{
     execution::builder::{ExecutionBuild, ExecutionBuilder},
     system::builder::{AuditMode, SystemBuild},
 };
-use barter_data::event::MarketEvent;
+use barter_data::{event::MarketEvent, streams::consumer::MarketStreamEvent};
 use barter_execution::AccountEvent;
 use barter_instrument::{index::IndexedInstruments, instrument::InstrumentIndex};
-use futures::future::try_join_all;
+use fnv::FnvHashSet;
+use futures::{StreamExt, future::try_join_all};
 use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
 use smol_str::SmolStr;
 use std::{fmt::Debug, sync::Arc};
 
@@ -58,6 +63,59 @@ pub struct BacktestArgsConstant<MarketData, SummaryInterval, State> {
     pub summary_interval: SummaryInterval,
     /// EngineState.
     pub engine_state: State,
+    /// [`TimeSource`] used by the backtest [`HistoricalClock`] to estimate time elapsed between
+    /// market events.
+    ///
+    /// Use `Utc::now` to include wall-clock processing time, or a fixed source
+    /// (eg/ `|| DateTime::<Utc>::MIN_UTC`) for fully reproducible backtests.
+    pub time_source: TimeSource,
+    /// Selects which instruments' market events are replayed to the [`Engine`], allowing a
+    /// backtest to run over a subset of the instruments in the market data.
+    pub instrument_filter: BacktestInstrumentFilter,
+}
+
+/// Selects which instruments' market events are replayed to a backtest [`Engine`].
+///
+/// Market events for instruments that are filtered out are skipped entirely, so they never
+/// reach the strategy.
+#[derive(Debug, Clone, PartialEq, Default, Deserialize, Serialize)]
+pub enum BacktestInstrumentFilter {
+    /// Replay market events for every instrument.
+    #[default]
+    All,
+    /// Only replay market events for instruments matching the [`InstrumentFilter`].
+    Allow(InstrumentFilter),
+    /// Skip market events for instruments matching the [`InstrumentFilter`].
+    Deny(InstrumentFilter),
+}
+
+impl BacktestInstrumentFilter {
+    /// Resolve the set of [`InstrumentIndex`]s whose market events should be replayed.
+    ///
+    /// Returns `None` if every instrument is allowed.
+    pub fn allowed(&self, instruments: &IndexedInstruments) -> Option<FnvHashSet<InstrumentIndex>> {
+        let (filter, allow) = match self {
+            Self::All => return None,
+            Self::Allow(filter) => (filter, true),
+            Self::Deny(filter) => (filter, false),
+        };
+
+        let allowed = instruments
+            .instruments()
+            .iter()
+            .filter(|instrument| {
+                let matches = filter.matches(
+                    &instrument.value.exchange.key,
+                    &instrument.key,
+                    &instrument.value.underlying,
+                );
+                matches == allow
+            })
+            .map(|instrument| instrument.key)
+            .collect();
+
+        Some(allowed)
+    }
 }
 
 /// Configuration for variables that can change between individual backtests.
@@ -197,8 +255,23 @@ where
         .market_data
         .time_first_event()
         .await
-        .map(HistoricalClock::new)?;
-    let market_stream = args_constant.market_data.stream().await?;
+        .map(|time| HistoricalClock::with_time_source(time, args_constant.time_source))?;
+    let allowed = args_constant
+        .instrument_filter
+        .allowed(&args_constant.instruments);
+    let market_stream = args_constant
+        .market_data
+        .stream()
+        .await?
+        .filter(move |event| {
+            let replay = match (&allowed, event) {
+                (Some(allowed), MarketStreamEvent::Item(event)) => {
+                    allowed.contains(&event.instrument)
+                }
+                _ => true,
+            };
+            std::future::ready(replay)
+        });
 
     // Build Execution infrastructure
     let ExecutionBuild {
@@ -246,5 +319,188 @@ where
         id: args_dynamic.id,
         risk_free_return: args_dynamic.risk_free_return,
         trading_summary,
+        counters: engine.meta.counters,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backtest::market_data::MarketDataInMemory,
+        engine::state::{
+            builder::EngineStateBuilder, global::DefaultGlobalData,
+            instrument::data::DefaultInstrumentMarketData, trading::TradingState,
+        },
+        risk::DefaultRiskManager,
+        statistic::time::Daily,
+        strategy::DefaultStrategy,
+        test_utils::time_plus_secs,
+    };
+    use barter_data::{
+        event::DataKind, streams::consumer::MarketStreamEvent, test_utils::market_event_trade_buy,
+    };
+    use barter_instrument::{exchange::ExchangeId, test_utils::instrument};
+    use chrono::{DateTime, Utc};
+
+    type TestState = EngineState<DefaultGlobalData, DefaultInstrumentMarketData>;
+
+    fn time_base() -> DateTime<Utc> {
+        DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap()
+    }
+
+    fn market_events(
+        instrument: usize,
+        secs: std::ops::Range<i64>,
+    ) -> impl Iterator<Item = MarketStreamEvent<InstrumentIndex, DataKind>> {
+        secs.map(move |secs| {
+            let time = time_plus_secs(time_base(), secs);
+            MarketStreamEvent::Item(market_event_trade_buy(
+                time,
+                time,
+                InstrumentIndex::new(instrument),
+                100.0 + secs as f64,
+                1.0,
+            ))
+        })
+    }
+
+    fn args_constant(
+        time_source: TimeSource,
+    ) -> Arc<BacktestArgsConstant<MarketDataInMemory<DataKind>, Daily, TestState>> {
+        args_constant_with(
+            IndexedInstruments::new([instrument(ExchangeId::BinanceSpot, "btc", "usdt")]),
+            market_events(0, 0..10).collect(),
+            time_source,
+            BacktestInstrumentFilter::All,
+        )
+    }
+
+    fn args_constant_with(
+        instruments: IndexedInstruments,
+        market_events: Vec<MarketStreamEvent<InstrumentIndex, DataKind>>,
+        time_source: TimeSource,
+        instrument_filter: BacktestInstrumentFilter,
+    ) -> Arc<BacktestArgsConstant<MarketDataInMemory<DataKind>, Daily, TestState>> {
+        let time_base = time_base();
+
+        let engine_state = EngineStateBuilder::new(
+            &instruments,
+            DefaultGlobalData,
+            DefaultInstrumentMarketData::default,
+        )
+        .time_engine_start(time_base)
+        .trading_state(TradingState::Enabled)
+        .build();
+
+        Arc::new(BacktestArgsConstant {
+            instruments,
+            executions: vec![],
+            market_data: MarketDataInMemory::new(Arc::new(market_events)),
+            summary_interval: Daily,
+            engine_state,
+            time_source,
+            instrument_filter,
+        })
+    }
+
+    fn args_dynamic()
+    -> BacktestArgsDynamic<DefaultStrategy<TestState>, DefaultRiskManager<TestState>> {
+        BacktestArgsDynamic {
+            id: SmolStr::new("deterministic"),
+            risk_free_return: Decimal::ZERO,
+            strategy: DefaultStrategy::default(),
+            risk: DefaultRiskManager::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_backtest_with_fixed_time_source_is_reproducible() {
+        let time_source: TimeSource = || DateTime::<Utc>::MIN_UTC;
+
+        let first = backtest(args_constant(time_source), args_dynamic())
+            .await
+            .unwrap();
+        let second = backtest(args_constant(time_source), args_dynamic())
+            .await
+            .unwrap();
+
+        assert_eq!(format!("{first:?}"), format!("{second:?}"));
+        assert_eq!(first, second);
+
+        // Engine processed every market event, but DefaultStrategy generates no orders
+        assert_eq!(first.counters.events_processed, 10);
+        assert_eq!(first.counters.orders_generated, 0);
+
+        // Engine time is derived solely from the market data
+        assert_eq!(
+            first.trading_summary.time_engine_end,
+            DateTime::<Utc>::from_timestamp(1_700_000_009, 0).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_backtest_instrument_filter_skips_filtered_out_instruments() {
+        let instruments = IndexedInstruments::new([
+            instrument(ExchangeId::BinanceSpot, "btc", "usdt"),
+            instrument(ExchangeId::BinanceSpot, "eth", "usdt"),
+        ]);
+
+        // Instrument 0 trades for 10 secs, after which instrument 1 trades for 5 secs
+        let market_events = market_events(0, 0..10)
+            .chain(market_events(1, 10..15))
+            .collect::<Vec<_>>();
+
+        struct TestCase {
+            filter: BacktestInstrumentFilter,
+            expected_events: u64,
+            expected_time_end_secs: i64,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: no filter replays every instrument
+                filter: BacktestInstrumentFilter::All,
+                expected_events: 15,
+                expected_time_end_secs: 14,
+            },
+            TestCase {
+                // TC1: allow filter only replays instrument 0
+                filter: BacktestInstrumentFilter::Allow(InstrumentFilter::instruments([
+                    InstrumentIndex::new(0),
+                ])),
+                expected_events: 10,
+                expected_time_end_secs: 9,
+            },
+            TestCase {
+                // TC2: deny filter skips instrument 0
+                filter: BacktestInstrumentFilter::Deny(InstrumentFilter::instruments([
+                    InstrumentIndex::new(0),
+                ])),
+                expected_events: 5,
+                expected_time_end_secs: 14,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let args_constant = args_constant_with(
+                instruments.clone(),
+                market_events.clone(),
+                || DateTime::<Utc>::MIN_UTC,
+                test.filter,
+            );
+
+            let summary = backtest(args_constant, args_dynamic()).await.unwrap();
+
+            assert_eq!(
+                summary.counters.events_processed, test.expected_events,
+                "TC{index} failed"
+            );
+            assert_eq!(
+                summary.trading_summary.time_engine_end,
+                time_plus_secs(time_base(), test.expected_time_end_secs),
+                "TC{index} failed"
+            );
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::statistic::summary::TradingSummary;
+use crate::{engine::EngineCounters, statistic::summary::TradingSummary};
 use rust_decimal::Decimal;
 use smol_str::SmolStr;
 use std::time::Duration;
@@ -39,4 +39,6 @@ pub struct BacktestSummary<Interval> {
     pub risk_free_return: Decimal,
     /// Performance metrics and statistics from the backtest simulated trading.
     pub trading_summary: TradingSummary<Interval>,
+    /// [`EngineCounters`] of work done by the `Engine` during the backtest.
+    pub counters: EngineCounters,
 }
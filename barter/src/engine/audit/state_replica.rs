@@ -1,7 +1,7 @@
 use crate::{
     EngineEvent,
     engine::{
-        EngineMeta, EngineOutput, Processor,
+        EngineCounters, EngineMeta, EngineOutput, Processor,
         audit::{AuditTick, EngineAudit, context::EngineContext},
         state::{EngineState, instrument::data::InstrumentDataState},
     },
@@ -35,6 +35,7 @@ impl<State, Updates> StateReplicaManager<State, Updates> {
             meta_start: EngineMeta {
                 time_start: snapshot.context.time,
                 sequence: snapshot.context.sequence,
+                counters: EngineCounters::default(),
             },
             state_replica: snapshot,
             updates,
@@ -22,6 +22,10 @@ pub trait TimeExchange {
     fn time_exchange(&self) -> Option<DateTime<Utc>>;
 }
 
+/// Source of the current "live" time used by a [`HistoricalClock`] to estimate the time elapsed
+/// since the last processed event.
+pub type TimeSource = fn() -> DateTime<Utc>;
+
 /// Live `Clock` using `Utc::now()`.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
 pub struct LiveClock;
@@ -41,9 +45,13 @@ impl<Event> Processor<&Event> for LiveClock {
 /// Historical `Clock` using processed event timestamps to estimate current historical time.
 ///
 /// Note that this cannot be initialised without a starting `last_exchange_timestamp`.
+///
+/// By default, the wall-clock time elapsed since the last processed event is added to the most
+/// recent event time. See [`HistoricalClock::deterministic`] for a fully reproducible alternative.
 #[derive(Debug, Clone)]
 pub struct HistoricalClock {
     inner: Arc<parking_lot::RwLock<HistoricalClockInner>>,
+    time_source: TimeSource,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
@@ -55,11 +63,27 @@ struct HistoricalClockInner {
 impl HistoricalClock {
     /// Construct a new `HistoricalClock` using the provided `last_exchange_time` as a seed.
     pub fn new(last_exchange_time: DateTime<Utc>) -> Self {
+        Self::with_time_source(last_exchange_time, Utc::now)
+    }
+
+    /// Construct a new deterministic `HistoricalClock` using the provided `last_exchange_time`
+    /// as a seed.
+    ///
+    /// The current time is always the most recent processed event `time_exchange`, independent
+    /// of wall-clock time. Useful for fully reproducible backtests.
+    pub fn deterministic(last_exchange_time: DateTime<Utc>) -> Self {
+        Self::with_time_source(last_exchange_time, || DateTime::<Utc>::MIN_UTC)
+    }
+
+    /// Construct a new `HistoricalClock` using the provided `last_exchange_time` as a seed, and
+    /// the provided [`TimeSource`] to estimate the time elapsed since the last processed event.
+    pub fn with_time_source(last_exchange_time: DateTime<Utc>, time_source: TimeSource) -> Self {
         Self {
             inner: Arc::new(parking_lot::RwLock::new(HistoricalClockInner {
                 time_exchange_last: last_exchange_time,
-                time_live_last_event: Utc::now(),
+                time_live_last_event: time_source(),
             })),
+            time_source,
         }
     }
 }
@@ -72,7 +96,7 @@ impl EngineClock for HistoricalClock {
         drop(lock);
 
         let delta_since_last_event_live_time =
-            Utc::now().signed_duration_since(time_live_last_event);
+            (self.time_source)().signed_duration_since(time_live_last_event);
 
         // Edge case: only add TimeDelta if it's positive to handle out of order updates
         match delta_since_last_event_live_time {
@@ -106,7 +130,7 @@ where
                 "HistoricalClock updating based on input event time_exchange"
             );
             lock.time_exchange_last = time_event_exchange;
-            lock.time_live_last_event = Utc::now();
+            lock.time_live_last_event = (self.time_source)();
             return;
         };
 
@@ -158,6 +182,7 @@ impl<MarketEventKind: Debug> TimeExchange for EngineEvent<MarketEventKind> {
                     .map(|cancelled| cancelled.time_exchange)
                     .ok(),
                 AccountEventKind::Trade(trade) => Some(trade.time_exchange),
+                AccountEventKind::Funding(funding) => Some(funding.time_exchange),
             },
             _ => None,
         }
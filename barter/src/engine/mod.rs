@@ -28,7 +28,10 @@ use crate::{
     },
 };
 use barter_data::{event::MarketEvent, streams::consumer::MarketStreamEvent};
-use barter_execution::AccountEvent;
+use barter_execution::{
+    AccountEvent, AccountEventKind,
+    order::state::{InactiveOrderState, OrderState},
+};
 use barter_instrument::{asset::QuoteAsset, exchange::ExchangeIndex, instrument::InstrumentIndex};
 use barter_integration::channel::Tx;
 use chrono::{DateTime, Utc};
@@ -119,6 +122,58 @@ pub struct EngineMeta {
     pub time_start: DateTime<Utc>,
     /// Monotonically increasing [`Sequence`] associated with the number of events processed.
     pub sequence: Sequence,
+    /// [`EngineCounters`] of work done during the current [`Engine`] `run`.
+    pub counters: EngineCounters,
+}
+
+/// Counters of work done by an [`Engine`], useful for diagnosing why a strategy did not trade.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub struct EngineCounters {
+    /// Number of [`EngineEvent`]s processed (excluding `Shutdown`).
+    pub events_processed: u64,
+    /// Number of open order requests generated by the algorithmic `Strategy`.
+    pub orders_generated: u64,
+    /// Number of order fills (ie/ `Trade`s) received from the exchange.
+    pub orders_filled: u64,
+    /// Number of open order requests refused by the [`RiskManager`], or rejected by the exchange.
+    pub orders_rejected: u64,
+}
+
+impl EngineCounters {
+    /// Update the [`EngineCounters`] from an [`AccountStreamEvent`].
+    pub fn update_from_account_stream(&mut self, event: &AccountStreamEvent) {
+        let AccountStreamEvent::Item(event) = event else {
+            return;
+        };
+
+        match &event.kind {
+            AccountEventKind::Trade(_) => self.orders_filled += 1,
+            AccountEventKind::OrderSnapshot(order)
+                if matches!(
+                    order.value().state,
+                    OrderState::Inactive(InactiveOrderState::OpenFailed(_))
+                ) =>
+            {
+                self.orders_rejected += 1
+            }
+            _ => {}
+        }
+    }
+
+    /// Update the [`EngineCounters`] from a [`GenerateAlgoOrdersOutput`].
+    pub fn update_from_algo_orders<ExchangeKey, InstrumentKey>(
+        &mut self,
+        output: &GenerateAlgoOrdersOutput<ExchangeKey, InstrumentKey>,
+    ) {
+        let opens = &output.cancels_and_opens.opens;
+        let opens_refused = output.opens_refused.len() as u64;
+
+        self.orders_generated +=
+            opens.sent.len() as u64 + opens.errors.len() as u64 + opens_refused;
+        self.orders_rejected += opens_refused;
+    }
 }
 
 impl<Clock, GlobalData, InstrumentData, ExecutionTxs, Strategy, Risk>
@@ -144,6 +199,10 @@ where
     fn process(&mut self, event: EngineEvent<InstrumentData::MarketEventKind>) -> Self::Audit {
         self.clock.process(&event);
 
+        if !matches!(event, EngineEvent::Shutdown(_)) {
+            self.meta.counters.events_processed += 1;
+        }
+
         let process_audit = match &event {
             EngineEvent::Shutdown(_) => return EngineAudit::process(event),
             EngineEvent::Command(command) => {
@@ -160,6 +219,7 @@ where
                 ProcessAudit::with_trading_state_update(event, trading_disabled)
             }
             EngineEvent::Account(account) => {
+                self.meta.counters.update_from_account_stream(account);
                 let output = self.update_from_account_stream(account);
                 ProcessAudit::with_account_update(event, output)
             }
@@ -171,6 +231,7 @@ where
 
         if let TradingState::Enabled = self.state.trading {
             let output = self.generate_algo_orders();
+            self.meta.counters.update_from_algo_orders(&output);
 
             if output.is_empty() {
                 EngineAudit::from(process_audit)
@@ -347,6 +408,7 @@ where
             meta: EngineMeta {
                 time_start: clock.time(),
                 sequence: Sequence(0),
+                counters: EngineCounters::default(),
             },
             clock,
             state,
@@ -361,10 +423,12 @@ where
         self.clock.time()
     }
 
-    /// Reset the internal `EngineMeta` to the `clock` time and `Sequence(0)`.
+    /// Reset the internal `EngineMeta` to the `clock` time, `Sequence(0)` and default
+    /// [`EngineCounters`].
     pub fn reset_metadata(&mut self) {
         self.meta.time_start = self.clock.time();
         self.meta.sequence = Sequence(0);
+        self.meta.counters = EngineCounters::default();
     }
 }
 
@@ -435,3 +499,56 @@ impl<OnTradingDisabled, OnDisconnect, ExchangeKey, InstrumentKey>
         Self::AlgoOrders(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{risk::RiskRefused, test_utils::order_request_open};
+    use barter_execution::{
+        error::{ApiError, OrderError},
+        order::Order,
+    };
+    use barter_instrument::Side;
+    use barter_integration::{collection::none_one_or_many::NoneOneOrMany, snapshot::Snapshot};
+
+    #[test]
+    fn test_engine_counters_rejected() {
+        let mut counters = EngineCounters::default();
+
+        // Open request refused by the RiskManager
+        let output = GenerateAlgoOrdersOutput {
+            opens_refused: NoneOneOrMany::One(RiskRefused::new(
+                order_request_open(0, Side::Buy, 100.0, 1.0),
+                "refused",
+            )),
+            ..Default::default()
+        };
+        counters.update_from_algo_orders(&output);
+
+        // Open request rejected by the exchange
+        let request = order_request_open(0, Side::Buy, 100.0, 1.0);
+        let event = AccountStreamEvent::Item(AccountEvent::new(
+            ExchangeIndex(0),
+            AccountEventKind::OrderSnapshot(Snapshot(Order {
+                key: request.key,
+                side: request.state.side,
+                price: request.state.price,
+                quantity: request.state.quantity,
+                kind: request.state.kind,
+                time_in_force: request.state.time_in_force,
+                state: OrderState::inactive(OrderError::Rejected(ApiError::RateLimit)),
+            })),
+        ));
+        counters.update_from_account_stream(&event);
+
+        assert_eq!(
+            counters,
+            EngineCounters {
+                events_processed: 0,
+                orders_generated: 1,
+                orders_filled: 0,
+                orders_rejected: 2,
+            }
+        );
+    }
+}
@@ -32,3 +32,28 @@ impl<ExchangeKey, AssetKey, InstrumentKey> InstrumentFilter<ExchangeKey, AssetKe
         Self::Underlyings(OneOrMany::from_iter(exchanges))
     }
 }
+
+impl<ExchangeKey, AssetKey, InstrumentKey> InstrumentFilter<ExchangeKey, AssetKey, InstrumentKey>
+where
+    ExchangeKey: PartialEq,
+    AssetKey: PartialEq,
+    InstrumentKey: PartialEq,
+{
+    /// Returns `true` if an instrument with the provided `exchange`, `instrument` key and
+    /// `underlying` passes the filter.
+    ///
+    /// [`InstrumentFilter::None`] matches every instrument.
+    pub fn matches(
+        &self,
+        exchange: &ExchangeKey,
+        instrument: &InstrumentKey,
+        underlying: &Underlying<AssetKey>,
+    ) -> bool {
+        match self {
+            Self::None => true,
+            Self::Exchanges(exchanges) => exchanges.contains(exchange),
+            Self::Instruments(instruments) => instruments.contains(instrument),
+            Self::Underlyings(underlyings) => underlyings.contains(underlying),
+        }
+    }
+}
@@ -9,6 +9,7 @@ use crate::{
 use barter_data::event::MarketEvent;
 use barter_execution::{
     InstrumentAccountSnapshot,
+    funding::FundingPayment,
     order::{
         Order, OrderKey,
         request::OrderResponseCancel,
@@ -29,8 +30,10 @@ use barter_integration::{collection::FnvIndexMap, snapshot::Snapshot};
 use chrono::{DateTime, Utc};
 use derive_more::Constructor;
 use itertools::Either;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
+use tracing::warn;
 
 /// Defines the state interface [`InstrumentDataState`] that can be implemented for custom
 /// instrument level data state.
@@ -318,20 +321,48 @@ impl<InstrumentData, ExchangeKey, AssetKey, InstrumentKey>
     /// Updates the instrument state based on a new trade.
     ///
     /// This method handles:
+    /// - Converting the trade fees into the instrument quote asset, since [`Position`] PnL is
+    ///   denominated in quote.
     /// - Opening/updating the current position state based on a new trade.
     /// - Updating the internal [`TearSheetGenerator`] if a position is exited.
+    ///
+    /// Fees charged in an asset outside the instrument [`Underlying`](barter_instrument::Underlying)
+    /// (eg/ an exchange native token) cannot be converted, so are excluded from the
+    /// [`Position`] PnL. The fee asset balance is still updated by its associated
+    /// `BalanceSnapshot`.
     pub fn update_from_trade(
         &mut self,
-        trade: &Trade<QuoteAsset, InstrumentKey>,
+        trade: &Trade<AssetKey, InstrumentKey>,
     ) -> Option<PositionExited<QuoteAsset, InstrumentKey>>
     where
+        AssetKey: Debug + PartialEq,
         InstrumentKey: Debug + Clone + PartialEq,
     {
+        let fees_quote = trade
+            .fees_quote(&self.instrument.underlying)
+            .unwrap_or_else(|| {
+                warn!(
+                    instrument = ?trade.instrument,
+                    fees = ?trade.fees,
+                    "InstrumentState cannot convert Trade fees into quote asset - excluding from Position PnL"
+                );
+                Decimal::ZERO
+            });
+
         self.position
-            .update_from_trade(trade)
+            .update_from_trade(&trade.with_quote_fees(fees_quote))
             .inspect(|closed| self.tear_sheet.update_from_position(closed))
     }
 
+    /// Updates the instrument state based on a new [`FundingPayment`].
+    ///
+    /// The funding `amount` is added to the `pnl_realised` of any open [`Position`].
+    pub fn update_from_funding(&mut self, funding: &FundingPayment<InstrumentKey>) {
+        if let Some(position) = &mut self.position.current {
+            position.pnl_realised += funding.amount;
+        }
+    }
+
     /// Updates the instrument state based on a new market event.
     ///
     /// If the market event has a price associated with it (eg/ `PublicTrade`, `OrderBookL1`), any
@@ -156,6 +156,13 @@ impl<GlobalData, InstrumentData> EngineState<GlobalData, InstrumentData> {
                 instrument_state.data.process(event);
                 instrument_state.update_from_trade(trade)
             }
+            AccountEventKind::Funding(funding) => {
+                let instrument_state = self.instruments.instrument_index_mut(&funding.instrument);
+
+                instrument_state.update_from_funding(funding);
+                instrument_state.data.process(event);
+                None
+            }
         };
 
         // Update any user provided GlobalData State
@@ -135,6 +135,24 @@ where
             (Entry::Occupied(entry), Some(update)) => (entry, update),
         };
 
+        // Ignore illegal regressions from stale or out-of-order snapshots
+        // eg/ Open -> OpenInFlight, or a decreasing filled quantity
+        if let Err(error) =
+            OrderState::<AssetKey, InstrumentKey>::Active(current_entry.get().state.clone())
+                .transition(OrderState::Active(update.state.clone()))
+        {
+            warn!(
+                exchange = ?snapshot.key.exchange,
+                instrument = ?snapshot.key.instrument,
+                strategy = %snapshot.key.strategy,
+                cid = %snapshot.key.cid,
+                update = ?snapshot,
+                %error,
+                "OrderManager received an illegal OrderState transition - ignoring"
+            );
+            return;
+        }
+
         match (&current_entry.get().state, update.state) {
             (ActiveOrderState::OpenInFlight(_), ActiveOrderState::OpenInFlight(_)) => {
                 warn!(
@@ -172,16 +190,6 @@ where
                 );
                 current_entry.get_mut().state = ActiveOrderState::CancelInFlight(update);
             }
-            (ActiveOrderState::Open(_), ActiveOrderState::OpenInFlight(_)) => {
-                warn!(
-                    exchange = ?snapshot.key.exchange,
-                    instrument = ?snapshot.key.instrument,
-                    strategy = %snapshot.key.strategy,
-                    cid = %snapshot.key.cid,
-                    update = ?snapshot,
-                    "OrderManager received an OpenInFlight recording for an Open order - ignoring"
-                );
-            }
             (ActiveOrderState::Open(current), ActiveOrderState::Open(update)) => {
                 if current.time_exchange <= update.time_exchange {
                     debug!(
@@ -225,15 +233,11 @@ where
                     order: Some(latest_open),
                 })
             }
-            (ActiveOrderState::CancelInFlight(_), ActiveOrderState::OpenInFlight(_)) => {
-                error!(
-                    exchange = ?snapshot.key.exchange,
-                    instrument = ?snapshot.key.instrument,
-                    strategy = %snapshot.key.strategy,
-                    cid = %snapshot.key.cid,
-                    update = ?snapshot,
-                    "OrderManager received an OpenInFlight recording for a CancelInFlight - ignoring"
-                );
+            (
+                ActiveOrderState::Open(_) | ActiveOrderState::CancelInFlight(_),
+                ActiveOrderState::OpenInFlight(_),
+            ) => {
+                // Rejected by OrderState::transition above
             }
             (ActiveOrderState::CancelInFlight(current), ActiveOrderState::Open(update)) => {
                 debug!(
@@ -587,6 +591,8 @@ mod tests {
                 quantity: dec!(1),
                 kind: OrderKind::Limit,
                 time_in_force: TimeInForce::GoodUntilEndOfDay,
+                reduce_only: false,
+                position_side: None,
             },
         }
     }
@@ -837,6 +843,30 @@ mod tests {
                     ActiveOrderState::Open(open(time_plus_secs(time_base, 1))),
                 )]),
             },
+            TestCase {
+                name: "tracked Open, out-of-order Snapshot is active Open with newer time but lower filled quantity, so ignore",
+                state: orders([order(
+                    cid.clone(),
+                    ActiveOrderState::Open(Open {
+                        filled_quantity: dec!(0.5),
+                        ..open(time_base)
+                    }),
+                )]),
+                input: Snapshot(order(
+                    cid.clone(),
+                    OrderState::active(Open {
+                        filled_quantity: dec!(0.2),
+                        ..open(time_plus_secs(time_base, 1))
+                    }),
+                )),
+                expected: orders([order(
+                    cid.clone(),
+                    ActiveOrderState::Open(Open {
+                        filled_quantity: dec!(0.5),
+                        ..open(time_base)
+                    }),
+                )]),
+            },
             TestCase {
                 name: "tracked Open, Snapshot is active CancelInFlight w/ newer Open, update accordingly",
                 state: orders([order(
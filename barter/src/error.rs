@@ -18,6 +18,9 @@ pub enum JackbotError {
     #[error("market data: {0}")]
     MarketData(#[from] DataError),
 
+    #[error("backtest market data: {0}")]
+    BacktestMarketData(String),
+
     #[error("execution: {0}")]
     Execution(#[from] ExecutionError),
 
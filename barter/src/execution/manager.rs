@@ -258,9 +258,10 @@ where
                     },
                     Some(ExecutionRequest::Open(request)) => {
                         // Panic since the system is set up incorrectly, so it's foolish to continue
+                        // Round to the instrument tick & step sizes before sending
                         let client_request = self
                             .indexer
-                            .order_request(&request)
+                            .order_request_open(&request)
                             .unwrap_or_else(|error| panic!(
                                 "ExecutionManager received open request for non-configured key: {error}"
                             ));
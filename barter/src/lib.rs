@@ -182,15 +182,25 @@ impl Sequence {
 /// Jackbot core test utilities.
 pub mod test_utils {
     use crate::{
-        Timed, engine::state::asset::AssetState, statistic::summary::asset::TearSheetAssetGenerator,
+        Timed,
+        engine::state::{asset::AssetState, position::Position},
+        statistic::summary::asset::TearSheetAssetGenerator,
     };
     use barter_execution::{
         balance::Balance,
-        order::id::{OrderId, StrategyId},
+        order::{
+            OrderEvent, OrderKey, OrderKind, TimeInForce,
+            id::{ClientOrderId, OrderId, StrategyId},
+            request::{OrderRequestOpen, RequestOpen},
+        },
         trade::{AssetFees, Trade, TradeId},
     };
     use barter_instrument::{
-        Side, asset::QuoteAsset, instrument::name::InstrumentNameInternal, test_utils::asset,
+        Side,
+        asset::QuoteAsset,
+        exchange::ExchangeIndex,
+        instrument::{InstrumentIndex, name::InstrumentNameInternal},
+        test_utils::asset,
     };
     use chrono::{DateTime, Days, TimeDelta, Utc};
     use rust_decimal::Decimal;
@@ -272,4 +282,48 @@ pub mod test_utils {
             statistics: TearSheetAssetGenerator::init(&balance),
         }
     }
+
+    pub fn order_request_open(
+        instrument: usize,
+        side: Side,
+        price: f64,
+        quantity: f64,
+    ) -> OrderRequestOpen {
+        OrderEvent {
+            key: OrderKey {
+                exchange: ExchangeIndex(0),
+                instrument: InstrumentIndex(instrument),
+                strategy: StrategyId::new("strategy"),
+                cid: ClientOrderId::new("cid"),
+            },
+            state: RequestOpen {
+                side,
+                price: price.try_into().unwrap(),
+                quantity: quantity.try_into().unwrap(),
+                kind: OrderKind::Market,
+                time_in_force: TimeInForce::ImmediateOrCancel,
+                reduce_only: false,
+                position_side: None,
+            },
+        }
+    }
+
+    pub fn position(
+        instrument: usize,
+        side: Side,
+        price: f64,
+        quantity: f64,
+    ) -> Position<QuoteAsset, InstrumentIndex> {
+        Position::from(&Trade {
+            id: TradeId::new("trade_id"),
+            order_id: OrderId::new("order_id"),
+            instrument: InstrumentIndex(instrument),
+            strategy: StrategyId::new("strategy"),
+            time_exchange: DateTime::<Utc>::MIN_UTC,
+            side,
+            price: price.try_into().unwrap(),
+            quantity: quantity.try_into().unwrap(),
+            fees: AssetFees::quote_fees(Decimal::ZERO),
+        })
+    }
 }
@@ -1,3 +1,4 @@
+use crate::engine::state::position::Position;
 use barter_instrument::Side;
 use rust_decimal::Decimal;
 
@@ -60,3 +61,105 @@ pub fn calculate_delta(
         Side::Sell => -delta,
     }
 }
+
+/// Calculates the signed net quantity of an optional [`Position`].
+///
+/// A positive return value indicates a LONG position, a negative value indicates a SHORT
+/// position, and zero indicates no position.
+pub fn calculate_net_position<AssetKey, InstrumentKey>(
+    position: Option<&Position<AssetKey, InstrumentKey>>,
+) -> Decimal {
+    position.map_or(Decimal::ZERO, |position| match position.side {
+        Side::Buy => position.quantity_abs,
+        Side::Sell => -position.quantity_abs,
+    })
+}
+
+/// Determines if an order of the provided `side` & `quantity` only reduces the provided net
+/// position (see [`calculate_net_position`]), without flipping it into the opposite direction.
+pub fn is_position_reducing(net_position: Decimal, side: Side, quantity: Decimal) -> bool {
+    match side {
+        Side::Buy => net_position < Decimal::ZERO && quantity <= net_position.abs(),
+        Side::Sell => net_position > Decimal::ZERO && quantity <= net_position,
+    }
+}
+
+/// Calculates the position quantity that risks a fixed fraction of equity should the stop price
+/// be hit.
+///
+/// quantity = (equity * risk_fraction) / |entry - stop|
+///
+/// Works for both long (stop below entry) and short (stop above entry) setups. Returns
+/// `Decimal::ZERO` if the entry and stop prices are equal (ie/ zero risk distance), or if
+/// overflow has occurred.
+///
+/// # Arguments
+/// * `equity` - Total account equity in quote asset
+/// * `risk_fraction` - Fraction of equity to risk on the trade (eg/ 0.01 for 1%)
+/// * `entry` - Expected entry price
+/// * `stop` - Stop-loss price
+pub fn position_size(
+    equity: Decimal,
+    risk_fraction: Decimal,
+    entry: Decimal,
+    stop: Decimal,
+) -> Decimal {
+    let distance = match entry.checked_sub(stop) {
+        Some(distance) if !distance.is_zero() => distance.abs(),
+        _ => return Decimal::ZERO,
+    };
+
+    equity
+        .checked_mul(risk_fraction)
+        .and_then(|risk_amount| risk_amount.checked_div(distance))
+        .unwrap_or(Decimal::ZERO)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_position_size() {
+        struct TestCase {
+            equity: Decimal,
+            risk_fraction: Decimal,
+            entry: Decimal,
+            stop: Decimal,
+            expected: Decimal,
+        }
+
+        let cases = vec![
+            // TC0: long setup with stop below entry
+            TestCase {
+                equity: dec!(10000),
+                risk_fraction: dec!(0.01),
+                entry: dec!(100),
+                stop: dec!(95),
+                expected: dec!(20),
+            },
+            // TC1: short setup with stop above entry
+            TestCase {
+                equity: dec!(10000),
+                risk_fraction: dec!(0.02),
+                entry: dec!(50),
+                stop: dec!(52),
+                expected: dec!(100),
+            },
+            // TC2: degenerate setup with equal entry & stop
+            TestCase {
+                equity: dec!(10000),
+                risk_fraction: dec!(0.01),
+                entry: dec!(100),
+                stop: dec!(100),
+                expected: Decimal::ZERO,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = position_size(test.equity, test.risk_fraction, test.entry, test.stop);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
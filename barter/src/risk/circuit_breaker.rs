@@ -0,0 +1,390 @@
+use crate::{
+    engine::state::{EngineState, trading::TradingState},
+    risk::{
+        RiskApproved, RiskManager, RiskRefused,
+        check::util::{calculate_net_position, is_position_reducing},
+    },
+};
+use barter_execution::order::request::{OrderRequestCancel, OrderRequestOpen};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{Debug, Formatter},
+    sync::Arc,
+};
+use tracing::{info, warn};
+
+/// [`RiskManager`] wrapper that acts as an account level kill switch based on equity drawdown.
+///
+/// The circuit breaker tracks the peak account equity (as calculated by the provided `FnEquity`
+/// from the `Engine` state) and trips once the drawdown from peak exceeds `max_drawdown`.
+///
+/// Whilst tripped, all new position opening/increasing orders are refused, and only orders that
+/// reduce an existing position are approved. The circuit breaker resets once equity recovers to
+/// within `reset_drawdown` of the peak.
+///
+/// The `Engine` `TradingState` is left untouched, so algo orders continue to be generated and
+/// checked whilst tripped. This allows position reducing orders through, and re-evaluates equity
+/// on each check so the circuit breaker can reset.
+///
+/// Note that all orders are first checked by the `inner` [`RiskManager`].
+#[derive(Clone)]
+pub struct DrawdownCircuitBreaker<Risk, FnEquity> {
+    /// Inner [`RiskManager`] that checks orders before the circuit breaker is applied.
+    pub inner: Risk,
+
+    /// Drawdown fraction from peak equity (eg/ 0.2 for 20%) that trips the circuit breaker.
+    pub max_drawdown: Decimal,
+
+    /// Drawdown fraction from peak equity (eg/ 0.05 for 5%) that equity must recover within to
+    /// reset a tripped circuit breaker.
+    pub reset_drawdown: Decimal,
+
+    /// Calculates the current account equity from the `Engine` state, if available.
+    pub equity: FnEquity,
+
+    state: Arc<parking_lot::RwLock<DrawdownCircuitBreakerState>>,
+}
+
+/// Current state of a [`DrawdownCircuitBreaker`].
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub struct DrawdownCircuitBreakerState {
+    /// Peak account equity observed, if any.
+    pub equity_peak: Option<Decimal>,
+
+    /// Whether the circuit breaker is tripped.
+    pub tripped: bool,
+}
+
+impl<Risk, FnEquity> DrawdownCircuitBreaker<Risk, FnEquity> {
+    /// Construct a new [`DrawdownCircuitBreaker`] wrapping the provided inner [`RiskManager`].
+    pub fn new(
+        inner: Risk,
+        max_drawdown: Decimal,
+        reset_drawdown: Decimal,
+        equity: FnEquity,
+    ) -> Self {
+        Self {
+            inner,
+            max_drawdown,
+            reset_drawdown,
+            equity,
+            state: Arc::new(parking_lot::RwLock::new(
+                DrawdownCircuitBreakerState::default(),
+            )),
+        }
+    }
+
+    /// Returns the current [`DrawdownCircuitBreakerState`].
+    pub fn state(&self) -> DrawdownCircuitBreakerState {
+        *self.state.read()
+    }
+
+    /// Returns `true` if the circuit breaker is tripped.
+    pub fn is_tripped(&self) -> bool {
+        self.state.read().tripped
+    }
+
+    /// Returns the [`TradingState`] implied by the circuit breaker for position opening orders.
+    ///
+    /// This is `TradingState::Disabled` whilst tripped, and `TradingState::Enabled` otherwise.
+    pub fn trading_state(&self) -> TradingState {
+        if self.is_tripped() {
+            TradingState::Disabled
+        } else {
+            TradingState::Enabled
+        }
+    }
+
+    /// Updates the circuit breaker with the latest account equity, tripping or resetting it if
+    /// the drawdown from peak equity crosses the configured thresholds.
+    ///
+    /// Returns the updated [`DrawdownCircuitBreakerState`].
+    pub fn update(&self, equity: Decimal) -> DrawdownCircuitBreakerState {
+        let mut state = self.state.write();
+
+        let equity_peak = match state.equity_peak {
+            Some(peak) if peak >= equity => peak,
+            _ => {
+                state.equity_peak = Some(equity);
+                equity
+            }
+        };
+
+        let drawdown = if equity_peak > Decimal::ZERO {
+            (equity_peak - equity)
+                .checked_div(equity_peak)
+                .unwrap_or(Decimal::ZERO)
+        } else {
+            Decimal::ZERO
+        };
+
+        if !state.tripped && drawdown > self.max_drawdown {
+            warn!(
+                %equity,
+                %equity_peak,
+                %drawdown,
+                max_drawdown = %self.max_drawdown,
+                "DrawdownCircuitBreaker tripped: only position reducing orders will be approved"
+            );
+            state.tripped = true;
+        } else if state.tripped && drawdown <= self.reset_drawdown {
+            info!(
+                %equity,
+                %equity_peak,
+                %drawdown,
+                reset_drawdown = %self.reset_drawdown,
+                "DrawdownCircuitBreaker reset"
+            );
+            state.tripped = false;
+        }
+
+        *state
+    }
+}
+
+impl<Risk, FnEquity> Debug for DrawdownCircuitBreaker<Risk, FnEquity>
+where
+    Risk: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DrawdownCircuitBreaker")
+            .field("inner", &self.inner)
+            .field("max_drawdown", &self.max_drawdown)
+            .field("reset_drawdown", &self.reset_drawdown)
+            .field("state", &self.state())
+            .finish()
+    }
+}
+
+impl<Risk, FnEquity, GlobalData, InstrumentData> RiskManager
+    for DrawdownCircuitBreaker<Risk, FnEquity>
+where
+    Risk: RiskManager<State = EngineState<GlobalData, InstrumentData>>,
+    FnEquity: Fn(&EngineState<GlobalData, InstrumentData>) -> Option<Decimal>,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn check(
+        &self,
+        state: &Self::State,
+        cancels: impl IntoIterator<Item = OrderRequestCancel>,
+        opens: impl IntoIterator<Item = OrderRequestOpen>,
+    ) -> (
+        impl IntoIterator<Item = RiskApproved<OrderRequestCancel>>,
+        impl IntoIterator<Item = RiskApproved<OrderRequestOpen>>,
+        impl IntoIterator<Item = RiskRefused<OrderRequestCancel>>,
+        impl IntoIterator<Item = RiskRefused<OrderRequestOpen>>,
+    ) {
+        let (approved_cancels, approved_opens, refused_cancels, refused_opens) =
+            self.inner.check(state, cancels, opens);
+
+        if let Some(equity) = (self.equity)(state) {
+            self.update(equity);
+        }
+
+        let tripped = self.is_tripped();
+
+        let (approved_opens, tripped_opens): (Vec<_>, Vec<_>) = approved_opens.into_iter().fold(
+            (Vec::new(), Vec::new()),
+            |(mut approved, mut refused), approved_open| {
+                let RiskApproved(request_open) = approved_open;
+
+                if !tripped {
+                    approved.push(RiskApproved::new(request_open));
+                    return (approved, refused);
+                }
+
+                let net_position = calculate_net_position(
+                    state
+                        .instruments
+                        .instrument_index(&request_open.key.instrument)
+                        .position
+                        .current
+                        .as_ref(),
+                );
+
+                if is_position_reducing(
+                    net_position,
+                    request_open.state.side,
+                    request_open.state.quantity,
+                ) {
+                    approved.push(RiskApproved::new(request_open));
+                } else {
+                    refused.push(RiskRefused::new(
+                        request_open,
+                        "DrawdownCircuitBreaker tripped: only position reducing orders approved",
+                    ));
+                }
+
+                (approved, refused)
+            },
+        );
+
+        (
+            approved_cancels,
+            approved_opens,
+            refused_cancels,
+            refused_opens.into_iter().chain(tripped_opens),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Timed,
+        engine::state::{global::DefaultGlobalData, instrument::data::DefaultInstrumentMarketData},
+        risk::DefaultRiskManager,
+        test_utils::{order_request_open, position},
+    };
+    use barter_execution::balance::Balance;
+    use barter_instrument::{
+        Side, asset::ExchangeAsset, exchange::ExchangeId, index::IndexedInstruments,
+        instrument::InstrumentIndex, test_utils::instrument,
+    };
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+
+    type TestState = EngineState<DefaultGlobalData, DefaultInstrumentMarketData>;
+
+    fn equity_usdt(state: &TestState) -> Option<Decimal> {
+        state
+            .assets
+            .asset(&ExchangeAsset::new(ExchangeId::BinanceSpot, "usdt"))
+            .balance
+            .map(|balance| balance.value.total)
+    }
+
+    fn set_equity_usdt(state: &mut TestState, total: Decimal) {
+        state
+            .assets
+            .asset_mut(&ExchangeAsset::new(ExchangeId::BinanceSpot, "usdt"))
+            .balance = Some(Timed::new(
+            Balance::new(total, total),
+            DateTime::<Utc>::MIN_UTC,
+        ));
+    }
+
+    fn state() -> TestState {
+        let instruments =
+            IndexedInstruments::new([instrument(ExchangeId::BinanceSpot, "btc", "usdt")]);
+
+        let mut state = EngineState::builder(
+            &instruments,
+            DefaultGlobalData,
+            DefaultInstrumentMarketData::default,
+        )
+        .build();
+
+        set_equity_usdt(&mut state, dec!(1000));
+        state
+    }
+
+    fn check(
+        risk: &impl RiskManager<State = TestState>,
+        state: &TestState,
+        request: OrderRequestOpen,
+    ) -> (
+        Vec<RiskApproved<OrderRequestOpen>>,
+        Vec<RiskRefused<OrderRequestOpen>>,
+    ) {
+        let (_, approved, _, refused) = risk.check(state, [], [request]);
+        (
+            approved.into_iter().collect(),
+            refused.into_iter().collect(),
+        )
+    }
+
+    #[test]
+    fn test_drawdown_circuit_breaker_trips_and_resets() {
+        let mut state = state();
+        let breaker = DrawdownCircuitBreaker::new(
+            DefaultRiskManager::default(),
+            dec!(0.2),
+            dec!(0.05),
+            equity_usdt,
+        );
+
+        // Equity at peak, so opening orders are approved
+        let (approved, refused) = check(
+            &breaker,
+            &state,
+            order_request_open(0, Side::Buy, 100.0, 1.0),
+        );
+        assert_eq!(approved.len(), 1);
+        assert!(refused.is_empty());
+        assert_eq!(breaker.state().equity_peak, Some(dec!(1000)));
+        assert_eq!(breaker.trading_state(), TradingState::Enabled);
+
+        // Equity declines within max_drawdown, so opening orders are still approved
+        set_equity_usdt(&mut state, dec!(850));
+        let (approved, _) = check(
+            &breaker,
+            &state,
+            order_request_open(0, Side::Buy, 100.0, 1.0),
+        );
+        assert_eq!(approved.len(), 1);
+        assert!(!breaker.is_tripped());
+
+        // Equity declines beyond max_drawdown, so opening orders are refused
+        set_equity_usdt(&mut state, dec!(750));
+        let (approved, refused) = check(
+            &breaker,
+            &state,
+            order_request_open(0, Side::Buy, 100.0, 1.0),
+        );
+        assert!(approved.is_empty());
+        assert_eq!(refused.len(), 1);
+        assert!(breaker.is_tripped());
+        assert_eq!(breaker.trading_state(), TradingState::Disabled);
+
+        // Whilst tripped, position reducing orders are approved
+        state
+            .instruments
+            .instrument_index_mut(&InstrumentIndex(0))
+            .position
+            .current = Some(position(0, Side::Buy, 100.0, 2.0));
+
+        let (approved, _) = check(
+            &breaker,
+            &state,
+            order_request_open(0, Side::Sell, 100.0, 1.0),
+        );
+        assert_eq!(approved.len(), 1);
+
+        // Whilst tripped, position flipping orders are refused
+        let (approved, refused) = check(
+            &breaker,
+            &state,
+            order_request_open(0, Side::Sell, 100.0, 3.0),
+        );
+        assert!(approved.is_empty());
+        assert_eq!(refused.len(), 1);
+
+        // Equity recovers, but not within reset_drawdown, so still tripped
+        set_equity_usdt(&mut state, dec!(900));
+        let (approved, _) = check(
+            &breaker,
+            &state,
+            order_request_open(0, Side::Buy, 100.0, 1.0),
+        );
+        assert!(approved.is_empty());
+        assert!(breaker.is_tripped());
+
+        // Equity recovers within reset_drawdown, so circuit breaker resets
+        set_equity_usdt(&mut state, dec!(960));
+        let (approved, refused) = check(
+            &breaker,
+            &state,
+            order_request_open(0, Side::Buy, 100.0, 1.0),
+        );
+        assert_eq!(approved.len(), 1);
+        assert!(refused.is_empty());
+        assert!(!breaker.is_tripped());
+        assert_eq!(breaker.state().equity_peak, Some(dec!(1000)));
+    }
+}
@@ -8,6 +8,18 @@ use std::{fmt::Debug, hash::Hash, marker::PhantomData};
 /// RiskManager checks and utilities.
 pub mod check;
 
+/// [`DrawdownCircuitBreaker`](circuit_breaker::DrawdownCircuitBreaker) [`RiskManager`] that
+/// only approves position reducing orders once an equity drawdown limit is exceeded.
+pub mod circuit_breaker;
+
+/// [`PositionLimits`](position_limit::PositionLimits) [`RiskManager`] that enforces a maximum
+/// net position per instrument.
+pub mod position_limit;
+
+/// [`NotionalThrottle`](throttle::NotionalThrottle) [`RiskManager`] that caps the total notional
+/// approved within a rolling time window.
+pub mod throttle;
+
 /// RiskManager interface that reviews and optionally filters cancel and open order requests
 /// generated by an [`AlgoStrategy`](super::strategy::algo::AlgoStrategy).
 ///
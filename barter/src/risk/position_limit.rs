@@ -0,0 +1,240 @@
+use crate::{
+    engine::state::EngineState,
+    risk::{
+        RiskApproved, RiskManager, RiskRefused,
+        check::{CheckHigherThan, RiskCheck, util::calculate_net_position},
+    },
+};
+use barter_execution::order::request::{OrderRequestCancel, OrderRequestOpen};
+use barter_instrument::{Side, instrument::InstrumentIndex};
+use derive_more::Constructor;
+use fnv::FnvHashMap;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+/// [`RiskManager`] wrapper that enforces a maximum absolute net position per instrument.
+///
+/// Open order requests that would push the net position of an instrument beyond its configured
+/// `max_position` are refused. Orders that reduce the absolute net position are always approved,
+/// and instruments without a configured limit are not checked.
+///
+/// The net position is derived from the current `Position` in the `Engine` state, plus any
+/// approved open requests for the same instrument in the current batch.
+///
+/// Note that all orders are first checked by the `inner` [`RiskManager`].
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Constructor)]
+pub struct PositionLimits<Risk> {
+    /// Inner [`RiskManager`] that checks orders before position limits are applied.
+    pub inner: Risk,
+
+    /// Maximum absolute net position quantity for each limited instrument.
+    pub max_position: FnvHashMap<InstrumentIndex, CheckHigherThan<Decimal>>,
+}
+
+impl<Risk, GlobalData, InstrumentData> RiskManager for PositionLimits<Risk>
+where
+    Risk: RiskManager<State = EngineState<GlobalData, InstrumentData>>,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn check(
+        &self,
+        state: &Self::State,
+        cancels: impl IntoIterator<Item = OrderRequestCancel>,
+        opens: impl IntoIterator<Item = OrderRequestOpen>,
+    ) -> (
+        impl IntoIterator<Item = RiskApproved<OrderRequestCancel>>,
+        impl IntoIterator<Item = RiskApproved<OrderRequestOpen>>,
+        impl IntoIterator<Item = RiskRefused<OrderRequestCancel>>,
+        impl IntoIterator<Item = RiskRefused<OrderRequestOpen>>,
+    ) {
+        let (approved_cancels, approved_opens, refused_cancels, refused_opens) =
+            self.inner.check(state, cancels, opens);
+
+        // Net position of each instrument, including approved opens from this batch
+        let mut net_positions = FnvHashMap::<InstrumentIndex, Decimal>::default();
+
+        let (approved_opens, limited_opens): (Vec<_>, Vec<_>) = approved_opens.into_iter().fold(
+            (Vec::new(), Vec::new()),
+            |(mut approved, mut refused), approved_open| {
+                let RiskApproved(request_open) = approved_open;
+                let instrument = request_open.key.instrument;
+
+                let Some(max_position) = self.max_position.get(&instrument) else {
+                    approved.push(RiskApproved::new(request_open));
+                    return (approved, refused);
+                };
+
+                let net_position = *net_positions.entry(instrument).or_insert_with(|| {
+                    calculate_net_position(
+                        state
+                            .instruments
+                            .instrument_index(&instrument)
+                            .position
+                            .current
+                            .as_ref(),
+                    )
+                });
+
+                let net_position_next = match request_open.state.side {
+                    Side::Buy => net_position + request_open.state.quantity,
+                    Side::Sell => net_position - request_open.state.quantity,
+                };
+
+                // Orders that reduce the absolute net position are always approved
+                if net_position_next.abs() <= net_position.abs() {
+                    net_positions.insert(instrument, net_position_next);
+                    approved.push(RiskApproved::new(request_open));
+                    return (approved, refused);
+                }
+
+                if let Err(error) = max_position.check(&net_position_next.abs()) {
+                    warn!(
+                        %instrument,
+                        ?request_open,
+                        ?error,
+                        "PositionLimits filtered order: max_position failed"
+                    );
+                    refused.push(RiskRefused::new(
+                        request_open,
+                        "PositionLimits max_position failed",
+                    ));
+                    return (approved, refused);
+                }
+
+                net_positions.insert(instrument, net_position_next);
+                approved.push(RiskApproved::new(request_open));
+                (approved, refused)
+            },
+        );
+
+        (
+            approved_cancels,
+            approved_opens,
+            refused_cancels,
+            refused_opens.into_iter().chain(limited_opens),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        engine::state::{global::DefaultGlobalData, instrument::data::DefaultInstrumentMarketData},
+        risk::DefaultRiskManager,
+        test_utils::{order_request_open, position},
+    };
+    use barter_instrument::{
+        exchange::ExchangeId, index::IndexedInstruments, test_utils::instrument,
+    };
+    use rust_decimal_macros::dec;
+
+    type TestState = EngineState<DefaultGlobalData, DefaultInstrumentMarketData>;
+
+    fn state() -> TestState {
+        let instruments = IndexedInstruments::new([
+            instrument(ExchangeId::BinanceSpot, "btc", "usdt"),
+            instrument(ExchangeId::BinanceSpot, "eth", "usdt"),
+        ]);
+
+        EngineState::builder(
+            &instruments,
+            DefaultGlobalData,
+            DefaultInstrumentMarketData::default,
+        )
+        .build()
+    }
+
+    fn risk_manager() -> PositionLimits<DefaultRiskManager<TestState>> {
+        PositionLimits::new(
+            DefaultRiskManager::default(),
+            FnvHashMap::from_iter([(InstrumentIndex(0), CheckHigherThan::new(dec!(2)))]),
+        )
+    }
+
+    #[test]
+    fn test_position_limits() {
+        struct TestCase {
+            position: Option<(Side, f64)>,
+            opens: Vec<OrderRequestOpen>,
+            expected_approved: usize,
+            expected_refused: usize,
+        }
+
+        let cases = vec![
+            // TC0: order within limit is approved
+            TestCase {
+                position: None,
+                opens: vec![order_request_open(0, Side::Buy, 100.0, 1.5)],
+                expected_approved: 1,
+                expected_refused: 0,
+            },
+            // TC1: order exceeding limit from existing position is refused
+            TestCase {
+                position: Some((Side::Buy, 1.5)),
+                opens: vec![order_request_open(0, Side::Buy, 100.0, 1.0)],
+                expected_approved: 0,
+                expected_refused: 1,
+            },
+            // TC2: order reducing a position already beyond the limit is approved
+            TestCase {
+                position: Some((Side::Sell, 5.0)),
+                opens: vec![order_request_open(0, Side::Buy, 100.0, 1.0)],
+                expected_approved: 1,
+                expected_refused: 0,
+            },
+            // TC3: cumulative orders in a batch exceeding limit are refused
+            TestCase {
+                position: None,
+                opens: vec![
+                    order_request_open(0, Side::Sell, 100.0, 1.5),
+                    order_request_open(0, Side::Sell, 100.0, 1.0),
+                ],
+                expected_approved: 1,
+                expected_refused: 1,
+            },
+            // TC4: order flipping position beyond limit is refused
+            TestCase {
+                position: Some((Side::Buy, 1.0)),
+                opens: vec![order_request_open(0, Side::Sell, 100.0, 3.5)],
+                expected_approved: 0,
+                expected_refused: 1,
+            },
+            // TC5: order for an instrument without a limit is approved
+            TestCase {
+                position: None,
+                opens: vec![order_request_open(1, Side::Buy, 100.0, 100.0)],
+                expected_approved: 1,
+                expected_refused: 0,
+            },
+        ];
+
+        let risk = risk_manager();
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let mut state = state();
+            state
+                .instruments
+                .instrument_index_mut(&InstrumentIndex(0))
+                .position
+                .current = test
+                .position
+                .map(|(side, quantity)| position(0, side, 100.0, quantity));
+
+            let (_, approved, _, refused) = risk.check(&state, [], test.opens);
+
+            assert_eq!(
+                approved.into_iter().count(),
+                test.expected_approved,
+                "TC{index} failed"
+            );
+            assert_eq!(
+                refused.into_iter().count(),
+                test.expected_refused,
+                "TC{index} failed"
+            );
+        }
+    }
+}
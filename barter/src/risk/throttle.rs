@@ -0,0 +1,242 @@
+use crate::{
+    engine::state::EngineState,
+    risk::{RiskApproved, RiskManager, RiskRefused, check::util::calculate_quote_notional},
+};
+use barter_execution::order::request::{OrderRequestCancel, OrderRequestOpen};
+use rust_decimal::Decimal;
+use std::{collections::VecDeque, sync::Arc, time::Duration};
+use tokio::time::Instant;
+use tracing::warn;
+
+/// [`RiskManager`] wrapper that caps the total quote notional of open order requests approved
+/// within a rolling time `window`.
+///
+/// Useful for protecting against "fat-finger" bursts of orders. Open requests that would push the
+/// total approved notional within the `window` beyond `max_notional` are refused.
+///
+/// Note that notional values are summed across all instruments, so all instruments are assumed to
+/// share a common quote asset (eg/ usdt).
+///
+/// Note that all orders are first checked by the `inner` [`RiskManager`].
+#[derive(Debug, Clone)]
+pub struct NotionalThrottle<Risk> {
+    /// Inner [`RiskManager`] that checks orders before the throttle is applied.
+    pub inner: Risk,
+
+    /// Maximum total quote notional that can be approved within the `window`.
+    pub max_notional: Decimal,
+
+    /// Rolling time window over which approved notional is summed.
+    pub window: Duration,
+
+    history: Arc<parking_lot::RwLock<VecDeque<(Instant, Decimal)>>>,
+}
+
+impl<Risk> NotionalThrottle<Risk> {
+    /// Construct a new [`NotionalThrottle`] wrapping the provided inner [`RiskManager`].
+    pub fn new(inner: Risk, max_notional: Decimal, window: Duration) -> Self {
+        Self {
+            inner,
+            max_notional,
+            window,
+            history: Arc::new(parking_lot::RwLock::new(VecDeque::new())),
+        }
+    }
+
+    /// Returns the total quote notional approved within the current rolling `window`.
+    pub fn notional_in_window(&self) -> Decimal {
+        let now = Instant::now();
+        self.history
+            .read()
+            .iter()
+            .filter(|(time, _)| now.duration_since(*time) < self.window)
+            .map(|(_, notional)| *notional)
+            .sum()
+    }
+}
+
+impl<Risk, GlobalData, InstrumentData> RiskManager for NotionalThrottle<Risk>
+where
+    Risk: RiskManager<State = EngineState<GlobalData, InstrumentData>>,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn check(
+        &self,
+        state: &Self::State,
+        cancels: impl IntoIterator<Item = OrderRequestCancel>,
+        opens: impl IntoIterator<Item = OrderRequestOpen>,
+    ) -> (
+        impl IntoIterator<Item = RiskApproved<OrderRequestCancel>>,
+        impl IntoIterator<Item = RiskApproved<OrderRequestOpen>>,
+        impl IntoIterator<Item = RiskRefused<OrderRequestCancel>>,
+        impl IntoIterator<Item = RiskRefused<OrderRequestOpen>>,
+    ) {
+        let (approved_cancels, approved_opens, refused_cancels, refused_opens) =
+            self.inner.check(state, cancels, opens);
+
+        let now = Instant::now();
+        let mut history = self.history.write();
+
+        // Remove approved notionals that have rolled out of the window
+        while history
+            .front()
+            .is_some_and(|(time, _)| now.duration_since(*time) >= self.window)
+        {
+            history.pop_front();
+        }
+
+        let mut notional_in_window = history
+            .iter()
+            .map(|(_, notional)| *notional)
+            .sum::<Decimal>();
+
+        let (approved_opens, throttled_opens): (Vec<_>, Vec<_>) = approved_opens.into_iter().fold(
+            (Vec::new(), Vec::new()),
+            |(mut approved, mut refused), approved_open| {
+                let RiskApproved(request_open) = approved_open;
+
+                let contract_size = state
+                    .instruments
+                    .instrument_index(&request_open.key.instrument)
+                    .instrument
+                    .kind
+                    .contract_size();
+
+                let notional_next = calculate_quote_notional(
+                    request_open.state.quantity,
+                    request_open.state.price,
+                    contract_size,
+                )
+                .and_then(|notional| notional.abs().checked_add(notional_in_window));
+
+                match notional_next {
+                    Some(notional_next) if notional_next <= self.max_notional => {
+                        history.push_back((now, notional_next - notional_in_window));
+                        notional_in_window = notional_next;
+                        approved.push(RiskApproved::new(request_open));
+                    }
+                    _ => {
+                        warn!(
+                            ?request_open,
+                            %notional_in_window,
+                            max_notional = %self.max_notional,
+                            window = ?self.window,
+                            "NotionalThrottle filtered order: max_notional in window exceeded"
+                        );
+                        refused.push(RiskRefused::new(
+                            request_open,
+                            "NotionalThrottle max_notional in window exceeded",
+                        ));
+                    }
+                }
+
+                (approved, refused)
+            },
+        );
+
+        (
+            approved_cancels,
+            approved_opens,
+            refused_cancels,
+            refused_opens.into_iter().chain(throttled_opens),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        engine::state::{global::DefaultGlobalData, instrument::data::DefaultInstrumentMarketData},
+        risk::DefaultRiskManager,
+        test_utils::order_request_open,
+    };
+    use barter_instrument::{
+        Side, exchange::ExchangeId, index::IndexedInstruments, test_utils::instrument,
+    };
+    use rust_decimal_macros::dec;
+
+    type TestState = EngineState<DefaultGlobalData, DefaultInstrumentMarketData>;
+
+    fn state() -> TestState {
+        let instruments =
+            IndexedInstruments::new([instrument(ExchangeId::BinanceSpot, "btc", "usdt")]);
+
+        EngineState::builder(
+            &instruments,
+            DefaultGlobalData,
+            DefaultInstrumentMarketData::default,
+        )
+        .build()
+    }
+
+    fn count_approved(
+        risk: &NotionalThrottle<DefaultRiskManager<TestState>>,
+        state: &TestState,
+        opens: Vec<OrderRequestOpen>,
+    ) -> usize {
+        let (_, approved, _, _) = risk.check(state, [], opens);
+        approved.into_iter().count()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_notional_throttle() {
+        let state = state();
+        let throttle = NotionalThrottle::new(
+            DefaultRiskManager::default(),
+            dec!(1000),
+            Duration::from_secs(60),
+        );
+
+        // 600 + 300 notional within max_notional, but additional 200 exceeds it
+        let approved = count_approved(
+            &throttle,
+            &state,
+            vec![
+                order_request_open(0, Side::Buy, 100.0, 6.0),
+                order_request_open(0, Side::Sell, 100.0, 3.0),
+                order_request_open(0, Side::Buy, 100.0, 2.0),
+            ],
+        );
+        assert_eq!(approved, 2);
+        assert_eq!(throttle.notional_in_window(), dec!(900));
+
+        // Still within the window, so further orders exceeding the cap are throttled
+        tokio::time::advance(Duration::from_secs(30)).await;
+        let approved = count_approved(
+            &throttle,
+            &state,
+            vec![order_request_open(0, Side::Buy, 100.0, 2.0)],
+        );
+        assert_eq!(approved, 0);
+
+        // Orders fitting under the cap are still approved
+        let approved = count_approved(
+            &throttle,
+            &state,
+            vec![order_request_open(0, Side::Buy, 100.0, 1.0)],
+        );
+        assert_eq!(approved, 1);
+        assert_eq!(throttle.notional_in_window(), dec!(1000));
+
+        // Initial orders roll out of the window, so orders are approved again
+        tokio::time::advance(Duration::from_secs(31)).await;
+        assert_eq!(throttle.notional_in_window(), dec!(100));
+        let approved = count_approved(
+            &throttle,
+            &state,
+            vec![order_request_open(0, Side::Buy, 100.0, 9.0)],
+        );
+        assert_eq!(approved, 1);
+
+        // Orders that alone exceed the cap are always throttled
+        tokio::time::advance(Duration::from_secs(120)).await;
+        let approved = count_approved(
+            &throttle,
+            &state,
+            vec![order_request_open(0, Side::Buy, 100.0, 11.0)],
+        );
+        assert_eq!(approved, 0);
+    }
+}
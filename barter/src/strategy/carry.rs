@@ -0,0 +1,285 @@
+use barter_data::{event::MarketEvent, subscription::mark_price::MarkPrice};
+use barter_execution::order::{
+    OrderKey, OrderKind, TimeInForce,
+    id::{ClientOrderId, StrategyId},
+    request::{OrderRequestOpen, RequestOpen},
+};
+use barter_instrument::Side;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Configuration of a [`CarryStrategy`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct CarryConfig {
+    /// Absolute funding rate at or above which a carry position is entered.
+    pub entry_threshold: Decimal,
+
+    /// Absolute funding rate at or below which an open carry position is exited.
+    pub exit_threshold: Decimal,
+
+    /// Quantity of each spot & perpetual leg.
+    pub quantity: Decimal,
+}
+
+/// Direction of a carry position.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+pub enum CarryDirection {
+    /// Long spot & short perpetual, collecting positive funding paid by perpetual longs.
+    LongSpotShortPerp,
+
+    /// Short spot & long perpetual, collecting negative funding paid by perpetual shorts.
+    ShortSpotLongPerp,
+}
+
+impl CarryDirection {
+    /// Returns the spot & perpetual [`Side`]s used to enter a carry position in this direction.
+    pub fn entry_sides(&self) -> (Side, Side) {
+        match self {
+            Self::LongSpotShortPerp => (Side::Buy, Side::Sell),
+            Self::ShortSpotLongPerp => (Side::Sell, Side::Buy),
+        }
+    }
+}
+
+/// Action generated by a [`CarryStrategy`] in response to a funding rate update.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+pub enum CarryIntent {
+    Enter(CarryDirection),
+    Exit(CarryDirection),
+}
+
+/// Instrument leg of a carry position, and the price used for its order.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+pub struct CarryLeg<ExchangeKey, InstrumentKey> {
+    pub exchange: ExchangeKey,
+    pub instrument: InstrumentKey,
+    pub price: Decimal,
+}
+
+/// Funding rate carry strategy scaffold.
+///
+/// Enters a delta-neutral spot & perpetual position to collect funding when the absolute
+/// [`MarkPrice`] funding rate reaches the `entry_threshold`, and exits once it normalises to the
+/// `exit_threshold` (or flips against the position).
+///
+/// Each leg is sized using the fixed [`CarryConfig`] `quantity`.
+///
+/// *THIS IS FOR DEMONSTRATION PURPOSES ONLY, NEVER USE FOR REAL TRADING OR IN PRODUCTION*.
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct CarryStrategy {
+    pub id: StrategyId,
+    pub config: CarryConfig,
+    position: Option<CarryDirection>,
+}
+
+impl CarryStrategy {
+    /// Construct a new [`CarryStrategy`] with no open carry position.
+    pub fn new(id: StrategyId, config: CarryConfig) -> Self {
+        Self {
+            id,
+            config,
+            position: None,
+        }
+    }
+
+    /// Returns the [`CarryDirection`] of the open carry position, if any.
+    pub fn position(&self) -> Option<CarryDirection> {
+        self.position
+    }
+
+    /// Update the [`CarryStrategy`] with the funding rate of the perpetual [`MarkPrice`]
+    /// [`MarketEvent`], returning a [`CarryIntent`] if the carry position should change.
+    pub fn process<InstrumentKey>(
+        &mut self,
+        event: &MarketEvent<InstrumentKey, MarkPrice>,
+    ) -> Option<CarryIntent> {
+        self.update_funding_rate(event.kind.funding_rate)
+    }
+
+    /// Update the [`CarryStrategy`] with the latest perpetual funding rate, returning a
+    /// [`CarryIntent`] if the carry position should change.
+    pub fn update_funding_rate(&mut self, funding_rate: Decimal) -> Option<CarryIntent> {
+        let CarryConfig {
+            entry_threshold,
+            exit_threshold,
+            ..
+        } = self.config;
+
+        match self.position {
+            None => {
+                let direction = if funding_rate >= entry_threshold {
+                    CarryDirection::LongSpotShortPerp
+                } else if funding_rate <= -entry_threshold {
+                    CarryDirection::ShortSpotLongPerp
+                } else {
+                    return None;
+                };
+
+                self.position = Some(direction);
+                Some(CarryIntent::Enter(direction))
+            }
+            Some(direction) => {
+                let normalised = match direction {
+                    CarryDirection::LongSpotShortPerp => funding_rate <= exit_threshold,
+                    CarryDirection::ShortSpotLongPerp => funding_rate >= -exit_threshold,
+                };
+
+                normalised.then(|| {
+                    self.position = None;
+                    CarryIntent::Exit(direction)
+                })
+            }
+        }
+    }
+
+    /// Build the `ImmediateOrCancel` `Market` spot & perpetual [`OrderRequestOpen`]s that action
+    /// the provided [`CarryIntent`].
+    pub fn order_requests<ExchangeKey, InstrumentKey>(
+        &self,
+        intent: CarryIntent,
+        spot: &CarryLeg<ExchangeKey, InstrumentKey>,
+        perp: &CarryLeg<ExchangeKey, InstrumentKey>,
+        gen_cid: impl Fn() -> ClientOrderId,
+    ) -> [OrderRequestOpen<ExchangeKey, InstrumentKey>; 2]
+    where
+        ExchangeKey: Clone,
+        InstrumentKey: Clone,
+    {
+        let (spot_side, perp_side) = match intent {
+            CarryIntent::Enter(direction) => direction.entry_sides(),
+            CarryIntent::Exit(direction) => {
+                // Legs are always entered on opposite Sides, so exiting swaps them
+                let (spot_side, perp_side) = direction.entry_sides();
+                (perp_side, spot_side)
+            }
+        };
+
+        [(spot, spot_side), (perp, perp_side)].map(|(leg, side)| OrderRequestOpen {
+            key: OrderKey {
+                exchange: leg.exchange.clone(),
+                instrument: leg.instrument.clone(),
+                strategy: self.id.clone(),
+                cid: gen_cid(),
+            },
+            state: RequestOpen {
+                side,
+                price: leg.price,
+                quantity: self.config.quantity,
+                kind: OrderKind::Market,
+                time_in_force: TimeInForce::ImmediateOrCancel,
+                reduce_only: false,
+                position_side: None,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn strategy() -> CarryStrategy {
+        CarryStrategy::new(
+            StrategyId::new("carry"),
+            CarryConfig {
+                entry_threshold: dec!(0.0005),
+                exit_threshold: dec!(0.0001),
+                quantity: dec!(2),
+            },
+        )
+    }
+
+    #[test]
+    fn test_carry_strategy_update_funding_rate() {
+        struct TestCase {
+            funding_rate: Decimal,
+            expected: Option<CarryIntent>,
+        }
+
+        let mut strategy = strategy();
+
+        let tests = vec![
+            TestCase {
+                // TC0: normal funding rate generates no intent
+                funding_rate: dec!(0.0001),
+                expected: None,
+            },
+            TestCase {
+                // TC1: high positive funding rate enters long spot / short perp
+                funding_rate: dec!(0.001),
+                expected: Some(CarryIntent::Enter(CarryDirection::LongSpotShortPerp)),
+            },
+            TestCase {
+                // TC2: funding rate above the exit threshold holds the position
+                funding_rate: dec!(0.0003),
+                expected: None,
+            },
+            TestCase {
+                // TC3: normalised funding rate exits the position
+                funding_rate: dec!(0.0001),
+                expected: Some(CarryIntent::Exit(CarryDirection::LongSpotShortPerp)),
+            },
+            TestCase {
+                // TC4: high negative funding rate enters short spot / long perp
+                funding_rate: dec!(-0.0005),
+                expected: Some(CarryIntent::Enter(CarryDirection::ShortSpotLongPerp)),
+            },
+            TestCase {
+                // TC5: funding rate flipping against the position exits
+                funding_rate: dec!(0.002),
+                expected: Some(CarryIntent::Exit(CarryDirection::ShortSpotLongPerp)),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = strategy.update_funding_rate(test.funding_rate);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+
+        assert_eq!(strategy.position(), None);
+    }
+
+    #[test]
+    fn test_carry_strategy_order_requests() {
+        let strategy = strategy();
+        let spot = CarryLeg {
+            exchange: "binance_spot",
+            instrument: "btc_usdt",
+            price: dec!(100),
+        };
+        let perp = CarryLeg {
+            exchange: "binance_futures_usd",
+            instrument: "btc_usdt_perp",
+            price: dec!(101),
+        };
+
+        let sides = |intent| {
+            strategy
+                .order_requests(intent, &spot, &perp, || ClientOrderId::new("cid"))
+                .map(|request| {
+                    assert_eq!(request.state.quantity, dec!(2));
+                    (
+                        request.key.instrument,
+                        request.state.side,
+                        request.state.price,
+                    )
+                })
+        };
+
+        assert_eq!(
+            sides(CarryIntent::Enter(CarryDirection::LongSpotShortPerp)),
+            [
+                ("btc_usdt", Side::Buy, dec!(100)),
+                ("btc_usdt_perp", Side::Sell, dec!(101))
+            ]
+        );
+        assert_eq!(
+            sides(CarryIntent::Exit(CarryDirection::LongSpotShortPerp)),
+            [
+                ("btc_usdt", Side::Sell, dec!(100)),
+                ("btc_usdt_perp", Side::Buy, dec!(101))
+            ]
+        );
+    }
+}
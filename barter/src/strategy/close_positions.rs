@@ -126,6 +126,87 @@ where
             quantity: position.quantity_abs,
             kind: OrderKind::Market,
             time_in_force: TimeInForce::ImmediateOrCancel,
+            reduce_only: true,
+            position_side: None,
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_execution::trade::{AssetFees, TradeId};
+    use barter_instrument::asset::QuoteAsset;
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+
+    fn position(side: Side, quantity_abs: Decimal) -> Position<QuoteAsset, InstrumentIndex> {
+        Position {
+            instrument: InstrumentIndex(0),
+            side,
+            price_entry_average: dec!(100),
+            quantity_abs,
+            quantity_abs_max: quantity_abs,
+            pnl_unrealised: Decimal::ZERO,
+            pnl_realised: Decimal::ZERO,
+            fees_enter: AssetFees::default(),
+            fees_exit: AssetFees::default(),
+            time_enter: DateTime::<Utc>::MIN_UTC,
+            time_exchange_update: DateTime::<Utc>::MIN_UTC,
+            trades: vec![TradeId::new("trade")],
+        }
+    }
+
+    #[test]
+    fn test_build_ioc_market_order_to_close_position() {
+        struct TestCase {
+            position: Position<QuoteAsset, InstrumentIndex>,
+            expected: RequestOpen,
+        }
+
+        let cases = vec![
+            // TC0: LONG position is closed by a reduce only sell
+            TestCase {
+                position: position(Side::Buy, dec!(2)),
+                expected: RequestOpen {
+                    side: Side::Sell,
+                    price: dec!(110),
+                    quantity: dec!(2),
+                    kind: OrderKind::Market,
+                    time_in_force: TimeInForce::ImmediateOrCancel,
+                    reduce_only: true,
+                    position_side: None,
+                },
+            },
+            // TC1: SHORT position is closed by a reduce only buy
+            TestCase {
+                position: position(Side::Sell, dec!(0.5)),
+                expected: RequestOpen {
+                    side: Side::Buy,
+                    price: dec!(110),
+                    quantity: dec!(0.5),
+                    kind: OrderKind::Market,
+                    time_in_force: TimeInForce::ImmediateOrCancel,
+                    reduce_only: true,
+                    position_side: None,
+                },
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = build_ioc_market_order_to_close_position(
+                ExchangeIndex(0),
+                &test.position,
+                StrategyId::new("strategy"),
+                dec!(110),
+                || ClientOrderId::new("cid"),
+            );
+            assert_eq!(
+                actual.key.instrument,
+                InstrumentIndex(0),
+                "TC{index} failed"
+            );
+            assert_eq!(actual.state, test.expected, "TC{index} failed");
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use barter_execution::order::{
+    OrderKey, OrderKind, TimeInForce,
+    id::{ClientOrderId, StrategyId},
+    request::{OrderRequestOpen, RequestOpen},
+};
+use barter_instrument::Side;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Configuration of a [`GridStrategy`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct GridConfig {
+    /// Price the grid ladder is centred around.
+    pub reference_price: Decimal,
+
+    /// Price distance between adjacent grid levels.
+    pub spacing: Decimal,
+
+    /// Number of buy levels below, and sell levels above, the `reference_price`.
+    pub levels: usize,
+
+    /// Quantity of each grid order.
+    pub quantity: Decimal,
+}
+
+/// Price level & [`Side`] of a grid limit order.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct GridLevel {
+    pub side: Side,
+    pub price: Decimal,
+}
+
+/// Grid trading strategy scaffold.
+///
+/// Places a ladder of buy limit orders below, and sell limit orders above, the reference price
+/// at the configured spacing. Each time a grid order fills, the opposing order is placed one
+/// level away, realising the spacing as profit on every round trip.
+///
+/// *THIS IS FOR DEMONSTRATION PURPOSES ONLY, NEVER USE FOR REAL TRADING OR IN PRODUCTION*.
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct GridStrategy {
+    pub id: StrategyId,
+    pub config: GridConfig,
+}
+
+impl GridStrategy {
+    /// Construct a new [`GridStrategy`].
+    pub fn new(id: StrategyId, config: GridConfig) -> Self {
+        Self { id, config }
+    }
+
+    /// Returns the initial ladder of [`GridLevel`]s, ordered from nearest to furthest from the
+    /// reference price.
+    pub fn initial_levels(&self) -> Vec<GridLevel> {
+        let GridConfig {
+            reference_price,
+            spacing,
+            levels,
+            ..
+        } = self.config;
+
+        (1..=levels)
+            .flat_map(|level| {
+                let offset = spacing * Decimal::from(level);
+                [
+                    GridLevel {
+                        side: Side::Buy,
+                        price: reference_price - offset,
+                    },
+                    GridLevel {
+                        side: Side::Sell,
+                        price: reference_price + offset,
+                    },
+                ]
+            })
+            .collect()
+    }
+
+    /// Returns the opposing [`GridLevel`] to place after the provided [`GridLevel`] fills.
+    ///
+    /// A filled buy is followed by a sell one level above, and a filled sell by a buy one level
+    /// below.
+    pub fn opposing_level(&self, filled: GridLevel) -> GridLevel {
+        match filled.side {
+            Side::Buy => GridLevel {
+                side: Side::Sell,
+                price: filled.price + self.config.spacing,
+            },
+            Side::Sell => GridLevel {
+                side: Side::Buy,
+                price: filled.price - self.config.spacing,
+            },
+        }
+    }
+
+    /// Build the `GoodUntilCancelled` `Limit` [`OrderRequestOpen`] for the provided
+    /// [`GridLevel`].
+    pub fn order_request<ExchangeKey, InstrumentKey>(
+        &self,
+        exchange: ExchangeKey,
+        instrument: InstrumentKey,
+        level: GridLevel,
+        cid: ClientOrderId,
+    ) -> OrderRequestOpen<ExchangeKey, InstrumentKey> {
+        OrderRequestOpen {
+            key: OrderKey {
+                exchange,
+                instrument,
+                strategy: self.id.clone(),
+                cid,
+            },
+            state: RequestOpen {
+                side: level.side,
+                price: level.price,
+                quantity: self.config.quantity,
+                kind: OrderKind::Limit,
+                time_in_force: TimeInForce::GoodUntilCancelled { post_only: true },
+                reduce_only: false,
+                position_side: None,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn strategy() -> GridStrategy {
+        GridStrategy::new(
+            StrategyId::new("grid"),
+            GridConfig {
+                reference_price: dec!(100),
+                spacing: dec!(1),
+                levels: 2,
+                quantity: dec!(1),
+            },
+        )
+    }
+
+    #[test]
+    fn test_grid_strategy_initial_levels() {
+        let level = |side, price| GridLevel { side, price };
+
+        assert_eq!(
+            strategy().initial_levels(),
+            vec![
+                level(Side::Buy, dec!(99)),
+                level(Side::Sell, dec!(101)),
+                level(Side::Buy, dec!(98)),
+                level(Side::Sell, dec!(102)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_grid_strategy_order_request() {
+        let request = strategy().order_request(
+            "exchange",
+            "btc_usdt",
+            GridLevel {
+                side: Side::Sell,
+                price: dec!(101),
+            },
+            ClientOrderId::new("cid"),
+        );
+
+        assert_eq!(request.state.side, Side::Sell);
+        assert_eq!(request.state.price, dec!(101));
+        assert_eq!(request.state.quantity, dec!(1));
+        assert_eq!(request.state.kind, OrderKind::Limit);
+    }
+
+    #[test]
+    fn test_grid_strategy_realises_profit_from_oscillating_prices() {
+        let strategy = strategy();
+        let quantity = strategy.config.quantity;
+
+        // Simulate resting limit orders filling at their limit price when crossed
+        let mut resting = strategy.initial_levels();
+        let mut cash = Decimal::ZERO;
+        let mut inventory = Decimal::ZERO;
+
+        let prices = [
+            dec!(100),
+            dec!(98),
+            dec!(100),
+            dec!(102),
+            dec!(100),
+            dec!(98),
+            dec!(100),
+        ];
+
+        for price in prices {
+            let (filled, unfilled): (Vec<_>, Vec<_>) =
+                resting.into_iter().partition(|level| match level.side {
+                    Side::Buy => price <= level.price,
+                    Side::Sell => price >= level.price,
+                });
+
+            for level in &filled {
+                match level.side {
+                    Side::Buy => {
+                        cash -= level.price * quantity;
+                        inventory += quantity;
+                    }
+                    Side::Sell => {
+                        cash += level.price * quantity;
+                        inventory -= quantity;
+                    }
+                }
+            }
+
+            resting = unfilled;
+            resting.extend(
+                filled
+                    .into_iter()
+                    .map(|level| strategy.opposing_level(level)),
+            );
+        }
+
+        // Six completed round trips, each realising the spacing
+        assert_eq!(inventory, Decimal::ZERO);
+        assert_eq!(cash, dec!(6));
+    }
+}
@@ -0,0 +1,446 @@
+use crate::engine::state::position::PositionManager;
+use barter_data::books::{OrderBook, aggregator::OrderBookAggregator, map::OrderBookMap};
+use barter_execution::order::{
+    Order, OrderKey, OrderKind, TimeInForce,
+    id::{ClientOrderId, StrategyId},
+    request::{OrderRequestCancel, OrderRequestOpen, RequestCancel, RequestOpen},
+    state::Open,
+};
+use barter_instrument::{Side, exchange::ExchangeId};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Configuration of a [`MarketMaker`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct MarketMakerConfig {
+    /// Absolute price distance between the bid & ask quotes.
+    pub spread: Decimal,
+
+    /// Base quantity of each quote, before being skewed by the book imbalance.
+    pub quote_size: Decimal,
+
+    /// Maximum absolute net inventory the [`MarketMaker`] may accumulate.
+    pub max_inventory: Decimal,
+
+    /// Number of [`OrderBook`] levels of each side used to calculate the book imbalance.
+    pub imbalance_depth: usize,
+
+    /// Fraction of the half-spread the quotes are shifted by at maximum book imbalance.
+    pub imbalance_skew: Decimal,
+
+    /// Fraction of the half-spread the quotes are shifted by at maximum net inventory.
+    pub inventory_skew: Decimal,
+}
+
+/// Price & quantity of a single quote.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct Quote {
+    pub price: Decimal,
+    pub quantity: Decimal,
+}
+
+/// Two-sided quotes generated by a [`MarketMaker`].
+///
+/// A side is `None` if quoting it would breach the maximum inventory.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct Quotes {
+    pub bid: Option<Quote>,
+    pub ask: Option<Quote>,
+}
+
+/// Market making strategy scaffold that quotes both sides around the [`OrderBook`] microprice.
+///
+/// Quote prices and sizes are skewed in the direction of the [`OrderBook::imbalance`]:
+/// - Prices are shifted up when bids dominate, and down when asks dominate.
+/// - The bid size is increased (and ask size decreased) when bids dominate, and vice versa.
+///
+/// Quote prices are also skewed against the current net inventory to mean-revert it towards flat:
+/// - Prices are shifted down when long, encouraging the ask to fill.
+/// - Prices are shifted up when short, encouraging the bid to fill.
+///
+/// Quotes are placed as post-only limit orders, so they always add liquidity.
+///
+/// *THIS IS FOR DEMONSTRATION PURPOSES ONLY, NEVER USE FOR REAL TRADING OR IN PRODUCTION*.
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct MarketMaker {
+    pub id: StrategyId,
+    pub config: MarketMakerConfig,
+}
+
+impl MarketMaker {
+    /// Construct a new [`MarketMaker`].
+    pub fn new(id: StrategyId, config: MarketMakerConfig) -> Self {
+        Self { id, config }
+    }
+
+    /// Generate [`Quotes`] for the provided [`OrderBook`] and current net `inventory`.
+    ///
+    /// Returns `None` if the [`OrderBook`] is empty.
+    pub fn quotes(&self, book: &OrderBook, inventory: Decimal) -> Option<Quotes> {
+        let MarketMakerConfig {
+            spread,
+            quote_size,
+            max_inventory,
+            imbalance_depth,
+            imbalance_skew,
+            inventory_skew,
+        } = self.config;
+
+        let microprice = book.volume_weighed_mid_price()?;
+        let imbalance = book.imbalance(imbalance_depth).unwrap_or_default();
+
+        let half_spread = spread / Decimal::TWO;
+        let inventory_ratio = if max_inventory > Decimal::ZERO {
+            (inventory / max_inventory).clamp(-Decimal::ONE, Decimal::ONE)
+        } else {
+            Decimal::ZERO
+        };
+
+        let fair_price = microprice + imbalance * imbalance_skew * half_spread
+            - inventory_ratio * inventory_skew * half_spread;
+
+        // Cap each side so filling it cannot breach the maximum inventory
+        let bid_quantity = (quote_size * (Decimal::ONE + imbalance)).min(max_inventory - inventory);
+        let ask_quantity = (quote_size * (Decimal::ONE - imbalance)).min(max_inventory + inventory);
+
+        let quote = |price, quantity: Decimal| {
+            (quantity > Decimal::ZERO).then_some(Quote { price, quantity })
+        };
+
+        Some(Quotes {
+            bid: quote(fair_price - half_spread, bid_quantity),
+            ask: quote(fair_price + half_spread, ask_quantity),
+        })
+    }
+
+    /// Generate [`Quotes`] for the provided [`OrderBook`], using the net inventory of the
+    /// engine [`PositionManager`] of the instrument.
+    pub fn quotes_for_position<InstrumentKey>(
+        &self,
+        book: &OrderBook,
+        position: &PositionManager<InstrumentKey>,
+    ) -> Option<Quotes> {
+        self.quotes(book, net_inventory(position))
+    }
+
+    /// Generate [`Quotes`] for the instrument [`OrderBook`] of the provided exchange held by the
+    /// [`OrderBookAggregator`].
+    pub fn quotes_from_aggregator<Map>(
+        &self,
+        aggregator: &OrderBookAggregator<Map>,
+        exchange: ExchangeId,
+        instrument: &Map::Key,
+        inventory: Decimal,
+    ) -> Option<Quotes>
+    where
+        Map: OrderBookMap,
+    {
+        let book = aggregator.exchanges.get(&exchange)?.find(instrument)?;
+        self.quotes(&book.read(), inventory)
+    }
+
+    /// Build cancel requests for every provided open order placed by this [`MarketMaker`], used
+    /// to cancel stale quotes before placing replacements.
+    pub fn cancel_requests<'a, ExchangeKey, InstrumentKey>(
+        &'a self,
+        open_orders: impl IntoIterator<Item = &'a Order<ExchangeKey, InstrumentKey, Open>> + 'a,
+    ) -> impl Iterator<Item = OrderRequestCancel<ExchangeKey, InstrumentKey>> + 'a
+    where
+        ExchangeKey: Clone + 'a,
+        InstrumentKey: Clone + 'a,
+    {
+        open_orders
+            .into_iter()
+            .filter(|order| order.key.strategy == self.id)
+            .map(|order| OrderRequestCancel {
+                key: order.key.clone(),
+                state: RequestCancel::new(Some(order.state.id.clone())),
+            })
+    }
+
+    /// Build the post-only `Limit` [`OrderRequestOpen`]s that place the provided [`Quotes`].
+    pub fn open_requests<ExchangeKey, InstrumentKey>(
+        &self,
+        exchange: ExchangeKey,
+        instrument: InstrumentKey,
+        quotes: Quotes,
+        gen_cid: impl Fn() -> ClientOrderId,
+    ) -> Vec<OrderRequestOpen<ExchangeKey, InstrumentKey>>
+    where
+        ExchangeKey: Clone,
+        InstrumentKey: Clone,
+    {
+        [(Side::Buy, quotes.bid), (Side::Sell, quotes.ask)]
+            .into_iter()
+            .filter_map(|(side, quote)| {
+                let quote = quote?;
+                Some(OrderRequestOpen {
+                    key: OrderKey {
+                        exchange: exchange.clone(),
+                        instrument: instrument.clone(),
+                        strategy: self.id.clone(),
+                        cid: gen_cid(),
+                    },
+                    state: RequestOpen {
+                        side,
+                        price: quote.price,
+                        quantity: quote.quantity,
+                        kind: OrderKind::Limit,
+                        time_in_force: TimeInForce::GoodUntilCancelled { post_only: true },
+                        reduce_only: false,
+                        position_side: None,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+/// Returns the signed net inventory of the [`PositionManager`] current
+/// [`Position`](crate::engine::state::position::Position), positive when long and negative when
+/// short.
+pub fn net_inventory<InstrumentKey>(position: &PositionManager<InstrumentKey>) -> Decimal {
+    position
+        .current
+        .as_ref()
+        .map(|position| match position.side {
+            Side::Buy => position.quantity_abs,
+            Side::Sell => -position.quantity_abs,
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::state::position::Position;
+    use barter_data::books::{Level, map::OrderBookMapSingle};
+    use barter_execution::{
+        order::id::OrderId,
+        trade::{AssetFees, Trade, TradeId},
+    };
+    use chrono::Utc;
+    use fnv::FnvHashMap;
+    use parking_lot::RwLock;
+    use rust_decimal_macros::dec;
+    use std::sync::Arc;
+
+    fn market_maker() -> MarketMaker {
+        MarketMaker::new(
+            StrategyId::new("mm"),
+            MarketMakerConfig {
+                spread: dec!(2),
+                quote_size: dec!(1),
+                max_inventory: dec!(10),
+                imbalance_depth: 5,
+                imbalance_skew: dec!(0.5),
+                inventory_skew: dec!(0.5),
+            },
+        )
+    }
+
+    fn book(bid_amount: Decimal, ask_amount: Decimal) -> OrderBook {
+        OrderBook::new(
+            0,
+            None,
+            vec![Level::new(dec!(99), bid_amount)],
+            vec![Level::new(dec!(101), ask_amount)],
+        )
+    }
+
+    #[test]
+    fn test_market_maker_quotes() {
+        struct TestCase {
+            book: OrderBook,
+            inventory: Decimal,
+            expected: Option<Quotes>,
+        }
+
+        let quote = |price, quantity| Some(Quote { price, quantity });
+
+        let tests = vec![
+            TestCase {
+                // TC0: empty OrderBook
+                book: OrderBook::new(0, None, Vec::<Level>::new(), vec![]),
+                inventory: Decimal::ZERO,
+                expected: None,
+            },
+            TestCase {
+                // TC1: balanced book quotes symmetrically around the mid-price
+                book: book(dec!(5), dec!(5)),
+                inventory: Decimal::ZERO,
+                expected: Some(Quotes {
+                    bid: quote(dec!(99), dec!(1)),
+                    ask: quote(dec!(101), dec!(1)),
+                }),
+            },
+            TestCase {
+                // TC2: bid heavy book (imbalance 0.5) skews quotes up & bid size larger
+                // microprice = (99 * 1 + 101 * 3) / 4 = 100.5, fair = 100.5 + 0.5 * 0.5 * 1
+                book: book(dec!(3), dec!(1)),
+                inventory: Decimal::ZERO,
+                expected: Some(Quotes {
+                    bid: quote(dec!(99.75), dec!(1.5)),
+                    ask: quote(dec!(101.75), dec!(0.5)),
+                }),
+            },
+            TestCase {
+                // TC3: ask heavy book (imbalance -0.5) skews quotes down & ask size larger
+                book: book(dec!(1), dec!(3)),
+                inventory: Decimal::ZERO,
+                expected: Some(Quotes {
+                    bid: quote(dec!(98.25), dec!(0.5)),
+                    ask: quote(dec!(100.25), dec!(1.5)),
+                }),
+            },
+            TestCase {
+                // TC4: inventory at the maximum stops quoting the bid & skews the ask down
+                book: book(dec!(5), dec!(5)),
+                inventory: dec!(10),
+                expected: Some(Quotes {
+                    bid: None,
+                    ask: quote(dec!(100.5), dec!(1)),
+                }),
+            },
+            TestCase {
+                // TC5: bid size capped by the remaining inventory headroom
+                // fair = 100 - (9.75 / 10) * 0.5 * 1
+                book: book(dec!(5), dec!(5)),
+                inventory: dec!(9.75),
+                expected: Some(Quotes {
+                    bid: quote(dec!(98.5125), dec!(0.25)),
+                    ask: quote(dec!(100.5125), dec!(1)),
+                }),
+            },
+            TestCase {
+                // TC6: short inventory skews quotes up
+                // fair = 100 - (-4 / 10) * 0.5 * 1
+                book: book(dec!(5), dec!(5)),
+                inventory: dec!(-4),
+                expected: Some(Quotes {
+                    bid: quote(dec!(99.2), dec!(1)),
+                    ask: quote(dec!(101.2), dec!(1)),
+                }),
+            },
+        ];
+
+        let market_maker = market_maker();
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = market_maker.quotes(&test.book, test.inventory);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_market_maker_quotes_for_position() {
+        let market_maker = market_maker();
+        let book = book(dec!(3), dec!(1));
+
+        let position = |side| PositionManager {
+            current: Some(Position::from(&Trade {
+                id: TradeId::new("trade"),
+                order_id: OrderId::new("order"),
+                instrument: "btc_usdt",
+                strategy: StrategyId::new("mm"),
+                time_exchange: Utc::now(),
+                side,
+                price: dec!(100),
+                quantity: dec!(2),
+                fees: AssetFees::quote_fees(Decimal::ZERO),
+            })),
+        };
+
+        let flat = market_maker
+            .quotes_for_position(&book, &PositionManager::<&str>::default())
+            .unwrap();
+        let long = market_maker
+            .quotes_for_position(&book, &position(Side::Buy))
+            .unwrap();
+        let short = market_maker
+            .quotes_for_position(&book, &position(Side::Sell))
+            .unwrap();
+
+        let prices = |quotes: Quotes| (quotes.bid.unwrap().price, quotes.ask.unwrap().price);
+        let (flat_bid, flat_ask) = prices(flat);
+        let (long_bid, long_ask) = prices(long);
+        let (short_bid, short_ask) = prices(short);
+
+        assert!(long_bid < flat_bid && long_ask < flat_ask);
+        assert!(short_bid > flat_bid && short_ask > flat_ask);
+        assert_eq!(flat_bid - long_bid, dec!(0.1));
+    }
+
+    #[test]
+    fn test_market_maker_quotes_from_aggregator() {
+        let aggregator = OrderBookAggregator::new(FnvHashMap::from_iter([(
+            ExchangeId::BinanceSpot,
+            OrderBookMapSingle::new("btc_usdt", Arc::new(RwLock::new(book(dec!(3), dec!(1))))),
+        )]));
+
+        let market_maker = market_maker();
+        let quotes = market_maker
+            .quotes_from_aggregator(
+                &aggregator,
+                ExchangeId::BinanceSpot,
+                &"btc_usdt",
+                Decimal::ZERO,
+            )
+            .unwrap();
+        assert_eq!(quotes.bid.unwrap().price, dec!(99.75));
+
+        assert!(
+            market_maker
+                .quotes_from_aggregator(&aggregator, ExchangeId::Okx, &"btc_usdt", Decimal::ZERO)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_market_maker_cancel_replace_requests() {
+        let market_maker = market_maker();
+
+        let open = |strategy: &str, cid: &str| Order {
+            key: OrderKey {
+                exchange: ExchangeId::BinanceSpot,
+                instrument: "btc_usdt",
+                strategy: StrategyId::new(strategy),
+                cid: ClientOrderId::new(cid),
+            },
+            side: Side::Buy,
+            price: dec!(99),
+            quantity: dec!(1),
+            kind: OrderKind::Limit,
+            time_in_force: TimeInForce::GoodUntilCancelled { post_only: true },
+            state: Open {
+                id: OrderId::new(cid),
+                time_exchange: Utc::now(),
+                filled_quantity: Decimal::ZERO,
+            },
+        };
+        let open_orders = [open("mm", "1"), open("other", "2"), open("mm", "3")];
+
+        let cancelled = market_maker
+            .cancel_requests(&open_orders)
+            .map(|request| request.key.cid)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            cancelled,
+            vec![ClientOrderId::new("1"), ClientOrderId::new("3")]
+        );
+
+        let quotes = market_maker
+            .quotes(&book(dec!(3), dec!(1)), dec!(10))
+            .unwrap();
+        let requests =
+            market_maker.open_requests(ExchangeId::BinanceSpot, "btc_usdt", quotes, || {
+                ClientOrderId::new("cid")
+            });
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].state.side, Side::Sell);
+        assert_eq!(requests[0].state.price, dec!(101.25));
+        assert_eq!(
+            requests[0].state.time_in_force,
+            TimeInForce::GoodUntilCancelled { post_only: true }
+        );
+    }
+}
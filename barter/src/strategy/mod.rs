@@ -28,10 +28,22 @@ use std::marker::PhantomData;
 /// on the current `EngineState`.
 pub mod algo;
 
+/// Funding rate carry [`CarryStrategy`](carry::CarryStrategy) scaffold that pairs spot &
+/// perpetual positions to collect funding.
+pub mod carry;
+
 /// Defines a strategy interface for generating open and cancel order requests that close open
 /// positions.
 pub mod close_positions;
 
+/// Grid trading [`GridStrategy`](grid::GridStrategy) scaffold that ladders limit orders around a
+/// reference price.
+pub mod grid;
+
+/// Market making [`MarketMaker`](market_maker::MarketMaker) scaffold that quotes both sides
+/// around the microprice, skewed by the order book imbalance.
+pub mod market_maker;
+
 /// Defines a strategy interface enables custom [`Engine`] to be performed in the event of an
 /// exchange disconnection.
 pub mod on_disconnect;
@@ -77,8 +77,8 @@ impl From<InstrumentConfig> for Instrument<ExchangeId, Asset> {
             ),
             name_exchange: value.name_exchange,
             underlying: Underlying {
-                base: Asset::new_from_exchange(value.underlying.base),
-                quote: Asset::new_from_exchange(value.underlying.quote),
+                base: Asset::new_from_exchange_id(value.exchange, value.underlying.base),
+                quote: Asset::new_from_exchange_id(value.exchange, value.underlying.quote),
             },
             quote: value.quote,
             kind: match value.kind {
@@ -86,17 +86,26 @@ impl From<InstrumentConfig> for Instrument<ExchangeId, Asset> {
                 InstrumentKind::Perpetual(contract) => {
                     InstrumentKind::Perpetual(PerpetualContract {
                         contract_size: contract.contract_size,
-                        settlement_asset: Asset::new_from_exchange(contract.settlement_asset),
+                        settlement_asset: Asset::new_from_exchange_id(
+                            value.exchange,
+                            contract.settlement_asset,
+                        ),
                     })
                 }
                 InstrumentKind::Future(contract) => InstrumentKind::Future(FutureContract {
                     contract_size: contract.contract_size,
-                    settlement_asset: Asset::new_from_exchange(contract.settlement_asset),
+                    settlement_asset: Asset::new_from_exchange_id(
+                        value.exchange,
+                        contract.settlement_asset,
+                    ),
                     expiry: contract.expiry,
                 }),
                 InstrumentKind::Option(contract) => InstrumentKind::Option(OptionContract {
                     contract_size: contract.contract_size,
-                    settlement_asset: Asset::new_from_exchange(contract.settlement_asset),
+                    settlement_asset: Asset::new_from_exchange_id(
+                        value.exchange,
+                        contract.settlement_asset,
+                    ),
                     kind: contract.kind,
                     exercise: contract.exercise,
                     expiry: contract.expiry,
@@ -107,9 +116,9 @@ impl From<InstrumentConfig> for Instrument<ExchangeId, Asset> {
                 price: spec.price,
                 quantity: InstrumentSpecQuantity {
                     unit: match spec.quantity.unit {
-                        OrderQuantityUnits::Asset(asset) => {
-                            OrderQuantityUnits::Asset(Asset::new_from_exchange(asset))
-                        }
+                        OrderQuantityUnits::Asset(asset) => OrderQuantityUnits::Asset(
+                            Asset::new_from_exchange_id(value.exchange, asset),
+                        ),
                         OrderQuantityUnits::Contract => OrderQuantityUnits::Contract,
                         OrderQuantityUnits::Quote => OrderQuantityUnits::Quote,
                     },
@@ -121,3 +130,32 @@ impl From<InstrumentConfig> for Instrument<ExchangeId, Asset> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_instrument::asset::name::AssetNameInternal;
+
+    #[test]
+    fn test_instrument_from_instrument_config_normalises_asset_names() {
+        let config = InstrumentConfig {
+            exchange: ExchangeId::Kraken,
+            name_exchange: InstrumentNameExchange::from("XBTUSD"),
+            underlying: Underlying::new("XBT", "ZUSD"),
+            quote: InstrumentQuoteAsset::UnderlyingQuote,
+            kind: InstrumentKind::Spot,
+            spec: None,
+        };
+
+        let instrument = Instrument::from(config);
+
+        assert_eq!(
+            instrument.underlying.base,
+            Asset::new(AssetNameInternal::from("btc"), "XBT")
+        );
+        assert_eq!(
+            instrument.underlying.quote,
+            Asset::new(AssetNameInternal::from("usd"), "ZUSD")
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use barter::{
     EngineEvent, Sequence, Timed,
     engine::{
-        Engine, EngineOutput,
+        Engine, EngineCounters, EngineOutput,
         action::{
             ActionOutput,
             generate_algo_orders::GenerateAlgoOrdersOutput,
@@ -27,6 +27,7 @@ use barter::{
     },
     execution::{AccountStreamEvent, request::ExecutionRequest},
     risk::DefaultRiskManager,
+    statistic::time::Daily,
     strategy::{
         algo::AlgoStrategy,
         close_positions::{ClosePositionsStrategy, close_open_positions_with_market_orders},
@@ -34,7 +35,6 @@ use barter::{
         on_trading_disabled::OnTradingDisabled,
     },
     test_utils::time_plus_days,
-    statistic::time::Daily,
 };
 use barter_data::{
     event::{DataKind, MarketEvent},
@@ -136,6 +136,8 @@ fn test_engine_process_engine_event_with_audit() {
             time_in_force: TimeInForce::ImmediateOrCancel,
             price: dec!(10_000),
             quantity: dec!(1),
+            reduce_only: false,
+            position_side: None,
         },
     };
     let eth_btc_buy_order = OrderRequestOpen {
@@ -151,6 +153,8 @@ fn test_engine_process_engine_event_with_audit() {
             time_in_force: TimeInForce::ImmediateOrCancel,
             price: dec!(0.1),
             quantity: dec!(1),
+            reduce_only: false,
+            position_side: None,
         },
     };
     assert_eq!(
@@ -337,6 +341,8 @@ fn test_engine_process_engine_event_with_audit() {
             time_in_force: TimeInForce::ImmediateOrCancel,
             price: dec!(20_000),
             quantity: dec!(1),
+            reduce_only: true,
+            position_side: None,
         },
     };
     assert_eq!(
@@ -473,6 +479,8 @@ fn test_engine_process_engine_event_with_audit() {
             time_in_force: TimeInForce::GoodUntilCancelled { post_only: true },
             price: dec!(0.05),
             quantity: dec!(1),
+            reduce_only: false,
+            position_side: None,
         },
     };
     let event = EngineEvent::Command(Command::SendOpenRequests(OneOrMany::One(
@@ -631,6 +639,17 @@ fn test_engine_process_engine_event_with_audit() {
         )
     );
 
+    // Ensure EngineCounters match the processed event & order sequence
+    assert_eq!(
+        engine.meta.counters,
+        EngineCounters {
+            events_processed: 27,
+            orders_generated: 2,
+            orders_filled: 4,
+            orders_rejected: 0,
+        }
+    );
+
     // End trading session and produce TradingSummaryGenerator
     let mut summary = engine.trading_summary_generator(RISK_FREE_RETURN);
     summary.update_time_now(time_plus_days(STARTING_TIMESTAMP, 5));
@@ -705,6 +724,8 @@ impl AlgoStrategy for TestBuyAndHoldStrategy {
                         time_in_force: TimeInForce::ImmediateOrCancel,
                         price,
                         quantity: dec!(1),
+                        reduce_only: false,
+                        position_side: None,
                     },
                 })
             });
@@ -964,6 +985,13 @@ fn account_event_trade(
     price: f64,
     quantity: f64,
 ) -> EngineEvent<DataKind> {
+    // Fees are charged in the quote asset: btc_usdt -> AssetIndex(2)/usdt, eth_btc -> AssetIndex(0)/btc
+    let quote = match instrument {
+        0 => AssetIndex(2),
+        1 => AssetIndex(0),
+        _ => panic!("unexpected instrument: {instrument}"),
+    };
+
     EngineEvent::Account(AccountStreamEvent::Item(AccountEvent {
         exchange: ExchangeIndex(0),
         kind: AccountEventKind::Trade(Trade {
@@ -975,7 +1003,8 @@ fn account_event_trade(
             side,
             price: Decimal::try_from(price).unwrap(),
             quantity: Decimal::try_from(quantity).unwrap(),
-            fees: AssetFees::quote_fees(
+            fees: AssetFees::new(
+                quote,
                 Decimal::try_from(price * quantity * QUOTE_FEES_PERCENT).unwrap(),
             ),
         }),
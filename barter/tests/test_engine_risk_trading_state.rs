@@ -0,0 +1,265 @@
+use barter::{
+    EngineEvent,
+    engine::{
+        Engine, EngineOutput, Processor,
+        action::generate_algo_orders::GenerateAlgoOrdersOutput,
+        audit::EngineAudit,
+        clock::HistoricalClock,
+        execution_tx::MultiExchangeTxMap,
+        state::{
+            EngineState,
+            global::DefaultGlobalData,
+            instrument::{data::DefaultInstrumentMarketData, filter::InstrumentFilter},
+            trading::TradingState,
+        },
+    },
+    execution::{AccountStreamEvent, request::ExecutionRequest},
+    risk::{DefaultRiskManager, circuit_breaker::DrawdownCircuitBreaker},
+    strategy::{
+        DefaultStrategy,
+        algo::AlgoStrategy,
+        close_positions::{ClosePositionsStrategy, close_open_positions_with_market_orders},
+        on_disconnect::OnDisconnectStrategy,
+        on_trading_disabled::OnTradingDisabled,
+    },
+    test_utils::{order_request_open, position},
+};
+use barter_data::event::DataKind;
+use barter_execution::{
+    AccountEvent, AccountEventKind,
+    balance::{AssetBalance, Balance},
+    order::{
+        id::ClientOrderId,
+        request::{OrderRequestCancel, OrderRequestOpen},
+    },
+};
+use barter_instrument::{
+    Side,
+    asset::{AssetIndex, ExchangeAsset, name::AssetNameInternal},
+    exchange::{ExchangeId, ExchangeIndex},
+    index::IndexedInstruments,
+    instrument::InstrumentIndex,
+    test_utils::instrument,
+};
+use barter_integration::{
+    channel::{UnboundedRx, UnboundedTx, mpsc_unbounded},
+    snapshot::Snapshot,
+};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+const STARTING_TIMESTAMP: DateTime<Utc> = DateTime::<Utc>::MIN_UTC;
+
+type State = EngineState<DefaultGlobalData, DefaultInstrumentMarketData>;
+
+type Risk = DrawdownCircuitBreaker<DefaultRiskManager<State>, fn(&State) -> Option<Decimal>>;
+
+type ExecutionTxs = MultiExchangeTxMap<UnboundedTx<ExecutionRequest>>;
+
+type TestEngine = Engine<HistoricalClock, State, ExecutionTxs, TestOpenAndReduceStrategy, Risk>;
+
+/// Strategy that generates a position increasing `Side::Buy` and a position reducing `Side::Sell`
+/// btc_usdt order for a long position on every [`AlgoStrategy::generate_algo_orders`].
+#[derive(Debug, Default)]
+struct TestOpenAndReduceStrategy {
+    inner: DefaultStrategy<State>,
+}
+
+impl AlgoStrategy for TestOpenAndReduceStrategy {
+    type State = State;
+
+    fn generate_algo_orders(
+        &self,
+        _: &Self::State,
+    ) -> (
+        impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>>,
+        impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+    ) {
+        let mut buy = order_request_open(0, Side::Buy, 100.0, 1.0);
+        buy.key.cid = ClientOrderId::new("buy");
+
+        let mut sell = order_request_open(0, Side::Sell, 100.0, 1.0);
+        sell.key.cid = ClientOrderId::new("sell");
+
+        (std::iter::empty(), [buy, sell])
+    }
+}
+
+impl ClosePositionsStrategy for TestOpenAndReduceStrategy {
+    type State = State;
+
+    fn close_positions_requests<'a>(
+        &'a self,
+        state: &'a Self::State,
+        filter: &'a InstrumentFilter,
+    ) -> (
+        impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>> + 'a,
+        impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>> + 'a,
+    )
+    where
+        ExchangeIndex: 'a,
+        AssetIndex: 'a,
+        InstrumentIndex: 'a,
+    {
+        close_open_positions_with_market_orders(&self.inner.id, state, filter, |_| {
+            ClientOrderId::new("close")
+        })
+    }
+}
+
+impl OnDisconnectStrategy<HistoricalClock, State, ExecutionTxs, Risk>
+    for TestOpenAndReduceStrategy
+{
+    type OnDisconnect = ();
+
+    fn on_disconnect(_: &mut TestEngine, _: ExchangeId) -> Self::OnDisconnect {}
+}
+
+impl OnTradingDisabled<HistoricalClock, State, ExecutionTxs, Risk> for TestOpenAndReduceStrategy {
+    type OnTradingDisabled = ();
+
+    fn on_trading_disabled(_: &mut TestEngine) -> Self::OnTradingDisabled {}
+}
+
+fn equity_usdt(state: &State) -> Option<Decimal> {
+    state
+        .assets
+        .asset(&ExchangeAsset::new(ExchangeId::BinanceSpot, "usdt"))
+        .balance
+        .map(|balance| balance.value.total)
+}
+
+fn build_engine(instruments: &IndexedInstruments) -> (TestEngine, UnboundedRx<ExecutionRequest>) {
+    let (execution_tx, execution_rx) = mpsc_unbounded();
+
+    let state = EngineState::builder(
+        instruments,
+        DefaultGlobalData,
+        DefaultInstrumentMarketData::default,
+    )
+    .time_engine_start(STARTING_TIMESTAMP)
+    .trading_state(TradingState::Enabled)
+    .balances([(
+        ExchangeId::BinanceSpot,
+        "usdt",
+        Balance::new(dec!(1000), dec!(1000)),
+    )])
+    .build();
+
+    let engine = Engine::new(
+        HistoricalClock::new(STARTING_TIMESTAMP),
+        state,
+        MultiExchangeTxMap::from_iter([(ExchangeId::BinanceSpot, Some(execution_tx))]),
+        TestOpenAndReduceStrategy::default(),
+        DrawdownCircuitBreaker::new(
+            DefaultRiskManager::default(),
+            dec!(0.2),
+            dec!(0.05),
+            equity_usdt as fn(&State) -> Option<Decimal>,
+        ),
+    );
+
+    (engine, execution_rx)
+}
+
+fn account_event_balance_usdt(
+    instruments: &IndexedInstruments,
+    total: Decimal,
+) -> EngineEvent<DataKind> {
+    let usdt = instruments
+        .find_asset_index(ExchangeId::BinanceSpot, &AssetNameInternal::from("usdt"))
+        .unwrap();
+
+    EngineEvent::Account(AccountStreamEvent::Item(AccountEvent {
+        exchange: ExchangeIndex(0),
+        kind: AccountEventKind::BalanceSnapshot(Snapshot(AssetBalance {
+            asset: usdt,
+            balance: Balance::new(total, total),
+            time_exchange: STARTING_TIMESTAMP,
+        })),
+    }))
+}
+
+/// Returns the `(sent, refused)` algo order [`ClientOrderId`]s of the processed event.
+fn algo_orders(
+    audit: EngineAudit<EngineEvent<DataKind>, EngineOutput<(), ()>>,
+) -> (Vec<ClientOrderId>, Vec<ClientOrderId>) {
+    let EngineAudit::Process(audit) = audit else {
+        panic!("expected EngineAudit::Process, got: {audit:?}");
+    };
+
+    audit
+        .outputs
+        .into_iter()
+        .find_map(|output| match output {
+            EngineOutput::AlgoOrders(GenerateAlgoOrdersOutput {
+                cancels_and_opens,
+                opens_refused,
+                ..
+            }) => Some((
+                cancels_and_opens
+                    .opens
+                    .sent
+                    .into_iter()
+                    .map(|open| open.key.cid)
+                    .collect(),
+                opens_refused
+                    .into_iter()
+                    .map(|refused| refused.item.key.cid)
+                    .collect(),
+            )),
+            _ => None,
+        })
+        .expect("expected EngineOutput::AlgoOrders")
+}
+
+#[test]
+fn test_engine_drawdown_circuit_breaker_trips_allows_reducing_orders_and_resets() {
+    let instruments = IndexedInstruments::new([instrument(ExchangeId::BinanceSpot, "btc", "usdt")]);
+    let (mut engine, _execution_rx) = build_engine(&instruments);
+
+    // Open long btc_usdt Position, so Side::Sell orders are position reducing
+    engine
+        .state
+        .instruments
+        .instrument_index_mut(&InstrumentIndex(0))
+        .position
+        .current = Some(position(0, Side::Buy, 100.0, 2.0));
+
+    let buy = ClientOrderId::new("buy");
+    let sell = ClientOrderId::new("sell");
+
+    // Equity at peak, so all algo orders are approved
+    let audit = engine.process(account_event_balance_usdt(&instruments, dec!(1000)));
+    assert_eq!(
+        algo_orders(audit),
+        (vec![buy.clone(), sell.clone()], vec![])
+    );
+    assert_eq!(engine.risk.state().equity_peak, Some(dec!(1000)));
+
+    // Equity declines within max_drawdown, so all algo orders are approved
+    let audit = engine.process(account_event_balance_usdt(&instruments, dec!(850)));
+    assert_eq!(
+        algo_orders(audit),
+        (vec![buy.clone(), sell.clone()], vec![])
+    );
+    assert!(!engine.risk.is_tripped());
+
+    // Equity declines beyond max_drawdown, so circuit breaker trips & only the close is approved
+    let audit = engine.process(account_event_balance_usdt(&instruments, dec!(750)));
+    assert_eq!(algo_orders(audit), (vec![sell.clone()], vec![buy.clone()]));
+    assert!(engine.risk.is_tripped());
+    assert_eq!(engine.state.trading, TradingState::Enabled);
+
+    // Equity recovers, but not within reset_drawdown, so circuit breaker remains tripped
+    let audit = engine.process(account_event_balance_usdt(&instruments, dec!(900)));
+    assert_eq!(algo_orders(audit), (vec![sell.clone()], vec![buy.clone()]));
+    assert!(engine.risk.is_tripped());
+
+    // Equity recovers within reset_drawdown, so circuit breaker resets & all orders are approved
+    let audit = engine.process(account_event_balance_usdt(&instruments, dec!(960)));
+    assert_eq!(algo_orders(audit), (vec![buy, sell], vec![]));
+    assert!(!engine.risk.is_tripped());
+    assert_eq!(engine.state.trading, TradingState::Enabled);
+}
@@ -0,0 +1,186 @@
+use barter::engine::state::{
+    EngineState, global::DefaultGlobalData, instrument::data::DefaultInstrumentMarketData,
+    position::Position,
+};
+use barter_execution::{
+    AccountEventKind, UnindexedAccountSnapshot,
+    balance::{AssetBalance, Balance},
+    client::{
+        ExecutionClient,
+        mock::{MockExecution, MockExecutionClientConfig, MockExecutionConfig},
+    },
+    exchange::mock::{MockExchange, fees::FeeAsset},
+    indexer::AccountEventIndexer,
+    map::generate_execution_instrument_map,
+    order::{
+        OrderKey, OrderKind, TimeInForce,
+        id::{ClientOrderId, StrategyId},
+        request::{OrderRequestOpen, RequestOpen},
+    },
+    trade::AssetFees,
+};
+use barter_instrument::{
+    Side,
+    asset::{ExchangeAsset, name::AssetNameInternal},
+    exchange::ExchangeId,
+    index::IndexedInstruments,
+    instrument::InstrumentIndex,
+    test_utils::instrument as test_instrument,
+};
+use chrono::{DateTime, Utc};
+use fnv::FnvHashMap;
+use futures::StreamExt;
+use rust_decimal_macros::dec;
+use std::sync::Arc;
+use tokio::sync::{broadcast, mpsc};
+
+const STARTING_TIMESTAMP: DateTime<Utc> = DateTime::<Utc>::MIN_UTC;
+const STARTING_BALANCE_USDT: Balance = Balance {
+    total: dec!(10_000),
+    free: dec!(10_000),
+};
+
+#[tokio::test]
+async fn test_engine_state_reconciles_mock_execution_account_events() {
+    let exchange = ExchangeId::Mock;
+    let instrument = test_instrument(exchange, "btc", "usdt");
+    let instruments = IndexedInstruments::builder()
+        .add_instrument(instrument.clone())
+        .build();
+
+    let mut state = EngineState::builder(
+        &instruments,
+        DefaultGlobalData,
+        DefaultInstrumentMarketData::default,
+    )
+    .time_engine_start(STARTING_TIMESTAMP)
+    .balances([(exchange, "usdt", STARTING_BALANCE_USDT)])
+    .build();
+
+    // Run MockExchange with a matching initial account state
+    let mock_instrument = instrument
+        .map_asset_key_with_lookup(|asset| Ok::<_, ()>(asset.name_exchange.clone()))
+        .unwrap();
+    let instrument_name = mock_instrument.name_exchange.clone();
+
+    let (request_tx, request_rx) = mpsc::unbounded_channel();
+    let (event_tx, event_rx) = broadcast::channel(16);
+
+    let mock_exchange = MockExchange::new(
+        MockExecutionConfig {
+            mocked_exchange: exchange,
+            initial_state: UnindexedAccountSnapshot {
+                exchange,
+                balances: [
+                    ("usdt", STARTING_BALANCE_USDT),
+                    ("btc", Balance::new(dec!(0), dec!(0))),
+                ]
+                .into_iter()
+                .map(|(asset, balance)| AssetBalance {
+                    asset: asset.into(),
+                    balance,
+                    time_exchange: STARTING_TIMESTAMP,
+                })
+                .collect(),
+                instruments: vec![],
+            },
+            latency_ms: 0,
+            fees_percent: dec!(0.001),
+            fee_schedule: None,
+            fee_asset: FeeAsset::Quote,
+        },
+        request_rx,
+        event_tx,
+        FnvHashMap::from_iter([(instrument_name.clone(), mock_instrument)]),
+    );
+    tokio::spawn(mock_exchange.run());
+
+    let client = <MockExecution<_> as ExecutionClient>::new(MockExecutionClientConfig {
+        mocked_exchange: exchange,
+        clock: Utc::now,
+        request_tx,
+        event_rx,
+    });
+    let mut account_stream = client.account_stream(&[], &[]).await.unwrap();
+
+    // Submit a market buy order for 2 btc @ 100 usdt
+    let response = client
+        .open_order(OrderRequestOpen {
+            key: OrderKey {
+                exchange,
+                instrument: &instrument_name,
+                strategy: StrategyId::new("strategy"),
+                cid: ClientOrderId::new("cid"),
+            },
+            state: RequestOpen {
+                side: Side::Buy,
+                kind: OrderKind::Market,
+                time_in_force: TimeInForce::ImmediateOrCancel,
+                price: dec!(100),
+                quantity: dec!(2),
+                reduce_only: false,
+                position_side: None,
+            },
+        })
+        .await;
+    assert_eq!(response.state.unwrap().filled_quantity, dec!(2));
+
+    // Apply the quote & base BalanceSnapshot and Trade AccountEvents to the EngineState
+    let indexer = AccountEventIndexer::new(Arc::new(
+        generate_execution_instrument_map(&instruments, exchange).unwrap(),
+    ));
+
+    let (mut balances_applied, mut trade_applied) = (0, false);
+    for _ in 0..3 {
+        let event = indexer
+            .account_event(account_stream.next().await.unwrap())
+            .unwrap();
+
+        match &event.kind {
+            AccountEventKind::BalanceSnapshot(_) => balances_applied += 1,
+            AccountEventKind::Trade(_) => trade_applied = true,
+            kind => panic!("unexpected AccountEventKind: {kind:?}"),
+        }
+
+        assert!(state.update_from_account(&event).is_none());
+    }
+    assert!(balances_applied == 2 && trade_applied);
+
+    // Quote balance is debited by the order value plus fees: 10_000 - (200 + 0.2)
+    let usdt = state.assets.asset(&ExchangeAsset::new(
+        exchange,
+        AssetNameInternal::from("usdt"),
+    ));
+    assert_eq!(
+        usdt.balance.unwrap().value,
+        Balance::new(dec!(9_799.8), dec!(9_799.8))
+    );
+
+    // Base balance is credited by the fill quantity
+    let btc = state.assets.asset(&ExchangeAsset::new(
+        exchange,
+        AssetNameInternal::from("btc"),
+    ));
+    assert_eq!(btc.balance.unwrap().value, Balance::new(dec!(2), dec!(2)));
+
+    // Position is opened with the fill quantity, price & fees
+    let position = state
+        .instruments
+        .instrument_index(&InstrumentIndex(0))
+        .position
+        .current
+        .clone()
+        .unwrap();
+
+    let Position {
+        side,
+        price_entry_average,
+        quantity_abs,
+        fees_enter,
+        ..
+    } = position;
+    assert_eq!(side, Side::Buy);
+    assert_eq!(price_entry_average, dec!(100));
+    assert_eq!(quantity_abs, dec!(2));
+    assert_eq!(fees_enter, AssetFees::quote_fees(dec!(0.2)));
+}